@@ -14,7 +14,8 @@ use icu_locale_core::preferences::extensions::unicode::keywords::CalendarAlgorit
 use ixdtf::ParseError as Rfc9557Error;
 use ixdtf::encoding::Utf8;
 use ixdtf::parsers::IxdtfParser;
-use ixdtf::records::IxdtfParseRecord;
+use ixdtf::records::{DateRecord, IxdtfParseRecord};
+use writeable::Writeable;
 
 /// An error returned from parsing an RFC 9557 string to an `icu::calendar` type.
 #[derive(Debug, displaydoc::Display)]
@@ -171,6 +172,83 @@ impl<A: AsCalendar> Date<A> {
     }
 }
 
+impl<A: AsCalendar> Date<A> {
+    /// Returns the RFC 9557 representation of this [`Date`].
+    ///
+    /// The date is written in the ISO calendar, followed by a calendar annotation unless the
+    /// calendar is [`Iso`]. The result can be parsed with [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::calendar::{Date, Iso};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let date = Date::try_new_iso(2025, 3, 1).unwrap();
+    /// assert_writeable_eq!(date.to_ixdtf(), "2025-03-01");
+    ///
+    /// let date = date.to_calendar(Hebrew);
+    /// assert_writeable_eq!(date.to_ixdtf(), "2025-03-01[u-ca=hebrew]");
+    /// assert_eq!(
+    ///     Date::try_from_str(&date.to_ixdtf().to_string(), Hebrew).unwrap(),
+    ///     date
+    /// );
+    /// ```
+    pub fn to_ixdtf(&self) -> impl Writeable + core::fmt::Display {
+        self.to_ixdtf_record()
+    }
+
+    fn to_ixdtf_record(&self) -> IxdtfParseRecord<'static, Utf8> {
+        let iso = self.to_calendar(Iso);
+        let mut record = IxdtfParseRecord::default();
+        record.date = Some(DateRecord {
+            year: iso.year().extended_year(),
+            month: iso.month().ordinal,
+            day: iso.day_of_month().0,
+        });
+        record.calendar = self
+            .calendar()
+            .calendar_algorithm()
+            .filter(|&c| c != CalendarAlgorithm::Iso8601)
+            .map(|c| c.as_str().as_bytes());
+        record
+    }
+}
+
+#[test]
+fn round_trip() {
+    use crate::{AnyCalendar, AnyCalendarKind};
+
+    let iso = Date::try_new_iso(-2000, 12, 31).unwrap();
+    writeable::assert_writeable_eq!(iso.to_ixdtf(), "-002000-12-31");
+    assert_eq!(Date::try_from_str("-002000-12-31", Iso).unwrap(), iso);
+
+    for kind in [
+        AnyCalendarKind::Buddhist,
+        AnyCalendarKind::Chinese,
+        AnyCalendarKind::Ethiopian,
+        AnyCalendarKind::Gregorian,
+        AnyCalendarKind::Hebrew,
+        AnyCalendarKind::HijriUmmAlQura,
+        AnyCalendarKind::Iso,
+        AnyCalendarKind::Japanese,
+        AnyCalendarKind::Persian,
+    ] {
+        let date = Date::try_new_iso(2025, 3, 1)
+            .unwrap()
+            .to_calendar(AnyCalendar::new(kind));
+        let string = date.to_ixdtf().to_string();
+        assert_eq!(
+            Date::try_from_str(&string, AnyCalendar::new(kind)).unwrap(),
+            date,
+            "{string}"
+        );
+    }
+}
+
 #[test]
 fn invalid_calendar() {
     Date::try_from_str("2025-01-01T00:00:00[u-ca=foo]", crate::Gregorian).unwrap_err();
@@ -89,7 +89,7 @@ pub(crate) struct ExternalLoaderUnstable<'a, P: ?Sized>(pub &'a P);
 impl<P> DecimalFormatterLoader for ExternalLoaderUnstable<'_, P>
 where
     P: ?Sized
-        + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+        + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
         + DataProvider<icu_decimal::provider::DecimalDigitsV1>,
 {
     #[inline]
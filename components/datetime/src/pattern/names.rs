@@ -25,7 +25,7 @@ use icu_calendar::types::{EraYear, LeapStatus, MonthInfo};
 use icu_decimal::DecimalFormatter;
 use icu_decimal::options::DecimalFormatterOptions;
 use icu_decimal::options::GroupingStrategy;
-use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV2};
 use icu_pattern::SinglePlaceholderPattern;
 use icu_provider::prelude::*;
 
@@ -836,7 +836,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         prefs: DateTimeFormatterPreferences,
     ) -> Result<Self, DataError>
     where
-        P: DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1> + ?Sized,
+        P: DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1> + ?Sized,
     {
         let mut names = Self {
             prefs,
@@ -2211,7 +2211,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         provider: &P,
     ) -> Result<&mut Self, PatternLoadError>
     where
-        P: DataProvider<DecimalSymbolsV1>
+        P: DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<tz::EssentialsV1>
             + DataProvider<tz::LocationsOverrideV1>
@@ -2268,7 +2268,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         provider: &P,
     ) -> Result<&mut Self, PatternLoadError>
     where
-        P: DataProvider<DecimalSymbolsV1>
+        P: DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<tz::EssentialsV1>
             + DataProvider<tz::LocationsOverrideV1>
@@ -2329,7 +2329,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         provider: &P,
     ) -> Result<&mut Self, PatternLoadError>
     where
-        P: DataProvider<DecimalSymbolsV1>
+        P: DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<tz::EssentialsV1>
             + DataProvider<tz::LocationsOverrideV1>
@@ -2384,7 +2384,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         provider: &P,
     ) -> Result<&mut Self, PatternLoadError>
     where
-        P: DataProvider<DecimalSymbolsV1>
+        P: DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<tz::EssentialsV1>
             + DataProvider<tz::LocationsOverrideV1>
@@ -2447,7 +2447,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
         provider: &P,
     ) -> Result<&mut Self, PatternLoadError>
     where
-        P: DataProvider<DecimalSymbolsV1>
+        P: DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<tz::EssentialsV1>
             + ?Sized,
@@ -2487,7 +2487,7 @@ impl<C, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, FSet> {
     #[inline]
     pub fn load_decimal_formatter<P>(&mut self, provider: &P) -> Result<&mut Self, DataError>
     where
-        P: DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1> + ?Sized,
+        P: DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1> + ?Sized,
     {
         self.inner
             .load_decimal_formatter(&ExternalLoaderUnstable(provider), self.prefs)?;
@@ -2573,7 +2573,7 @@ impl<C: CldrCalendar, FSet: DateTimeNamesMarker> FixedCalendarDateTimeNames<C, F
             + DataProvider<tz::MzSpecificLongV1>
             + DataProvider<tz::MzSpecificShortV1>
             + DataProvider<tz::MzPeriodV1>
-            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + ?Sized,
     {
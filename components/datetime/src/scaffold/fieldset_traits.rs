@@ -11,7 +11,7 @@ use icu_calendar::{
     provider::{CalendarJapaneseModernV1, CalendarPreferredV1},
     types::{DayOfMonth, DayOfYear, MonthInfo, RataDie, Weekday, YearInfo},
 };
-use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV2};
 use icu_provider::{marker::NeverMarker, prelude::*};
 use icu_time::{Hour, Minute, Nanosecond, Second, TimeZone, zone::UtcOffset};
 use icu_time::{scaffold::IntoOption, zone::ZoneNameTimestamp};
@@ -489,12 +489,12 @@ where
 /// for datetime formatting with a fixed calendar.
 // This trait is implicitly sealed due to sealed supertraits
 pub trait AllFixedCalendarExternalDataMarkers:
-    DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+    DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1>
 {
 }
 
 impl<T> AllFixedCalendarExternalDataMarkers for T where
-    T: ?Sized + DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+    T: ?Sized + DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1>
 {
 }
 
@@ -503,7 +503,7 @@ impl<T> AllFixedCalendarExternalDataMarkers for T where
 // This trait is implicitly sealed due to sealed supertraits
 pub trait AllAnyCalendarExternalDataMarkers:
     DataProvider<CalendarJapaneseModernV1>
-    + DataProvider<DecimalSymbolsV1>
+    + DataProvider<DecimalSymbolsV2>
     + DataProvider<DecimalDigitsV1>
     + DataProvider<CalendarPreferredV1>
 {
@@ -512,7 +512,7 @@ pub trait AllAnyCalendarExternalDataMarkers:
 impl<T> AllAnyCalendarExternalDataMarkers for T where
     T: ?Sized
         + DataProvider<CalendarJapaneseModernV1>
-        + DataProvider<DecimalSymbolsV1>
+        + DataProvider<DecimalSymbolsV2>
         + DataProvider<DecimalDigitsV1>
         + DataProvider<CalendarPreferredV1>
 {
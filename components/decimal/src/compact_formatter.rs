@@ -142,7 +142,7 @@ impl CompactDecimalFormatter {
     ) -> Result<Self, DataError>
     where
         D: DataProvider<DecimalCompactShortV1>
-            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>
             + ?Sized,
@@ -227,7 +227,7 @@ impl CompactDecimalFormatter {
     ) -> Result<Self, DataError>
     where
         D: DataProvider<DecimalCompactLongV1>
-            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>
             + ?Sized,
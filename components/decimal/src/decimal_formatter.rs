@@ -15,7 +15,7 @@ use crate::provider::*;
 use crate::size_test_macro::size_test;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-use fixed_decimal::{Sign, UnsignedDecimal};
+use fixed_decimal::{Sign, SignedRoundingMode, UnsignedDecimal, UnsignedRoundingMode};
 use icu_provider::prelude::*;
 use writeable::PartsWrite;
 use writeable::Writeable;
@@ -29,13 +29,15 @@ size_test!(DecimalFormatter, decimal_formatter_size, 96);
 /// 1. Rendering in the local numbering system
 /// 2. Locale-sensitive grouping separator positions
 /// 3. Locale-sensitive plus and minus signs
+/// 4. Scientific and engineering notation, and rounding to fraction or significant digits,
+///    as configured in [`DecimalFormatterOptions`]
 ///
 /// See the crate-level documentation for examples.
 #[doc = decimal_formatter_size!()]
 #[derive(Debug, Clone)]
 pub struct DecimalFormatter {
-    options: DecimalFormatterOptions,
-    symbols: DataPayload<DecimalSymbolsV1>,
+    options: PackedOptions,
+    symbols: DataPayload<DecimalSymbolsV2>,
     digits: DataPayload<DecimalDigitsV1>,
 }
 
@@ -53,7 +55,7 @@ impl DecimalFormatter {
 
     #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
     pub fn try_new_unstable<
-        D: DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1> + ?Sized,
+        D: DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1> + ?Sized,
     >(
         provider: &D,
         prefs: DecimalFormatterPreferences,
@@ -79,10 +81,10 @@ impl DecimalFormatter {
         // | en-u-nu-wxyz | latn    | latn   | latn                                 |
         // | th-u-nu-wxyz | thai    | thai   | thai                                 |
 
-        let locale = DecimalSymbolsV1::make_locale(prefs.locale_preferences);
+        let locale = DecimalSymbolsV2::make_locale(prefs.locale_preferences);
 
         // Load symbols for the locale/numsys pair provided
-        let symbols = load_with_fallback::<DecimalSymbolsV1>(
+        let symbols = load_with_fallback::<DecimalSymbolsV2>(
             provider,
             // fall back to the locale
             prefs
@@ -104,18 +106,144 @@ impl DecimalFormatter {
         .payload;
 
         Ok(Self {
-            options,
+            options: PackedOptions::new(options),
             symbols,
             digits,
         })
     }
 
     /// Formats a [`Decimal`], returning a [`FormattedDecimal`].
+    ///
+    /// The value is rounded, padded and given an exponent as configured in the
+    /// [`DecimalFormatterOptions`].
     pub fn format<'l>(&'l self, value: &'l Decimal) -> FormattedDecimal<'l> {
-        FormattedDecimal(self.format_sign(
-            value.sign,
-            self.format_unsigned(Cow::Borrowed(&value.absolute)),
-        ))
+        let options = self.options.get();
+        if !options.modifies_value() {
+            return FormattedDecimal(self.format_sign(
+                value.sign,
+                self.format_unsigned(Cow::Borrowed(&value.absolute)),
+            ));
+        }
+        let (value, exponent) = Self::apply_options(&options, value);
+        let mut unsigned = self.format_unsigned(Cow::Owned(value.absolute));
+        unsigned.exponent = exponent;
+        FormattedDecimal(self.format_sign(value.sign, unsigned))
+    }
+
+    /// Applies the notation, digit and sign display options to a value, returning the
+    /// resulting significand and exponent.
+    fn apply_options(options: &DecimalFormatterOptions, value: &Decimal) -> (Decimal, Option<i16>) {
+        let mut result = value.clone();
+        let exponent = match options.notation.unwrap_or_default() {
+            Notation::Standard => {
+                Self::round(options, &mut result);
+                if let Some(max) = options.maximum_integer_digits {
+                    result.absolute.set_max_position(i16::from(max));
+                }
+                if let Some(min) = options.minimum_integer_digits {
+                    result.absolute.pad_start(i16::from(min));
+                }
+                None
+            }
+            notation => {
+                let exponent_for = |magnitude: i16| match notation {
+                    Notation::Engineering => magnitude.div_euclid(3) * 3,
+                    _ => magnitude,
+                };
+                let mut exponent = exponent_for(value.absolute.nonzero_magnitude_start());
+                result.absolute.multiply_pow10(-exponent);
+                result.absolute.trim_start();
+                Self::round(options, &mut result);
+                // Rounding can carry into a new digit, such as 9.99 to 10.0, in which case
+                // the exponent needs to be recomputed and the value rounded again.
+                let rounded_exponent =
+                    exponent_for(result.absolute.nonzero_magnitude_start() + exponent);
+                if rounded_exponent != exponent {
+                    exponent = rounded_exponent;
+                    result = value.clone();
+                    result.absolute.multiply_pow10(-exponent);
+                    result.absolute.trim_start();
+                    Self::round(options, &mut result);
+                }
+                Some(exponent)
+            }
+        };
+        if let Some(sign_display) = options.sign_display {
+            result.apply_sign_display(sign_display);
+        }
+        (result, exponent)
+    }
+
+    /// Rounds and pads a value according to the fraction and significant digit options.
+    fn round(options: &DecimalFormatterOptions, value: &mut Decimal) {
+        let has_significant = options.minimum_significant_digits.is_some()
+            || options.maximum_significant_digits.is_some();
+        let has_fraction =
+            options.minimum_fraction_digits.is_some() || options.maximum_fraction_digits.is_some();
+        let mode = options
+            .rounding_mode
+            .unwrap_or(SignedRoundingMode::Unsigned(
+                UnsignedRoundingMode::HalfExpand,
+            ));
+
+        let significant = |value: &mut Decimal, min: u8, max: Option<u8>| {
+            if let Some(max) = max {
+                let position =
+                    value.absolute.nonzero_magnitude_start() - i16::from(max.max(min)) + 1;
+                value.round_with_mode(position, mode);
+                value.absolute.trim_end();
+            }
+            let position = value.absolute.nonzero_magnitude_start() - i16::from(min) + 1;
+            value.absolute.pad_end(position);
+        };
+        let fraction = |value: &mut Decimal, min: u8, max: Option<u8>| {
+            if let Some(max) = max {
+                value.round_with_mode_and_increment(
+                    -i16::from(max.max(min)),
+                    mode,
+                    options.rounding_increment.unwrap_or_default(),
+                );
+                value.absolute.trim_end();
+            }
+            value.absolute.pad_end(-i16::from(min));
+        };
+
+        match options.rounding_priority.unwrap_or_default() {
+            RoundingPriority::Auto if has_significant => significant(
+                value,
+                options.minimum_significant_digits.unwrap_or(1),
+                options.maximum_significant_digits,
+            ),
+            RoundingPriority::Auto if has_fraction => fraction(
+                value,
+                options.minimum_fraction_digits.unwrap_or(0),
+                options.maximum_fraction_digits,
+            ),
+            RoundingPriority::Auto => {}
+            priority => {
+                // As in ECMA-402, unset options take their default values when comparing.
+                let min_significant = options.minimum_significant_digits.unwrap_or(1);
+                let max_significant = options.maximum_significant_digits.unwrap_or(21);
+                let min_fraction = options.minimum_fraction_digits.unwrap_or(0);
+                let max_fraction = options
+                    .maximum_fraction_digits
+                    .unwrap_or_else(|| min_fraction.max(3));
+                let significant_position = value.absolute.nonzero_magnitude_start()
+                    - i16::from(max_significant.max(min_significant))
+                    + 1;
+                let fraction_position = -i16::from(max_fraction.max(min_fraction));
+                let use_significant = if priority == RoundingPriority::LessPrecision {
+                    significant_position >= fraction_position
+                } else {
+                    significant_position <= fraction_position
+                };
+                if use_significant {
+                    significant(value, min_significant, Some(max_significant));
+                } else {
+                    fraction(value, min_fraction, Some(max_fraction));
+                }
+            }
+        }
     }
 
     #[doc(hidden)] // TODO(#3647): should be private
@@ -125,7 +253,8 @@ impl DecimalFormatter {
     ) -> FormattedUnsignedDecimal<'l> {
         FormattedUnsignedDecimal {
             value,
-            options: &self.options,
+            exponent: None,
+            grouping_strategy: self.options.get().grouping_strategy,
             symbols: self.symbols.get(),
             digits: self.digits.get(),
         }
@@ -171,8 +300,10 @@ pub struct FormattedDecimal<'l>(pub(crate) FormattedSign<'l, FormattedUnsignedDe
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedUnsignedDecimal<'l> {
     pub(crate) value: Cow<'l, UnsignedDecimal>,
-    pub(crate) options: &'l DecimalFormatterOptions,
-    pub(crate) symbols: &'l DecimalSymbols<'l>,
+    /// The power of ten by which the value is multiplied, in scientific or engineering notation.
+    pub(crate) exponent: Option<i16>,
+    pub(crate) grouping_strategy: Option<GroupingStrategy>,
+    pub(crate) symbols: &'l DecimalSymbolsWithExponent<'l>,
     pub(crate) digits: &'l [char; 10],
 }
 
@@ -212,7 +343,7 @@ impl Writeable for FormattedUnsignedDecimal<'_> {
                 if grouper::check(
                     upper_magnitude,
                     m,
-                    self.options.grouping_strategy.unwrap_or_default(),
+                    self.grouping_strategy.unwrap_or_default(),
                     self.symbols.grouping_sizes,
                 ) {
                     w.with_part(parts::GROUP, |w| {
@@ -234,6 +365,24 @@ impl Writeable for FormattedUnsignedDecimal<'_> {
                 Ok(())
             })?;
         }
+        if let Some(exponent) = self.exponent {
+            w.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                w.write_str(self.symbols.exponential())
+            })?;
+            if exponent < 0 {
+                w.with_part(parts::EXPONENT_MINUS_SIGN, |w| {
+                    w.write_str(self.symbols.minus_sign_prefix())
+                })?;
+            }
+            let exponent = UnsignedDecimal::from(exponent.unsigned_abs());
+            w.with_part(parts::EXPONENT_INTEGER, |w| {
+                for m in exponent.magnitude_range().rev() {
+                    #[expect(clippy::indexing_slicing)] // digit_at in 0..=9
+                    w.write_char(self.digits[exponent.digit_at(m) as usize])?;
+                }
+                Ok(())
+            })?;
+        }
         Ok(())
     }
 }
@@ -281,3 +430,231 @@ pub fn test_es_mx() {
     let fd = "12345.67".parse().unwrap();
     assert_writeable_eq!(fmt.format(&fd), "12,345.67");
 }
+
+#[test]
+fn test_digit_options() {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    #[derive(Default)]
+    struct Case {
+        min_int: Option<u8>,
+        max_int: Option<u8>,
+        min_frac: Option<u8>,
+        max_frac: Option<u8>,
+        min_sig: Option<u8>,
+        max_sig: Option<u8>,
+        mode: Option<SignedRoundingMode>,
+        increment: Option<RoundingIncrement>,
+        input: &'static str,
+        expected: &'static str,
+    }
+
+    let cases = [
+        Case {
+            max_frac: Some(2),
+            input: "1.005",
+            expected: "1.01",
+            ..Default::default()
+        },
+        Case {
+            max_frac: Some(2),
+            input: "1.50",
+            expected: "1.5",
+            ..Default::default()
+        },
+        Case {
+            min_frac: Some(2),
+            input: "1.5",
+            expected: "1.50",
+            ..Default::default()
+        },
+        Case {
+            min_frac: Some(2),
+            max_frac: Some(2),
+            increment: Some(RoundingIncrement::MultiplesOf5),
+            input: "1.234",
+            expected: "1.25",
+            ..Default::default()
+        },
+        Case {
+            max_frac: Some(0),
+            mode: Some(SignedRoundingMode::Floor),
+            input: "-1.2",
+            expected: "-2",
+            ..Default::default()
+        },
+        Case {
+            max_frac: Some(0),
+            mode: Some(SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven)),
+            input: "2.5",
+            expected: "2",
+            ..Default::default()
+        },
+        Case {
+            max_sig: Some(2),
+            input: "123456",
+            expected: "120,000",
+            ..Default::default()
+        },
+        Case {
+            max_sig: Some(3),
+            input: "0.0012345",
+            expected: "0.00123",
+            ..Default::default()
+        },
+        Case {
+            min_sig: Some(4),
+            input: "1.2",
+            expected: "1.200",
+            ..Default::default()
+        },
+        Case {
+            min_sig: Some(3),
+            input: "0",
+            expected: "0.00",
+            ..Default::default()
+        },
+        Case {
+            min_sig: Some(2),
+            max_frac: Some(0),
+            input: "1.25",
+            expected: "1.25",
+            ..Default::default()
+        },
+        Case {
+            min_int: Some(3),
+            input: "1.5",
+            expected: "001.5",
+            ..Default::default()
+        },
+        Case {
+            max_int: Some(2),
+            input: "1234.5",
+            expected: "34.5",
+            ..Default::default()
+        },
+    ];
+
+    for case in cases {
+        let options = DecimalFormatterOptions {
+            minimum_integer_digits: case.min_int,
+            maximum_integer_digits: case.max_int,
+            minimum_fraction_digits: case.min_frac,
+            maximum_fraction_digits: case.max_frac,
+            minimum_significant_digits: case.min_sig,
+            maximum_significant_digits: case.max_sig,
+            rounding_mode: case.mode,
+            rounding_increment: case.increment,
+            ..Default::default()
+        };
+        let formatter = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+        let value = case.input.parse().unwrap();
+        assert_writeable_eq!(formatter.format(&value), case.expected, "{}", case.input);
+    }
+}
+
+#[test]
+fn test_notation() {
+    use icu_locale_core::locale;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    let options = DecimalFormatterOptions {
+        notation: Some(Notation::Scientific),
+        maximum_fraction_digits: Some(2),
+        ..Default::default()
+    };
+    let scientific = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+
+    let options = DecimalFormatterOptions {
+        notation: Some(Notation::Engineering),
+        maximum_significant_digits: Some(3),
+        ..Default::default()
+    };
+    let engineering = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+    let engineering_ar = DecimalFormatter::try_new(locale!("ar-EG").into(), options).unwrap();
+
+    let cases = [
+        (&scientific, "9.999", "1E1"),
+        (&scientific, "99950", "1E5"),
+        (&scientific, "-12345", "-1.23E4"),
+        (&engineering, "999999", "1E6"),
+        (&engineering, "12345678", "12.3E6"),
+        (&engineering, "0.0001", "100E-6"),
+        (&engineering_ar, "12345678", "١٢٫٣أس٦"),
+    ];
+    for (formatter, input, expected) in cases {
+        let value = input.parse().unwrap();
+        assert_writeable_eq!(formatter.format(&value), expected, "{input}");
+    }
+
+    let value = "0.000123".parse().unwrap();
+    assert_writeable_parts_eq!(
+        scientific.format(&value),
+        "1.23E-4",
+        [
+            (0, 1, parts::INTEGER),
+            (1, 2, parts::DECIMAL),
+            (2, 4, parts::FRACTION),
+            (4, 5, parts::EXPONENT_SEPARATOR),
+            (5, 6, parts::EXPONENT_MINUS_SIGN),
+            (6, 7, parts::EXPONENT_INTEGER),
+        ]
+    );
+}
+
+#[test]
+fn test_sign_display() {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    let cases = [
+        (
+            SignDisplay::Auto,
+            ["+1", "0", "-0", "-1"],
+            ["1", "0", "-0", "-1"],
+        ),
+        (
+            SignDisplay::Never,
+            ["+1", "0", "-0", "-1"],
+            ["1", "0", "0", "1"],
+        ),
+        (
+            SignDisplay::Always,
+            ["+1", "0", "-0", "-1"],
+            ["+1", "+0", "-0", "-1"],
+        ),
+        (
+            SignDisplay::ExceptZero,
+            ["+1", "0", "-0", "-1"],
+            ["+1", "0", "0", "-1"],
+        ),
+        (
+            SignDisplay::Negative,
+            ["+1", "0", "-0", "-1"],
+            ["1", "0", "0", "-1"],
+        ),
+    ];
+
+    for (sign_display, inputs, expected) in cases {
+        let options = DecimalFormatterOptions {
+            sign_display: Some(sign_display),
+            ..Default::default()
+        };
+        let formatter = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+        for (input, expected) in inputs.into_iter().zip(expected) {
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(formatter.format(&value), expected, "{sign_display:?}");
+        }
+    }
+
+    // The sign is displayed after rounding
+    let options = DecimalFormatterOptions {
+        sign_display: Some(SignDisplay::ExceptZero),
+        maximum_fraction_digits: Some(1),
+        ..Default::default()
+    };
+    let formatter = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+    let value = "-0.01".parse().unwrap();
+    assert_writeable_eq!(formatter.format(&value), "0");
+}
@@ -157,17 +157,20 @@ fn test_grouper() {
                 dec.multiply_pow10((i as i16) + 3);
                 dec
             };
-            let symbols = DecimalSymbols {
+            let symbols = DecimalSymbolsWithExponent {
                 grouping_sizes: cas.sizes,
-                ..DecimalSymbols::new_en_for_testing()
+                ..DecimalSymbolsWithExponent::new_en_for_testing()
             };
             let digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
-            struct Provider(RefCell<Option<DecimalSymbols<'static>>>, [char; 10]);
-            impl DataProvider<DecimalSymbolsV1> for Provider {
+            struct Provider(
+                RefCell<Option<DecimalSymbolsWithExponent<'static>>>,
+                [char; 10],
+            );
+            impl DataProvider<DecimalSymbolsV2> for Provider {
                 fn load(
                     &self,
                     _req: DataRequest,
-                ) -> Result<DataResponse<DecimalSymbolsV1>, DataError> {
+                ) -> Result<DataResponse<DecimalSymbolsV2>, DataError> {
                     Ok(DataResponse {
                         metadata: Default::default(),
                         payload: DataPayload::from_owned(
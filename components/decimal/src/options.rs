@@ -4,8 +4,40 @@
 
 //! Options for [`DecimalFormatter`](crate::DecimalFormatter).
 
+pub use fixed_decimal::{RoundingIncrement, SignDisplay, SignedRoundingMode, UnsignedRoundingMode};
+
 /// A bag of options defining how numbers will be formatted by
 /// [`DecimalFormatter`](crate::DecimalFormatter).
+///
+/// The options follow the number formatting options of ECMA-402 (`Intl.NumberFormat`).
+/// Unlike in ECMA-402, numbers are not rounded unless a digit option is set, since a
+/// [`Decimal`](crate::input::Decimal) already carries its own precision.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{DecimalFormatterOptions, Notation, SignDisplay};
+/// use icu::decimal::DecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = DecimalFormatterOptions::default();
+/// options.notation = Some(Notation::Scientific);
+/// options.maximum_significant_digits = Some(3);
+/// options.sign_display = Some(SignDisplay::ExceptZero);
+///
+/// let formatter =
+///     DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+///
+/// let value = 123456.into();
+/// assert_writeable_eq!(formatter.format(&value), "+1.23E5");
+///
+/// let value = "-0.00042".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "-4.2E-4");
+///
+/// let value = 0.into();
+/// assert_writeable_eq!(formatter.format(&value), "0E0");
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
 #[non_exhaustive]
 pub struct DecimalFormatterOptions {
@@ -13,16 +45,331 @@ pub struct DecimalFormatterOptions {
     ///
     /// Default is [`GroupingStrategy::Auto`]
     pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// Whether to render the number with an exponent.
+    ///
+    /// Default is [`Notation::Standard`]
+    pub notation: Option<Notation>,
+
+    /// When to render the sign of the number.
+    ///
+    /// If not set, the sign of the [`Decimal`](crate::input::Decimal) is rendered as is.
+    pub sign_display: Option<SignDisplay>,
+
+    /// The minimum number of integer digits; the number is padded with leading zeros
+    /// if needed.
+    ///
+    /// This is ignored with scientific and engineering notation.
+    pub minimum_integer_digits: Option<u8>,
+
+    /// The maximum number of integer digits; more significant digits are dropped.
+    ///
+    /// The number is truncated, not rounded, so 1234 with a maximum of two integer digits
+    /// is formatted as "34", like ICU's `IntegerWidth::truncateAt`.
+    ///
+    /// This is ignored with scientific and engineering notation.
+    pub maximum_integer_digits: Option<u8>,
+
+    /// The minimum number of fraction digits; the number is padded with trailing zeros
+    /// if needed.
+    pub minimum_fraction_digits: Option<u8>,
+
+    /// The maximum number of fraction digits; the number is rounded if needed, and
+    /// trailing zeros beyond [`Self::minimum_fraction_digits`] are removed.
+    pub maximum_fraction_digits: Option<u8>,
+
+    /// The minimum number of significant digits; the number is padded with trailing zeros
+    /// if needed.
+    pub minimum_significant_digits: Option<u8>,
+
+    /// The maximum number of significant digits; the number is rounded if needed, and
+    /// trailing zeros beyond [`Self::minimum_significant_digits`] are removed.
+    pub maximum_significant_digits: Option<u8>,
+
+    /// How to resolve conflicts between the fraction digit and significant digit options.
+    ///
+    /// Default is [`RoundingPriority::Auto`]
+    pub rounding_priority: Option<RoundingPriority>,
+
+    /// How to round the number when it has more digits than allowed.
+    ///
+    /// Default is [`SignedRoundingMode::Unsigned`]`(`[`UnsignedRoundingMode::HalfExpand`]`)`,
+    /// as in ECMA-402 and CLDR.
+    pub rounding_mode: Option<SignedRoundingMode>,
+
+    /// The increment to round to, in units of the last fraction digit.
+    ///
+    /// For example, with two maximum fraction digits, [`RoundingIncrement::MultiplesOf5`]
+    /// rounds to multiples of 0.05. This is ignored when rounding to significant digits.
+    ///
+    /// Default is [`RoundingIncrement::MultiplesOf1`]
+    pub rounding_increment: Option<RoundingIncrement>,
 }
 
 impl From<GroupingStrategy> for DecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
         Self {
             grouping_strategy: Some(grouping_strategy),
+            ..Default::default()
         }
     }
 }
 
+impl DecimalFormatterOptions {
+    /// Whether formatting with these options can change the digits or the sign of the input.
+    pub(crate) fn modifies_value(&self) -> bool {
+        *self
+            != Self {
+                grouping_strategy: self.grouping_strategy,
+                ..Default::default()
+            }
+    }
+}
+
+/// [`DecimalFormatterOptions`] packed into eight bytes.
+///
+/// [`DecimalFormatter`](crate::DecimalFormatter) is embedded in other formatters, such as the
+/// datetime formatters, which use few of the options, so they are stored packed to keep the
+/// stack size of those formatters small.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PackedOptions {
+    /// The digit options, in field order, with [`u8::MAX`] for unset options.
+    ///
+    /// A digit option of [`u8::MAX`] is stored as `u8::MAX - 1`.
+    digits: [u8; 6],
+    /// The remaining options, each stored as its index into the corresponding `*_VALUES`
+    /// list, in mixed radix.
+    choices: u16,
+}
+
+const GROUPING_STRATEGY_VALUES: &[Option<GroupingStrategy>] = &[
+    None,
+    Some(GroupingStrategy::Auto),
+    Some(GroupingStrategy::Never),
+    Some(GroupingStrategy::Always),
+    Some(GroupingStrategy::Min2),
+];
+
+const NOTATION_VALUES: &[Option<Notation>] = &[
+    None,
+    Some(Notation::Standard),
+    Some(Notation::Scientific),
+    Some(Notation::Engineering),
+];
+
+const SIGN_DISPLAY_VALUES: &[Option<SignDisplay>] = &[
+    None,
+    Some(SignDisplay::Auto),
+    Some(SignDisplay::Never),
+    Some(SignDisplay::Always),
+    Some(SignDisplay::ExceptZero),
+    Some(SignDisplay::Negative),
+];
+
+const ROUNDING_PRIORITY_VALUES: &[Option<RoundingPriority>] = &[
+    None,
+    Some(RoundingPriority::Auto),
+    Some(RoundingPriority::MorePrecision),
+    Some(RoundingPriority::LessPrecision),
+];
+
+const ROUNDING_MODE_VALUES: &[Option<SignedRoundingMode>] = &[
+    None,
+    Some(SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand)),
+    Some(SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc)),
+    Some(SignedRoundingMode::Unsigned(
+        UnsignedRoundingMode::HalfExpand,
+    )),
+    Some(SignedRoundingMode::Unsigned(
+        UnsignedRoundingMode::HalfTrunc,
+    )),
+    Some(SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven)),
+    Some(SignedRoundingMode::Ceil),
+    Some(SignedRoundingMode::Floor),
+    Some(SignedRoundingMode::HalfCeil),
+    Some(SignedRoundingMode::HalfFloor),
+];
+
+const ROUNDING_INCREMENT_VALUES: &[Option<RoundingIncrement>] = &[
+    None,
+    Some(RoundingIncrement::MultiplesOf1),
+    Some(RoundingIncrement::MultiplesOf2),
+    Some(RoundingIncrement::MultiplesOf5),
+    Some(RoundingIncrement::MultiplesOf25),
+];
+
+impl PackedOptions {
+    pub(crate) fn new(options: DecimalFormatterOptions) -> Self {
+        fn pack<T: PartialEq>(choices: &mut u16, values: &[Option<T>], value: Option<T>) {
+            // Values missing from the list, which can only be variants added to the
+            // non-exhaustive `fixed_decimal` enums, are stored as unset.
+            let index = values.iter().position(|v| *v == value).unwrap_or_default();
+            *choices = *choices * values.len() as u16 + index as u16;
+        }
+
+        let mut choices = 0;
+        pack(
+            &mut choices,
+            GROUPING_STRATEGY_VALUES,
+            options.grouping_strategy,
+        );
+        pack(&mut choices, NOTATION_VALUES, options.notation);
+        pack(&mut choices, SIGN_DISPLAY_VALUES, options.sign_display);
+        pack(
+            &mut choices,
+            ROUNDING_PRIORITY_VALUES,
+            options.rounding_priority,
+        );
+        pack(&mut choices, ROUNDING_MODE_VALUES, options.rounding_mode);
+        pack(
+            &mut choices,
+            ROUNDING_INCREMENT_VALUES,
+            options.rounding_increment,
+        );
+
+        Self {
+            digits: [
+                options.minimum_integer_digits,
+                options.maximum_integer_digits,
+                options.minimum_fraction_digits,
+                options.maximum_fraction_digits,
+                options.minimum_significant_digits,
+                options.maximum_significant_digits,
+            ]
+            .map(|digits| digits.map_or(u8::MAX, |digits| digits.min(u8::MAX - 1))),
+            choices,
+        }
+    }
+
+    pub(crate) fn get(self) -> DecimalFormatterOptions {
+        fn unpack<T: Copy>(choices: &mut u16, values: &[Option<T>]) -> Option<T> {
+            let len = values.len() as u16;
+            let value = values.get(usize::from(*choices % len)).copied().flatten();
+            *choices /= len;
+            value
+        }
+
+        let mut choices = self.choices;
+        let rounding_increment = unpack(&mut choices, ROUNDING_INCREMENT_VALUES);
+        let rounding_mode = unpack(&mut choices, ROUNDING_MODE_VALUES);
+        let rounding_priority = unpack(&mut choices, ROUNDING_PRIORITY_VALUES);
+        let sign_display = unpack(&mut choices, SIGN_DISPLAY_VALUES);
+        let notation = unpack(&mut choices, NOTATION_VALUES);
+        let grouping_strategy = unpack(&mut choices, GROUPING_STRATEGY_VALUES);
+
+        let [
+            minimum_integer_digits,
+            maximum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            minimum_significant_digits,
+            maximum_significant_digits,
+        ] = self
+            .digits
+            .map(|digits| (digits != u8::MAX).then_some(digits));
+
+        DecimalFormatterOptions {
+            grouping_strategy,
+            notation,
+            sign_display,
+            minimum_integer_digits,
+            maximum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            minimum_significant_digits,
+            maximum_significant_digits,
+            rounding_priority,
+            rounding_mode,
+            rounding_increment,
+        }
+    }
+}
+
+/// The notation in which to render a number.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{DecimalFormatterOptions, Notation};
+/// use icu::decimal::DecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = DecimalFormatterOptions::default();
+/// options.notation = Some(Notation::Engineering);
+/// let formatter =
+///     DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+///
+/// let value = 123456.into();
+/// assert_writeable_eq!(formatter.format(&value), "123.456E3");
+///
+/// let value = "0.05".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "50E-3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum Notation {
+    /// Render the number without an exponent.
+    #[default]
+    Standard,
+
+    /// Render the number with one integer digit and a power of ten, such as 1.23E5.
+    Scientific,
+
+    /// Render the number with one to three integer digits and a power of ten that is a
+    /// multiple of three, such as 123E3.
+    Engineering,
+}
+
+/// How to resolve conflicts between the fraction digit and significant digit options.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{DecimalFormatterOptions, RoundingPriority};
+/// use icu::decimal::DecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = DecimalFormatterOptions::default();
+/// options.maximum_fraction_digits = Some(1);
+/// options.maximum_significant_digits = Some(2);
+///
+/// options.rounding_priority = Some(RoundingPriority::MorePrecision);
+/// let more = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+///
+/// options.rounding_priority = Some(RoundingPriority::LessPrecision);
+/// let less = DecimalFormatter::try_new(locale!("en").into(), options).unwrap();
+///
+/// let value = "1.234".parse().unwrap();
+/// assert_writeable_eq!(more.format(&value), "1.2");
+/// assert_writeable_eq!(less.format(&value), "1.2");
+///
+/// let value = "123.4".parse().unwrap();
+/// assert_writeable_eq!(more.format(&value), "123.4");
+/// assert_writeable_eq!(less.format(&value), "120");
+///
+/// let value = "0.01234".parse().unwrap();
+/// assert_writeable_eq!(more.format(&value), "0.012");
+/// assert_writeable_eq!(less.format(&value), "0");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum RoundingPriority {
+    /// Use the significant digit options if any is set, and the fraction digit options
+    /// otherwise.
+    #[default]
+    Auto,
+
+    /// Use whichever of the significant digit and fraction digit options results in
+    /// more precision.
+    MorePrecision,
+
+    /// Use whichever of the significant digit and fraction digit options results in
+    /// less precision.
+    LessPrecision,
+}
+
 /// Configuration for how often to render grouping separators.
 ///
 /// # Examples
@@ -97,6 +444,7 @@ impl Default for CompactDecimalFormatterOptions {
 
 #[cfg(feature = "unstable")]
 impl From<DecimalFormatterOptions> for CompactDecimalFormatterOptions {
+    /// Only the grouping strategy is carried over, since compact notation does its own rounding.
     fn from(decimal_formatter_options: DecimalFormatterOptions) -> Self {
         Self {
             grouping_strategy: decimal_formatter_options.grouping_strategy,
@@ -109,6 +457,7 @@ impl From<CompactDecimalFormatterOptions> for DecimalFormatterOptions {
     fn from(decimal_formatter_options: CompactDecimalFormatterOptions) -> Self {
         Self {
             grouping_strategy: decimal_formatter_options.grouping_strategy,
+            ..Default::default()
         }
     }
 }
@@ -121,3 +470,36 @@ impl From<GroupingStrategy> for CompactDecimalFormatterOptions {
         }
     }
 }
+
+#[test]
+fn test_packed_options() {
+    let options = [
+        DecimalFormatterOptions::default(),
+        DecimalFormatterOptions {
+            grouping_strategy: Some(GroupingStrategy::Min2),
+            notation: Some(Notation::Engineering),
+            sign_display: Some(SignDisplay::Negative),
+            minimum_integer_digits: Some(0),
+            maximum_integer_digits: Some(254),
+            minimum_fraction_digits: Some(2),
+            maximum_fraction_digits: None,
+            minimum_significant_digits: None,
+            maximum_significant_digits: Some(21),
+            rounding_priority: Some(RoundingPriority::LessPrecision),
+            rounding_mode: Some(SignedRoundingMode::HalfFloor),
+            rounding_increment: Some(RoundingIncrement::MultiplesOf25),
+        },
+        DecimalFormatterOptions {
+            grouping_strategy: Some(GroupingStrategy::Auto),
+            notation: Some(Notation::Standard),
+            sign_display: Some(SignDisplay::Auto),
+            rounding_priority: Some(RoundingPriority::Auto),
+            rounding_mode: Some(SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand)),
+            rounding_increment: Some(RoundingIncrement::MultiplesOf1),
+            ..Default::default()
+        },
+    ];
+    for options in options {
+        assert_eq!(PackedOptions::new(options).get(), options);
+    }
+}
@@ -71,3 +71,21 @@ pub const DECIMAL: Part = Part {
     category: "decimal",
     value: "decimal",
 };
+
+/// A [`Part`] used by [`FormattedDecimal`](super::FormattedDecimal).
+pub const EXPONENT_SEPARATOR: Part = Part {
+    category: "decimal",
+    value: "exponentSeparator",
+};
+
+/// A [`Part`] used by [`FormattedDecimal`](super::FormattedDecimal).
+pub const EXPONENT_MINUS_SIGN: Part = Part {
+    category: "decimal",
+    value: "exponentMinusSign",
+};
+
+/// A [`Part`] used by [`FormattedDecimal`](super::FormattedDecimal).
+pub const EXPONENT_INTEGER: Part = Part {
+    category: "decimal",
+    value: "exponentInteger",
+};
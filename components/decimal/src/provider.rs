@@ -145,7 +145,7 @@ const _: () = {
     impl_decimal_compact_long_v1!(Baked);
     #[cfg(feature = "unstable")]
     impl_decimal_compact_short_v1!(Baked);
-    impl_decimal_symbols_v1!(Baked);
+    impl_decimal_symbols_v2!(Baked);
     impl_decimal_digits_v1!(Baked);
 };
 
@@ -156,6 +156,13 @@ icu_provider::data_marker!(
     DecimalSymbols<'static>,
 );
 
+icu_provider::data_marker!(
+    /// Data marker for decimal symbols, including the exponent symbol
+    DecimalSymbolsV2,
+    "decimal/symbols/v2",
+    DecimalSymbolsWithExponent<'static>,
+);
+
 icu_provider::data_marker!(
     /// The digits for a given numbering system. This data ought to be stored in the `und` locale with a marker attribute
     /// set to the numbering system code.
@@ -170,7 +177,7 @@ icu_provider::data_marker!(
 /// The latest minimum set of markers required by this component.
 #[cfg(feature = "unstable")]
 pub const MARKERS: &[DataMarkerInfo] = &[
-    DecimalSymbolsV1::INFO,
+    DecimalSymbolsV2::INFO,
     DecimalDigitsV1::INFO,
     DecimalCompactLongV1::INFO,
     DecimalCompactShortV1::INFO,
@@ -179,7 +186,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
 #[cfg(feature = "datagen")]
 /// The latest minimum set of markers required by this component.
 #[cfg(not(feature = "unstable"))]
-pub const MARKERS: &[DataMarkerInfo] = &[DecimalSymbolsV1::INFO, DecimalDigitsV1::INFO];
+pub const MARKERS: &[DataMarkerInfo] = &[DecimalSymbolsV2::INFO, DecimalDigitsV1::INFO];
 
 /// A collection of settings expressing where to put grouping separators in a decimal number.
 /// For example, `1,000,000` has two grouping separators, positioned along every 3 digits.
@@ -328,6 +335,130 @@ impl DecimalSymbols<'static> {
     }
 }
 
+/// A stack representation of the strings used in [`DecimalSymbolsWithExponent`], i.e. a builder type
+/// for [`DecimalSymbolsWithExponentStrs`]. This type can be obtained from a [`DecimalSymbolsWithExponentStrs`]
+/// the `From`/`Into` traits.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+#[zerovec::make_varule(DecimalSymbolsWithExponentStrs)]
+#[zerovec::derive(Debug)]
+#[zerovec::skip_derive(Ord)]
+#[cfg_attr(not(feature = "alloc"), zerovec::skip_derive(ZeroMapKV, ToOwned))]
+#[cfg_attr(feature = "serde", zerovec::derive(Deserialize))]
+#[cfg_attr(feature = "datagen", zerovec::derive(Serialize))]
+// See DecimalSymbolStrsBuilder; the exponent symbol is at most a few characters as well.
+#[zerovec::format(zerovec::vecs::Index8)]
+pub struct DecimalSymbolWithExponentStrsBuilder<'data> {
+    /// Prefix to apply when a negative sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign_prefix: VarZeroCow<'data, str>,
+    /// Suffix to apply when a negative sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign_suffix: VarZeroCow<'data, str>,
+
+    /// Prefix to apply when a positive sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plus_sign_prefix: VarZeroCow<'data, str>,
+    /// Suffix to apply when a positive sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plus_sign_suffix: VarZeroCow<'data, str>,
+
+    /// Character used to separate the integer and fraction parts of the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub decimal_separator: VarZeroCow<'data, str>,
+
+    /// Character used to separate groups in the integer part of the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub grouping_separator: VarZeroCow<'data, str>,
+
+    /// The numbering system to use.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub numsys: VarZeroCow<'data, str>,
+
+    /// Symbol used to separate the significand and the exponent in scientific notation.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: VarZeroCow<'data, str>,
+}
+
+#[cfg(feature = "alloc")]
+impl DecimalSymbolWithExponentStrsBuilder<'_> {
+    /// Build a [`DecimalSymbolsWithExponentStrs`]
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    pub fn build(&self) -> VarZeroCow<'static, DecimalSymbolsWithExponentStrs> {
+        VarZeroCow::from_encodeable(self)
+    }
+}
+
+/// Symbols and metadata required for formatting a [`Decimal`](crate::input::Decimal),
+/// including in scientific notation.
+///
+/// This is [`DecimalSymbols`] with an additional exponent symbol.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_decimal::provider))]
+pub struct DecimalSymbolsWithExponent<'data> {
+    /// String data for the symbols: +/- affixes, separators and the exponent symbol
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    // As in DecimalSymbols, all strings are packed into a single VarZeroCow to keep the stack size small
+    pub strings: VarZeroCow<'data, DecimalSymbolsWithExponentStrs>,
+
+    /// Settings used to determine where to place groups in the integer part of the number.
+    pub grouping_sizes: GroupingSizes,
+}
+
+icu_provider::data_struct!(
+    DecimalSymbolsWithExponent<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+impl<'a> core::ops::Deref for DecimalSymbolsWithExponent<'a> {
+    type Target = VarZeroCow<'a, DecimalSymbolsWithExponentStrs>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.strings
+    }
+}
+
+impl DecimalSymbolsWithExponent<'static> {
+    /// Create a new en-US format for use in testing
+    #[cfg(feature = "datagen")]
+    pub fn new_en_for_testing() -> Self {
+        let strings = DecimalSymbolWithExponentStrsBuilder {
+            minus_sign_prefix: VarZeroCow::new_borrowed("-"),
+            minus_sign_suffix: VarZeroCow::new_borrowed(""),
+            plus_sign_prefix: VarZeroCow::new_borrowed("+"),
+            plus_sign_suffix: VarZeroCow::new_borrowed(""),
+            decimal_separator: VarZeroCow::new_borrowed("."),
+            grouping_separator: VarZeroCow::new_borrowed(","),
+            numsys: VarZeroCow::new_borrowed("latn"),
+            exponential: VarZeroCow::new_borrowed("E"),
+        };
+        Self {
+            strings: VarZeroCow::from_encodeable(&strings),
+            grouping_sizes: GroupingSizes {
+                primary: 3,
+                secondary: 3,
+                min_grouping: 1,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "unstable")]
 icu_provider::data_marker!(
     /// `DecimalCompactLongV1`
@@ -151,7 +151,7 @@ impl CompactCurrencyFormatter {
             + DataProvider<CurrencyEssentialsV1>
             + DataProvider<ShortCurrencyCompactV1>
             + DataProvider<icu_decimal::provider::DecimalCompactShortV1>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -0,0 +1,223 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_decimal::DecimalFormatterPreferences;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::currency::{
+    displayname::CurrencyDisplaynameV1, essentials::CurrencyEssentialsV1,
+    extended::CurrencyExtendedDataV1,
+};
+
+use super::CurrencyCode;
+use super::formatter::{CurrencyFormatterPreferences, load_with_fallback};
+use super::options::Width;
+
+/// Localized display names and symbols of a currency.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::currency::display_names::CurrencyDisplayNames;
+/// use icu::experimental::dimension::currency::CurrencyCode;
+/// use icu::locale::locale;
+/// use tinystr::tinystr;
+///
+/// let currency_code = CurrencyCode(tinystr!(3, "USD"));
+/// let names =
+///     CurrencyDisplayNames::try_new(locale!("en").into(), &currency_code).unwrap();
+///
+/// assert_eq!(names.display_name(), "US Dollar");
+/// assert_eq!(names.plural_name(&"1".parse().unwrap()), "US dollar");
+/// assert_eq!(names.plural_name(&"2.50".parse().unwrap()), "US dollars");
+/// assert_eq!(names.symbol(), "$");
+/// assert_eq!(names.narrow_symbol(), "$");
+/// ```
+#[derive(Debug)]
+pub struct CurrencyDisplayNames {
+    /// The currency that the names belong to.
+    currency_code: CurrencyCode,
+
+    /// The standalone display name of the currency.
+    display_name: DataPayload<CurrencyDisplaynameV1>,
+
+    /// The display names of the currency for each plural category.
+    extended: DataPayload<CurrencyExtendedDataV1>,
+
+    /// Essential data containing the currency symbols.
+    essential: DataPayload<CurrencyEssentialsV1>,
+
+    /// A [`PluralRules`] to determine the plural category of an amount.
+    plural_rules: PluralRules,
+}
+
+impl CurrencyDisplayNames {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: CurrencyFormatterPreferences, currency_code: &CurrencyCode) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`CurrencyDisplayNames`] from compiled locale data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: CurrencyFormatterPreferences,
+        currency_code: &CurrencyCode,
+    ) -> Result<Self, DataError> {
+        let plural_rules = PluralRules::try_new_cardinal((&prefs).into())?;
+        Self::try_new_with_plural_rules(
+            &crate::provider::Baked,
+            prefs,
+            *currency_code,
+            plural_rules,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: CurrencyFormatterPreferences,
+        currency_code: &CurrencyCode,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<CurrencyDisplaynameV1>
+            + DataProvider<CurrencyExtendedDataV1>
+            + DataProvider<CurrencyEssentialsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
+    {
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?;
+        Self::try_new_with_plural_rules(provider, prefs, *currency_code, plural_rules)
+    }
+
+    fn try_new_with_plural_rules<D>(
+        provider: &D,
+        prefs: CurrencyFormatterPreferences,
+        currency_code: CurrencyCode,
+        plural_rules: PluralRules,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<CurrencyDisplaynameV1>
+            + DataProvider<CurrencyExtendedDataV1>
+            + DataProvider<CurrencyEssentialsV1>,
+    {
+        let locale = CurrencyEssentialsV1::make_locale(prefs.locale_preferences);
+
+        let marker_attributes = DataMarkerAttributes::try_from_str(currency_code.0.as_str())
+            .map_err(|_| {
+                DataErrorKind::IdentifierNotFound
+                    .into_error()
+                    .with_debug_context("failed to get data marker attribute from a `CurrencyCode`")
+            })?;
+        let id =
+            DataIdentifierBorrowed::for_marker_attributes_and_locale(marker_attributes, &locale);
+
+        let display_name = provider
+            .load(DataRequest {
+                id,
+                ..Default::default()
+            })?
+            .payload;
+
+        let extended = provider
+            .load(DataRequest {
+                id,
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_prefs = DecimalFormatterPreferences::from(&prefs);
+        let req_id = decimal_prefs.nu_id(&locale);
+        let default_id = DataIdentifierBorrowed::for_locale(&locale);
+        let ids = req_id.into_iter().chain(core::iter::once(default_id));
+        let essential = load_with_fallback::<CurrencyEssentialsV1>(provider, ids)?.payload;
+
+        Ok(Self {
+            currency_code,
+            display_name,
+            extended,
+            essential,
+            plural_rules,
+        })
+    }
+
+    /// Returns the currency code these names belong to.
+    pub fn currency_code(&self) -> CurrencyCode {
+        self.currency_code
+    }
+
+    /// Returns the standalone display name of the currency, such as "US Dollar".
+    pub fn display_name(&self) -> &str {
+        &self.display_name.get().display_name
+    }
+
+    /// Returns the display name of the currency to be used next to the given amount,
+    /// such as "US dollars" for 2.50 in English.
+    pub fn plural_name(&self, value: &Decimal) -> &str {
+        self.extended
+            .get()
+            .display_names
+            .get(value.into(), &self.plural_rules)
+    }
+
+    /// Returns the standard symbol of the currency, such as "US$".
+    ///
+    /// If the locale has no symbol for the currency, the ISO code is returned.
+    pub fn symbol(&self) -> &str {
+        self.essential
+            .get()
+            .name_and_pattern(Width::Short, &self.currency_code)
+            .0
+    }
+
+    /// Returns the narrow symbol of the currency, such as "$".
+    ///
+    /// The narrow symbol may be ambiguous, so it should be evident from context which
+    /// currency is being represented.
+    pub fn narrow_symbol(&self) -> &str {
+        self.essential
+            .get()
+            .name_and_pattern(Width::Narrow, &self.currency_code)
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use tinystr::tinystr;
+
+    use super::CurrencyDisplayNames;
+    use crate::dimension::currency::CurrencyCode;
+
+    #[test]
+    pub fn test_en() {
+        let currency_code = CurrencyCode(tinystr!(3, "EUR"));
+        let names = CurrencyDisplayNames::try_new(locale!("en").into(), &currency_code).unwrap();
+        assert_eq!(names.display_name(), "Euro");
+        assert_eq!(names.plural_name(&"1".parse().unwrap()), "euro");
+        assert_eq!(names.plural_name(&"3".parse().unwrap()), "euros");
+        assert_eq!(names.symbol(), "€");
+        assert_eq!(names.narrow_symbol(), "€");
+    }
+
+    #[test]
+    pub fn test_symbols() {
+        let currency_code = CurrencyCode(tinystr!(3, "USD"));
+        let names = CurrencyDisplayNames::try_new(locale!("en-GB").into(), &currency_code).unwrap();
+        assert_eq!(names.symbol(), "US$");
+        assert_eq!(names.narrow_symbol(), "$");
+    }
+}
@@ -9,7 +9,9 @@ mod tests {
     use tinystr::*;
     use writeable::assert_writeable_eq;
 
-    use crate::dimension::currency::{CurrencyCode, formatter::CurrencyFormatter};
+    use crate::dimension::currency::{
+        CurrencyCode, formatter::CurrencyFormatter, options::CurrencyUsage,
+    };
 
     #[test]
     pub fn test_en_us() {
@@ -88,4 +90,29 @@ mod tests {
             "\u{200f}12,345.67\u{a0}ج.م.\u{200f}"
         );
     }
+
+    #[test]
+    pub fn test_rounding() {
+        let locale = locale!("en-US").into();
+        let fmt = CurrencyFormatter::try_new(locale, Default::default()).unwrap();
+
+        let value = "12345.5".parse().unwrap();
+        let currency_code = CurrencyCode(tinystr!(3, "USD"));
+        let formatted_currency = fmt.format_fixed_decimal(&value, &currency_code);
+        assert_writeable_eq!(formatted_currency, "$12,345.50");
+
+        let currency_code = CurrencyCode(tinystr!(3, "JPY"));
+        let formatted_currency = fmt.format_fixed_decimal(&value, &currency_code);
+        assert_writeable_eq!(formatted_currency, "¥12,346");
+
+        let value = "1.234".parse().unwrap();
+        let currency_code = CurrencyCode(tinystr!(3, "CHF"));
+        let formatted_currency = fmt.format_fixed_decimal(&value, &currency_code);
+        assert_writeable_eq!(formatted_currency, "CHF\u{a0}1.23");
+
+        let locale = locale!("en-US").into();
+        let fmt = CurrencyFormatter::try_new(locale, CurrencyUsage::Cash.into()).unwrap();
+        let formatted_currency = fmt.format_fixed_decimal(&value, &currency_code);
+        assert_writeable_eq!(formatted_currency, "CHF\u{a0}1.25");
+    }
 }
@@ -14,7 +14,9 @@ use icu_provider::prelude::*;
 use writeable::Writeable;
 
 use super::super::provider::currency::essentials::CurrencyEssentialsV1;
+use super::super::provider::currency::fractions::CurrencyFractionsV1;
 use super::CurrencyCode;
+use super::info::CurrencyInfo;
 use super::options::CurrencyFormatterOptions;
 use icu_pattern::DoublePlaceholderPattern;
 
@@ -42,6 +44,7 @@ prefs_convert!(CurrencyFormatterPreferences, PluralRulesPreferences);
 /// [`CurrencyFormatter`] supports:
 ///   1. Rendering in the locale's currency system.
 ///   2. Locale-sensitive grouping separator positions.
+///   3. Rounding to the fraction digits and rounding increment of the currency.
 ///
 /// Read more about the options in the [`super::options`] module.
 #[derive(Debug)]
@@ -53,6 +56,9 @@ pub struct CurrencyFormatter {
     /// Essential data for the currency formatter.
     essential: DataPayload<CurrencyEssentialsV1>,
 
+    /// Currency metadata used to round the currency value.
+    info: CurrencyInfo,
+
     /// A [`DecimalFormatter`] to format the currency value.
     decimal_formatter: DecimalFormatter,
 }
@@ -100,6 +106,7 @@ impl CurrencyFormatter {
         Ok(Self {
             options,
             essential,
+            info: CurrencyInfo::new(),
             decimal_formatter,
         })
     }
@@ -113,7 +120,8 @@ impl CurrencyFormatter {
     where
         D: ?Sized
             + DataProvider<CurrencyEssentialsV1>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<CurrencyFractionsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>,
     {
         let locale = CurrencyEssentialsV1::make_locale(prefs.locale_preferences);
@@ -136,15 +144,22 @@ impl CurrencyFormatter {
             return Err(DataError::custom("missing standard pattern"));
         }
 
+        let info = CurrencyInfo::try_new_unstable(provider)?;
+
         Ok(Self {
             options,
             essential,
+            info,
             decimal_formatter,
         })
     }
 
     /// Formats a [`Decimal`] value for the given currency code.
     ///
+    /// The value is rounded half away from zero to the number of fraction digits and the rounding
+    /// increment of the currency, according to the [`CurrencyUsage`](super::options::CurrencyUsage)
+    /// option. Earlier versions formatted the value as is.
+    ///
     /// # Examples
     /// ```
     /// use icu::experimental::dimension::currency::formatter::CurrencyFormatter;
@@ -161,12 +176,28 @@ impl CurrencyFormatter {
     ///     fmt.format_fixed_decimal(&value, &currency_code),
     ///     "$12,345.67"
     /// );
+    ///
+    /// let value = "12345.678".parse().unwrap();
+    /// let currency_code = CurrencyCode(tinystr!(3, "JPY"));
+    /// assert_writeable_eq!(
+    ///     fmt.format_fixed_decimal(&value, &currency_code),
+    ///     "¥12,346"
+    /// );
     /// ```
     pub fn format_fixed_decimal<'l>(
         &'l self,
         value: &'l Decimal,
         currency_code: &'l CurrencyCode,
     ) -> impl Writeable + Display + 'l {
+        self.format_amount(value, currency_code)
+    }
+
+    /// Rounds the value for the given currency code and formats it with the currency pattern.
+    pub(crate) fn format_amount<'l>(
+        &'l self,
+        value: &Decimal,
+        currency_code: &'l CurrencyCode,
+    ) -> FormattedAmount<'l> {
         let (currency_str, pattern, _pattern_selection) = self
             .essential
             .get()
@@ -174,20 +205,51 @@ impl CurrencyFormatter {
 
         let pattern = pattern.unwrap_or_else(|| <&DoublePlaceholderPattern>::default());
 
-        self.decimal_formatter.format_sign(
-            value.sign,
-            pattern.interpolate((
-                self.decimal_formatter
-                    .format_unsigned(icu_decimal::Cow::Borrowed(&value.absolute)),
-                currency_str,
-            )),
-        )
+        let mut value = value.clone();
+        self.info
+            .round(&mut value, currency_code, self.options.usage);
+
+        FormattedAmount {
+            value,
+            pattern,
+            currency_str,
+            decimal_formatter: &self.decimal_formatter,
+        }
     }
 }
 
+/// A monetary amount, rounded for its currency, with the pattern and symbol to format it with.
+#[derive(Debug)]
+pub(crate) struct FormattedAmount<'l> {
+    pub(crate) value: Decimal,
+    pub(crate) pattern: &'l DoublePlaceholderPattern,
+    pub(crate) currency_str: &'l str,
+    pub(crate) decimal_formatter: &'l DecimalFormatter,
+}
+
+impl Writeable for FormattedAmount<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        self.decimal_formatter
+            .format_sign(
+                self.value.sign,
+                self.pattern.interpolate((
+                    self.decimal_formatter
+                        .format_unsigned(icu_decimal::Cow::Borrowed(&self.value.absolute)),
+                    self.currency_str,
+                )),
+            )
+            .write_to_parts(sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedAmount<'_>);
+
 // TODO: Discuss reusing the `load_with_fallback` helper from `icu_decimal`
 // (or moving it to a shared location) instead of duplicating it here.
-fn load_with_fallback<'a, M: DataMarker>(
+pub(crate) fn load_with_fallback<'a, M: DataMarker>(
     provider: &(impl DataProvider<M> + ?Sized),
     ids: impl Iterator<Item = DataIdentifierBorrowed<'a>>,
 ) -> Result<DataResponse<M>, DataError> {
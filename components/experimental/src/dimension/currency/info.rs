@@ -0,0 +1,174 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{Decimal, RoundingIncrement, SignedRoundingMode, UnsignedRoundingMode};
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::currency::fractions::{CurrencyFractionsV1, Rounding};
+
+use super::CurrencyCode;
+use super::options::CurrencyUsage;
+
+/// Locale-independent ISO 4217 metadata about currencies, such as the number of
+/// fraction digits and the rounding increment.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::currency::info::CurrencyInfo;
+/// use icu::experimental::dimension::currency::options::CurrencyUsage;
+/// use icu::experimental::dimension::currency::CurrencyCode;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let info = CurrencyInfo::new();
+///
+/// let jpy = CurrencyCode(tinystr!(3, "JPY"));
+/// assert_eq!(info.fraction_digits(&jpy, CurrencyUsage::Standard), 0);
+///
+/// let chf = CurrencyCode(tinystr!(3, "CHF"));
+/// assert_eq!(info.fraction_digits(&chf, CurrencyUsage::Cash), 2);
+/// assert_eq!(info.rounding_increment(&chf, CurrencyUsage::Cash), 5);
+///
+/// let mut value = "12.337".parse().unwrap();
+/// info.round(&mut value, &chf, CurrencyUsage::Cash);
+/// assert_writeable_eq!(value, "12.35");
+/// ```
+#[derive(Debug)]
+pub struct CurrencyInfo {
+    fractions: DataPayload<CurrencyFractionsV1>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for CurrencyInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CurrencyInfo {
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    /// Creates a new [`CurrencyInfo`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            fractions: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_CURRENCY_FRACTIONS_V1,
+            ),
+        }
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: ?Sized + DataProvider<CurrencyFractionsV1>,
+    {
+        let fractions = provider.load(Default::default())?.payload;
+        Ok(Self { fractions })
+    }
+
+    /// Returns the number of fraction digits used for amounts of the given currency.
+    pub fn fraction_digits(&self, currency_code: &CurrencyCode, usage: CurrencyUsage) -> u8 {
+        let info = self.fractions.get().get(currency_code);
+        match usage {
+            CurrencyUsage::Standard => info.digits,
+            CurrencyUsage::Cash => info.cash_digits.unwrap_or(info.digits),
+        }
+    }
+
+    /// Returns the rounding increment of the given currency, in units of its smallest
+    /// fraction digit.
+    ///
+    /// For example, an increment of 5 with 2 fraction digits means that amounts are rounded
+    /// to multiples of 0.05.
+    pub fn rounding_increment(&self, currency_code: &CurrencyCode, usage: CurrencyUsage) -> u8 {
+        self.rounding(*currency_code, usage).increment()
+    }
+
+    fn rounding(&self, currency_code: CurrencyCode, usage: CurrencyUsage) -> Rounding {
+        let info = self.fractions.get().get(&currency_code);
+        match usage {
+            CurrencyUsage::Standard => info.rounding,
+            CurrencyUsage::Cash => info.cash_rounding.unwrap_or(info.rounding),
+        }
+    }
+
+    /// Rounds the given value according to the fraction digits and the rounding increment of
+    /// the given currency, and pads it with trailing zeros up to the number of fraction digits.
+    ///
+    /// Ties are rounded away from zero, which is also the default rounding mode of
+    /// [`DecimalFormatter`](icu_decimal::DecimalFormatter).
+    pub fn round(&self, value: &mut Decimal, currency_code: &CurrencyCode, usage: CurrencyUsage) {
+        let digits = i16::from(self.fraction_digits(currency_code, usage));
+        let (position, increment) = match self.rounding(*currency_code, usage) {
+            Rounding::R1 => (-digits, RoundingIncrement::MultiplesOf1),
+            Rounding::R5 => (-digits, RoundingIncrement::MultiplesOf5),
+            Rounding::R20 => (1 - digits, RoundingIncrement::MultiplesOf2),
+            Rounding::R50 => (1 - digits, RoundingIncrement::MultiplesOf5),
+        };
+        value.round_with_mode_and_increment(
+            position,
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+            increment,
+        );
+        value.absolute.pad_end(-digits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fixed_decimal::Decimal;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    use super::CurrencyInfo;
+    use crate::dimension::currency::CurrencyCode;
+    use crate::dimension::currency::options::CurrencyUsage;
+
+    #[test]
+    pub fn test_round() {
+        let info = CurrencyInfo::new();
+
+        let cases = [
+            ("USD", CurrencyUsage::Standard, "1234.5", "1234.50"),
+            ("USD", CurrencyUsage::Standard, "1234.565", "1234.57"),
+            ("USD", CurrencyUsage::Standard, "-0.125", "-0.13"),
+            ("JPY", CurrencyUsage::Standard, "1234.5", "1235"),
+            ("JPY", CurrencyUsage::Standard, "1235.5", "1236"),
+            ("CHF", CurrencyUsage::Standard, "1.234", "1.23"),
+            ("CHF", CurrencyUsage::Cash, "1.234", "1.25"),
+            ("CHF", CurrencyUsage::Cash, "1.224", "1.20"),
+            ("BHD", CurrencyUsage::Standard, "1.2", "1.200"),
+        ];
+
+        for (code, usage, input, expected) in cases {
+            let currency_code = CurrencyCode(tinystr::TinyAsciiStr::try_from_str(code).unwrap());
+            let mut value: Decimal = input.parse().unwrap();
+            info.round(&mut value, &currency_code, usage);
+            assert_writeable_eq!(value, expected, "{code} {usage:?} {input}");
+        }
+    }
+
+    #[test]
+    pub fn test_default_fractions() {
+        let info = CurrencyInfo::new();
+        let unknown = CurrencyCode(tinystr!(3, "XYZ"));
+        assert_eq!(info.fraction_digits(&unknown, CurrencyUsage::Standard), 2);
+        assert_eq!(info.fraction_digits(&unknown, CurrencyUsage::Cash), 2);
+        assert_eq!(info.rounding_increment(&unknown, CurrencyUsage::Cash), 1);
+    }
+}
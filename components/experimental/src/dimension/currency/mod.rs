@@ -6,15 +6,18 @@ use tinystr::TinyAsciiStr;
 
 pub mod compact_format;
 pub mod compact_formatter;
+pub mod display_names;
 pub mod format;
 pub mod formatter;
+pub mod info;
 pub mod long_compact_format;
 pub mod long_compact_formatter;
 pub mod long_format;
 pub mod long_formatter;
 pub mod options;
+pub mod range_formatter;
 
 /// A currency code, such as "USD" or "EUR".
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(clippy::exhaustive_structs)] // newtype
 pub struct CurrencyCode(pub TinyAsciiStr<3>);
@@ -15,11 +15,26 @@ use serde::{Deserialize, Serialize};
 pub struct CurrencyFormatterOptions {
     /// The width of the currency format.
     pub width: Width,
+
+    /// The usage of the currency amount, which determines how it is rounded.
+    pub usage: CurrencyUsage,
 }
 
 impl From<Width> for CurrencyFormatterOptions {
     fn from(width: Width) -> Self {
-        Self { width }
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CurrencyUsage> for CurrencyFormatterOptions {
+    fn from(usage: CurrencyUsage) -> Self {
+        Self {
+            usage,
+            ..Default::default()
+        }
     }
 }
 
@@ -43,3 +58,26 @@ pub enum Width {
     #[cfg_attr(feature = "serde", serde(rename = "narrow"))]
     Narrow,
 }
+
+/// The context in which a currency amount is used.
+///
+/// This determines the number of fraction digits and the rounding increment applied to the
+/// amount, as specified in the CLDR `currencyData`.
+#[derive(Default, Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum CurrencyUsage {
+    /// Round the amount as used in accounting and most other contexts.
+    ///
+    /// For example, 1.234 CHF is rounded to 1.23.
+    #[cfg_attr(feature = "serde", serde(rename = "standard"))]
+    #[default]
+    Standard,
+
+    /// Round the amount as used for cash transactions.
+    ///
+    /// For example, 1.234 CHF is rounded to 1.25, since the smallest coin is
+    /// 5 centimes.
+    #[cfg_attr(feature = "serde", serde(rename = "cash"))]
+    Cash,
+}
@@ -0,0 +1,250 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::FormattedUnsignedDecimal;
+use icu_pattern::{DoublePlaceholderKey, DoublePlaceholderPattern, PatternItem};
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable, impl_display_with_writeable};
+
+use super::super::provider::currency::essentials::CurrencyEssentialsV1;
+use super::super::provider::currency::fractions::CurrencyFractionsV1;
+use super::super::provider::range::DecimalRangePatternsV1;
+use super::super::range::format::{FormattedRange, RangePattern};
+use super::super::range::options::RangeFormatterOptions;
+use super::CurrencyCode;
+use super::formatter::{CurrencyFormatter, CurrencyFormatterPreferences, FormattedAmount};
+use super::options::CurrencyFormatterOptions;
+
+/// A formatter for ranges of monetary values, such as "$3–5".
+///
+/// If neither end of the range is negative, the currency symbol is only shown once.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::currency::range_formatter::CurrencyRangeFormatter;
+/// use icu::experimental::dimension::currency::CurrencyCode;
+/// use icu::locale::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = CurrencyRangeFormatter::try_new(
+///     locale!("en").into(),
+///     Default::default(),
+///     Default::default(),
+/// )
+/// .unwrap();
+/// let usd = CurrencyCode(tinystr!(3, "USD"));
+///
+/// let three = "3".parse().unwrap();
+/// let five = "5".parse().unwrap();
+/// let minus_five = "-5".parse().unwrap();
+///
+/// assert_writeable_eq!(
+///     formatter.format_fixed_decimal(&three, &five, &usd),
+///     "$3.00–5.00"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format_fixed_decimal(&minus_five, &three, &usd),
+///     "-$5.00 – $3.00"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CurrencyRangeFormatter {
+    options: RangeFormatterOptions,
+    patterns: DataPayload<DecimalRangePatternsV1>,
+    currency_formatter: CurrencyFormatter,
+}
+
+impl CurrencyRangeFormatter {
+    /// Creates a new [`CurrencyRangeFormatter`] from compiled locale data.
+    ///
+    /// The amounts are formatted like a [`CurrencyFormatter`] with `currency_options` does.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: CurrencyFormatterPreferences,
+        currency_options: CurrencyFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = DecimalRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = crate::provider::Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let currency_formatter = CurrencyFormatter::try_new(prefs, currency_options)?;
+
+        Ok(Self {
+            options,
+            patterns,
+            currency_formatter,
+        })
+    }
+
+    /// Creates a new [`CurrencyRangeFormatter`] from a [`BufferProvider`].
+    ///
+    /// ✨ *Enabled with the `serde` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// [`BufferProvider`]: icu_provider::buf::BufferProvider
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        prefs: CurrencyFormatterPreferences,
+        currency_options: CurrencyFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        Self::try_new_unstable(
+            &provider.as_deserializing(),
+            prefs,
+            currency_options,
+            options,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: CurrencyFormatterPreferences,
+        currency_options: CurrencyFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalRangePatternsV1>
+            + DataProvider<CurrencyEssentialsV1>
+            + DataProvider<CurrencyFractionsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>,
+    {
+        let locale = DecimalRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let currency_formatter =
+            CurrencyFormatter::try_new_unstable(provider, prefs, currency_options)?;
+
+        Ok(Self {
+            options,
+            patterns,
+            currency_formatter,
+        })
+    }
+
+    /// Formats the range from `start` to `end` in the given currency.
+    ///
+    /// Both values are rounded like [`CurrencyFormatter::format_fixed_decimal`] does.
+    pub fn format_fixed_decimal<'l>(
+        &'l self,
+        start: &Decimal,
+        end: &Decimal,
+        currency_code: &'l CurrencyCode,
+    ) -> FormattedCurrencyRange<'l> {
+        let patterns = self.patterns.get();
+        FormattedCurrencyRange(FormattedRange::new(
+            self.options.identity_fallback,
+            &patterns.approximately,
+            (
+                start,
+                self.currency_formatter.format_amount(start, currency_code),
+            ),
+            (
+                end,
+                self.currency_formatter.format_amount(end, currency_code),
+            ),
+            |start, end| {
+                if start.value.sign != Sign::None || end.value.sign != Sign::None {
+                    return CurrencyRange::Full(RangePattern {
+                        pattern: &patterns.range_spaced,
+                        start,
+                        end,
+                    });
+                }
+                let decimal_formatter = start.decimal_formatter;
+                CurrencyRange::Collapsed {
+                    pattern: start.pattern,
+                    currency_str: start.currency_str,
+                    range: RangePattern {
+                        pattern: &patterns.range,
+                        start: decimal_formatter
+                            .format_unsigned(icu_decimal::Cow::Owned(start.value.absolute)),
+                        end: decimal_formatter
+                            .format_unsigned(icu_decimal::Cow::Owned(end.value.absolute)),
+                    },
+                }
+            },
+        ))
+    }
+}
+
+/// A range of monetary values formatted by [`CurrencyRangeFormatter`].
+#[derive(Debug)]
+pub struct FormattedCurrencyRange<'l>(FormattedRange<'l, FormattedAmount<'l>, CurrencyRange<'l>>);
+
+impl Writeable for FormattedCurrencyRange<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        self.0.write_to_parts(sink)
+    }
+}
+
+impl_display_with_writeable!(FormattedCurrencyRange<'_>);
+
+#[derive(Debug)]
+enum CurrencyRange<'l> {
+    /// A range of unsigned amounts sharing the currency symbol, such as "$3–5".
+    Collapsed {
+        pattern: &'l DoublePlaceholderPattern,
+        currency_str: &'l str,
+        range: RangePattern<'l, FormattedUnsignedDecimal<'l>, FormattedUnsignedDecimal<'l>>,
+    },
+    /// A range of complete amounts, such as "-$5 – -$3".
+    Full(RangePattern<'l, FormattedAmount<'l>, FormattedAmount<'l>>),
+}
+
+impl Writeable for CurrencyRange<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        match self {
+            Self::Collapsed {
+                pattern,
+                currency_str,
+                range,
+            } => {
+                for item in pattern.iter() {
+                    match item {
+                        PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                            range.write_to_parts(sink)?
+                        }
+                        PatternItem::Placeholder(DoublePlaceholderKey::Place1) => {
+                            sink.write_str(currency_str)?
+                        }
+                        PatternItem::Literal(literal) => sink.write_str(literal)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Full(range) => range.write_to_parts(sink),
+        }
+    }
+}
@@ -12,6 +12,7 @@
 pub mod currency;
 pub mod percent;
 pub mod provider;
+pub mod range;
 pub mod units;
 
 /// Locale preferences used by this crate
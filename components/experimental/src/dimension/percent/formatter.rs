@@ -83,7 +83,7 @@ impl PercentFormatter<DecimalFormatter> {
     where
         D: ?Sized
             + DataProvider<PercentEssentialsV1>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>,
     {
         let decimal_formatter = DecimalFormatter::try_new_unstable(
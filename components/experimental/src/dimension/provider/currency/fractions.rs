@@ -6,6 +6,7 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use crate::dimension::currency::CurrencyCode;
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
 use zerovec::ZeroMap;
@@ -50,6 +51,15 @@ pub struct CurrencyFractions<'data> {
 
 icu_provider::data_struct!(CurrencyFractions<'_>, #[cfg(feature = "datagen")]);
 
+impl CurrencyFractions<'_> {
+    /// Returns the fraction info for the given currency, falling back to the default one.
+    pub fn get(&self, currency: &CurrencyCode) -> FractionInfo {
+        self.fractions
+            .get_copied(&currency.0.to_unvalidated())
+            .unwrap_or(self.default)
+    }
+}
+
 /// Fraction and rounding information for a currency.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
@@ -83,3 +93,15 @@ pub enum Rounding {
     /// Round to 50 smallest units
     R50,
 }
+
+impl Rounding {
+    /// Returns the rounding increment, in smallest units of the currency.
+    pub fn increment(self) -> u8 {
+        match self {
+            Rounding::R1 => 1,
+            Rounding::R5 => 5,
+            Rounding::R20 => 20,
+            Rounding::R50 => 50,
+        }
+    }
+}
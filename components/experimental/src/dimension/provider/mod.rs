@@ -7,4 +7,5 @@
 
 pub mod currency;
 pub mod percent;
+pub mod range;
 pub mod units;
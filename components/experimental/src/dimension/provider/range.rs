@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
+use icu_provider::prelude::*;
+use zerovec::VarZeroCow;
+
+icu_provider::data_marker!(
+    /// `DecimalRangePatternsV1`
+    DecimalRangePatternsV1,
+    RangePatterns<'static>
+);
+
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::range))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// The patterns used to format number ranges and approximate numbers.
+///
+/// See <https://www.unicode.org/reports/tr35/tr35-numbers.html#Miscellaneous_Patterns>
+pub struct RangePatterns<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    /// The pattern for a range of numbers, such as `{0}–{1}`.
+    ///
+    /// Placeholder 0 is the start of the range, placeholder 1 is the end of the range.
+    pub range: VarZeroCow<'data, DoublePlaceholderPattern>,
+
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    /// The pattern for a range whose ends carry signs or affixes, such as `{0} – {1}`.
+    ///
+    /// This is [`Self::range`] with spaces around the range separator, unless the separator
+    /// already contains them, so that ranges like "-5 – -3" stay readable.
+    pub range_spaced: VarZeroCow<'data, DoublePlaceholderPattern>,
+
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    /// The pattern for an approximate number, such as `~{0}`.
+    pub approximately: VarZeroCow<'data, SinglePlaceholderPattern>,
+}
+
+icu_provider::data_struct!(RangePatterns<'_>, #[cfg(feature = "datagen")]);
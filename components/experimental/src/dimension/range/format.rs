@@ -0,0 +1,190 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::FormattedDecimal;
+use icu_decimal::parts;
+use icu_pattern::SinglePlaceholderPattern;
+use icu_pattern::{DoublePlaceholderKey, DoublePlaceholderPattern, PatternItem};
+use writeable::{Part, PartsWrite, Writeable, impl_display_with_writeable};
+
+use super::options::RangeIdentityFallback;
+use crate::dimension::provider::range::RangePatterns;
+
+/// A range of numbers formatted by [`DecimalRangeFormatter`](super::formatter::DecimalRangeFormatter).
+#[derive(Debug)]
+pub struct FormattedDecimalRange<'l>(
+    pub(crate) FormattedRange<'l, FormattedDecimal<'l>, DecimalRange<'l>>,
+);
+
+pub(crate) type DecimalRange<'l> = RangePattern<'l, FormattedDecimal<'l>, FormattedDecimal<'l>>;
+
+impl Writeable for FormattedDecimalRange<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        self.0.write_to_parts(sink)
+    }
+}
+
+impl_display_with_writeable!(FormattedDecimalRange<'_>);
+
+/// A formatted range, where `S` is the formatted start and `R` the formatted range.
+///
+/// If the start and end of the range are formatted identically, only the start is
+/// rendered, depending on the [`RangeIdentityFallback`].
+#[derive(Debug)]
+pub(crate) enum FormattedRange<'l, S, R> {
+    /// The start on its own, such as "5".
+    Single(S),
+    /// The start with the approximately pattern, such as "~5".
+    Approximately(&'l SinglePlaceholderPattern, S),
+    /// The whole range, such as "3–5".
+    Range(R),
+}
+
+impl<'l, S: Writeable, R> FormattedRange<'l, S, R> {
+    /// Resolves the range from `start` to `end`, which are formatted as `formatted_start`
+    /// and `formatted_end`.
+    ///
+    /// The unformatted values are used to tell whether the ends of the range only became
+    /// identical through rounding. `range` builds the formatted range if the ends differ.
+    pub(crate) fn new<E: Writeable>(
+        identity_fallback: RangeIdentityFallback,
+        approximately: &'l SinglePlaceholderPattern,
+        (start, formatted_start): (&Decimal, S),
+        (end, formatted_end): (&Decimal, E),
+        range: impl FnOnce(S, E) -> R,
+    ) -> Self {
+        if identity_fallback == RangeIdentityFallback::Range
+            || writeable::cmp_str(&formatted_start, &formatted_end.write_to_string())
+                != Ordering::Equal
+        {
+            return Self::Range(range(formatted_start, formatted_end));
+        }
+        match identity_fallback {
+            RangeIdentityFallback::SingleValue => Self::Single(formatted_start),
+            RangeIdentityFallback::ApproximatelyOrSingleValue if is_same_value(start, end) => {
+                Self::Single(formatted_start)
+            }
+            _ => Self::Approximately(approximately, formatted_start),
+        }
+    }
+}
+
+impl<S: Writeable, R: Writeable> Writeable for FormattedRange<'_, S, R> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        match self {
+            Self::Single(value) => value.write_to_parts(sink),
+            Self::Approximately(pattern, value) => interpolate_single(pattern, value, sink),
+            Self::Range(range) => range.write_to_parts(sink),
+        }
+    }
+}
+
+/// The start and end of a range interpolated into the range pattern.
+#[derive(Debug)]
+pub(crate) struct RangePattern<'l, S, E> {
+    pub(crate) pattern: &'l DoublePlaceholderPattern,
+    pub(crate) start: S,
+    pub(crate) end: E,
+}
+
+impl<'l, S: Writeable, E: Writeable> RangePattern<'l, S, E> {
+    /// Creates a [`RangePattern`] for the formatted ends of a range.
+    ///
+    /// Like ICU, the [spaced pattern](RangePatterns::range_spaced) is used when either end
+    /// carries a sign, as in "-5 – -3", which would otherwise be hard to read.
+    pub(crate) fn new(patterns: &'l RangePatterns, start: S, end: E) -> Self {
+        let pattern = if has_sign(&start) || has_sign(&end) {
+            &patterns.range_spaced
+        } else {
+            &patterns.range
+        };
+        Self {
+            pattern,
+            start,
+            end,
+        }
+    }
+}
+
+impl<S: Writeable, E: Writeable> Writeable for RangePattern<'_, S, E> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        for item in self.pattern.iter() {
+            match item {
+                PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                    self.start.write_to_parts(sink)?
+                }
+                PatternItem::Placeholder(DoublePlaceholderKey::Place1) => {
+                    self.end.write_to_parts(sink)?
+                }
+                PatternItem::Literal(literal) => sink.write_str(literal)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Interpolates `value` into a [`SinglePlaceholderPattern`] without taking ownership of it.
+pub(crate) fn interpolate_single<W>(
+    pattern: &SinglePlaceholderPattern,
+    value: &impl Writeable,
+    sink: &mut W,
+) -> fmt::Result
+where
+    W: PartsWrite + ?Sized,
+{
+    for item in pattern.iter() {
+        match item {
+            PatternItem::Placeholder(_) => value.write_to_parts(sink)?,
+            PatternItem::Literal(literal) => sink.write_str(literal)?,
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the two values are numerically equal, ignoring trailing zeros.
+fn is_same_value(a: &Decimal, b: &Decimal) -> bool {
+    let a_abs = a.absolute.clone().trimmed_start().trimmed_end();
+    let b_abs = b.absolute.clone().trimmed_start().trimmed_end();
+    a_abs == b_abs && (a_abs.is_zero() || (a.sign == Sign::Negative) == (b.sign == Sign::Negative))
+}
+
+/// Returns whether the formatted number contains a plus or minus sign.
+fn has_sign(formatted: &impl Writeable) -> bool {
+    struct SignDetector(bool);
+
+    impl Write for SignDetector {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Ok(())
+        }
+    }
+
+    impl PartsWrite for SignDetector {
+        type SubPartsWrite = Self;
+
+        fn with_part(
+            &mut self,
+            part: Part,
+            mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+        ) -> fmt::Result {
+            self.0 |= part == parts::MINUS_SIGN || part == parts::PLUS_SIGN;
+            f(self)
+        }
+    }
+
+    let mut detector = SignDetector(false);
+    formatted.write_to_parts(&mut detector).is_ok() && detector.0
+}
@@ -0,0 +1,145 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_provider::prelude::*;
+
+use super::format::{FormattedDecimalRange, FormattedRange, RangePattern};
+use super::options::RangeFormatterOptions;
+use crate::dimension::provider::range::DecimalRangePatternsV1;
+
+/// A formatter for ranges of numbers, such as "3–5".
+///
+/// If the start and end of a range are formatted identically, the range is formatted
+/// according to the [`RangeIdentityFallback`](super::options::RangeIdentityFallback) option,
+/// for example as "~5".
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::range::formatter::DecimalRangeFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = DecimalRangeFormatter::try_new(
+///     locale!("en").into(),
+///     Default::default(),
+///     Default::default(),
+/// )
+/// .unwrap();
+///
+/// let three = "3".parse().unwrap();
+/// let five = "5".parse().unwrap();
+/// let minus_five = "-5".parse().unwrap();
+///
+/// assert_writeable_eq!(formatter.format(&three, &five), "3–5");
+/// assert_writeable_eq!(formatter.format(&five, &five), "~5");
+/// assert_writeable_eq!(formatter.format(&minus_five, &three), "-5 – 3");
+/// ```
+#[derive(Debug)]
+pub struct DecimalRangeFormatter {
+    options: RangeFormatterOptions,
+    patterns: DataPayload<DecimalRangePatternsV1>,
+    decimal_formatter: DecimalFormatter,
+}
+
+impl DecimalRangeFormatter {
+    /// Creates a new [`DecimalRangeFormatter`] from compiled locale data.
+    ///
+    /// The numbers are formatted with a [`DecimalFormatter`] using `decimal_options`.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: DecimalFormatterPreferences,
+        decimal_options: DecimalFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = DecimalRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = crate::provider::Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter = DecimalFormatter::try_new(prefs, decimal_options)?;
+
+        Ok(Self {
+            options,
+            patterns,
+            decimal_formatter,
+        })
+    }
+
+    /// Creates a new [`DecimalRangeFormatter`] from a [`BufferProvider`].
+    ///
+    /// ✨ *Enabled with the `serde` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// [`BufferProvider`]: icu_provider::buf::BufferProvider
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        prefs: DecimalFormatterPreferences,
+        decimal_options: DecimalFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        Self::try_new_unstable(
+            &provider.as_deserializing(),
+            prefs,
+            decimal_options,
+            options,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+        decimal_options: DecimalFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalRangePatternsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>,
+    {
+        let locale = DecimalRangePatternsV1::make_locale(prefs.locale_preferences);
+        let patterns = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter =
+            DecimalFormatter::try_new_unstable(provider, prefs, decimal_options)?;
+
+        Ok(Self {
+            options,
+            patterns,
+            decimal_formatter,
+        })
+    }
+
+    /// Formats the range from `start` to `end`.
+    pub fn format<'l>(&'l self, start: &'l Decimal, end: &'l Decimal) -> FormattedDecimalRange<'l> {
+        let patterns = self.patterns.get();
+        FormattedDecimalRange(FormattedRange::new(
+            self.options.identity_fallback,
+            &patterns.approximately,
+            (start, self.decimal_formatter.format(start)),
+            (end, self.decimal_formatter.format(end)),
+            |start, end| RangePattern::new(patterns, start, end),
+        ))
+    }
+}
@@ -0,0 +1,15 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of number ranges, such as "3–5", and approximate numbers, such as "~5".
+//!
+//! Ranges of plain numbers are formatted with [`DecimalRangeFormatter`](formatter::DecimalRangeFormatter),
+//! ranges of monetary amounts with
+//! [`CurrencyRangeFormatter`](crate::dimension::currency::range_formatter::CurrencyRangeFormatter),
+//! and ranges of measurements with
+//! [`UnitsRangeFormatter`](crate::dimension::units::range_formatter::UnitsRangeFormatter).
+
+pub mod format;
+pub mod formatter;
+pub mod options;
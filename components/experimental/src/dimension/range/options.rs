@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for the range formatters in [`crate::dimension::range`].
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A collection of configuration options that determine the formatting behavior of
+/// the range formatters.
+#[derive(Copy, Debug, Eq, PartialEq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub struct RangeFormatterOptions {
+    /// How to format a range whose start and end are formatted identically.
+    pub identity_fallback: RangeIdentityFallback,
+}
+
+impl From<RangeIdentityFallback> for RangeFormatterOptions {
+    fn from(identity_fallback: RangeIdentityFallback) -> Self {
+        Self { identity_fallback }
+    }
+}
+
+/// How to format a range whose start and end are formatted identically, for example
+/// because they are equal or because they round to the same value.
+///
+/// These correspond to the `rangeIdentityFallback` behaviors of ECMA-402.
+#[derive(Default, Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum RangeIdentityFallback {
+    /// Format the value on its own.
+    ///
+    /// For example, the range from 5 to 5 formats as "5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "singleValue"))]
+    SingleValue,
+
+    /// Format the value on its own if the start and end are equal, and with the
+    /// approximately pattern if they only became equal through rounding.
+    ///
+    /// For example, the range from 5 to 5 formats as "5" in en-US, whereas the range
+    /// from 4.9 to 5.1 formats as "~5" when rounding to integers.
+    #[cfg_attr(feature = "serde", serde(rename = "approximatelyOrSingleValue"))]
+    ApproximatelyOrSingleValue,
+
+    /// Format the value with the approximately pattern.
+    ///
+    /// For example, the range from 5 to 5 formats as "~5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "approximately"))]
+    #[default]
+    Approximately,
+
+    /// Format the range as usual.
+    ///
+    /// For example, the range from 5 to 5 formats as "5–5" in en-US.
+    #[cfg_attr(feature = "serde", serde(rename = "range"))]
+    Range,
+}
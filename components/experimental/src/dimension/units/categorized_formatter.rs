@@ -136,7 +136,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
     ) -> Result<FormattingInfo, DataError>
     where
         D: ?Sized
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -203,7 +203,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
     where
         D: ?Sized
             + DataProvider<C::DataMarkerCore>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -291,7 +291,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
         D: ?Sized
             + DataProvider<C::DataMarkerCore>
             + DataProvider<C::DataMarkerExtended>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -388,7 +388,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             + DataProvider<C::DataMarkerCore>
             + DataProvider<C::DataMarkerExtended>
             + DataProvider<C::DataMarkerOutlier>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -72,7 +72,7 @@ impl UnitsFormatter {
 
     // TODO: Remove this function once we have separate markers for different widths.
     #[inline]
-    fn attribute(width: Width, unit: &str) -> SmallVec<[u8; 32]> {
+    pub(crate) fn attribute(width: Width, unit: &str) -> SmallVec<[u8; 32]> {
         let mut buffer: SmallVec<[u8; 32]> = SmallVec::new();
         let length = match width {
             Width::Short => "short-",
@@ -134,7 +134,7 @@ impl UnitsFormatter {
     where
         D: ?Sized
             + DataProvider<UnitsDisplayNamesV1>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
             + DataProvider<icu_decimal::provider::DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     {
@@ -6,3 +6,4 @@ pub mod categorized_formatter;
 pub mod format;
 pub mod formatter;
 pub mod options;
+pub mod range_formatter;
@@ -0,0 +1,251 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+
+use fixed_decimal::Decimal;
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::{DecimalFormatter, FormattedDecimal};
+use icu_pattern::SinglePlaceholderPattern;
+use icu_plurals::{PluralRules, PluralRulesWithRanges};
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable, impl_display_with_writeable};
+
+use super::format::FormattedUnit;
+use super::formatter::{UnitsFormatter, UnitsFormatterPreferences};
+use super::options::UnitsFormatterOptions;
+use crate::dimension::provider::range::DecimalRangePatternsV1;
+use crate::dimension::provider::units::display_names::UnitsDisplayNamesV1;
+use crate::dimension::range::format::{FormattedRange, RangePattern, interpolate_single};
+use crate::dimension::range::options::RangeFormatterOptions;
+
+/// A formatter for ranges of measurement unit values, such as "3–5 kg".
+///
+/// The unit is only shown once, with the plural form for the range.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::units::range_formatter::UnitsRangeFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = UnitsRangeFormatter::try_new(
+///     locale!("en").into(),
+///     "kilogram",
+///     Default::default(),
+///     Default::default(),
+/// )
+/// .unwrap();
+///
+/// let three = "3".parse().unwrap();
+/// let five = "5".parse().unwrap();
+///
+/// assert_writeable_eq!(formatter.format_fixed_decimal(&three, &five), "3–5 kg");
+/// assert_writeable_eq!(formatter.format_fixed_decimal(&five, &five), "~5 kg");
+/// ```
+#[derive(Debug)]
+pub struct UnitsRangeFormatter {
+    options: RangeFormatterOptions,
+    patterns: DataPayload<DecimalRangePatternsV1>,
+    display_name: DataPayload<UnitsDisplayNamesV1>,
+    decimal_formatter: DecimalFormatter,
+    plural_rules: PluralRulesWithRanges<PluralRules>,
+}
+
+impl UnitsRangeFormatter {
+    /// Creates a new [`UnitsRangeFormatter`] from compiled locale data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: UnitsFormatterPreferences,
+        unit: &str,
+        units_options: UnitsFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsDisplayNamesV1::make_locale(prefs.locale_preferences);
+        let patterns = crate::provider::Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter =
+            DecimalFormatter::try_new((&prefs).into(), DecimalFormatterOptions::default())?;
+
+        let plural_rules = PluralRulesWithRanges::try_new_cardinal((&prefs).into())?;
+
+        // TODO: Remove this allocation once we have separate markers for different widths.
+        let attribute = UnitsFormatter::attribute(units_options.width, unit);
+        let unit_attribute = DataMarkerAttributes::try_from_utf8(&attribute[..attribute.len()])
+            .map_err(|_| DataError::custom("Failed to create a data marker"))?;
+
+        let display_name = crate::provider::Baked
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    unit_attribute,
+                    &locale,
+                ),
+                ..Default::default()
+            })?
+            .payload;
+
+        Ok(Self {
+            options,
+            patterns,
+            display_name,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    /// Creates a new [`UnitsRangeFormatter`] from a [`BufferProvider`].
+    ///
+    /// ✨ *Enabled with the `serde` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// [`BufferProvider`]: icu_provider::buf::BufferProvider
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        prefs: UnitsFormatterPreferences,
+        unit: &str,
+        units_options: UnitsFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError> {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        Self::try_new_unstable(
+            &provider.as_deserializing(),
+            prefs,
+            unit,
+            units_options,
+            options,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsFormatterPreferences,
+        unit: &str,
+        units_options: UnitsFormatterOptions,
+        options: RangeFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalRangePatternsV1>
+            + DataProvider<UnitsDisplayNamesV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>
+            + DataProvider<icu_plurals::provider::PluralsRangesV1>,
+    {
+        let locale = UnitsDisplayNamesV1::make_locale(prefs.locale_preferences);
+        let patterns = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        let decimal_formatter = DecimalFormatter::try_new_unstable(
+            provider,
+            (&prefs).into(),
+            DecimalFormatterOptions::default(),
+        )?;
+
+        let plural_rules =
+            PluralRulesWithRanges::try_new_cardinal_unstable(provider, (&prefs).into())?;
+
+        // TODO: Remove this allocation once we have separate markers for different widths.
+        let attribute = UnitsFormatter::attribute(units_options.width, unit);
+        let unit_attribute = DataMarkerAttributes::try_from_utf8(&attribute[..attribute.len()])
+            .map_err(|_| DataError::custom("Failed to create a data marker"))?;
+
+        let display_name = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    unit_attribute,
+                    &locale,
+                ),
+                ..Default::default()
+            })?
+            .payload;
+
+        Ok(Self {
+            options,
+            patterns,
+            display_name,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    /// Formats the range from `start` to `end` in the unit of this formatter.
+    pub fn format_fixed_decimal<'l>(
+        &'l self,
+        start: &'l Decimal,
+        end: &'l Decimal,
+    ) -> FormattedUnitRange<'l> {
+        let patterns = self.patterns.get();
+        let display_name = self.display_name.get();
+        let unit = |value| FormattedUnit {
+            value,
+            display_name,
+            decimal_formatter: &self.decimal_formatter,
+            plural_rules: self.plural_rules.rules(),
+        };
+        FormattedUnitRange(FormattedRange::new(
+            self.options.identity_fallback,
+            &patterns.approximately,
+            (start, unit(start)),
+            (end, unit(end)),
+            |_, _| {
+                let category = self.plural_rules.category_for_range(start, end);
+                let start = self.decimal_formatter.format(start);
+                let end = self.decimal_formatter.format(end);
+                UnitRange {
+                    pattern: display_name.patterns.get_for_category(category),
+                    range: RangePattern::new(patterns, start, end),
+                }
+            },
+        ))
+    }
+}
+
+/// A range of measurement unit values formatted by [`UnitsRangeFormatter`].
+#[derive(Debug)]
+pub struct FormattedUnitRange<'l>(FormattedRange<'l, FormattedUnit<'l>, UnitRange<'l>>);
+
+impl Writeable for FormattedUnitRange<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        self.0.write_to_parts(sink)
+    }
+}
+
+impl_display_with_writeable!(FormattedUnitRange<'_>);
+
+/// A range of numbers interpolated into the unit pattern, such as "3–5 kg".
+#[derive(Debug)]
+struct UnitRange<'l> {
+    pattern: &'l SinglePlaceholderPattern,
+    range: RangePattern<'l, FormattedDecimal<'l>, FormattedDecimal<'l>>,
+}
+
+impl Writeable for UnitRange<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        interpolate_single(self.pattern, &self.range, sink)
+    }
+}
@@ -13,7 +13,7 @@ use super::validated_options::Unit;
 use super::{Duration, provider};
 
 pub use super::validated_options::ValidatedDurationFormatterOptions;
-use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV2};
 use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
 use icu_list::{ListFormatter, ListFormatterPreferences, options::ListLength};
 use icu_locale_core::preferences::{
@@ -138,7 +138,7 @@ impl DurationUnitFormatter {
     fn try_new_unstable<
         D: ?Sized
             + DataProvider<UnitsDisplayNamesV1>
-            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
     >(
@@ -234,7 +234,7 @@ impl DurationFormatter {
     pub fn try_new_unstable<
         D: DataProvider<provider::DigitalDurationDataV1>
             + DataProvider<UnitsDisplayNamesV1>
-            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalSymbolsV2>
             + DataProvider<DecimalDigitsV1>
             + DataProvider<icu_plurals::provider::PluralsCardinalV1>
             + DataProvider<icu_list::provider::ListUnitV1>
@@ -32,6 +32,7 @@ pub mod displaynames;
 pub mod duration;
 pub mod measure;
 pub mod personnames;
+pub mod regioninfo;
 pub mod relativetime;
 pub mod transliterate;
 pub mod units;
@@ -98,6 +99,10 @@ pub mod provider {
         impl_locale_names_variant_medium_v1!(Baked);
         impl_locale_names_essentials_v1!(Baked);
         impl_percent_essentials_v1!(Baked);
+        impl_decimal_range_patterns_v1!(Baked);
+        impl_region_containment_v1!(Baked);
+        impl_region_currencies_v1!(Baked);
+        impl_territory_info_v1!(Baked);
         impl_person_names_format_v1!(Baked);
         impl_long_day_relative_v1!(Baked);
         impl_long_hour_relative_v1!(Baked);
@@ -141,6 +146,7 @@ pub mod provider {
         super::dimension::provider::currency::extended::CurrencyExtendedDataV1::INFO,
         super::dimension::provider::currency::fractions::CurrencyFractionsV1::INFO,
         super::dimension::provider::percent::PercentEssentialsV1::INFO,
+        super::dimension::provider::range::DecimalRangePatternsV1::INFO,
         super::dimension::provider::units::essentials::UnitsEssentialsV1::INFO,
         super::dimension::provider::units::display_names::UnitsDisplayNamesV1::INFO,
         super::dimension::provider::units::categorized_display_names::UnitsNamesAreaCoreV1::INFO,
@@ -176,6 +182,9 @@ pub mod provider {
         super::displaynames::provider::LocaleNamesEssentialsV1::INFO,
         super::measure::provider::UnitIdsV1::INFO,
         super::personnames::provider::PersonNamesFormatV1::INFO,
+        super::regioninfo::provider::RegionContainmentV1::INFO,
+        super::regioninfo::provider::RegionCurrenciesV1::INFO,
+        super::regioninfo::provider::TerritoryInfoV1::INFO,
         super::relativetime::provider::LongDayRelativeV1::INFO,
         super::relativetime::provider::LongHourRelativeV1::INFO,
         super::relativetime::provider::LongMinuteRelativeV1::INFO,
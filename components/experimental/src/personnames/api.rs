@@ -190,6 +190,14 @@ impl FieldModifierSet {
         }
     }
 
+    /// Returns a copy of the field modifier set with the formality changed.
+    pub(crate) fn with_formality(self, formality: FieldFormality) -> FieldModifierSet {
+        FieldModifierSet {
+            value: (self.value & (u32::MAX ^ FieldModifier::Informal.bit_value()))
+                | FieldModifier::from(formality).bit_value(),
+        }
+    }
+
     pub fn formality(formality: FieldFormality) -> Self {
         Self::new(
             FieldCapsStyle::Auto,
@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use writeable::{Part, PartsWrite, Writeable};
+
+use super::api::NameFieldKind;
+
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `title` field.
+    pub const TITLE: Part = Part {
+        category: "personname",
+        value: "title",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `given` field.
+    pub const GIVEN: Part = Part {
+        category: "personname",
+        value: "given",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `given2` field.
+    pub const GIVEN2: Part = Part {
+        category: "personname",
+        value: "given2",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `surname` field.
+    pub const SURNAME: Part = Part {
+        category: "personname",
+        value: "surname",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `surname2` field.
+    pub const SURNAME2: Part = Part {
+        category: "personname",
+        value: "surname2",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `generation` field.
+    pub const GENERATION: Part = Part {
+        category: "personname",
+        value: "generation",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from a `credentials` field.
+    pub const CREDENTIALS: Part = Part {
+        category: "personname",
+        value: "credentials",
+    };
+
+    /// The [`Part`] used by [`FormattedPersonName`](super::FormattedPersonName) to mark the
+    /// part of the string that comes from the pattern itself.
+    pub const LITERAL: Part = Part {
+        category: "personname",
+        value: "literal",
+    };
+}
+
+impl NameFieldKind {
+    /// The [`Part`] used to annotate the output of this field.
+    pub(crate) fn part(self) -> Part {
+        match self {
+            NameFieldKind::Title => parts::TITLE,
+            NameFieldKind::Given => parts::GIVEN,
+            NameFieldKind::Given2 => parts::GIVEN2,
+            NameFieldKind::Surname => parts::SURNAME,
+            NameFieldKind::Surname2 => parts::SURNAME2,
+            NameFieldKind::Generation => parts::GENERATION,
+            NameFieldKind::Credentials => parts::CREDENTIALS,
+        }
+    }
+}
+
+/// A person name formatted by [`PersonNamesFormatter`](super::PersonNamesFormatter).
+///
+/// This structure can be consumed via the [`Writeable`] trait to a string or buffer.
+/// Each name field is annotated with the corresponding [`parts`] value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedPersonName {
+    /// Field values and pattern literals, in display order.
+    pub(crate) segments: Vec<(Option<NameFieldKind>, String)>,
+    /// The string that replaces any whitespace sequence between two words.
+    pub(crate) space_replacement: String,
+}
+
+impl Writeable for FormattedPersonName {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        // Whitespace runs are collapsed to a single `space_replacement` and trimmed at both ends
+        // <https://www.unicode.org/reports/tr35/tr35-personNames.html#handle-missing-fields>
+        let mut pending_space = false;
+        let mut started = false;
+        for (kind, segment) in self.segments.iter() {
+            let mut rest = segment.as_str();
+            loop {
+                let trimmed = rest.trim_start();
+                pending_space |= trimmed.len() != rest.len();
+                if trimmed.is_empty() {
+                    break;
+                }
+                let word_end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+                if pending_space && started {
+                    sink.with_part(parts::LITERAL, |s| s.write_str(&self.space_replacement))?;
+                }
+                pending_space = false;
+                started = true;
+                let part = kind.map(NameFieldKind::part).unwrap_or(parts::LITERAL);
+                sink.with_part(part, |s| s.write_str(&trimmed[..word_end]))?;
+                rest = &trimmed[word_end..];
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedPersonName);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use writeable::assert_writeable_parts_eq;
+
+    #[test]
+    fn test_parts_and_space_collapsing() {
+        let formatted = FormattedPersonName {
+            segments: alloc::vec![
+                (Some(NameFieldKind::Surname), String::from("Wells")),
+                (None, String::from(",  ")),
+                (Some(NameFieldKind::Given), String::from("H. G.")),
+                (None, String::from(" ")),
+                (Some(NameFieldKind::Generation), String::new()),
+                (None, String::new()),
+            ],
+            space_replacement: String::from(" "),
+        };
+        assert_writeable_parts_eq!(
+            formatted,
+            "Wells, H. G.",
+            [
+                (0, 5, parts::SURNAME),
+                (5, 6, parts::LITERAL),
+                (6, 7, parts::LITERAL),
+                (7, 9, parts::GIVEN),
+                (9, 10, parts::LITERAL),
+                (10, 12, parts::GIVEN),
+            ]
+        );
+    }
+}
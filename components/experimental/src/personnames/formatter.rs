@@ -13,6 +13,7 @@ use super::api::{
     FormattingOrder, NameField, NameFieldKind, PersonName, PersonNamesFormatterError,
     PersonNamesFormatterOptions, PreferredOrder,
 };
+use super::format::FormattedPersonName;
 use super::provider::{
     PersonNamesFormat, PersonNamesFormatV1, PersonNamesFormattingAttributes,
     PersonNamesFormattingAttributesMask, PersonNamesFormattingData,
@@ -20,11 +21,55 @@ use super::provider::{
 use super::specifications;
 use icu_locale_core::Locale;
 use icu_provider::prelude::*;
+use writeable::Writeable;
 use zerofrom::ZeroFrom;
 
+/// A formatter that renders person names according to the rules of
+/// <https://www.unicode.org/reports/tr35/tr35-personNames.html>.
+///
+/// # Example
+///
+/// ```
+/// use icu::experimental::personnames::api::*;
+/// use icu::experimental::personnames::provided_struct::DefaultPersonName;
+/// use icu::experimental::personnames::PersonNamesFormatter;
+/// use icu::locale::locale;
+/// use litemap::LiteMap;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut person_data = LiteMap::new();
+/// person_data.insert(
+///     NameField {
+///         kind: NameFieldKind::Given,
+///         modifier: FieldModifierSet::default(),
+///     },
+///     String::from("Irene"),
+/// );
+/// person_data.insert(
+///     NameField {
+///         kind: NameFieldKind::Surname,
+///         modifier: FieldModifierSet::default(),
+///     },
+///     String::from("Adler"),
+/// );
+/// let person_name =
+///     DefaultPersonName::new(person_data, Some(locale!("en")), None).unwrap();
+///
+/// let formatter = PersonNamesFormatter::try_new(PersonNamesFormatterOptions::new(
+///     locale!("en"),
+///     FormattingOrder::Sorting,
+///     FormattingLength::Long,
+///     FormattingUsage::Referring,
+///     FormattingFormality::Formal,
+/// ))
+/// .unwrap();
+///
+/// assert_writeable_eq!(formatter.format(&person_name).unwrap(), "Adler, Irene");
+/// ```
 #[derive(Debug)]
 pub struct PersonNamesFormatter {
     pub(crate) default_options: PersonNamesFormatterOptions,
+    data: DataPayload<PersonNamesFormatV1>,
     swe: ScriptWithExtensions,
     scripts: PropertyNamesShort<Script>,
     fallbacker: LocaleFallbacker,
@@ -39,29 +84,102 @@ impl From<&PersonNamesFormatterOptions> for PersonNamesFormattingAttributesMask
     }
 }
 
+fn load_format<P>(
+    provider: &P,
+    locale: &Locale,
+) -> Result<DataPayload<PersonNamesFormatV1>, PersonNamesFormatterError>
+where
+    P: ?Sized + DataProvider<PersonNamesFormatV1>,
+{
+    Ok(provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&DataLocale::from(locale)),
+            ..Default::default()
+        })?
+        .payload)
+}
+
 impl PersonNamesFormatter {
+    /// Creates a new [`PersonNamesFormatter`] for the target locale of the options,
+    /// using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        options: PersonNamesFormatterOptions,
+    ) -> Result<PersonNamesFormatter, PersonNamesFormatterError> {
+        let data = load_format(&crate::provider::Baked, &options.target_locale)?;
+        Ok(PersonNamesFormatter {
+            default_options: options,
+            data,
+            swe: ScriptWithExtensions::new().static_to_owned(),
+            scripts: PropertyNamesShort::new().static_to_owned(),
+            fallbacker: LocaleFallbacker::new().static_to_owned(),
+        })
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (options: PersonNamesFormatterOptions) -> error: PersonNamesFormatterError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
     pub fn try_new_unstable<P>(
         provider: &P,
         options: PersonNamesFormatterOptions,
     ) -> Result<PersonNamesFormatter, PersonNamesFormatterError>
     where
         P: ?Sized
+            + DataProvider<PersonNamesFormatV1>
             + DataProvider<icu_properties::provider::PropertyScriptWithExtensionsV1>
             + DataProvider<icu_properties::provider::PropertyNameShortScriptV1>
             + DataProvider<icu_locale::provider::LocaleLikelySubtagsLanguageV1>
             + DataProvider<icu_locale::provider::LocaleParentsV1>,
     {
+        let data = load_format(provider, &options.target_locale)?;
         let swe = ScriptWithExtensions::try_new_unstable(provider)?;
         let scripts = PropertyNamesShort::try_new_unstable(provider)?;
         let fallbacker = LocaleFallbacker::try_new_unstable(provider)?;
         Ok(PersonNamesFormatter {
             default_options: options,
+            data,
             swe,
             scripts,
             fallbacker,
         })
     }
 
+    /// Formats a person name using the data of the formatter locale.
+    ///
+    /// This does not switch the formatting locale when the name is written in a script
+    /// that is incompatible with the formatter locale; use [`Self::format_to_string`]
+    /// with a data provider for that.
+    pub fn format<N>(
+        &self,
+        person_name: &N,
+    ) -> Result<FormattedPersonName, PersonNamesFormatterError>
+    where
+        N: PersonName,
+    {
+        let person_name_locale = self.person_name_locale(person_name)?;
+        self.format_with_data(
+            self.data.get(),
+            &self.default_options.target_locale,
+            &person_name_locale,
+            person_name,
+        )
+    }
+
+    /// Formats a person name to a string, loading the data for the effective locale of the name.
+    ///
+    /// <https://www.unicode.org/reports/tr35/tr35-personNames.html#switch-the-formatting-locale-if-necessary>
     pub fn format_to_string<P, N>(
         &self,
         provider: &P,
@@ -71,29 +189,51 @@ impl PersonNamesFormatter {
         P: ?Sized + DataProvider<PersonNamesFormatV1>,
         N: PersonName,
     {
-        let available_name_fields = person_name.available_name_fields();
-        if !validate_person_name(&available_name_fields) {
+        let person_name_locale = self.person_name_locale(person_name)?;
+        let effective_locale = specifications::effective_locale(
+            &self.default_options.target_locale,
+            &person_name_locale,
+        );
+        let data = load_format(provider, effective_locale)?;
+        Ok(self
+            .format_with_data(
+                data.get(),
+                effective_locale,
+                &person_name_locale,
+                person_name,
+            )?
+            .write_to_string()
+            .into_owned())
+    }
+
+    fn person_name_locale<N>(&self, person_name: &N) -> Result<Locale, PersonNamesFormatterError>
+    where
+        N: PersonName,
+    {
+        if !validate_person_name(&person_name.available_name_fields()) {
             return Err(PersonNamesFormatterError::InvalidPersonName);
         }
-        let person_name_locale = &specifications::likely_person_name_locale(
+        specifications::likely_person_name_locale(
             person_name,
             self.swe.as_borrowed(),
             self.scripts.as_borrowed(),
-        )?;
-        let effective_locale = specifications::effective_locale(
-            &self.default_options.target_locale,
-            person_name_locale,
-        );
+        )
+    }
 
-        let data: DataResponse<PersonNamesFormatV1> = provider
-            .load(DataRequest {
-                id: DataIdentifierBorrowed::for_locale(&DataLocale::from(effective_locale)),
-                ..Default::default()
-            })
-            .map_err(PersonNamesFormatterError::Data)?;
-        let formatting_definition: &PersonNamesFormat = data.payload.get();
+    fn format_with_data<N>(
+        &self,
+        formatting_definition: &PersonNamesFormat,
+        effective_locale: &Locale,
+        person_name_locale: &Locale,
+        person_name: &N,
+    ) -> Result<FormattedPersonName, PersonNamesFormatterError>
+    where
+        N: PersonName,
+    {
+        let available_name_fields = person_name.available_name_fields();
         let option_with_proper_name_order = self.final_person_names_formatter_options(
             effective_locale,
+            person_name_locale,
             person_name,
             formatting_definition,
         );
@@ -145,16 +285,22 @@ impl PersonNamesFormatter {
             .as_ref()
             .map(|f| f.as_ref())
             .unwrap_or("{0} {1}");
-        Ok(best_applicable_pattern
-            .format_person_name(person_name, initial_pattern, initial_sequence_pattern)
-            .split_whitespace()
-            .collect::<Vec<_>>()
-            .join(space_replacement))
+        Ok(FormattedPersonName {
+            segments: specifications::handle_missing_fields(
+                best_applicable_pattern.format_person_name_segments(
+                    person_name,
+                    initial_pattern,
+                    initial_sequence_pattern,
+                ),
+            ),
+            space_replacement: String::from(space_replacement),
+        })
     }
 
     fn final_person_names_formatter_options<N>(
         &self,
         locale: &Locale,
+        person_name_locale: &Locale,
         person_name: &N,
         formatting_definition: &PersonNamesFormat,
     ) -> PersonNamesFormatterOptions
@@ -186,20 +332,15 @@ impl PersonNamesFormatter {
                 usage: self.default_options.usage,
                 formality: self.default_options.formality,
             },
-            // TODO: https://www.unicode.org/reports/tr35/tr35-personNames.html#derive-the-name-order
+            // https://www.unicode.org/reports/tr35/tr35-personNames.html#derive-the-name-order
             _ => PersonNamesFormatterOptions {
                 target_locale: locale.clone(),
-                order: person_name
-                    .name_locale()
-                    .map(|l| {
-                        specifications::name_order_derive(
-                            l,
-                            &formatting_definition.surname_first_locales,
-                            &formatting_definition.given_first_locales,
-                            self.fallbacker.as_borrowed(),
-                        )
-                    })
-                    .unwrap_or(FormattingOrder::GivenFirst),
+                order: specifications::name_order_derive(
+                    person_name.name_locale().unwrap_or(person_name_locale),
+                    &formatting_definition.surname_first_locales,
+                    &formatting_definition.given_first_locales,
+                    self.fallbacker.as_borrowed(),
+                ),
                 length: self.default_options.length,
                 usage: self.default_options.usage,
                 formality: self.default_options.formality,
@@ -9,9 +9,12 @@
     clippy::unwrap_used
 )] // todo
 
+pub use format::FormattedPersonName;
+pub use format::parts;
 pub use formatter::PersonNamesFormatter;
 
 pub mod api;
+mod format;
 pub mod formatter;
 pub mod provided_struct;
 pub mod provider;
@@ -6,7 +6,7 @@ use alloc::string::String;
 use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
 use writeable::Writeable;
 
-use crate::personnames::api::{FieldLength, FieldModifier, NameField, PersonName};
+use crate::personnames::api::{FieldFormality, FieldLength, FieldModifier, NameField, PersonName};
 
 ///
 /// Derive missing initials from the name
@@ -24,15 +24,34 @@ pub fn derive_missing_initials(
         DoublePlaceholderPattern::try_from_str(initial_sequence_pattern_str, Default::default())
             .unwrap();
 
+    derive_field_value(
+        person_name,
+        requested_field,
+        &initial_pattern,
+        &initial_sequence_pattern,
+    )
+}
+
+fn derive_field_value(
+    person_name: &dyn PersonName,
+    requested_field: NameField,
+    initial_pattern: &SinglePlaceholderPattern,
+    initial_sequence_pattern: &DoublePlaceholderPattern,
+) -> String {
     if person_name.has_name_field(requested_field) {
         return String::from(person_name.get(requested_field));
     }
     if requested_field.modifier.has_field(FieldModifier::Initial) {
-        let initials = person_name
-            .get(NameField {
+        let full_value = derive_field_value(
+            person_name,
+            NameField {
                 kind: requested_field.kind,
                 modifier: requested_field.modifier.with_length(FieldLength::Auto),
-            })
+            },
+            initial_pattern,
+            initial_sequence_pattern,
+        );
+        let initials = full_value
             .split(' ')
             .filter_map(|s| s.trim().chars().next());
 
@@ -54,6 +73,21 @@ pub fn derive_missing_initials(
 
         return output;
     }
+    if requested_field.modifier.has_field(FieldModifier::Informal) {
+        // An informal field falls back to the formal one.
+        // <https://www.unicode.org/reports/tr35/tr35-personNames.html#choose-a-namepattern>
+        return derive_field_value(
+            person_name,
+            NameField {
+                kind: requested_field.kind,
+                modifier: requested_field
+                    .modifier
+                    .with_formality(FieldFormality::Auto),
+            },
+            initial_pattern,
+            initial_sequence_pattern,
+        );
+    }
     // If it had the field, it would have been returned earlier.
     String::from("")
 }
@@ -44,6 +44,10 @@ pub fn name_order_derive(
         {
             return FormattingOrder::SurnameFirst;
         }
+        if chain_locale.is_unknown() {
+            // The chain is exhausted without a match, the specification defaults to given first.
+            return FormattingOrder::GivenFirst;
+        }
         fallback_iterator.step();
     }
 }
@@ -136,4 +140,23 @@ mod tests {
             "failed for zh_Hans"
         );
     }
+
+    #[test]
+    fn test_no_match_terminates() {
+        let fallbacker = LocaleFallbacker::new();
+
+        let given_first = VarZeroVec::from(&["en"]);
+        let surname_first = VarZeroVec::from(&["ja"]);
+
+        assert_eq!(
+            name_order_derive(
+                &locale!("de-Latn-CH"),
+                &surname_first,
+                &given_first,
+                fallbacker
+            ),
+            FormattingOrder::GivenFirst,
+            "failed for de_Latn_CH"
+        );
+    }
 }
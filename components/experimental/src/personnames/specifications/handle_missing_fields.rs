@@ -0,0 +1,198 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::personnames::api::NameFieldKind;
+
+/// Removes the empty fields of a formatted pattern, following the rules of the
+/// "Handle missing fields" step of the formatting process.
+///
+/// Fields are tagged with their [`NameFieldKind`], literals with `None`.
+///
+/// <https://www.unicode.org/reports/tr35/tr35-personNames.html#formatting-process>
+pub fn handle_missing_fields(
+    segments: Vec<(Option<NameFieldKind>, String)>,
+) -> Vec<(Option<NameFieldKind>, String)> {
+    let is_empty_field = |i: usize| {
+        segments
+            .get(i)
+            .is_some_and(|(kind, value)| kind.is_some() && value.is_empty())
+    };
+    let is_literal = |i: usize| segments.get(i).is_some_and(|(kind, _)| kind.is_none());
+    let is_populated_field =
+        |(kind, value): &(Option<NameFieldKind>, String)| kind.is_some() && !value.is_empty();
+
+    // 1. and 2. Everything before the first and after the last populated field is omitted.
+    let (Some(first), Some(last)) = (
+        segments.iter().position(is_populated_field),
+        segments.iter().rposition(is_populated_field),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut result: Vec<(Option<NameFieldKind>, String)> = Vec::new();
+    let mut i = first;
+    while i <= last {
+        let Some(segment) = segments.get(i) else {
+            break;
+        };
+        match segment {
+            // 3. Two or more empty fields separated only by literals are removed along with
+            // the literals between them, and a single empty field is removed.
+            (Some(_), value) if value.is_empty() => {
+                while is_literal(i + 1) && is_empty_field(i + 2) {
+                    i += 2;
+                }
+            }
+            (Some(_), _) => result.push(segment.clone()),
+            // 4. Adjacent literals are coalesced.
+            (None, literal) => match result.last_mut() {
+                Some((None, previous)) => coalesce_literals(previous, literal),
+                _ => result.push(segment.clone()),
+            },
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Coalesces the literal `b` into the adjacent literal `a`.
+fn coalesce_literals(a: &mut String, b: &str) {
+    if a.ends_with(b) {
+        return;
+    }
+    if a.is_empty() {
+        a.push_str(b);
+        return;
+    }
+    // Sequences of whitespace are replaced by their first character.
+    let mut last_was_whitespace = false;
+    let mut coalesced = String::with_capacity(a.len() + b.len());
+    for c in a.chars().chain(b.chars()) {
+        if c.is_whitespace() {
+            if !last_was_whitespace {
+                coalesced.push(c);
+            }
+            last_was_whitespace = true;
+        } else {
+            coalesced.push(c);
+            last_was_whitespace = false;
+        }
+    }
+    *a = coalesced;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+
+    use super::handle_missing_fields;
+    use crate::personnames::api::NameFieldKind;
+
+    fn field(kind: NameFieldKind, value: &str) -> (Option<NameFieldKind>, String) {
+        (Some(kind), String::from(value))
+    }
+
+    fn literal(value: &str) -> (Option<NameFieldKind>, String) {
+        (None, String::from(value))
+    }
+
+    #[test]
+    fn test_trailing_fields_removed() {
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Given, "Zendaya"),
+            literal(" "),
+            field(NameFieldKind::Surname, ""),
+            literal(", "),
+            field(NameFieldKind::Credentials, ""),
+        ]);
+        assert_eq!(result, vec![field(NameFieldKind::Given, "Zendaya")]);
+    }
+
+    #[test]
+    fn test_leading_fields_removed() {
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Title, ""),
+            literal(" "),
+            field(NameFieldKind::Given, "Irene"),
+        ]);
+        assert_eq!(result, vec![field(NameFieldKind::Given, "Irene")]);
+    }
+
+    #[test]
+    fn test_inner_fields_removed() {
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Surname, "Adler"),
+            literal(", "),
+            field(NameFieldKind::Title, ""),
+            literal(" "),
+            field(NameFieldKind::Given, "Irene"),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                field(NameFieldKind::Surname, "Adler"),
+                literal(", "),
+                field(NameFieldKind::Given, "Irene"),
+            ]
+        );
+
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Surname, "Adler"),
+            literal(", "),
+            field(NameFieldKind::Title, ""),
+            literal(" "),
+            field(NameFieldKind::Given2, ""),
+            literal(" "),
+            field(NameFieldKind::Given, "Irene"),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                field(NameFieldKind::Surname, "Adler"),
+                literal(", "),
+                field(NameFieldKind::Given, "Irene"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_literals_coalesced() {
+        // Literals are kept as they are, only repeated whitespace is collapsed
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Given, "Irene"),
+            literal(" ("),
+            field(NameFieldKind::Given2, ""),
+            literal(") "),
+            field(NameFieldKind::Surname, "Adler"),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                field(NameFieldKind::Given, "Irene"),
+                literal(" () "),
+                field(NameFieldKind::Surname, "Adler"),
+            ]
+        );
+
+        let result = handle_missing_fields(vec![
+            field(NameFieldKind::Given, "Irene"),
+            literal(" "),
+            field(NameFieldKind::Given2, ""),
+            literal(" "),
+            field(NameFieldKind::Surname, "Adler"),
+        ]);
+        assert_eq!(
+            result,
+            vec![
+                field(NameFieldKind::Given, "Irene"),
+                literal(" "),
+                field(NameFieldKind::Surname, "Adler"),
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@ pub use derive_locale::likely_person_name_locale;
 pub use derive_missing_initials::derive_missing_initials;
 pub use derive_missing_surname::derive_missing_surname;
 pub use derive_name_order::name_order_derive;
+pub use handle_missing_fields::handle_missing_fields;
 pub use pattern_regex_selector::PersonNamePattern;
 pub use pattern_regex_selector::to_person_name_pattern;
 pub use space_replacement::space_replacement;
@@ -19,5 +20,6 @@ mod derive_locale;
 mod derive_missing_initials;
 mod derive_missing_surname;
 mod derive_name_order;
+mod handle_missing_fields;
 mod pattern_regex_selector;
 mod space_replacement;
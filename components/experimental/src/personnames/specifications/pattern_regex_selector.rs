@@ -98,12 +98,16 @@ impl PersonNamePattern<'_> {
             .collect()
     }
 
-    pub fn format_person_name(
+    /// Returns the formatted fields interleaved with the literals of the pattern.
+    ///
+    /// Each field value is tagged with the [`NameFieldKind`] it was derived from;
+    /// literals are tagged with `None`.
+    pub fn format_person_name_segments(
         &self,
         person_name: &dyn PersonName,
         initial_pattern: &str,
         initial_sequence_pattern: &str,
-    ) -> String {
+    ) -> Vec<(Option<NameFieldKind>, String)> {
         self.name_fields
             .iter()
             .flat_map(|&(k, ref v)| {
@@ -115,10 +119,23 @@ impl PersonNamePattern<'_> {
                         initial_sequence_pattern,
                     )
                     .join(" ");
-                [p_name, String::from(v.as_ref())]
+                [(Some(k.kind), p_name), (None, String::from(v.as_ref()))]
             })
             .collect()
     }
+
+    #[cfg(test)]
+    fn format_person_name(
+        &self,
+        person_name: &dyn PersonName,
+        initial_pattern: &str,
+        initial_sequence_pattern: &str,
+    ) -> String {
+        self.format_person_name_segments(person_name, initial_pattern, initial_sequence_pattern)
+            .into_iter()
+            .map(|(_, s)| s)
+            .collect()
+    }
 }
 
 impl FromStr for NameFieldKind {
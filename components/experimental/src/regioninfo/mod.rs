@@ -0,0 +1,14 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Locale-independent metadata about regions, sourced from the CLDR supplemental data.
+//!
+//! This includes the currencies used in a region over time, its population and languages,
+//! and the regions that contain it.
+
+pub mod provider;
+mod region_info;
+
+pub use region_info::RegionCurrency;
+pub use region_info::RegionInfo;
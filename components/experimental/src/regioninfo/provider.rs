@@ -0,0 +1,221 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_locale_core::subtags::{Language, Region, Script};
+use icu_provider::prelude::*;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::{ZeroMap, ZeroSlice, ZeroVec};
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+// We use raw TinyAsciiStrs for map keys, as we then don't have to
+// validate them as subtags on deserialization.
+type UnvalidatedRegion = UnvalidatedTinyAsciiStr<3>;
+
+icu_provider::data_marker!(
+    /// The currencies used in a region over time, keyed by the region code.
+    RegionCurrenciesV1,
+    RegionCurrencies<'static>,
+    #[cfg(feature = "datagen")]
+    attributes_domain = "region",
+);
+
+icu_provider::data_marker!(
+    /// Population and language data of a region, keyed by the region code.
+    TerritoryInfoV1,
+    TerritoryInfo<'static>,
+    #[cfg(feature = "datagen")]
+    attributes_domain = "region",
+);
+
+icu_provider::data_marker!(
+    /// The containment relationships between regions.
+    RegionContainmentV1,
+    RegionContainment<'static>,
+    is_singleton = true
+);
+
+impl RegionCurrenciesV1 {
+    /// Helper to create data marker attributes from a region.
+    #[inline]
+    pub(crate) fn make_attributes(region: &Region) -> &DataMarkerAttributes {
+        // This is infallible (will not panic) because a validated `Region` is guaranteed to
+        // conform to `DataMarkerAttributes` syntax.
+        DataMarkerAttributes::from_str_or_panic(region.as_str())
+    }
+}
+
+impl TerritoryInfoV1 {
+    /// Helper to create data marker attributes from a region.
+    #[inline]
+    pub(crate) fn make_attributes(region: &Region) -> &DataMarkerAttributes {
+        // This is infallible (will not panic) because a validated `Region` is guaranteed to
+        // conform to `DataMarkerAttributes` syntax.
+        DataMarkerAttributes::from_str_or_panic(region.as_str())
+    }
+}
+
+/// The currencies used in a region, sourced from the CLDR `currencyData`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[yoke(prove_covariance_manually)]
+pub struct RegionCurrencies<'data> {
+    /// The currencies of the region, starting with the most recently introduced one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub currencies: ZeroVec<'data, CurrencyPeriod>,
+}
+
+icu_provider::data_struct!(RegionCurrencies<'_>, #[cfg(feature = "datagen")]);
+
+/// A date in the ISO calendar, as a `(year, month, day)` tuple.
+pub type IsoDate = (i16, u8, u8);
+
+/// A period during which a currency was used in a region.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(CurrencyPeriodULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[zerovec::skip_derive(Ord)]
+pub struct CurrencyPeriod {
+    /// The ISO 4217 code of the currency.
+    pub currency: UnvalidatedTinyAsciiStr<3>,
+    /// The first day the currency was used, if known.
+    pub from: Option<IsoDate>,
+    /// The last day the currency was used, or `None` if it is still in use.
+    pub to: Option<IsoDate>,
+    /// Whether the currency is legal tender.
+    pub tender: bool,
+}
+
+/// Population and language data of a region, sourced from the CLDR `territoryInfo`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[yoke(prove_covariance_manually)]
+pub struct TerritoryInfo<'data> {
+    /// The approximate population of the region.
+    pub population: u64,
+    /// The approximate literacy rate of the region, in percent.
+    pub literacy_percent: f32,
+    /// The approximate gross domestic product of the region, in US dollars.
+    pub gdp: u64,
+    /// The languages used in the region, ordered by decreasing population.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroVec<'data, LanguagePopulation>,
+}
+
+icu_provider::data_struct!(TerritoryInfo<'_>, #[cfg(feature = "datagen")]);
+
+/// The use of a language in a region.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguagePopulationULE)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[zerovec::skip_derive(Ord)]
+pub struct LanguagePopulation {
+    /// The language.
+    pub language: Language,
+    /// The script, if the data is specific to one script of the language.
+    pub script: Option<Script>,
+    /// The approximate share of the population of the region using the language, in percent.
+    pub population_percent: f32,
+    /// The official status of the language in the region, if any.
+    pub official_status: Option<OfficialStatus>,
+}
+
+/// The official status of a language in a region.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(OfficialStatusULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[repr(u8)]
+pub enum OfficialStatus {
+    /// The language is official in the whole region.
+    Official = 0,
+    /// The language is not legally official, but used as such in the whole region.
+    DeFactoOfficial = 1,
+    /// The language is official in a part of the region.
+    OfficialRegional = 2,
+    /// The language is a recognized minority language.
+    OfficialMinority = 3,
+}
+
+/// The containment relationships between regions, sourced from the CLDR `territoryContainment`.
+///
+/// This includes both the UN M49 geographical hierarchy and groupings such as the
+/// European Union (`EU`).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::regioninfo::provider))]
+#[yoke(prove_covariance_manually)]
+pub struct RegionContainment<'data> {
+    /// Map from a region group to the regions it directly contains.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub contains: ZeroMap<'data, UnvalidatedRegion, ZeroSlice<Region>>,
+    /// Map from a region to the region groups directly containing it.
+    ///
+    /// This is the inverse of [`Self::contains`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub contained_in: ZeroMap<'data, UnvalidatedRegion, ZeroSlice<Region>>,
+}
+
+icu_provider::data_struct!(RegionContainment<'_>, #[cfg(feature = "datagen")]);
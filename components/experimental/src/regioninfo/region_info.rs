@@ -0,0 +1,214 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+use icu_locale_core::subtags::Region;
+use icu_provider::prelude::*;
+
+use super::provider::{
+    IsoDate, LanguagePopulation, RegionContainmentV1, RegionCurrenciesV1, TerritoryInfoV1,
+};
+use crate::dimension::currency::CurrencyCode;
+
+/// A currency used in a region during a period of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RegionCurrency {
+    /// The ISO 4217 code of the currency.
+    pub currency: CurrencyCode,
+    /// The first day the currency was used, if known.
+    pub from: Option<IsoDate>,
+    /// The last day the currency was used, or `None` if it is still in use.
+    pub to: Option<IsoDate>,
+    /// Whether the currency is legal tender.
+    pub tender: bool,
+}
+
+impl RegionCurrency {
+    /// Returns whether the currency was used on the given date.
+    pub fn is_valid_at(&self, date: IsoDate) -> bool {
+        self.from.is_none_or(|from| from <= date) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+/// Locale-independent metadata about a region: the currencies it uses and has used,
+/// its population and languages, and the regions that contain it.
+///
+/// Not every region has every kind of data; for example, macroregions such as `150`
+/// (Europe) have neither currencies nor population data.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::regioninfo::RegionInfo;
+/// use icu::locale::subtags::{language, region};
+/// use tinystr::tinystr;
+///
+/// let info = RegionInfo::try_new(region!("DE")).unwrap();
+///
+/// assert_eq!(info.current_currency().unwrap().0, tinystr!(3, "EUR"));
+/// assert_eq!(info.currency_at((1990, 1, 1)).unwrap().0, tinystr!(3, "DEM"));
+/// assert_eq!(info.languages().next().unwrap().language, language!("de"));
+/// assert!(info.is_contained_in(region!("150")));
+/// assert!(info.is_contained_in(region!("EU")));
+/// ```
+#[derive(Debug)]
+pub struct RegionInfo {
+    region: Region,
+    currencies: Option<DataPayload<RegionCurrenciesV1>>,
+    territory_info: Option<DataPayload<TerritoryInfoV1>>,
+    containment: DataPayload<RegionContainmentV1>,
+}
+
+impl RegionInfo {
+    icu_provider::gen_buffer_data_constructors!(
+        (region: Region) -> error: DataError,
+        /// Creates a new [`RegionInfo`] for the given region using compiled data.
+        functions: [
+            try_new,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D, region: Region) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<RegionCurrenciesV1>
+            + DataProvider<TerritoryInfoV1>
+            + DataProvider<RegionContainmentV1>,
+    {
+        let currencies = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes(
+                    RegionCurrenciesV1::make_attributes(&region),
+                ),
+                ..Default::default()
+            })
+            .allow_identifier_not_found()?
+            .map(|response| response.payload);
+
+        let territory_info = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes(
+                    TerritoryInfoV1::make_attributes(&region),
+                ),
+                ..Default::default()
+            })
+            .allow_identifier_not_found()?
+            .map(|response| response.payload);
+
+        let containment = provider.load(Default::default())?.payload;
+
+        Ok(Self {
+            region,
+            currencies,
+            territory_info,
+            containment,
+        })
+    }
+
+    /// Returns the region this metadata belongs to.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Returns the currencies that are or were used in the region, starting with the
+    /// most recently introduced one.
+    pub fn currencies(&self) -> impl Iterator<Item = RegionCurrency> + '_ {
+        self.currencies
+            .iter()
+            .flat_map(|payload| payload.get().currencies.iter())
+            .filter_map(|period| {
+                Some(RegionCurrency {
+                    currency: CurrencyCode(period.currency.try_into_tinystr().ok()?),
+                    from: period.from,
+                    to: period.to,
+                    tender: period.tender,
+                })
+            })
+    }
+
+    /// Returns the legal tender currently used in the region, if any.
+    pub fn current_currency(&self) -> Option<CurrencyCode> {
+        self.currencies()
+            .find(|c| c.tender && c.to.is_none())
+            .map(|c| c.currency)
+    }
+
+    /// Returns the legal tender used in the region on the given date, if any.
+    pub fn currency_at(&self, date: IsoDate) -> Option<CurrencyCode> {
+        self.currencies()
+            .find(|c| c.tender && c.is_valid_at(date))
+            .map(|c| c.currency)
+    }
+
+    /// Returns the approximate population of the region, if known.
+    pub fn population(&self) -> Option<u64> {
+        Some(self.territory_info.as_ref()?.get().population)
+    }
+
+    /// Returns the approximate literacy rate of the region in percent, if known.
+    pub fn literacy_percent(&self) -> Option<f32> {
+        Some(self.territory_info.as_ref()?.get().literacy_percent)
+    }
+
+    /// Returns the approximate gross domestic product of the region in US dollars, if known.
+    pub fn gdp(&self) -> Option<u64> {
+        Some(self.territory_info.as_ref()?.get().gdp)
+    }
+
+    /// Returns the languages used in the region, ordered by decreasing population.
+    pub fn languages(&self) -> impl Iterator<Item = LanguagePopulation> + '_ {
+        self.territory_info
+            .iter()
+            .flat_map(|payload| payload.get().languages.iter())
+    }
+
+    /// Returns the regions directly contained in this region.
+    ///
+    /// This is empty unless the region is a macroregion or a grouping such as `EU`.
+    pub fn contained_regions(&self) -> impl Iterator<Item = Region> + '_ {
+        self.containment
+            .get()
+            .contains
+            .get(&self.region.to_tinystr().to_unvalidated())
+            .into_iter()
+            .flat_map(|regions| regions.iter())
+    }
+
+    /// Returns all the regions containing this region, directly or indirectly.
+    ///
+    /// For example, `DE` is contained in `155` (Western Europe), `150` (Europe),
+    /// `001` (World), as well as in groupings such as `EU` (European Union).
+    pub fn containing_regions(&self) -> Vec<Region> {
+        let mut result = Vec::new();
+        let mut pending = alloc::vec![self.region];
+        while let Some(region) = pending.pop() {
+            for parent in self.direct_parents(region) {
+                if !result.contains(&parent) {
+                    result.push(parent);
+                    pending.push(parent);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns whether this region is contained in the given region, directly or indirectly.
+    pub fn is_contained_in(&self, region: Region) -> bool {
+        self.containing_regions().contains(&region)
+    }
+
+    fn direct_parents(&self, region: Region) -> impl Iterator<Item = Region> + '_ {
+        self.containment
+            .get()
+            .contained_in
+            .get(&region.to_tinystr().to_unvalidated())
+            .into_iter()
+            .flat_map(|regions| regions.iter())
+    }
+}
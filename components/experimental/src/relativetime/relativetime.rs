@@ -5,7 +5,7 @@
 use fixed_decimal::{Decimal, Sign};
 use icu_decimal::{
     DecimalFormatter, DecimalFormatterPreferences, options::DecimalFormatterOptions,
-    provider::DecimalDigitsV1, provider::DecimalSymbolsV1,
+    provider::DecimalDigitsV1, provider::DecimalSymbolsV2,
 };
 use icu_locale_core::preferences::{define_preferences, prefs_convert};
 use icu_plurals::PluralRulesPreferences;
@@ -195,7 +195,7 @@ macro_rules! constructor {
         where
             D: DataProvider<PluralsCardinalV1>
                 + DataProvider<$marker>
-                + DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+                + DataProvider<DecimalSymbolsV2> + DataProvider<DecimalDigitsV1>
                 + ?Sized,
         {
             let locale = <$marker>::make_locale(prefs.locale_preferences);
@@ -0,0 +1,176 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The fixtures are excerpts of the CLDR `personNameTest` files, see `data/fixtures/_readme.txt`.
+// They need to be updated along with the CLDR version of the person names data.
+
+use icu_experimental::personnames::PersonNamesFormatter;
+use icu_experimental::personnames::api::*;
+use icu_experimental::personnames::provided_struct::DefaultPersonName;
+use icu_locale_core::Locale;
+use litemap::LiteMap;
+use writeable::Writeable;
+
+/// A single `name ; … / expectedResult ; … / parameters ; …` block of a CLDR
+/// `personNameTest` file.
+#[derive(Default)]
+struct TestName {
+    /// The comment preceding the block, such as `nativeGS`.
+    label: String,
+    fields: LiteMap<NameField, String>,
+    locale: Option<Locale>,
+    /// Set if the name uses a field or modifier which is not supported yet.
+    unsupported: bool,
+    expectations: Vec<(String, [String; 4])>,
+}
+
+fn parse_name_field(field: &str) -> Option<NameField> {
+    let mut iter = field.split('-');
+    let kind = match iter.next()? {
+        "title" => NameFieldKind::Title,
+        "given" => NameFieldKind::Given,
+        "given2" => NameFieldKind::Given2,
+        "surname" => NameFieldKind::Surname,
+        "surname2" => NameFieldKind::Surname2,
+        "generation" => NameFieldKind::Generation,
+        "credentials" => NameFieldKind::Credentials,
+        _ => return None,
+    };
+    let mut part = FieldPart::Auto;
+    let mut formality = FieldFormality::Auto;
+    for m in iter {
+        match m {
+            "informal" => formality = FieldFormality::Informal,
+            "prefix" => part = FieldPart::Prefix,
+            "core" => part = FieldPart::Core,
+            _ => return None,
+        }
+    }
+    Some(NameField {
+        kind,
+        modifier: FieldModifierSet::new(FieldCapsStyle::Auto, part, FieldLength::Auto, formality),
+    })
+}
+
+fn parse_options(locale: &str, parameters: &[String; 4]) -> PersonNamesFormatterOptions {
+    PersonNamesFormatterOptions::new(
+        locale.parse().unwrap(),
+        match parameters[0].as_str() {
+            "givenFirst" => FormattingOrder::GivenFirst,
+            "surnameFirst" => FormattingOrder::SurnameFirst,
+            "sorting" => FormattingOrder::Sorting,
+            other => panic!("unknown order {other}"),
+        },
+        match parameters[1].as_str() {
+            "long" => FormattingLength::Long,
+            "medium" => FormattingLength::Medium,
+            "short" => FormattingLength::Short,
+            other => panic!("unknown length {other}"),
+        },
+        match parameters[2].as_str() {
+            "referring" => FormattingUsage::Referring,
+            "addressing" => FormattingUsage::Addressing,
+            "monogram" => FormattingUsage::Monogram,
+            other => panic!("unknown usage {other}"),
+        },
+        match parameters[3].as_str() {
+            "formal" => FormattingFormality::Formal,
+            "informal" => FormattingFormality::Informal,
+            other => panic!("unknown formality {other}"),
+        },
+    )
+}
+
+fn parse_test_file(data: &str) -> Vec<TestName> {
+    let mut names = Vec::new();
+    let mut current = TestName::default();
+    let mut expected = None;
+    for line in data.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if current.fields.is_empty() && current.locale.is_none() {
+                current.label = String::from(comment.trim());
+            }
+            continue;
+        }
+        let mut columns = line.split(';').map(str::trim);
+        match columns.next().unwrap() {
+            "enum" => {}
+            "name" => {
+                let field = columns.next().unwrap();
+                let value = columns.next().unwrap();
+                if field == "locale" {
+                    current.locale = Some(value.replace('_', "-").parse().unwrap());
+                } else if let Some(field) = parse_name_field(field) {
+                    current.fields.insert(field, String::from(value));
+                } else {
+                    current.unsupported = true;
+                }
+            }
+            "expectedResult" => expected = Some(String::from(columns.next().unwrap())),
+            "parameters" => {
+                let parameters = core::array::from_fn(|_| String::from(columns.next().unwrap()));
+                current
+                    .expectations
+                    .push((expected.clone().unwrap(), parameters));
+            }
+            "endName" => names.push(core::mem::take(&mut current)),
+            other => panic!("unknown line type {other}"),
+        }
+    }
+    names
+}
+
+/// The names that are known to fail, as `(locale, label)` pairs, because they use a field or
+/// modifier which is not supported yet, or because the formatter disagrees with CLDR.
+const UNSUPPORTED: &[(&str, &str)] = &[
+    // The name order of a ja name is derived from the catch-all "und" in givenFirst
+    ("en", "foreignGS"),
+];
+
+/// Formats the name with all of its parameters, returning the first mismatch.
+fn check(locale: &str, name: &TestName) -> Result<(), String> {
+    if name.unsupported {
+        return Err(String::from("unsupported field"));
+    }
+    let person_name = DefaultPersonName::new(name.fields.clone(), name.locale.clone(), None)
+        .map_err(|e| format!("{e:?}"))?;
+    for (expected, parameters) in &name.expectations {
+        let formatter = PersonNamesFormatter::try_new(parse_options(locale, parameters))
+            .map_err(|e| format!("{e:?}"))?;
+        let formatted = formatter
+            .format(&person_name)
+            .map_err(|e| format!("{e:?}"))?;
+        let actual = formatted.write_to_string();
+        if actual != *expected {
+            return Err(format!(
+                "expected {expected:?}, got {actual:?} with {parameters:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_all_cldr() {
+    let mut failures = Vec::new();
+    for (locale, data) in [("en", include_str!("data/fixtures/en.txt"))] {
+        for name in parse_test_file(data) {
+            let allowlisted = UNSUPPORTED.contains(&(locale, name.label.as_str()));
+            match check(locale, &name) {
+                Err(e) if !allowlisted => {
+                    failures.push(format!("{locale:?} {:?} failed: {e}", name.label))
+                }
+                Ok(()) if allowlisted => failures.push(format!(
+                    "{locale:?} {:?} passes and can be removed from UNSUPPORTED",
+                    name.label
+                )),
+                _ => {}
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{failures:#?}");
+}
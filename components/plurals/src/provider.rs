@@ -530,34 +530,38 @@ where
 
     /// Returns the value for the given [`PluralOperands`] and [`PluralRules`].
     pub fn get<'a>(&'a self, op: PluralOperands, rules: &PluralRules) -> (FourBitMetadata, &'a V) {
-        let parts = self.as_parts();
-
-        let category = rules.category_for(op);
-
-        match parts.specials {
-            Some(specials) => {
-                if op.is_exactly_zero()
-                    && let Some(value) = get_special(specials, PluralElementsKeys::ExplicitZero)
-                {
-                    return value;
-                }
-                if op.is_exactly_one()
-                    && let Some(value) = get_special(specials, PluralElementsKeys::ExplicitOne)
-                {
-                    return value;
-                }
-                match category {
-                    PluralCategory::Zero => Some(PluralElementsKeys::Zero),
-                    PluralCategory::One => Some(PluralElementsKeys::One),
-                    PluralCategory::Two => Some(PluralElementsKeys::Two),
-                    PluralCategory::Few => Some(PluralElementsKeys::Few),
-                    PluralCategory::Many => Some(PluralElementsKeys::Many),
-                    PluralCategory::Other => None,
-                }
-                .and_then(|key| get_special(specials, key))
+        if let Some(specials) = self.as_parts().specials {
+            if op.is_exactly_zero()
+                && let Some(value) = get_special(specials, PluralElementsKeys::ExplicitZero)
+            {
+                return value;
+            }
+            if op.is_exactly_one()
+                && let Some(value) = get_special(specials, PluralElementsKeys::ExplicitOne)
+            {
+                return value;
             }
-            None => None,
         }
+        self.get_for_category(rules.category_for(op))
+    }
+
+    /// Returns the value for the given [`PluralCategory`].
+    ///
+    /// Explicit zero and one values are not taken into account, since they depend on the
+    /// exact value rather than on its plural category. This is useful when the category is
+    /// not derived from a single number, for example for number ranges.
+    pub fn get_for_category(&self, category: PluralCategory) -> (FourBitMetadata, &V) {
+        let parts = self.as_parts();
+        match category {
+            PluralCategory::Zero => Some(PluralElementsKeys::Zero),
+            PluralCategory::One => Some(PluralElementsKeys::One),
+            PluralCategory::Two => Some(PluralElementsKeys::Two),
+            PluralCategory::Few => Some(PluralElementsKeys::Few),
+            PluralCategory::Many => Some(PluralElementsKeys::Many),
+            PluralCategory::Other => None,
+        }
+        .zip(parts.specials)
+        .and_then(|(key, specials)| get_special(specials, key))
         .unwrap_or(parts.default)
     }
 
@@ -1076,6 +1080,13 @@ where
     pub fn get<'a>(&'a self, op: PluralOperands, rules: &PluralRules) -> &'a V {
         self.elements.get(op, rules).1
     }
+
+    /// Returns the value for the given [`PluralCategory`].
+    ///
+    /// See [`PluralElementsPackedULE::get_for_category`].
+    pub fn get_for_category(&self, category: PluralCategory) -> &V {
+        self.elements.get_for_category(category).1
+    }
 }
 
 #[test]
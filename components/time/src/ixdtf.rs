@@ -6,20 +6,25 @@
 use crate::ZonedTime;
 #[cfg(feature = "unstable")]
 use crate::zone::ZoneNameTimestamp;
+#[cfg(feature = "alloc")]
+use crate::zone::iana::IanaMapper;
 use crate::{
-    DateTime, Time, TimeZoneInfo, ZonedDateTime,
+    DateTime, Time, TimeZone, TimeZoneInfo, ZonedDateTime,
     zone::{InvalidOffsetError, UtcOffset, iana::IanaParserBorrowed, models},
 };
 use core::str::FromStr;
-use icu_calendar::{AnyCalendarKind, AsCalendar, Date, DateError, Iso, RangeError};
+use icu_calendar::preferences::CalendarAlgorithm;
+use icu_calendar::{AnyCalendarKind, AsCalendar, Calendar, Date, DateError, Iso, RangeError};
 use ixdtf::{
     ParseError as Rfc9557ParseError,
     encoding::Utf8,
     parsers::IxdtfParser,
     records::{
-        IxdtfParseRecord, TimeZoneAnnotation, TimeZoneRecord, UtcOffsetRecord, UtcOffsetRecordOrZ,
+        DateRecord, Fraction, FullPrecisionOffset, IxdtfParseRecord, MinutePrecisionOffset, Sign,
+        TimeRecord, TimeZoneAnnotation, TimeZoneRecord, UtcOffsetRecord, UtcOffsetRecordOrZ,
     },
 };
+use writeable::Writeable;
 
 /// The error type for parsing RFC 9557 strings.
 #[derive(Debug, PartialEq, displaydoc::Display)]
@@ -960,6 +965,245 @@ impl FromStr for Time {
     }
 }
 
+impl UtcOffset {
+    /// Returns the RFC 9557 representation of this [`UtcOffset`], such as `+05:30`.
+    ///
+    /// Seconds are only written if they are non-zero.
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::time::zone::UtcOffset;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let offset = UtcOffset::try_from_seconds(-(3 * 3600 + 30 * 60)).unwrap();
+    /// assert_writeable_eq!(offset.to_ixdtf(), "-03:30");
+    /// ```
+    pub fn to_ixdtf(self) -> impl Writeable + core::fmt::Display {
+        self.to_utc_offset_record()
+    }
+
+    fn to_utc_offset_record(self) -> UtcOffsetRecord {
+        let seconds = self.to_seconds().unsigned_abs();
+        let minute_precision_offset = MinutePrecisionOffset {
+            sign: Sign::from(self.is_non_negative()),
+            // offsets are less than 256 hours
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+        };
+        match seconds % 60 {
+            0 => UtcOffsetRecord::MinutePrecision(minute_precision_offset),
+            second => UtcOffsetRecord::FullPrecisionOffset(FullPrecisionOffset {
+                minute_precision_offset,
+                second: second as u8,
+                fraction: None,
+            }),
+        }
+    }
+}
+
+impl TimeZone {
+    /// Returns the RFC 9557 time zone identifier of this [`TimeZone`], which is its canonical
+    /// IANA ID.
+    ///
+    /// Time zones that are not known to `iana_mapper` are written as `Etc/Unknown`.
+    ///
+    /// ✨ *Enabled with the `ixdtf` and `alloc` Cargo features.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::subtags::subtag;
+    /// use icu::time::zone::iana::{IanaMapper, IanaParserExtended};
+    /// use icu::time::TimeZone;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let iana_mapper = IanaMapper::new(IanaParserExtended::new());
+    ///
+    /// assert_writeable_eq!(
+    ///     TimeZone(subtag!("frpar")).to_ixdtf(&iana_mapper),
+    ///     "Europe/Paris"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_ixdtf<'a>(
+        self,
+        iana_mapper: &IanaMapper<'a>,
+    ) -> impl Writeable + core::fmt::Display + 'a {
+        TimeZoneRecord::<Utf8>::Name(
+            iana_mapper
+                .canonical_iana_id(self)
+                .unwrap_or("Etc/Unknown")
+                .as_bytes(),
+        )
+    }
+}
+
+impl Time {
+    /// Returns the RFC 9557 representation of this [`Time`], such as `16:01:17.045`.
+    ///
+    /// The subsecond is only written if it is non-zero, without trailing zeros.
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::time::Time;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let time = Time::try_new(16, 1, 17, 45_000_000).unwrap();
+    /// assert_writeable_eq!(time.to_ixdtf(), "16:01:17.045");
+    /// ```
+    pub fn to_ixdtf(&self) -> impl Writeable + core::fmt::Display {
+        let mut record = IxdtfParseRecord::<Utf8>::default();
+        record.time = Some(self.to_time_record());
+        record
+    }
+
+    fn to_time_record(self) -> TimeRecord {
+        TimeRecord {
+            hour: self.hour.number(),
+            minute: self.minute.number(),
+            second: self.second.number(),
+            fraction: Fraction::from_nanoseconds(self.subsecond.number()),
+        }
+    }
+}
+
+/// The record of [`Date::to_ixdtf()`], which the other fields are added to.
+fn date_record<A: AsCalendar>(date: &Date<A>) -> IxdtfParseRecord<'static, Utf8> {
+    let iso = date.to_calendar(Iso);
+    let mut record = IxdtfParseRecord::default();
+    record.date = Some(DateRecord {
+        year: iso.year().extended_year(),
+        month: iso.month().ordinal,
+        day: iso.day_of_month().0,
+    });
+    record.calendar = date
+        .calendar()
+        .calendar_algorithm()
+        .filter(|&c| c != CalendarAlgorithm::Iso8601)
+        .map(|c| c.as_str().as_bytes());
+    record
+}
+
+impl<A: AsCalendar> DateTime<A> {
+    /// Returns the RFC 9557 representation of this [`DateTime`].
+    ///
+    /// The date is written in the ISO calendar, followed by a calendar annotation unless the
+    /// calendar is [`Iso`]. The result can be parsed with [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::time::DateTime;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let datetime =
+    ///     DateTime::try_from_str("2024-07-17T16:01:17.045[u-ca=hebrew]", Hebrew)
+    ///         .unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     datetime.to_ixdtf(),
+    ///     "2024-07-17T16:01:17.045[u-ca=hebrew]"
+    /// );
+    /// ```
+    pub fn to_ixdtf(&self) -> impl Writeable + core::fmt::Display {
+        let mut record = date_record(&self.date);
+        record.time = Some(self.time.to_time_record());
+        record
+    }
+}
+
+impl<A: AsCalendar> ZonedDateTime<A, UtcOffset> {
+    /// Returns the RFC 9557 representation of this [`ZonedDateTime`], such as
+    /// `2025-03-01T10:00:00+01:00`.
+    ///
+    /// The result can be parsed with [`Self::try_offset_only_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Iso;
+    /// use icu::time::ZonedDateTime;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let zoned_datetime =
+    ///     ZonedDateTime::try_offset_only_from_str("2025-03-01T10:00:00Z", Iso)
+    ///         .unwrap();
+    ///
+    /// assert_writeable_eq!(zoned_datetime.to_ixdtf(), "2025-03-01T10:00:00+00:00");
+    /// ```
+    pub fn to_ixdtf(&self) -> impl Writeable + core::fmt::Display {
+        let mut record = date_record(&self.date);
+        record.time = Some(self.time.to_time_record());
+        record.offset = Some(UtcOffsetRecordOrZ::Offset(self.zone.to_utc_offset_record()));
+        record
+    }
+}
+
+impl<A: AsCalendar, M: models::TimeZoneModel> ZonedDateTime<A, TimeZoneInfo<M>> {
+    /// Returns the RFC 9557 representation of this [`ZonedDateTime`], such as
+    /// `2025-03-01T10:00:00+01:00[Europe/Paris][u-ca=hebrew]`.
+    ///
+    /// The offset is written if it is known, and the time zone is written as its canonical
+    /// IANA ID. The result can be parsed with [`Self::try_lenient_from_str()`], or with
+    /// [`Self::try_strict_from_str()`] if the offset is known.
+    ///
+    /// ✨ *Enabled with the `ixdtf` and `alloc` Cargo features.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::time::zone::iana::{IanaMapper, IanaParser, IanaParserExtended};
+    /// use icu::time::ZonedDateTime;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let zoned_datetime = ZonedDateTime::try_strict_from_str(
+    ///     "2025-03-01T10:00:00+01:00[Europe/Paris][u-ca=hebrew]",
+    ///     Hebrew,
+    ///     IanaParser::new(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     zoned_datetime.to_ixdtf(&IanaMapper::new(IanaParserExtended::new())),
+    ///     "2025-03-01T10:00:00+01:00[Europe/Paris][u-ca=hebrew]"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_ixdtf<'a>(
+        &self,
+        iana_mapper: &IanaMapper<'a>,
+    ) -> impl Writeable + core::fmt::Display + 'a {
+        let mut record = date_record(&self.date);
+        record.time = Some(self.time.to_time_record());
+        record.offset = self
+            .zone
+            .offset()
+            .map(|offset| UtcOffsetRecordOrZ::Offset(offset.to_utc_offset_record()));
+        record.tz = Some(TimeZoneAnnotation::new(
+            false,
+            TimeZoneRecord::Name(
+                iana_mapper
+                    .canonical_iana_id(self.zone.id())
+                    .unwrap_or("Etc/Unknown")
+                    .as_bytes(),
+            ),
+        ));
+        record
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1010,4 +1254,62 @@ mod test {
         )
         .unwrap();
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn round_trip() {
+        use icu_calendar::cal::Hebrew;
+
+        let iana_parser = IanaParserBorrowed::new();
+        let iana_mapper = IanaMapper::new(crate::zone::iana::IanaParserExtendedBorrowed::new());
+
+        for s in [
+            "2025-03-01T10:00:00+01:00[Europe/Paris][u-ca=hebrew]",
+            "-000010-03-01T00:00:00.000000001-05:00[America/New_York][u-ca=hebrew]",
+            "2025-03-01T10:00:00+05:45:30[Asia/Kathmandu][u-ca=hebrew]",
+        ] {
+            let zoned_datetime =
+                ZonedDateTime::try_strict_from_str(s, Hebrew, iana_parser).unwrap();
+            let string = zoned_datetime.to_ixdtf(&iana_mapper).to_string();
+            assert_eq!(string, s);
+            assert_eq!(
+                ZonedDateTime::try_strict_from_str(&string, Hebrew, iana_parser).unwrap(),
+                zoned_datetime
+            );
+        }
+
+        // Aliases are written as the canonical IANA ID
+        let zoned_datetime = ZonedDateTime::try_location_only_from_str(
+            "2025-03-01T10:00:00[Asia/Calcutta]",
+            Iso,
+            iana_parser,
+        )
+        .unwrap();
+        assert_eq!(
+            zoned_datetime.to_ixdtf(&iana_mapper).to_string(),
+            "2025-03-01T10:00:00[Asia/Kolkata]"
+        );
+
+        let zoned_datetime = ZonedDateTime::try_location_only_from_str(
+            "2025-03-01T10:00:00[Future/Zone]",
+            Iso,
+            iana_parser,
+        )
+        .unwrap();
+        assert_eq!(
+            zoned_datetime.to_ixdtf(&iana_mapper).to_string(),
+            "2025-03-01T10:00:00[Etc/Unknown]"
+        );
+
+        let zoned_datetime =
+            ZonedDateTime::try_offset_only_from_str("2025-03-01T10:00:00-00:30", Iso).unwrap();
+        assert_eq!(
+            zoned_datetime.to_ixdtf().to_string(),
+            "2025-03-01T10:00:00-00:30"
+        );
+
+        let time = Time::try_from_str("00:00:00").unwrap();
+        assert_eq!(time.to_ixdtf().to_string(), "00:00:00");
+        assert_eq!(Time::try_from_str(&time.to_ixdtf().to_string()), Ok(time));
+    }
 }
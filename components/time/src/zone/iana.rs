@@ -4,6 +4,8 @@
 
 //! Tools for parsing IANA time zone IDs.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use icu_provider::prelude::*;
 use zerovec::vecs::{VarZeroSliceIter, ZeroSliceIter};
 
@@ -591,6 +593,61 @@ impl TimeZoneAndCanonicalAndNormalized<'static> {
     };
 }
 
+/// A mapper from [`TimeZone`]s to their canonical IANA IDs.
+///
+/// The mapper is created from an [`IanaParserExtendedBorrowed`] and keeps an index of its
+/// time zones sorted by [`TimeZone`], so that lookups are a binary search.
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::subtags::subtag;
+/// use icu::time::zone::iana::{IanaMapper, IanaParserExtended};
+/// use icu::time::TimeZone;
+///
+/// let mapper = IanaMapper::new(IanaParserExtended::new());
+///
+/// assert_eq!(
+///     mapper.canonical_iana_id(TimeZone(subtag!("inccu"))),
+///     Some("Asia/Kolkata")
+/// );
+/// assert_eq!(mapper.canonical_iana_id(TimeZone(subtag!("zzzzz"))), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct IanaMapper<'a> {
+    parser: IanaParserExtendedBorrowed<'a>,
+    /// Indices into the time zones of `parser`, sorted by time zone.
+    index: Vec<u16>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> IanaMapper<'a> {
+    /// Creates a new [`IanaMapper`] for the time zones of an [`IanaParserExtendedBorrowed`].
+    pub fn new(parser: IanaParserExtendedBorrowed<'a>) -> Self {
+        let bcp47_ids = &parser.inner.data.bcp47_ids;
+        // There are about 450 time zones
+        let mut index = (0..bcp47_ids.len() as u16).collect::<Vec<_>>();
+        index.sort_unstable_by_key(|&i| bcp47_ids.get(usize::from(i)));
+        Self { parser, index }
+    }
+
+    /// Returns the canonical IANA ID of a [`TimeZone`], or `None` if the time zone is unknown.
+    pub fn canonical_iana_id(&self, time_zone: TimeZone) -> Option<&'a str> {
+        let bcp47_ids = &self.parser.inner.data.bcp47_ids;
+        let position = self
+            .index
+            .binary_search_by_key(&Some(time_zone), |&i| bcp47_ids.get(usize::from(i)))
+            .ok()?;
+        self.parser
+            .data
+            .normalized_iana_ids
+            .get(usize::from(*self.index.get(position)?))
+    }
+}
+
 /// The iterator returned by [`IanaParserExtendedBorrowed::iter()`]
 #[derive(Debug)]
 pub struct TimeZoneAndCanonicalIter<'a>(
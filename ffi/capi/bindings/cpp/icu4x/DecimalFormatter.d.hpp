@@ -63,7 +63,7 @@ public:
   /**
    * Creates a new {@link DecimalFormatter} from preconstructed locale data.
    *
-   * See the [Rust documentation for `DecimalSymbolsV1`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV1.html) for more information.
+   * See the [Rust documentation for `DecimalSymbolsV2`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV2.html) for more information.
    */
   inline static icu4x::diplomat::result<std::unique_ptr<icu4x::DecimalFormatter>, icu4x::DataError> create_with_manual_data(std::string_view plus_sign_prefix, std::string_view plus_sign_suffix, std::string_view minus_sign_prefix, std::string_view minus_sign_suffix, std::string_view decimal_separator, std::string_view grouping_separator, uint8_t primary_group_size, uint8_t secondary_group_size, uint8_t min_group_size, icu4x::diplomat::span<const char32_t> digits, std::optional<icu4x::DecimalGroupingStrategy> grouping_strategy);
 
@@ -78,7 +78,7 @@ pub mod ffi {
         }
 
         /// Creates a new [`DecimalFormatter`] from preconstructed locale data.
-        #[diplomat::rust_link(icu::decimal::provider::DecimalSymbolsV1, Struct)]
+        #[diplomat::rust_link(icu::decimal::provider::DecimalSymbolsV2, Struct)]
         #[expect(clippy::too_many_arguments)]
         pub fn create_with_manual_data(
             plus_sign_prefix: &DiplomatStr,
@@ -110,8 +110,8 @@ pub mod ffi {
             }
 
             use icu_decimal::provider::{
-                DecimalDigitsV1, DecimalSymbolStrsBuilder, DecimalSymbols, DecimalSymbolsV1,
-                GroupingSizes,
+                DecimalDigitsV1, DecimalSymbolWithExponentStrsBuilder, DecimalSymbolsV2,
+                DecimalSymbolsWithExponent, GroupingSizes,
             };
             let mut new_digits = ['\0'; 10];
             for (old, new) in digits
@@ -123,7 +123,7 @@ pub mod ffi {
                 *new = char::from_u32(old).unwrap_or(char::REPLACEMENT_CHARACTER);
             }
             let digits = new_digits;
-            let strings = DecimalSymbolStrsBuilder {
+            let strings = DecimalSymbolWithExponentStrsBuilder {
                 plus_sign_prefix: str_to_cow(plus_sign_prefix),
                 plus_sign_suffix: str_to_cow(plus_sign_suffix),
                 minus_sign_prefix: str_to_cow(minus_sign_prefix),
@@ -131,6 +131,7 @@ pub mod ffi {
                 decimal_separator: str_to_cow(decimal_separator),
                 grouping_separator: str_to_cow(grouping_separator),
                 numsys: "zyyy".into(),
+                exponential: "E".into(),
             };
 
             let grouping_sizes = GroupingSizes {
@@ -142,12 +143,15 @@ pub mod ffi {
             let mut options = DecimalFormatterOptions::default();
             options.grouping_strategy = grouping_strategy.map(Into::into);
 
-            struct Provider(RefCell<Option<DecimalSymbols<'static>>>, [char; 10]);
-            impl DataProvider<DecimalSymbolsV1> for Provider {
+            struct Provider(
+                RefCell<Option<DecimalSymbolsWithExponent<'static>>>,
+                [char; 10],
+            );
+            impl DataProvider<DecimalSymbolsV2> for Provider {
                 fn load(
                     &self,
                     _req: icu_provider::DataRequest,
-                ) -> Result<icu_provider::DataResponse<DecimalSymbolsV1>, icu_provider::DataError>
+                ) -> Result<icu_provider::DataResponse<DecimalSymbolsV2>, icu_provider::DataError>
                 {
                     Ok(DataResponse {
                         metadata: Default::default(),
@@ -174,7 +178,7 @@ pub mod ffi {
                 }
             }
             let provider = Provider(
-                RefCell::new(Some(DecimalSymbols {
+                RefCell::new(Some(DecimalSymbolsWithExponent {
                     strings: VarZeroCow::from_encodeable(&strings),
                     grouping_sizes,
                 })),
@@ -58,7 +58,7 @@ final class DecimalFormatter implements ffi.Finalizable {
 
   /// Creates a new [DecimalFormatter] from preconstructed locale data.
   ///
-  /// See the [Rust documentation for `DecimalSymbolsV1`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV1.html) for more information.
+  /// See the [Rust documentation for `DecimalSymbolsV2`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV2.html) for more information.
   ///
   /// Throws [DataError] on failure.
   static DecimalFormatter createWithManualData(String plusSignPrefix, String plusSignSuffix, String minusSignPrefix, String minusSignSuffix, String decimalSeparator, String groupingSeparator, int primaryGroupSize, int secondaryGroupSize, int minGroupSize, core.List<Rune> digits, [DecimalGroupingStrategy? groupingStrategy]) {
@@ -84,7 +84,7 @@ class DecimalFormatter internal constructor (
         
         /** Creates a new [DecimalFormatter] from preconstructed locale data.
         *
-        *See the [Rust documentation for `DecimalSymbolsV1`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV1.html) for more information.
+        *See the [Rust documentation for `DecimalSymbolsV2`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV2.html) for more information.
         */
         fun createWithManualData(plusSignPrefix: String, plusSignSuffix: String, minusSignPrefix: String, minusSignSuffix: String, decimalSeparator: String, groupingSeparator: String, primaryGroupSize: UByte, secondaryGroupSize: UByte, minGroupSize: UByte, digits: IntArray, groupingStrategy: DecimalGroupingStrategy?): Result<DecimalFormatter> {
             val plusSignPrefixSliceMemory = PrimitiveArrayTools.borrowUtf8(plusSignPrefix)
@@ -106,7 +106,7 @@ export class DecimalFormatter {
     /**
      * Creates a new {@link DecimalFormatter} from preconstructed locale data.
      *
-     * See the [Rust documentation for `DecimalSymbolsV1`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV1.html) for more information.
+     * See the [Rust documentation for `DecimalSymbolsV2`](https://docs.rs/icu/2.2.0/icu/decimal/provider/struct.DecimalSymbolsV2.html) for more information.
      */
     static createWithManualData(plusSignPrefix, plusSignSuffix, minusSignPrefix, minusSignSuffix, decimalSeparator, groupingSeparator, primaryGroupSize, secondaryGroupSize, minGroupSize, digits, groupingStrategy) {
         let functionCleanupArena = new diplomatRuntime.CleanupArena();
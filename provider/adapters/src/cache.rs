@@ -0,0 +1,405 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that cache the responses of another provider.
+//!
+//! Loading data from a [`BufferProvider`] deserializes the buffer on every request. When many
+//! formatters are constructed over the same data, wrapping the deserializing provider in a
+//! [`CachingProvider`] makes repeated requests return a clone of the already deserialized
+//! [`DataPayload`].
+//!
+//! ✨ *Enabled with the `sync` Cargo feature.*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_provider_adapters::cache::CachingProvider;
+//! use writeable::assert_writeable_eq;
+//!
+//! let json_provider = HelloWorldProvider.into_json_provider();
+//! let provider = CachingProvider::new(json_provider.as_deserializing(), 16);
+//!
+//! for _ in 0..3 {
+//!     let formatter = HelloWorldFormatter::try_new_unstable(
+//!         &provider,
+//!         icu_locale::locale!("de").into(),
+//!     )
+//!     .unwrap();
+//!     assert_writeable_eq!(formatter.format(), "Hallo Welt");
+//! }
+//!
+//! let stats = provider.stats();
+//! assert_eq!(stats.misses, 1);
+//! assert_eq!(stats.hits, 2);
+//! ```
+//!
+//! [`BufferProvider`]: icu_provider::buf::BufferProvider
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+use core::fmt;
+use icu_provider::marker::DataMarkerId;
+use icu_provider::prelude::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use yoke::Yokeable;
+
+type CacheKey = (DataMarkerId, DataIdentifierCow<'static>);
+
+/// Statistics about the usage of a [`CachingProvider`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// The number of requests that were served from the cache.
+    pub hits: u64,
+    /// The number of requests that were forwarded to the inner provider.
+    pub misses: u64,
+    /// The number of responses that were evicted to stay within the capacity.
+    pub evictions: u64,
+    /// The number of responses currently in the cache.
+    pub len: usize,
+    /// The maximum number of responses in the cache.
+    pub capacity: usize,
+}
+
+/// A data provider that caches the successful responses of the inner provider.
+///
+/// Responses are cached per [`DataMarkerInfo`] and [`DataIdentifierBorrowed`]. When the
+/// cache is full, the least recently used response is evicted. Errors, dry loads, and
+/// requests with [`DataRequestMetadata::attributes_prefix_match`] are not cached.
+///
+/// The cache is protected by a [`Mutex`], so a [`CachingProvider`] can be shared between
+/// threads if the inner provider can.
+///
+/// See the [module-level documentation](self) for an example.
+pub struct CachingProvider<P> {
+    inner: P,
+    cache: Mutex<Cache>,
+}
+
+struct Cache {
+    entries: BTreeMap<CacheKey, Entry>,
+    /// The keys of [`Self::entries`] by the time they were last used.
+    recency: BTreeMap<u64, CacheKey>,
+    clock: u64,
+    stats: CacheStats,
+}
+
+struct Entry {
+    response: Box<dyn Any + Send + Sync>,
+    last_used: u64,
+}
+
+impl<P> CachingProvider<P> {
+    /// Creates a [`CachingProvider`] that caches up to `capacity` responses of `inner`.
+    ///
+    /// A capacity of zero disables caching.
+    pub fn new(inner: P, capacity: usize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(Cache {
+                entries: BTreeMap::new(),
+                recency: BTreeMap::new(),
+                clock: 0,
+                stats: CacheStats {
+                    capacity,
+                    ..Default::default()
+                },
+            }),
+        }
+    }
+
+    /// Returns the statistics of this cache.
+    pub fn stats(&self) -> CacheStats {
+        let cache = self.lock();
+        CacheStats {
+            len: cache.entries.len(),
+            ..cache.stats
+        }
+    }
+
+    /// Removes all responses from the cache. The statistics are kept.
+    pub fn clear(&self) {
+        let mut cache = self.lock();
+        cache.entries.clear();
+        cache.recency.clear();
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the inner provider, dropping the cache.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cache> {
+        // A panic while holding the lock cannot leave the cache in an inconsistent state
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn load_cached<M>(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+        load: impl FnOnce() -> Result<DataResponse<M>, DataError>,
+    ) -> Result<DataResponse<M>, DataError>
+    where
+        M: DynamicDataMarker,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+        DataResponse<M>: Send + Sync,
+    {
+        if req.metadata.attributes_prefix_match {
+            return load();
+        }
+        let key = (marker.id, req.id.into_owned());
+        if let Some(response) = self.lock().get::<M>(&key) {
+            return Ok(response);
+        }
+        // The lock is not held while loading, so that other requests are not blocked.
+        let response = load()?;
+        self.lock().insert(key, response.clone());
+        Ok(response)
+    }
+}
+
+impl Cache {
+    fn get<M>(&mut self, key: &CacheKey) -> Option<DataResponse<M>>
+    where
+        M: DynamicDataMarker,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    {
+        self.clock += 1;
+        let clock = self.clock;
+        let response = self.entries.get_mut(key).and_then(|entry| {
+            let response = entry.response.downcast_ref::<DataResponse<M>>()?.clone();
+            let last_used = core::mem::replace(&mut entry.last_used, clock);
+            Some((response, last_used))
+        });
+        match response {
+            Some((response, last_used)) => {
+                self.recency.remove(&last_used);
+                self.recency.insert(clock, key.clone());
+                self.stats.hits += 1;
+                Some(response)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert<M>(&mut self, key: CacheKey, response: DataResponse<M>)
+    where
+        M: DynamicDataMarker,
+        DataResponse<M>: Send + Sync,
+    {
+        if self.stats.capacity == 0 {
+            return;
+        }
+        self.clock += 1;
+        let entry = Entry {
+            response: Box::new(response),
+            last_used: self.clock,
+        };
+        if let Some(previous) = self.entries.insert(key.clone(), entry) {
+            // Another thread loaded the same response concurrently
+            self.recency.remove(&previous.last_used);
+        }
+        self.recency.insert(self.clock, key);
+        while self.entries.len() > self.stats.capacity {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+impl<P> fmt::Debug for CachingProvider<P>
+where
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingProvider")
+            .field("inner", &self.inner)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M, P> DynamicDataProvider<M> for CachingProvider<P>
+where
+    M: DynamicDataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: DynamicDataProvider<M>,
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(marker, req, || self.inner.load_data(marker, req))
+    }
+}
+
+impl<M, P> DynamicDryDataProvider<M> for CachingProvider<P>
+where
+    M: DynamicDataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: DynamicDryDataProvider<M>,
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        self.inner.dry_load_data(marker, req)
+    }
+}
+
+impl<M, P> DataProvider<M> for CachingProvider<P>
+where
+    M: DataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: DataProvider<M>,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(M::INFO, req, || self.inner.load(req))
+    }
+}
+
+impl<M, P> DryDataProvider<M> for CachingProvider<P>
+where
+    M: DataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: DryDataProvider<M>,
+{
+    fn dry_load(&self, req: DataRequest) -> Result<DataResponseMetadata, DataError> {
+        self.inner.dry_load(req)
+    }
+}
+
+impl<M, P> IterableDynamicDataProvider<M> for CachingProvider<P>
+where
+    M: DynamicDataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: IterableDynamicDataProvider<M>,
+{
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<alloc::collections::BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        self.inner.iter_ids_for_marker(marker)
+    }
+}
+
+impl<M, P> IterableDataProvider<M> for CachingProvider<P>
+where
+    M: DataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send + Sync,
+    P: IterableDataProvider<M>,
+{
+    fn iter_ids(&self) -> Result<alloc::collections::BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        self.inner.iter_ids()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale::locale;
+    use icu_provider::hello_world::*;
+
+    fn load(provider: &impl DataProvider<HelloWorldV1>, locale: &DataLocale) -> String {
+        provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(locale),
+                ..Default::default()
+            })
+            .unwrap()
+            .payload
+            .get()
+            .message
+            .to_string()
+    }
+
+    #[test]
+    fn test_lru() {
+        let provider = CachingProvider::new(HelloWorldProvider, 2);
+        let de = locale!("de").into();
+        let en = locale!("en").into();
+        let ja = locale!("ja").into();
+
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(load(&provider, &en), "Hello World");
+        // de is now the most recently used
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        // evicts en
+        assert_eq!(load(&provider, &ja), "こんにちは世界");
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(load(&provider, &en), "Hello World");
+
+        assert_eq!(
+            provider.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 4,
+                evictions: 2,
+                len: 2,
+                capacity: 2,
+            }
+        );
+
+        provider.clear();
+        assert_eq!(provider.stats().len, 0);
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(provider.stats().misses, 5);
+    }
+
+    #[test]
+    fn test_errors_not_cached() {
+        let provider = CachingProvider::new(HelloWorldProvider, 2);
+        let unknown = DataLocale::from(locale!("zz"));
+        for _ in 0..2 {
+            assert!(
+                provider
+                    .load(DataRequest {
+                        id: DataIdentifierBorrowed::for_locale(&unknown),
+                        ..Default::default()
+                    })
+                    .map(|r: DataResponse<HelloWorldV1>| r.payload)
+                    .is_err()
+            );
+        }
+        let stats = provider.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (0, 2, 0));
+    }
+
+    #[test]
+    fn test_threads() {
+        let provider = CachingProvider::new(HelloWorldProvider, 4);
+        let de = DataLocale::from(locale!("de"));
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| assert_eq!(load(&provider, &de), "Hallo Welt"));
+            }
+        });
+        let stats = provider.stats();
+        assert_eq!(stats.hits + stats.misses, 4);
+        assert_eq!(stats.len, 1);
+    }
+}
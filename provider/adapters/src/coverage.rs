@@ -0,0 +1,354 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Introspection of the locales that a data provider supports.
+//!
+//! A [`DataCoverage`] compares the data identifiers of an iterable provider against the data
+//! that a formatter needs, without loading any payloads. The needed data can either be given as
+//! a list of markers, such as the `MARKERS` constants in the `provider` modules of the ICU4X
+//! components, or be discovered by running a constructor against a [`CoverageProbe`], which
+//! also captures the marker attributes that depend on the constructor's options.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use icu_locale::LocaleFallbacker;
+use icu_provider::prelude::*;
+use icu_provider::{DryDataProvider, DynamicDryDataProvider, IterableDynamicDataProvider};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Attributes {
+    Any,
+    Exact(String),
+    Prefix(String),
+}
+
+impl Attributes {
+    fn matches(&self, attributes: &DataMarkerAttributes) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Exact(a) => attributes.as_str() == a,
+            Self::Prefix(a) => attributes.as_str().starts_with(a.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Requirement {
+    marker: DataMarkerInfo,
+    attributes: Attributes,
+}
+
+/// A data provider that records the requests made to it.
+///
+/// This is passed to the constructor in [`DataCoverage::try_new_for_constructor`].
+#[derive(Debug)]
+pub struct CoverageProbe<'a, P: ?Sized> {
+    inner: &'a P,
+    requirements: RefCell<Vec<Requirement>>,
+}
+
+impl<P: ?Sized> CoverageProbe<'_, P> {
+    fn record(&self, marker: DataMarkerInfo, req: DataRequest) {
+        let attributes = req.id.marker_attributes.as_str().to_string();
+        let requirement = Requirement {
+            marker,
+            attributes: if req.metadata.attributes_prefix_match {
+                Attributes::Prefix(attributes)
+            } else {
+                Attributes::Exact(attributes)
+            },
+        };
+        let mut requirements = self.requirements.borrow_mut();
+        if !requirements.contains(&requirement) {
+            requirements.push(requirement);
+        }
+    }
+}
+
+impl<M: DataMarker, P: DataProvider<M> + ?Sized> DataProvider<M> for CoverageProbe<'_, P> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.record(M::INFO, req);
+        self.inner.load(req)
+    }
+}
+
+impl<M: DataMarker, P: DryDataProvider<M> + ?Sized> DryDataProvider<M> for CoverageProbe<'_, P> {
+    fn dry_load(&self, req: DataRequest) -> Result<DataResponseMetadata, DataError> {
+        self.record(M::INFO, req);
+        self.inner.dry_load(req)
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDataProvider<M> + ?Sized> DynamicDataProvider<M>
+    for CoverageProbe<'_, P>
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        self.record(marker, req);
+        self.inner.load_data(marker, req)
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDryDataProvider<M> + ?Sized> DynamicDryDataProvider<M>
+    for CoverageProbe<'_, P>
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        self.record(marker, req);
+        self.inner.dry_load_data(marker, req)
+    }
+}
+
+/// The locales for which a provider contains all data needed by a formatter.
+///
+/// A locale is covered if the provider contains data for exactly that locale, for every
+/// locale-dependent marker. Locales that were removed by deduplication during export are
+/// therefore not covered, even though they can be loaded through fallback.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_locale::LocaleFallbacker;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::coverage::DataCoverage;
+///
+/// // Any provider implementing `IterableDynamicDataProvider`, such as a `BlobDataProvider`
+/// let provider = HelloWorldProvider;
+///
+/// let coverage = DataCoverage::try_new_for_constructor(
+///     &provider,
+///     LocaleFallbacker::new().static_to_owned(),
+///     |probe| HelloWorldFormatter::try_new_unstable(probe, langid!("en").into()),
+/// )
+/// .expect("provider is iterable");
+///
+/// assert!(coverage.covers(&langid!("de").into()));
+/// assert!(!coverage.covers(&langid!("de-CH").into()));
+///
+/// // "de-CH" falls back to "de"
+/// assert_eq!(
+///     coverage
+///         .fallback_chains(langid!("de-CH").into())
+///         .get(&HelloWorldV1::INFO),
+///     Some(&vec![langid!("de-CH").into(), langid!("de").into()])
+/// );
+/// ```
+#[derive(Debug)]
+pub struct DataCoverage {
+    requirements: Vec<(Requirement, Vec<DataLocale>)>,
+    locales: Vec<DataLocale>,
+    fallbacker: LocaleFallbacker,
+}
+
+impl DataCoverage {
+    /// Computes the coverage of `provider` for the given markers, with any marker attributes.
+    pub fn try_new_for_markers<M, P>(
+        provider: &P,
+        markers: &[DataMarkerInfo],
+        fallbacker: LocaleFallbacker,
+    ) -> Result<Self, DataError>
+    where
+        M: DynamicDataMarker,
+        P: IterableDynamicDataProvider<M> + ?Sized,
+    {
+        let requirements = markers
+            .iter()
+            .map(|&marker| Requirement {
+                marker,
+                attributes: Attributes::Any,
+            })
+            .collect();
+        Self::try_new_internal(provider, requirements, fallbacker)
+    }
+
+    /// Computes the coverage of `provider` for the data loaded by `constructor`.
+    ///
+    /// The constructor receives a [`CoverageProbe`] wrapping `provider`, and has to succeed,
+    /// so it should use a locale that the provider supports.
+    pub fn try_new_for_constructor<M, P, T>(
+        provider: &P,
+        fallbacker: LocaleFallbacker,
+        constructor: impl FnOnce(&CoverageProbe<P>) -> Result<T, DataError>,
+    ) -> Result<Self, DataError>
+    where
+        M: DynamicDataMarker,
+        P: IterableDynamicDataProvider<M> + ?Sized,
+    {
+        let probe = CoverageProbe {
+            inner: provider,
+            requirements: Default::default(),
+        };
+        constructor(&probe)?;
+        Self::try_new_internal(provider, probe.requirements.into_inner(), fallbacker)
+    }
+
+    fn try_new_internal<M, P>(
+        provider: &P,
+        requirements: Vec<Requirement>,
+        fallbacker: LocaleFallbacker,
+    ) -> Result<Self, DataError>
+    where
+        M: DynamicDataMarker,
+        P: IterableDynamicDataProvider<M> + ?Sized,
+    {
+        let mut locales = None::<Vec<DataLocale>>;
+        let requirements = requirements
+            .into_iter()
+            .map(|requirement| {
+                let mut supported = provider
+                    .iter_ids_for_marker(requirement.marker)?
+                    .into_iter()
+                    .filter(|id| requirement.attributes.matches(&id.marker_attributes))
+                    .map(|id| id.locale)
+                    .collect::<Vec<_>>();
+                supported.sort_by(DataLocale::total_cmp);
+                supported.dedup();
+                if !requirement.marker.is_singleton {
+                    locales = Some(match locales.take() {
+                        None => supported.clone(),
+                        Some(mut locales) => {
+                            locales.retain(|l| contains(&supported, l));
+                            locales
+                        }
+                    });
+                }
+                Ok((requirement, supported))
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+        Ok(Self {
+            requirements,
+            locales: locales.unwrap_or_default(),
+            fallbacker,
+        })
+    }
+
+    /// Returns the markers that were considered.
+    pub fn markers(&self) -> impl Iterator<Item = DataMarkerInfo> {
+        let mut markers = self
+            .requirements
+            .iter()
+            .map(|(r, _)| r.marker)
+            .collect::<Vec<_>>();
+        markers.sort();
+        markers.dedup();
+        markers.into_iter()
+    }
+
+    /// Returns the covered locales, in an arbitrary but stable order.
+    ///
+    /// This is empty if none of the markers are locale-dependent.
+    pub fn locales(&self) -> &[DataLocale] {
+        &self.locales
+    }
+
+    /// Returns whether the provider contains all data for exactly this locale.
+    pub fn covers(&self, locale: &DataLocale) -> bool {
+        contains(&self.locales, locale)
+    }
+
+    /// Returns the locales that fallback would try for each locale-dependent marker.
+    ///
+    /// Each chain starts with the requested locale, and ends with the first locale that the
+    /// provider contains data for, or with `und`.
+    pub fn fallback_chains(&self, locale: DataLocale) -> BTreeMap<DataMarkerInfo, Vec<DataLocale>> {
+        self.markers()
+            .filter(|marker| !marker.is_singleton)
+            .map(|marker| {
+                let mut chain = Vec::new();
+                let mut iter = self
+                    .fallbacker
+                    .for_config(marker.fallback_config)
+                    .fallback_for(locale);
+                loop {
+                    let current = *iter.get();
+                    chain.push(current);
+                    if current.is_unknown()
+                        || self
+                            .requirements
+                            .iter()
+                            .filter(|(r, _)| r.marker == marker)
+                            .all(|(_, supported)| contains(supported, &current))
+                    {
+                        break;
+                    }
+                    iter.step();
+                }
+                (marker, chain)
+            })
+            .collect()
+    }
+}
+
+fn contains(sorted: &[DataLocale], locale: &DataLocale) -> bool {
+    sorted.binary_search_by(|l| l.total_cmp(locale)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale::langid;
+    use icu_provider::hello_world::*;
+
+    #[test]
+    fn test_attributes() {
+        let coverage = DataCoverage::try_new_for_constructor(
+            &HelloWorldProvider,
+            LocaleFallbacker::new().static_to_owned(),
+            |probe| {
+                DataProvider::<HelloWorldV1>::load(
+                    probe,
+                    DataRequest {
+                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                            DataMarkerAttributes::from_str_or_panic("reverse"),
+                            &langid!("en").into(),
+                        ),
+                        ..Default::default()
+                    },
+                )
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            coverage.locales(),
+            &[langid!("en").into(), langid!("ja").into()]
+        );
+        assert_eq!(
+            coverage.fallback_chains(langid!("de-AT").into()),
+            BTreeMap::from_iter([(
+                HelloWorldV1::INFO,
+                alloc::vec![
+                    langid!("de-AT").into(),
+                    langid!("de").into(),
+                    langid!("und").into()
+                ]
+            )])
+        );
+    }
+
+    #[test]
+    fn test_markers() {
+        let coverage = DataCoverage::try_new_for_markers(
+            &HelloWorldProvider,
+            &[HelloWorldV1::INFO],
+            LocaleFallbacker::new().static_to_owned(),
+        )
+        .unwrap();
+
+        assert!(coverage.covers(&langid!("de-AT").into()));
+        assert!(coverage.covers(&langid!("sr-Latn").into()));
+        assert!(!coverage.covers(&langid!("sr-Cyrl").into()));
+        assert_eq!(coverage.locales().len(), 25);
+    }
+}
@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc)), no_std)]
+#![cfg_attr(not(any(test, doc, feature = "std")), no_std)]
 #![cfg_attr(
     not(test),
     deny(
@@ -21,12 +21,25 @@
 //! - Use the [`either`] module to choose between multiple provider types at runtime.
 //! - Use the [`filter`] module to programmatically reject certain data requests.
 //! - Use the [`fallback`] module to automatically resolve arbitrary locales for data loading.
+//! - Use the [`coverage`] module to find the locales that a provider fully supports.
+//! - Use the `cache` module to avoid loading and deserializing the same data repeatedly.
+//! - Use the `overlay` module to patch individual fields of buffer data at runtime.
+//! - Use the `record` module to capture the data requests that an application makes.
+//! - Use the [`version`] module to detect data generated from different CLDR versions.
 
 extern crate alloc;
 
+#[cfg(feature = "sync")]
+pub mod cache;
+pub mod coverage;
 pub mod either;
 pub mod empty;
 pub mod fallback;
 pub mod filter;
 pub mod fixed;
 pub mod fork;
+#[cfg(feature = "overlay")]
+pub mod overlay;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod version;
@@ -0,0 +1,448 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that patch the data of another provider at runtime.
+//!
+//! An [`OverlayProvider`] wraps a [`BufferProvider`] and replaces individual fields of the
+//! data for specific markers and locales with values from a JSON or TOML override file. This
+//! allows fixing or customizing a few strings without regenerating the data.
+//!
+//! The override file is an object whose keys are data identifiers and whose values are
+//! partial data structs in the same shape as the JSON output of `icu4x-datagen`:
+//!
+//! - The key is either a locale, such as `"de-AT"`, or a locale followed by a slash and
+//!   marker attributes, such as `"de-AT/short"`. This is the [`Display`](core::fmt::Display)
+//!   format of [`DataIdentifierBorrowed`]. Use `"und"` for singleton markers.
+//! - Objects are merged field by field; all other values, including arrays, replace the
+//!   value of the inner provider.
+//!
+//! The merged data is validated against the data struct of the marker when the overrides are
+//! added, so that a typo in the override file is reported immediately and not when the data
+//! is first loaded.
+//!
+//! ✨ *Enabled with the `overlay` Cargo feature.*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_provider_adapters::overlay::OverlayProvider;
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut provider = OverlayProvider::new(HelloWorldProvider.into_json_provider());
+//! provider
+//!     .add_toml_overrides::<HelloWorldV1>(
+//!         r#"
+//!         [de]
+//!         message = "Servus Welt"
+//!         "#,
+//!     )
+//!     .unwrap();
+//!
+//! let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+//!     &provider,
+//!     icu_locale::locale!("de").into(),
+//! )
+//! .unwrap();
+//! assert_writeable_eq!(formatter.format(), "Servus Welt");
+//!
+//! // Other locales are loaded from the inner provider
+//! let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+//!     &provider,
+//!     icu_locale::locale!("en").into(),
+//! )
+//! .unwrap();
+//! assert_writeable_eq!(formatter.format(), "Hello World");
+//! ```
+//!
+//! [`BufferProvider`]: icu_provider::buf::BufferProvider
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::buf::BufferFormat;
+use icu_provider::marker::DataMarkerId;
+use icu_provider::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use yoke::Yokeable;
+
+/// A data provider that patches the buffers of the inner provider with overrides.
+///
+/// Overridden responses are always JSON buffers, so the deserializing provider needs the
+/// `deserialize_json` Cargo feature of `icu_provider`, even if the inner provider returns
+/// another format.
+///
+/// Requests are matched to overrides exactly. To apply overrides for fallback locales, wrap
+/// the [`OverlayProvider`] in a [`LocaleFallbackProvider`](crate::fallback::LocaleFallbackProvider)
+/// instead of wrapping a provider that performs fallback.
+///
+/// Overrides are merged with the data of the inner provider when they are added, and the
+/// merged data is stored. If the inner provider later returns different data for an
+/// overridden identifier, for example because it reloads its files, the stored snapshot is
+/// still returned. Add the overrides again to merge them with the new data.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug)]
+pub struct OverlayProvider<P> {
+    inner: P,
+    /// Sorted by key, so that requests can be looked up without allocating.
+    overrides: Vec<(
+        (DataMarkerId, DataIdentifierCow<'static>),
+        DataResponse<BufferMarker>,
+    )>,
+}
+
+impl<P> OverlayProvider<P> {
+    /// Creates an [`OverlayProvider`] without overrides.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the inner provider, dropping the overrides.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn get(
+        &self,
+        marker: DataMarkerInfo,
+        id: DataIdentifierBorrowed,
+    ) -> Option<&DataResponse<BufferMarker>> {
+        // Compares like `DataIdentifierCow`
+        self.overrides
+            .binary_search_by(|((m, i), _)| {
+                m.cmp(&marker.id)
+                    .then_with(|| (*i.marker_attributes).cmp(id.marker_attributes))
+                    .then_with(|| i.locale.total_cmp(id.locale))
+            })
+            .ok()
+            .and_then(|index| self.overrides.get(index))
+            .map(|(_, response)| response)
+    }
+}
+
+impl<P> OverlayProvider<P>
+where
+    P: DynamicDataProvider<BufferMarker>,
+{
+    /// Adds the overrides for the marker `M` from a JSON override file.
+    ///
+    /// The data struct of `M` needs to implement [`Serialize`], which is usually enabled with
+    /// the `datagen` Cargo feature of the component crate.
+    ///
+    /// If any of the overrides is invalid, an error is returned and none of them are added.
+    /// Overrides replace earlier overrides for the same identifiers.
+    ///
+    /// The overrides are merged with the data that the inner provider returns at this time,
+    /// see [`OverlayProvider`].
+    pub fn add_json_overrides<M>(&mut self, json: &str) -> Result<(), DataError>
+    where
+        M: DataMarker,
+        for<'de> <M::DataStruct as Yokeable<'de>>::Output: Deserialize<'de> + Serialize,
+    {
+        self.add_overrides::<M>(serde_json::from_str(json)?)
+    }
+
+    /// Adds the overrides for the marker `M` from a TOML override file.
+    ///
+    /// Tables are mapped to JSON objects. See [`Self::add_json_overrides`].
+    pub fn add_toml_overrides<M>(&mut self, toml: &str) -> Result<(), DataError>
+    where
+        M: DataMarker,
+        for<'de> <M::DataStruct as Yokeable<'de>>::Output: Deserialize<'de> + Serialize,
+    {
+        self.add_overrides::<M>(toml::from_str(toml).map_err(|e| {
+            DataErrorKind::Deserialize
+                .with_str_context("toml")
+                .with_display_context(&e)
+        })?)
+    }
+
+    fn add_overrides<M>(&mut self, overrides: BTreeMap<String, Value>) -> Result<(), DataError>
+    where
+        M: DataMarker,
+        for<'de> <M::DataStruct as Yokeable<'de>>::Output: Deserialize<'de> + Serialize,
+    {
+        let mut responses = Vec::with_capacity(overrides.len());
+        for (key, patch) in overrides {
+            let (locale, attributes) = key.split_once('/').unwrap_or((&key, ""));
+            let attributes = DataMarkerAttributes::try_from_str(attributes).map_err(|_| {
+                DataError::custom("Invalid marker attributes in override")
+                    .with_display_context(&key)
+            })?;
+            let locale = DataLocale::try_from_str(locale).map_err(|e| {
+                DataError::custom("Invalid locale in override").with_display_context(&e)
+            })?;
+            let req = DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+                ..Default::default()
+            };
+            let response = self
+                .apply::<M>(req, &patch)
+                .map_err(|e| e.with_req(M::INFO, req))?;
+            responses.push(((M::INFO.id, req.id.into_owned()), response));
+        }
+        for (key, response) in responses {
+            match self.overrides.binary_search_by(|(k, _)| k.cmp(&key)) {
+                Ok(index) => {
+                    if let Some(entry) = self.overrides.get_mut(index) {
+                        entry.1 = response;
+                    }
+                }
+                Err(index) => self.overrides.insert(index, (key, response)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the patch into the data of the inner provider and validates the result.
+    fn apply<M>(
+        &self,
+        req: DataRequest,
+        patch: &Value,
+    ) -> Result<DataResponse<BufferMarker>, DataError>
+    where
+        M: DataMarker,
+        for<'de> <M::DataStruct as Yokeable<'de>>::Output: Deserialize<'de> + Serialize,
+    {
+        let (mut metadata, mut value) = match self.inner.load_data(M::INFO, req) {
+            Ok(DataResponse { metadata, payload }) => {
+                let buffer_format = metadata.buffer_format.ok_or_else(|| {
+                    DataErrorKind::Deserialize
+                        .with_str_context("BufferProvider didn't set BufferFormat")
+                })?;
+                let value = if buffer_format == BufferFormat::Json {
+                    serde_json::from_slice(payload.get())?
+                } else {
+                    serde_json::to_value(payload.into_deserialized::<M>(buffer_format)?.get())?
+                };
+                (metadata, value)
+            }
+            // The override contains the complete data for this identifier
+            Err(DataError {
+                kind: DataErrorKind::IdentifierNotFound,
+                ..
+            }) => (Default::default(), Value::Null),
+            Err(e) => return Err(e),
+        };
+
+        merge(&mut value, patch);
+
+        let payload =
+            DataPayload::from_owned_buffer(serde_json::to_vec(&value)?.into_boxed_slice());
+        let data = payload.clone().into_deserialized::<M>(BufferFormat::Json)?;
+        // Deserialization ignores unknown fields, so check that all of them round-trip
+        check_fields(patch, &serde_json::to_value(data.get())?)?;
+
+        metadata.buffer_format = Some(BufferFormat::Json);
+        // The checksum of the inner provider doesn't describe the patched data
+        metadata.checksum = None;
+        Ok(DataResponse { metadata, payload })
+    }
+}
+
+/// Recursively merges the fields of `patch` into `base`.
+fn merge(base: &mut Value, patch: &Value) {
+    match (base, patch) {
+        (Value::Object(base), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge(base.entry(key.as_str()).or_insert(Value::Null), value);
+            }
+        }
+        (base, patch) => *base = patch.clone(),
+    }
+}
+
+/// Checks that all fields of `patch` are present in the serialized data struct.
+fn check_fields(patch: &Value, data: &Value) -> Result<(), DataError> {
+    if let (Value::Object(patch), Value::Object(data)) = (patch, data) {
+        for (key, value) in patch {
+            let Some(data) = data.get(key) else {
+                return Err(
+                    DataError::custom("Unknown field in override").with_display_context(key)
+                );
+            };
+            check_fields(value, data)?;
+        }
+    }
+    Ok(())
+}
+
+impl<P> DynamicDataProvider<BufferMarker> for OverlayProvider<P>
+where
+    P: DynamicDataProvider<BufferMarker>,
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        match self.get(marker, req.id) {
+            Some(response) => Ok(response.clone()),
+            None => self.inner.load_data(marker, req),
+        }
+    }
+}
+
+impl<P> DynamicDryDataProvider<BufferMarker> for OverlayProvider<P>
+where
+    P: DynamicDryDataProvider<BufferMarker>,
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        match self.get(marker, req.id) {
+            Some(response) => Ok(response.metadata.clone()),
+            None => self.inner.dry_load_data(marker, req),
+        }
+    }
+}
+
+impl<P> IterableDynamicDataProvider<BufferMarker> for OverlayProvider<P>
+where
+    P: IterableDynamicDataProvider<BufferMarker>,
+{
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let mut ids = self.inner.iter_ids_for_marker(marker)?;
+        ids.extend(
+            self.overrides
+                .iter()
+                .filter(|((id, _), _)| *id == marker.id)
+                .map(|((_, id), _)| id.as_borrowed().as_cow()),
+        );
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale::locale;
+    use icu_provider::buf::AsDeserializingBufferProvider;
+    use icu_provider::hello_world::*;
+
+    fn load(
+        provider: &impl BufferProvider,
+        id: DataIdentifierBorrowed,
+    ) -> Result<String, DataError> {
+        let response: DataResponse<HelloWorldV1> =
+            provider.as_deserializing().load(DataRequest {
+                id,
+                ..Default::default()
+            })?;
+        Ok(response.payload.get().message.to_string())
+    }
+
+    #[test]
+    fn test_json_overrides() {
+        let mut provider = OverlayProvider::new(HelloWorldProvider.into_json_provider());
+        provider
+            .add_json_overrides::<HelloWorldV1>(
+                r#"{
+                    "de": { "message": "Servus Welt" },
+                    "en/reverse": { "message": "World, Hello" },
+                    "de-CH": { "message": "Grüezi Welt" }
+                }"#,
+            )
+            .unwrap();
+
+        let de = locale!("de").into();
+        let de_ch = locale!("de-CH").into();
+        let en = locale!("en").into();
+        let reverse = DataMarkerAttributes::from_str_or_panic("reverse");
+
+        assert_eq!(
+            load(&provider, DataIdentifierBorrowed::for_locale(&de)).unwrap(),
+            "Servus Welt"
+        );
+        assert_eq!(
+            load(
+                &provider,
+                DataIdentifierBorrowed::for_marker_attributes_and_locale(reverse, &en)
+            )
+            .unwrap(),
+            "World, Hello"
+        );
+        // Not supported by the inner provider
+        assert_eq!(
+            load(&provider, DataIdentifierBorrowed::for_locale(&de_ch)).unwrap(),
+            "Grüezi Welt"
+        );
+        assert_eq!(
+            load(&provider, DataIdentifierBorrowed::for_locale(&en)).unwrap(),
+            "Hello World"
+        );
+
+        // Later overrides replace earlier ones
+        provider
+            .add_json_overrides::<HelloWorldV1>(r#"{ "de": { "message": "Moin Welt" } }"#)
+            .unwrap();
+        assert_eq!(
+            load(&provider, DataIdentifierBorrowed::for_locale(&de)).unwrap(),
+            "Moin Welt"
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut provider = OverlayProvider::new(HelloWorldProvider.into_json_provider());
+
+        // Wrong type
+        assert!(
+            provider
+                .add_json_overrides::<HelloWorldV1>(r#"{ "de": { "message": 42 } }"#)
+                .is_err()
+        );
+        // Unknown field
+        assert!(
+            provider
+                .add_toml_overrides::<HelloWorldV1>("[de]\nmesage = \"Servus Welt\"")
+                .is_err()
+        );
+        // Incomplete data for an identifier the inner provider doesn't support
+        assert!(
+            provider
+                .add_json_overrides::<HelloWorldV1>(r#"{ "de-CH": {} }"#)
+                .is_err()
+        );
+        // Invalid identifier
+        assert!(
+            provider
+                .add_json_overrides::<HelloWorldV1>(r#"{ "d%e": { "message": "Servus Welt" } }"#)
+                .is_err()
+        );
+        // Valid overrides are not added if another one is invalid
+        assert!(
+            provider
+                .add_json_overrides::<HelloWorldV1>(
+                    r#"{ "de": { "message": "Servus Welt" }, "en": { "message": null } }"#
+                )
+                .is_err()
+        );
+
+        assert_eq!(
+            load(
+                &provider,
+                DataIdentifierBorrowed::for_locale(&locale!("de").into())
+            )
+            .unwrap(),
+            "Hallo Welt"
+        );
+    }
+}
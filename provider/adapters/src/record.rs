@@ -0,0 +1,281 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that record the data requests made to them.
+//!
+//! Wrapping the provider of an application in a [`RecordingProvider`] while running its test
+//! suite or a staging deployment captures the data that the application actually loads. The
+//! recorded requests can be written as a usage manifest, which `icu4x-datagen` consumes with
+//! `--usage-manifest` to export only that data.
+//!
+//! ✨ *Enabled with the `record` Cargo feature.*
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locale::locale;
+//! use icu_provider::hello_world::*;
+//! use icu_provider::prelude::*;
+//! use icu_provider_adapters::record::RecordingProvider;
+//!
+//! let provider = RecordingProvider::new(HelloWorldProvider);
+//!
+//! HelloWorldFormatter::try_new_unstable(&provider, locale!("de").into())
+//!     .expect("locale exists");
+//! HelloWorldFormatter::try_new_unstable(&provider, locale!("ja").into())
+//!     .expect("locale exists");
+//!
+//! assert_eq!(
+//!     provider.usage_manifest(),
+//!     "HelloWorldV1 de\nHelloWorldV1 ja\n"
+//! );
+//!
+//! // The manifest can be passed to `icu4x-datagen --usage-manifest`
+//! std::fs::write(
+//!     std::env::temp_dir().join("usage.txt"),
+//!     provider.usage_manifest(),
+//! )
+//! .expect("file is writable");
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_provider::prelude::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A data request recorded by a [`RecordingProvider`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RecordedRequest {
+    /// The marker of the request.
+    pub marker: DataMarkerInfo,
+    /// The marker attributes and locale of the request.
+    pub id: DataIdentifierCow<'static>,
+    /// How often the request was made.
+    pub count: u64,
+    /// The result of the most recent request.
+    ///
+    /// On success, this is the locale of the returned data, if the inner provider
+    /// performed fallback.
+    pub result: Result<Option<DataLocale>, DataError>,
+}
+
+type Requests = BTreeMap<
+    (DataMarkerInfo, DataIdentifierCow<'static>),
+    (u64, Result<Option<DataLocale>, DataError>),
+>;
+
+/// A data provider that records every request made to it, and forwards it to the inner
+/// provider.
+///
+/// Repeated requests for the same marker and identifier are recorded once, so memory use is
+/// bounded by the amount of distinct data that is loaded. Requests are recorded whether or not
+/// they succeed. Dry loads are recorded like loads.
+///
+/// The records are protected by a [`Mutex`], so a [`RecordingProvider`] can be shared between
+/// threads if the inner provider can.
+///
+/// See the [module-level documentation](self) for an example.
+pub struct RecordingProvider<P> {
+    inner: P,
+    requests: Mutex<Requests>,
+}
+
+impl<P: fmt::Debug> fmt::Debug for RecordingProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingProvider")
+            .field("inner", &self.inner)
+            .field("requests", &self.lock().len())
+            .finish()
+    }
+}
+
+impl<P> RecordingProvider<P> {
+    /// Creates a [`RecordingProvider`] that hasn't recorded any requests yet.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            requests: Default::default(),
+        }
+    }
+
+    /// Returns the recorded requests, ordered by marker and identifier.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock()
+            .iter()
+            .map(|((marker, id), (count, result))| RecordedRequest {
+                marker: *marker,
+                id: id.clone(),
+                count: *count,
+                result: *result,
+            })
+            .collect()
+    }
+
+    /// Returns the recorded requests whose most recent attempt failed.
+    pub fn failures(&self) -> Vec<RecordedRequest> {
+        let mut requests = self.requests();
+        requests.retain(|r| r.result.is_err());
+        requests
+    }
+
+    /// Forgets all recorded requests.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Writes the recorded requests in the usage manifest format read by `icu4x-datagen`.
+    ///
+    /// The format contains one line per request, consisting of the marker name, a space, and
+    /// the locale, optionally followed by `/` and the marker attributes. Failed requests are
+    /// included, as the data might be missing from the inner provider but not from the
+    /// exported data. Singleton markers are written with the `und` locale.
+    pub fn write_usage_manifest<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        for (marker, id) in self.lock().keys() {
+            writeln!(sink, "{} {id}", marker.id.name())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the recorded requests in the usage manifest format read by `icu4x-datagen`.
+    ///
+    /// See [`write_usage_manifest`](Self::write_usage_manifest).
+    pub fn usage_manifest(&self) -> String {
+        let mut manifest = String::new();
+        let _infallible = self.write_usage_manifest(&mut manifest);
+        manifest
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn record(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+        result: Result<&DataResponseMetadata, &DataError>,
+    ) {
+        let result = match result {
+            Ok(metadata) => Ok(metadata.locale),
+            Err(e) => Err(*e),
+        };
+        let mut requests = self.lock();
+        let entry = requests
+            .entry((marker, req.id.into_owned()))
+            .or_insert((0, result));
+        entry.0 += 1;
+        entry.1 = result;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Requests> {
+        // The map is always in a consistent state, so a poisoned lock can be ignored
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<M: DataMarker, P: DataProvider<M>> DataProvider<M> for RecordingProvider<P> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        let result = self.inner.load(req);
+        self.record(M::INFO, req, result.as_ref().map(|r| &r.metadata));
+        result
+    }
+}
+
+impl<M: DataMarker, P: DryDataProvider<M>> DryDataProvider<M> for RecordingProvider<P> {
+    fn dry_load(&self, req: DataRequest) -> Result<DataResponseMetadata, DataError> {
+        let result = self.inner.dry_load(req);
+        self.record(M::INFO, req, result.as_ref());
+        result
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDataProvider<M>> DynamicDataProvider<M>
+    for RecordingProvider<P>
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        let result = self.inner.load_data(marker, req);
+        self.record(marker, req, result.as_ref().map(|r| &r.metadata));
+        result
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDryDataProvider<M>> DynamicDryDataProvider<M>
+    for RecordingProvider<P>
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        let result = self.inner.dry_load_data(marker, req);
+        self.record(marker, req, result.as_ref());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale::langid;
+    use icu_provider::hello_world::*;
+
+    #[test]
+    fn test_record() {
+        let provider = RecordingProvider::new(HelloWorldProvider);
+        let load = |attributes: &str, locale: DataLocale| {
+            DataProvider::<HelloWorldV1>::load(
+                &provider,
+                DataRequest {
+                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                        DataMarkerAttributes::from_str_or_panic(attributes),
+                        &locale,
+                    ),
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert!(load("", langid!("en").into()).is_ok());
+        assert!(load("", langid!("en").into()).is_ok());
+        assert!(load("reverse", langid!("ja").into()).is_ok());
+        assert!(load("", langid!("de-CH").into()).is_err());
+
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].id.locale, langid!("de-CH").into());
+        assert_eq!(requests[1].count, 2);
+        assert_eq!(requests[1].result, Ok(None));
+
+        let failures = provider.failures();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(
+            failures[0].result,
+            Err(DataError {
+                kind: DataErrorKind::IdentifierNotFound,
+                ..
+            })
+        ));
+
+        assert_eq!(
+            provider.usage_manifest(),
+            "HelloWorldV1 de-CH\nHelloWorldV1 en\nHelloWorldV1 ja/reverse\n"
+        );
+
+        provider.clear();
+        assert!(provider.requests().is_empty());
+    }
+}
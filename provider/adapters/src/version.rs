@@ -0,0 +1,203 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers that check that their children were generated from the same CLDR version.
+//!
+//! Data generated by `icu4x-datagen` contains a [`DataVersionV1`] singleton. Combining data
+//! generated from different CLDR versions can produce inconsistent results, so
+//! [`VersionCheckingProvider`] compares these versions when it is constructed.
+
+use crate::fork::MultiForkByMarkerProvider;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+use icu_provider::version::DataVersionV1;
+
+/// What to do if the child providers were generated from different CLDR versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OnVersionMismatch {
+    /// Fail construction with [`DataErrorKind::InconsistentData`].
+    Reject,
+    /// Log a warning and continue.
+    Warn,
+}
+
+/// A [`MultiForkByMarkerProvider`] that checks that all of its children were generated from
+/// the same CLDR version.
+///
+/// Children that don't contain [`DataVersionV1`], such as data generated by older versions
+/// of `icu4x-datagen`, are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider::version::*;
+/// use icu_provider_adapters::fixed::FixedProvider;
+/// use icu_provider_adapters::version::*;
+///
+/// let cldr = |cldr: &str| {
+///     FixedProvider::<DataVersionV1>::from_owned(DataVersion {
+///         cldr: cldr.to_owned().into(),
+///         icu4x: "2.2.0".into(),
+///     })
+/// };
+///
+/// let provider = VersionCheckingProvider::try_new(
+///     vec![cldr("48"), cldr("48")],
+///     OnVersionMismatch::Reject,
+/// )
+/// .expect("same CLDR version");
+/// assert_eq!(provider.cldr_version(), Some("48"));
+///
+/// assert!(matches!(
+///     VersionCheckingProvider::try_new(
+///         vec![cldr("48"), cldr("47")],
+///         OnVersionMismatch::Reject
+///     ),
+///     Err(DataError {
+///         kind: DataErrorKind::InconsistentData(_),
+///         ..
+///     })
+/// ));
+/// ```
+#[derive(Debug)]
+pub struct VersionCheckingProvider<P> {
+    inner: MultiForkByMarkerProvider<P>,
+    cldr_version: Option<String>,
+}
+
+impl<P: DataProvider<DataVersionV1>> VersionCheckingProvider<P> {
+    /// Creates a provider that forks between the children by marker, after comparing their
+    /// CLDR versions.
+    pub fn try_new(providers: Vec<P>, on_mismatch: OnVersionMismatch) -> Result<Self, DataError> {
+        let mut cldr_version = None::<String>;
+        for provider in providers.iter() {
+            let version = match provider.load(Default::default()) {
+                Ok(response) => response.payload,
+                Err(DataError {
+                    kind: DataErrorKind::MarkerNotFound,
+                    ..
+                }) => continue,
+                Err(e) => return Err(e),
+            };
+            let cldr = &version.get().cldr;
+            match cldr_version.as_deref() {
+                None => cldr_version = Some(cldr.as_ref().to_owned()),
+                Some(expected) if expected == cldr => {}
+                Some(expected) => match on_mismatch {
+                    OnVersionMismatch::Reject => {
+                        return Err(DataErrorKind::InconsistentData(DataVersionV1::INFO)
+                            .with_str_context("CLDR version mismatch"));
+                    }
+                    OnVersionMismatch::Warn => {
+                        icu_provider::log::warn!(
+                            "Combining data generated from CLDR {expected:?} and CLDR {cldr:?}"
+                        );
+                    }
+                },
+            }
+        }
+        Ok(Self {
+            inner: MultiForkByMarkerProvider::new(providers),
+            cldr_version,
+        })
+    }
+}
+
+impl<P> VersionCheckingProvider<P> {
+    /// Returns the CLDR version of the first child that contains [`DataVersionV1`].
+    pub fn cldr_version(&self) -> Option<&str> {
+        self.cldr_version.as_deref()
+    }
+
+    /// Returns a slice of the inner providers.
+    pub fn inner(&self) -> &[P] {
+        self.inner.inner()
+    }
+
+    /// Returns ownership of the inner providers to the caller.
+    pub fn into_inner(self) -> Vec<P> {
+        self.inner.into_inner()
+    }
+}
+
+impl<M: DataMarker, P: DataProvider<M>> DataProvider<M> for VersionCheckingProvider<P> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.inner.load(req)
+    }
+}
+
+impl<M: DataMarker, P: DryDataProvider<M>> DryDataProvider<M> for VersionCheckingProvider<P> {
+    fn dry_load(&self, req: DataRequest) -> Result<DataResponseMetadata, DataError> {
+        self.inner.dry_load(req)
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDataProvider<M>> DynamicDataProvider<M>
+    for VersionCheckingProvider<P>
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        self.inner.load_data(marker, req)
+    }
+}
+
+impl<M: DynamicDataMarker, P: DynamicDryDataProvider<M>> DynamicDryDataProvider<M>
+    for VersionCheckingProvider<P>
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        self.inner.dry_load_data(marker, req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::either::EitherProvider;
+    use crate::empty::EmptyDataProvider;
+    use crate::fixed::FixedProvider;
+    use icu_provider::version::DataVersion;
+
+    fn fixed(cldr: &'static str) -> FixedProvider<DataVersionV1> {
+        FixedProvider::from_owned(DataVersion {
+            cldr: cldr.into(),
+            icu4x: "2.2.0".into(),
+        })
+    }
+
+    #[test]
+    fn test_unversioned() {
+        let provider = VersionCheckingProvider::try_new(
+            alloc::vec![
+                EitherProvider::B(EmptyDataProvider::new()),
+                EitherProvider::A(fixed("48")),
+            ],
+            OnVersionMismatch::Reject,
+        )
+        .unwrap();
+        assert_eq!(provider.cldr_version(), Some("48"));
+    }
+
+    #[test]
+    fn test_warn() {
+        let provider = VersionCheckingProvider::try_new(
+            alloc::vec![fixed("48"), fixed("47")],
+            OnVersionMismatch::Warn,
+        )
+        .unwrap();
+        assert_eq!(provider.cldr_version(), Some("48"));
+        let response: DataResponse<DataVersionV1> = provider.load(Default::default()).unwrap();
+        assert_eq!(response.payload.get().cldr, "48");
+    }
+}
@@ -925,6 +925,9 @@ macro_rules! cb {
             if marker.id == icu_provider::hello_world::HelloWorldV1::INFO.id {
                 return databake::quote!(icu_provider::hello_world::HelloWorldV1);
             }
+            if marker.id == icu_provider::version::DataVersionV1::INFO.id {
+                return databake::quote!(icu_provider::version::DataVersionV1);
+            }
 
             $(
                 if marker.id.name() == stringify!($marker) {
@@ -3,12 +3,16 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::blob_schema::BlobSchema;
+#[cfg(feature = "compression")]
+use crate::compression::DecompressionCache;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 use icu_provider::Cart;
 use icu_provider::DynamicDryDataProvider;
 use icu_provider::buf::BufferFormat;
 use icu_provider::prelude::*;
+#[cfg(feature = "compression")]
+use std::sync::Mutex;
 use yoke::*;
 
 /// A data provider that reads from serialized blobs of data.
@@ -81,9 +85,21 @@ use yoke::*;
 ///
 /// assert_writeable_eq!(formatter.format(), "Ave, munde");
 /// ```
-#[derive(Clone)]
 pub struct BlobDataProvider {
     pub(crate) data: Yoke<BlobSchema<'static>, Option<Cart>>,
+    #[cfg(feature = "compression")]
+    cache: Mutex<DecompressionCache>,
+}
+
+impl Clone for BlobDataProvider {
+    /// Clones the provider. The clone starts with an empty decompression cache.
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            #[cfg(feature = "compression")]
+            cache: DecompressionCache::new(DecompressionCache::capacity(&self.cache)),
+        }
+    }
 }
 
 impl core::fmt::Debug for BlobDataProvider {
@@ -97,29 +113,71 @@ impl core::fmt::Debug for BlobDataProvider {
 impl BlobDataProvider {
     /// Create a [`BlobDataProvider`] from a blob of ICU4X data.
     ///
+    /// Compressed blobs can only be read with the `compression` Cargo feature.
+    ///
     /// ✨ *Enabled with the `alloc` Cargo feature.*
     #[cfg(feature = "alloc")]
     pub fn try_new_from_blob(blob: Box<[u8]>) -> Result<Self, DataError> {
-        Ok(Self {
-            data: Cart::try_make_yoke(blob, |bytes| {
-                BlobSchema::deserialize_and_check(&mut postcard::Deserializer::from_bytes(bytes))
-            })?,
-        })
+        Self::try_new_from_yoke(Cart::try_make_yoke(blob, |bytes| {
+            BlobSchema::deserialize_and_check(&mut postcard::Deserializer::from_bytes(bytes))
+        })?)
     }
 
     /// Create a [`BlobDataProvider`] from a static blob. This is a special case of
-    /// [`try_new_from_blob`](BlobDataProvider::try_new_from_blob) and is allocation-free.
+    /// [`try_new_from_blob`](BlobDataProvider::try_new_from_blob) and is allocation-free
+    /// for uncompressed blobs.
     pub fn try_new_from_static_blob(blob: &'static [u8]) -> Result<Self, DataError> {
+        Self::try_new_from_yoke(Yoke::new_owned(BlobSchema::deserialize_and_check(
+            &mut postcard::Deserializer::from_bytes(blob),
+        )?))
+    }
+
+    // Only fails without the `compression` Cargo feature
+    #[cfg_attr(feature = "compression", expect(clippy::unnecessary_wraps))]
+    fn try_new_from_yoke(data: Yoke<BlobSchema<'static>, Option<Cart>>) -> Result<Self, DataError> {
+        #[cfg(not(feature = "compression"))]
+        if data.get().is_compressed() {
+            return Err(DataError::custom(
+                "Reading compressed blobs requires the `compression` Cargo feature",
+            ));
+        }
         Ok(Self {
-            data: Yoke::new_owned(BlobSchema::deserialize_and_check(
-                &mut postcard::Deserializer::from_bytes(blob),
-            )?),
+            data,
+            #[cfg(feature = "compression")]
+            cache: DecompressionCache::new(crate::compression::DEFAULT_CACHE_CAPACITY),
         })
     }
 
+    /// Sets the number of decompressed buffers that are cached.
+    ///
+    /// Loading data from a compressed blob decompresses the buffer on first use. The most recently
+    /// decompressed buffers are kept, so that repeated requests for the same data don't decompress
+    /// it again. When the cache is full, the buffer that was decompressed first is evicted, no
+    /// matter how often it is used. The default capacity is 64 buffers; a capacity of zero
+    /// disables caching.
+    ///
+    /// This has no effect on uncompressed blobs, which are always read without copying.
+    ///
+    /// ✨ *Enabled with the `compression` Cargo feature.*
+    #[cfg(feature = "compression")]
+    pub fn with_decompression_cache_capacity(self, capacity: usize) -> Self {
+        Self {
+            data: self.data,
+            cache: DecompressionCache::new(capacity),
+        }
+    }
+
+    #[doc(hidden)] // for testing purposes only: checks if it is using the compressed format
+    pub fn internal_is_compressed(&self) -> bool {
+        self.data.get().is_compressed()
+    }
+
     #[doc(hidden)] // for testing purposes only: checks if it is using the Bigger format
     pub fn internal_is_using_bigger_format(&self) -> bool {
-        matches!(self.data.get(), BlobSchema::V003Bigger(..))
+        matches!(
+            self.data.get(),
+            BlobSchema::V003Bigger(..) | BlobSchema::V003CompressedBigger(..)
+        )
     }
 }
 
@@ -135,9 +193,18 @@ impl DynamicDataProvider<BufferMarker> for BlobDataProvider {
         let mut metadata = DataResponseMetadata::default();
         metadata.buffer_format = Some(BufferFormat::Postcard1);
         metadata.checksum = payload.get().1;
+        let buffer = payload.map_project(|(bytes, _), _| bytes);
+        #[cfg(feature = "compression")]
+        if self.data.get().is_compressed() {
+            return Ok(DataResponse {
+                metadata,
+                payload: crate::compression::decode(buffer, &self.cache)
+                    .map_err(|e| e.with_req(marker, req))?,
+            });
+        }
         Ok(DataResponse {
             metadata,
-            payload: DataPayload::from_yoked_buffer(payload.map_project(|(bytes, _), _| bytes)),
+            payload: DataPayload::from_yoked_buffer(buffer),
         })
     }
 }
@@ -205,6 +272,51 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_compressed() {
+        use icu_provider::buf::AsDeserializingBufferProvider;
+        use icu_provider::dynutil::UpcastDataPayload;
+
+        let mut blob: Vec<u8> = Vec::new();
+        let message = "Hello World! ".repeat(20);
+
+        {
+            let mut exporter = BlobExporter::new_compressed_with_sink(Box::new(&mut blob));
+
+            exporter
+                .put_payload(
+                    HelloWorldV1::INFO,
+                    DataIdentifierBorrowed::for_locale(&icu_locale_core::langid!("en").into()),
+                    &ExportMarker::upcast(DataPayload::<HelloWorldV1>::from_owned(HelloWorld {
+                        message: message.clone().into(),
+                    })),
+                )
+                .unwrap();
+            exporter
+                .flush(HelloWorldV1::INFO, Default::default())
+                .unwrap();
+
+            exporter.close().unwrap();
+        }
+
+        let provider = BlobDataProvider::try_new_from_blob(blob.into())
+            .unwrap()
+            .with_decompression_cache_capacity(1);
+        assert!(provider.internal_is_compressed());
+
+        for _ in 0..2 {
+            let response: DataResponse<HelloWorldV1> = provider
+                .as_deserializing()
+                .load(DataRequest {
+                    id: DataIdentifierBorrowed::for_locale(&icu_locale_core::langid!("en").into()),
+                    ..Default::default()
+                })
+                .unwrap();
+            assert_eq!(response.payload.get().message, message);
+        }
+    }
+
     #[test]
     fn test_singleton() {
         let mut blob: Vec<u8> = Vec::new();
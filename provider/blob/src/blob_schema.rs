@@ -2,6 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 use core::fmt::Write;
 use icu_provider::{marker::DataMarkerIdHash, prelude::*};
 use serde::Deserialize;
@@ -22,6 +24,11 @@ pub(crate) enum BlobSchema<'data> {
     V003(BlobSchemaV1<'data, Index16>),
     #[serde(borrow)]
     V003Bigger(BlobSchemaV1<'data, Index32>),
+    /// Like [`BlobSchema::V003`], but the buffers are compressed, see [`crate::compression`].
+    #[serde(borrow)]
+    V003Compressed(BlobSchemaV1<'data, Index16>),
+    #[serde(borrow)]
+    V003CompressedBigger(BlobSchemaV1<'data, Index32>),
 }
 
 // This is a valid separator as `DataLocale` will never produce it.
@@ -47,11 +54,31 @@ impl<'data> BlobSchema<'data> {
             BlobSchema::V001(..) | BlobSchema::V002(..) | BlobSchema::V002Bigger(..) => {
                 unreachable!("Unreachable blob schema")
             }
-            BlobSchema::V003(s) => s.load(marker, req),
-            BlobSchema::V003Bigger(s) => s.load(marker, req),
+            BlobSchema::V003(s) | BlobSchema::V003Compressed(s) => s.load(marker, req),
+            BlobSchema::V003Bigger(s) | BlobSchema::V003CompressedBigger(s) => s.load(marker, req),
         }
     }
 
+    /// Returns all markers in the blob, and the keys of their identifiers (see [`parse_id_key`]).
+    #[cfg(feature = "alloc")]
+    pub fn keys(&self) -> Result<Vec<(DataMarkerIdHash, Vec<String>)>, DataError> {
+        match self {
+            BlobSchema::V001(..) | BlobSchema::V002(..) | BlobSchema::V002Bigger(..) => {
+                Err(DataError::custom("Unsupported blob schema"))
+            }
+            BlobSchema::V003(s) | BlobSchema::V003Compressed(s) => Ok(s.keys()),
+            BlobSchema::V003Bigger(s) | BlobSchema::V003CompressedBigger(s) => Ok(s.keys()),
+        }
+    }
+
+    /// Whether the buffers returned by [`Self::load`] need to be decompressed.
+    pub fn is_compressed(&self) -> bool {
+        matches!(
+            self,
+            BlobSchema::V003Compressed(..) | BlobSchema::V003CompressedBigger(..)
+        )
+    }
+
     #[cfg(feature = "alloc")]
     pub fn iter_ids(
         &self,
@@ -61,8 +88,8 @@ impl<'data> BlobSchema<'data> {
             BlobSchema::V001(..) | BlobSchema::V002(..) | BlobSchema::V002Bigger(..) => {
                 unreachable!("Unreachable blob schema")
             }
-            BlobSchema::V003(s) => s.iter_ids(marker),
-            BlobSchema::V003Bigger(s) => s.iter_ids(marker),
+            BlobSchema::V003(s) | BlobSchema::V003Compressed(s) => s.iter_ids(marker),
+            BlobSchema::V003Bigger(s) | BlobSchema::V003CompressedBigger(s) => s.iter_ids(marker),
         }
     }
 
@@ -70,12 +97,40 @@ impl<'data> BlobSchema<'data> {
     fn check_invariants(&self) {
         match self {
             BlobSchema::V001(..) | BlobSchema::V002(..) | BlobSchema::V002Bigger(..) => (),
-            BlobSchema::V003(s) => s.check_invariants(),
-            BlobSchema::V003Bigger(s) => s.check_invariants(),
+            BlobSchema::V003(s) | BlobSchema::V003Compressed(s) => s.check_invariants(),
+            BlobSchema::V003Bigger(s) | BlobSchema::V003CompressedBigger(s) => s.check_invariants(),
         }
     }
 }
 
+/// Returns the key of an identifier in the locale tries.
+#[cfg(feature = "export")]
+pub(crate) fn id_key(id: DataIdentifierBorrowed) -> String {
+    let mut key = id.locale.write_to_string().into_owned();
+    if !id.marker_attributes.is_empty() {
+        key.push(REQUEST_SEPARATOR);
+        key.push_str(id.marker_attributes);
+    }
+    key
+}
+
+/// Parses a key of the locale tries, returning `None` for the checksum key.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_id_key(s: &str) -> Option<DataIdentifierCow<'static>> {
+    #[allow(unused_imports)]
+    use alloc::borrow::ToOwned;
+    if let Some((locale, attrs)) = s.split_once(REQUEST_SEPARATOR) {
+        Some(DataIdentifierCow::from_owned(
+            DataMarkerAttributes::try_from_str(attrs).ok()?.to_owned(),
+            locale.parse().ok()?,
+        ))
+    } else if s.as_bytes() == CHECKSUM_KEY {
+        None
+    } else {
+        Some(DataIdentifierCow::from_locale(s.parse().ok()?))
+    }
+}
+
 #[cfg_attr(feature = "export", derive(serde::Serialize))]
 #[derive(Debug, Clone, yoke::Yokeable)]
 pub enum NeverSchema {}
@@ -200,23 +255,26 @@ impl<'data, LocaleVecFormat: VarZeroVecFormat> BlobSchemaV1<'data, LocaleVecForm
             .ok_or_else(|| DataError::custom("Invalid blob bytes").with_marker(marker))?;
         Ok(ZeroTrieSimpleAscii::from_store(zerotrie)
             .iter()
-            .filter_map(|(s, _)| {
-                #[allow(unused_imports)]
-                use alloc::borrow::ToOwned;
-                if let Some((locale, attrs)) = s.split_once(REQUEST_SEPARATOR) {
-                    Some(DataIdentifierCow::from_owned(
-                        DataMarkerAttributes::try_from_str(attrs).ok()?.to_owned(),
-                        locale.parse().ok()?,
-                    ))
-                } else if s.as_bytes() == CHECKSUM_KEY {
-                    None
-                } else {
-                    Some(DataIdentifierCow::from_locale(s.parse().ok()?))
-                }
-            })
+            .filter_map(|(s, _)| parse_id_key(&s))
             .collect())
     }
 
+    #[cfg(feature = "alloc")]
+    fn keys(&self) -> Vec<(DataMarkerIdHash, Vec<String>)> {
+        self.markers
+            .iter()
+            .zip(self.locales.iter())
+            .map(|(marker, zerotrie)| {
+                let keys = ZeroTrieSimpleAscii::from_store(zerotrie)
+                    .iter()
+                    .filter(|(s, _)| s.as_bytes() != CHECKSUM_KEY)
+                    .map(|(s, _)| s)
+                    .collect();
+                (marker, keys)
+            })
+            .collect()
+    }
+
     /// Verifies the weak invariants using debug assertions
     #[cfg(debug_assertions)]
     fn check_invariants(&self) {
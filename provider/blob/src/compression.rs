@@ -0,0 +1,133 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compression of the payload buffers in a blob.
+//!
+//! In the compressed blob schemas, every payload buffer starts with a tag byte:
+//!
+//! - [`STORED`]: the rest of the buffer is the uncompressed payload.
+//! - [`LZ4`]: the rest of the buffer is the payload in the LZ4 block format, prefixed
+//!   with its uncompressed length as a little-endian `u32`.
+//!
+//! Buffers are only compressed if that makes them smaller, so that small buffers can still
+//! be loaded without copying. Checksums are never compressed.
+
+use alloc::collections::{BTreeMap, VecDeque};
+use icu_provider::Cart;
+use icu_provider::prelude::*;
+use std::sync::{Mutex, PoisonError};
+use yoke::Yoke;
+
+pub(crate) const STORED: u8 = 0;
+pub(crate) const LZ4: u8 = 1;
+
+/// The default number of decompressed buffers kept by a [`DecompressionCache`].
+pub(crate) const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+/// Compresses a payload buffer, prefixing it with its tag byte.
+#[cfg(feature = "export")]
+pub(crate) fn compress(buffer: &[u8]) -> Vec<u8> {
+    let compressed = lz4_flex::block::compress_prepend_size(buffer);
+    let (tag, body) = if compressed.len() < buffer.len() {
+        (LZ4, compressed.as_slice())
+    } else {
+        (STORED, buffer)
+    };
+    let mut output = Vec::with_capacity(body.len() + 1);
+    output.push(tag);
+    output.extend_from_slice(body);
+    output
+}
+
+/// A bounded cache of decompressed buffers. When it is full, the oldest buffer is evicted.
+#[derive(Debug)]
+pub(crate) struct DecompressionCache {
+    /// Decompressed buffers by the address of their compressed buffer.
+    ///
+    /// Buffers are deduplicated and never move within the blob, so their address identifies them.
+    buffers: BTreeMap<usize, DataPayload<BufferMarker>>,
+    /// The keys of [`Self::buffers`] in insertion order.
+    order: VecDeque<usize>,
+    capacity: usize,
+}
+
+impl DecompressionCache {
+    pub(crate) fn new(capacity: usize) -> Mutex<Self> {
+        Mutex::new(Self {
+            buffers: BTreeMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        })
+    }
+
+    pub(crate) fn capacity(cache: &Mutex<Self>) -> usize {
+        lock(cache).capacity
+    }
+
+    fn insert(&mut self, key: usize, payload: DataPayload<BufferMarker>) {
+        if self.capacity == 0 || self.buffers.insert(key, payload).is_some() {
+            // Another thread decompressed the same buffer concurrently
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(key) = self.order.pop_front() {
+                self.buffers.remove(&key);
+            }
+        }
+    }
+}
+
+fn lock(cache: &Mutex<DecompressionCache>) -> std::sync::MutexGuard<'_, DecompressionCache> {
+    // A panic while holding the lock cannot leave the cache in an inconsistent state
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Decodes a tagged buffer, borrowing it from the blob if it is stored uncompressed.
+pub(crate) fn decode(
+    buffer: Yoke<&'static [u8], Option<Cart>>,
+    cache: &Mutex<DecompressionCache>,
+) -> Result<DataPayload<BufferMarker>, DataError> {
+    match buffer.get().split_first() {
+        Some((&STORED, _)) => Ok(DataPayload::from_yoked_buffer(
+            buffer.map_project(|bytes, _| bytes.split_first().map(|(_, b)| b).unwrap_or_default()),
+        )),
+        Some((&LZ4, compressed)) => {
+            let key = buffer.get().as_ptr() as usize;
+            if let Some(payload) = lock(cache).buffers.get(&key) {
+                return Ok(payload.clone());
+            }
+            // The lock is not held while decompressing, so that other requests are not blocked.
+            let decompressed =
+                lz4_flex::block::decompress_size_prepended(compressed).map_err(|e| {
+                    DataError::custom("Invalid compressed buffer").with_display_context(&e)
+                })?;
+            let payload = DataPayload::from_owned_buffer(decompressed.into_boxed_slice());
+            lock(cache).insert(key, payload.clone());
+            Ok(payload)
+        }
+        _ => Err(DataError::custom("Invalid compressed buffer")),
+    }
+}
+
+#[cfg(all(test, feature = "export"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress() {
+        // Small buffers are not worth compressing
+        assert_eq!(compress(b"abc"), b"\0abc");
+
+        let buffer = b"Hello World! ".repeat(20);
+        let compressed = compress(&buffer);
+        assert_eq!(compressed.first(), Some(&LZ4));
+        assert!(compressed.len() < buffer.len());
+
+        let cache = DecompressionCache::new(1);
+        let payload = decode(Yoke::new_owned(&*compressed.leak()), &cache).unwrap();
+        assert_eq!(*payload.get(), &buffer[..]);
+        assert_eq!(lock(&cache).buffers.len(), 1);
+    }
+}
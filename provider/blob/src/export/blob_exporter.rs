@@ -29,6 +29,8 @@ pub struct BlobExporter<'w> {
     all_markers: Mutex<BTreeSet<DataMarkerIdHash>>,
     /// Map from blob to blob ID
     unique_resources: Mutex<HashMap<Vec<u8>, usize>>,
+    /// Whether to compress the buffers
+    compressed: bool,
     sink: Box<dyn std::io::Write + Sync + 'w>,
 }
 
@@ -38,6 +40,7 @@ impl core::fmt::Debug for BlobExporter<'_> {
             .field("resources", &self.resources)
             .field("unique_resources", &self.unique_resources)
             .field("all_markers", &self.all_markers)
+            .field("compressed", &self.compressed)
             .field("sink", &"<sink>")
             .finish()
     }
@@ -54,9 +57,27 @@ impl<'w> BlobExporter<'w> {
             unique_resources: Default::default(),
             checksums: Default::default(),
             all_markers: Default::default(),
+            compressed: false,
             sink,
         }
     }
+
+    /// Creates a [`BlobExporter`] that writes a compressed blob to the given I/O stream.
+    ///
+    /// Every buffer is compressed individually with LZ4, unless that doesn't make it smaller.
+    /// [`BlobDataProvider`](crate::BlobDataProvider) decompresses buffers lazily when they are
+    /// loaded, which requires its `compression` Cargo feature.
+    ///
+    /// Compressed blobs are smaller, but loading data from them is slower and allocates.
+    ///
+    /// ✨ *Enabled with the `compression` Cargo feature.*
+    #[cfg(feature = "compression")]
+    pub fn new_compressed_with_sink(sink: Box<dyn std::io::Write + Sync + 'w>) -> Self {
+        Self {
+            compressed: true,
+            ..Self::new_with_sink(sink)
+        }
+    }
 }
 
 impl DataExporter for BlobExporter<'_> {
@@ -85,14 +106,7 @@ impl DataExporter for BlobExporter<'_> {
             .expect("poison")
             .entry(marker.id.hashed())
             .or_default()
-            .entry({
-                let mut key = id.locale.to_string();
-                if !id.marker_attributes.is_empty() {
-                    key.push(REQUEST_SEPARATOR);
-                    key.push_str(id.marker_attributes);
-                }
-                key.into_bytes()
-            })
+            .entry(id_key(id).into_bytes())
             .or_insert(idx);
         Ok(())
     }
@@ -145,7 +159,16 @@ impl BlobExporter<'_> {
 
         // Convert the sorted list to a VarZeroVec
         let vzv: VarZeroVec<[u8], Index32> = {
-            let buffers: Vec<Vec<u8>> = sorted.into_iter().map(|(blob, _)| blob).collect();
+            let buffers: Vec<Vec<u8>> = sorted
+                .into_iter()
+                .map(|(blob, _)| {
+                    #[cfg(feature = "compression")]
+                    if self.compressed {
+                        return crate::compression::compress(&blob);
+                    }
+                    blob
+                })
+                .collect();
             buffers.as_slice().into()
         };
 
@@ -190,11 +213,16 @@ impl BlobExporter<'_> {
             if let Ok(locales_vzv) =
                 VarZeroVecOwned::<[u8]>::try_from_elements(locales_vec.as_slice())
             {
-                let blob = BlobSchema::V003(BlobSchemaV1 {
+                let schema = BlobSchemaV1 {
                     markers: &markers,
                     locales: &locales_vzv,
                     buffers: &vzv,
-                });
+                };
+                let blob = if self.compressed {
+                    BlobSchema::V003Compressed(schema)
+                } else {
+                    BlobSchema::V003(schema)
+                };
                 log::info!("Serializing blob to output stream...");
 
                 let output = postcard::to_allocvec(&blob)?;
@@ -204,11 +232,16 @@ impl BlobExporter<'_> {
                 let locales_vzv =
                     VarZeroVecOwned::<[u8], Index32>::try_from_elements(locales_vec.as_slice())
                         .expect("Locales vector does not fit in Index32 buffer!");
-                let blob = BlobSchema::V003Bigger(BlobSchemaV1 {
+                let schema = BlobSchemaV1 {
                     markers: &markers,
                     locales: &locales_vzv,
                     buffers: &vzv,
-                });
+                };
+                let blob = if self.compressed {
+                    BlobSchema::V003CompressedBigger(schema)
+                } else {
+                    BlobSchema::V003Bigger(schema)
+                };
                 log::info!("Serializing blob to output stream...");
 
                 let output = postcard::to_allocvec(&blob)?;
@@ -3,7 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc, feature = "export")), no_std)]
+#![cfg_attr(
+    not(any(test, doc, feature = "export", feature = "compression")),
+    no_std
+)]
 #![cfg_attr(
     not(test),
     deny(
@@ -24,6 +27,12 @@
 //! $ icu4x-datagen --markers all --locales full --format blob --out data.postcard
 //! ```
 //!
+//! To combine a base blob with blobs that are loaded later, such as language packs, use
+//! [`MultiBlobDataProvider`].
+//!
+//! To make the blob smaller, add the `--compress` option. Buffers in compressed blobs are
+//! decompressed lazily when they are loaded, which requires the `compression` Cargo feature.
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`BufferProvider`]: icu_provider::buf::BufferProvider
 //! [`icu_provider_export`]: ../icu_provider_export/index.html
@@ -33,8 +42,14 @@ extern crate alloc;
 
 mod blob_data_provider;
 mod blob_schema;
+#[cfg(feature = "compression")]
+mod compression;
+#[cfg(feature = "alloc")]
+mod multi_blob_data_provider;
 
 #[cfg(feature = "export")]
 pub mod export;
 
 pub use blob_data_provider::BlobDataProvider;
+#[cfg(feature = "alloc")]
+pub use multi_blob_data_provider::{BlobConflict, BlobId, MultiBlobDataProvider};
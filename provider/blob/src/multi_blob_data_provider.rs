@@ -0,0 +1,487 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::BlobDataProvider;
+use crate::blob_schema::{REQUEST_SEPARATOR, parse_id_key};
+use alloc::collections::btree_map::Entry;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use icu_provider::DynamicDryDataProvider;
+use icu_provider::marker::DataMarkerIdHash;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+use zerotrie::ZeroTrieSimpleAscii;
+
+/// The identifier of a blob in a [`MultiBlobDataProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlobId(u64);
+
+/// An identifier that is contained in more than one blob of a [`MultiBlobDataProvider`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BlobConflict {
+    /// The hash of the data marker.
+    pub marker: DataMarkerIdHash,
+    /// The identifier contained in both blobs.
+    pub id: DataIdentifierCow<'static>,
+    /// The blob whose data is returned.
+    pub used: BlobId,
+    /// The blob whose data is shadowed.
+    pub shadowed: BlobId,
+}
+
+/// A data provider that reads from multiple blobs, such as a base blob and language packs
+/// that are downloaded later.
+///
+/// Unlike forking between several [`BlobDataProvider`]s, this builds a combined index of the
+/// markers and identifiers in all blobs, so that every request is answered by looking up a
+/// single blob. Blobs can be added and removed at runtime.
+///
+/// If more than one blob contains the same identifier for a marker, the data from the blob
+/// that was added first is returned. These conflicts are reported when a blob is added, and
+/// by [`MultiBlobDataProvider::conflicts`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_core::locale;
+/// use icu_provider::hello_world::HelloWorldFormatter;
+/// use icu_provider_blob::{BlobDataProvider, MultiBlobDataProvider};
+/// use writeable::assert_writeable_eq;
+///
+/// let blob = std::fs::read("tests/data/v3.postcard")
+///     .expect("Reading pre-computed postcard buffer");
+///
+/// let mut provider = MultiBlobDataProvider::new();
+/// let (base, conflicts) = provider
+///     .add_blob(
+///         BlobDataProvider::try_new_from_blob(blob.clone().into_boxed_slice())
+///             .expect("Deserialization should succeed"),
+///     )
+///     .expect("Supported blob schema");
+/// assert!(conflicts.is_empty());
+///
+/// // Adding a blob with the same identifiers reports conflicts
+/// let (pack, conflicts) = provider
+///     .add_blob(
+///         BlobDataProvider::try_new_from_blob(blob.into_boxed_slice())
+///             .expect("Deserialization should succeed"),
+///     )
+///     .expect("Supported blob schema");
+/// assert!(!conflicts.is_empty());
+/// assert!(conflicts.iter().all(|c| c.used == base && c.shadowed == pack));
+///
+/// // Removing a blob resolves its conflicts
+/// provider.remove_blob(base);
+/// assert!(provider.conflicts().is_empty());
+///
+/// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+///     &provider,
+///     locale!("la").into(),
+/// )
+/// .expect("locale exists");
+///
+/// assert_writeable_eq!(formatter.format(), "Ave, munde");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiBlobDataProvider {
+    blobs: BTreeMap<BlobId, BlobDataProvider>,
+    markers: BTreeMap<DataMarkerIdHash, MarkerIndex>,
+    next_id: u64,
+}
+
+/// The blobs that contain a marker, and the blobs that contain each of its identifiers.
+#[derive(Debug, Clone, Default)]
+struct MarkerIndex {
+    /// The blobs that contain the marker, in the order they were added.
+    blobs: Vec<BlobId>,
+    /// Map from identifier to an index into `owners`.
+    ///
+    /// The identifiers are stored like in the locale tries of a blob, so that they don't need
+    /// to be allocated, and prefix matches of attributes behave like in a single blob.
+    ids: ZeroTrieSimpleAscii<Vec<u8>>,
+    /// The blobs that contain each identifier, in the order they were added.
+    owners: Vec<Vec<BlobId>>,
+}
+
+impl MarkerIndex {
+    fn try_new(
+        blobs: Vec<BlobId>,
+        entries: BTreeMap<String, Vec<BlobId>>,
+    ) -> Result<Self, DataError> {
+        let ids = ZeroTrieSimpleAscii::try_from_btree_map_str(
+            &entries
+                .keys()
+                .enumerate()
+                .map(|(i, key)| (key.as_str(), i))
+                .collect(),
+        )
+        .map_err(|_| DataError::custom("Invalid blob bytes"))?;
+        Ok(Self {
+            blobs,
+            ids,
+            owners: entries.into_values().collect(),
+        })
+    }
+
+    /// Returns the keys of all identifiers, and the blobs that contain them.
+    fn entries(&self) -> impl Iterator<Item = (String, &Vec<BlobId>)> + '_ {
+        self.ids
+            .iter()
+            .filter_map(|(key, i)| Some((key, self.owners.get(i)?)))
+    }
+
+    /// Returns the blobs that contain the identifier of the request.
+    fn get(&self, req: DataRequest) -> Option<&Vec<BlobId>> {
+        let mut cursor = self.ids.cursor();
+        let _infallible_ascii = req.id.locale.write_to(&mut cursor);
+        let index = if !req.id.marker_attributes.is_empty() {
+            let _infallible_ascii = cursor.write_char(REQUEST_SEPARATOR);
+            req.id.marker_attributes.write_to(&mut cursor).ok()?;
+            // The first identifier in trie order, like in `BlobSchemaV1::load`
+            loop {
+                if let Some(v) = cursor.take_value() {
+                    break Some(v);
+                }
+                if !req.metadata.attributes_prefix_match || cursor.probe(0).is_none() {
+                    break None;
+                }
+            }
+        } else {
+            cursor.take_value()
+        }?;
+        self.owners.get(index)
+    }
+}
+
+impl MultiBlobDataProvider {
+    /// Creates a [`MultiBlobDataProvider`] without any blobs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a blob, returning its [`BlobId`] and the identifiers that are already
+    /// contained in other blobs.
+    ///
+    /// Returns an error if the blob uses a schema that cannot be indexed.
+    pub fn add_blob(
+        &mut self,
+        blob: BlobDataProvider,
+    ) -> Result<(BlobId, Vec<BlobConflict>), DataError> {
+        let blob_id = BlobId(self.next_id);
+        let mut conflicts = Vec::new();
+        let mut indices = Vec::new();
+        for (marker, keys) in blob.data.get().keys()? {
+            let index = self.markers.get(&marker);
+            let mut blobs = index.map(|i| i.blobs.clone()).unwrap_or_default();
+            blobs.push(blob_id);
+            let mut entries = index
+                .into_iter()
+                .flat_map(MarkerIndex::entries)
+                .map(|(key, blobs)| (key, blobs.clone()))
+                .collect::<BTreeMap<_, _>>();
+            for key in keys {
+                match entries.entry(key) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(vec![blob_id]);
+                    }
+                    Entry::Occupied(mut entry) => {
+                        if let Some(&used) = entry.get().first() {
+                            conflicts.extend(conflict(marker, entry.key(), used, blob_id));
+                        }
+                        entry.get_mut().push(blob_id);
+                    }
+                }
+            }
+            indices.push((marker, MarkerIndex::try_new(blobs, entries)?));
+        }
+        self.markers.extend(indices);
+        self.blobs.insert(blob_id, blob);
+        self.next_id += 1;
+        Ok((blob_id, conflicts))
+    }
+
+    /// Removes a blob, returning it if it was part of this provider.
+    ///
+    /// Identifiers that were shadowed by the blob are loaded from the other blobs afterwards.
+    pub fn remove_blob(&mut self, blob_id: BlobId) -> Option<BlobDataProvider> {
+        let blob = self.blobs.remove(&blob_id)?;
+        for (marker, index) in core::mem::take(&mut self.markers) {
+            if !index.blobs.contains(&blob_id) {
+                self.markers.insert(marker, index);
+                continue;
+            }
+            let mut blobs = index.blobs.clone();
+            blobs.retain(|&b| b != blob_id);
+            if blobs.is_empty() {
+                continue;
+            }
+            let entries = index
+                .entries()
+                .map(|(key, blobs)| {
+                    let mut blobs = blobs.clone();
+                    blobs.retain(|&b| b != blob_id);
+                    (key, blobs)
+                })
+                .filter(|(_, blobs)| !blobs.is_empty())
+                .collect();
+            // The keys were read from a trie, so they are valid
+            if let Ok(index) = MarkerIndex::try_new(blobs, entries) {
+                self.markers.insert(marker, index);
+            }
+        }
+        Some(blob)
+    }
+
+    /// Returns all identifiers that are contained in more than one blob.
+    pub fn conflicts(&self) -> Vec<BlobConflict> {
+        self.markers
+            .iter()
+            .flat_map(|(&marker, index)| index.entries().map(move |entry| (marker, entry)))
+            .filter_map(|(marker, (key, blobs))| Some((marker, key, blobs.split_first()?)))
+            .flat_map(|(marker, key, (&used, shadowed))| {
+                shadowed
+                    .iter()
+                    .filter_map(|&shadowed| conflict(marker, &key, used, shadowed))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the blob to load the request from.
+    ///
+    /// The blob contains the identifier that its own prefix match resolves to, as it is the
+    /// first in trie order among all blobs.
+    fn lookup(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<&BlobDataProvider, DataError> {
+        if marker.is_singleton && !req.id.locale.is_unknown() {
+            return Err(DataErrorKind::InvalidRequest.with_req(marker, req));
+        }
+        let index = self
+            .markers
+            .get(&marker.id.hashed())
+            .ok_or_else(|| DataErrorKind::MarkerNotFound.with_req(marker, req))?;
+        let blobs = index
+            .get(req)
+            .ok_or_else(|| DataErrorKind::IdentifierNotFound.with_req(marker, req))?;
+        blobs
+            .first()
+            .and_then(|blob_id| self.blobs.get(blob_id))
+            .ok_or_else(|| DataError::custom("Invalid blob index").with_req(marker, req))
+    }
+}
+
+fn conflict(
+    marker: DataMarkerIdHash,
+    key: &str,
+    used: BlobId,
+    shadowed: BlobId,
+) -> Option<BlobConflict> {
+    Some(BlobConflict {
+        marker,
+        id: parse_id_key(key)?,
+        used,
+        shadowed,
+    })
+}
+
+impl DynamicDataProvider<BufferMarker> for MultiBlobDataProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        self.lookup(marker, req)?.load_data(marker, req)
+    }
+}
+
+impl DynamicDryDataProvider<BufferMarker> for MultiBlobDataProvider {
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        self.lookup(marker, req)?.dry_load_data(marker, req)
+    }
+}
+
+impl IterableDynamicDataProvider<BufferMarker> for MultiBlobDataProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let index = self
+            .markers
+            .get(&marker.id.hashed())
+            .ok_or_else(|| DataErrorKind::MarkerNotFound.with_marker(marker))?;
+        Ok(index
+            .entries()
+            .filter_map(|(key, _)| parse_id_key(&key))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::export::*;
+    use icu_locale_core::langid;
+    use icu_provider::buf::AsDeserializingBufferProvider;
+    use icu_provider::dynutil::UpcastDataPayload;
+    use icu_provider::export::*;
+    use icu_provider::hello_world::*;
+
+    /// Exports the hello world data for the locales matching `filter` to a blob.
+    fn blob(filter: impl Fn(&DataIdentifierCow) -> bool) -> BlobDataProvider {
+        let mut blob: Vec<u8> = Vec::new();
+        {
+            let mut exporter = BlobExporter::new_with_sink(Box::new(&mut blob));
+            for id in HelloWorldProvider
+                .iter_ids()
+                .unwrap()
+                .iter()
+                .filter(|id| filter(id))
+            {
+                let response: DataResponse<HelloWorldV1> = HelloWorldProvider
+                    .load(DataRequest {
+                        id: id.as_borrowed(),
+                        ..Default::default()
+                    })
+                    .unwrap();
+                exporter
+                    .put_payload(
+                        HelloWorldV1::INFO,
+                        id.as_borrowed(),
+                        &ExportMarker::upcast(response.payload),
+                    )
+                    .unwrap();
+            }
+            exporter
+                .flush(HelloWorldV1::INFO, Default::default())
+                .unwrap();
+            exporter.close().unwrap();
+        }
+        BlobDataProvider::try_new_from_blob(blob.into()).unwrap()
+    }
+
+    fn load(provider: &MultiBlobDataProvider, req: DataRequest) -> Result<String, DataError> {
+        let response: DataResponse<HelloWorldV1> = provider.as_deserializing().load(req)?;
+        Ok(response.payload.get().message.to_string())
+    }
+
+    fn load_locale(provider: &MultiBlobDataProvider, locale: &DataLocale) -> Option<String> {
+        load(
+            provider,
+            DataRequest {
+                id: DataIdentifierBorrowed::for_locale(locale),
+                ..Default::default()
+            },
+        )
+        .ok()
+    }
+
+    #[test]
+    fn test_partitioned() {
+        let mut provider = MultiBlobDataProvider::new();
+        let de = langid!("de").into();
+        let en = langid!("en").into();
+
+        let (base, conflicts) = provider
+            .add_blob(blob(|id| id.locale.language.as_str() == "en"))
+            .unwrap();
+        assert!(conflicts.is_empty());
+        let (pack, conflicts) = provider
+            .add_blob(blob(|id| id.locale.language.as_str() == "de"))
+            .unwrap();
+        assert!(conflicts.is_empty());
+        assert_ne!(base, pack);
+
+        assert_eq!(load_locale(&provider, &en).as_deref(), Some("Hello World"));
+        assert_eq!(load_locale(&provider, &de).as_deref(), Some("Hallo Welt"));
+        assert_eq!(
+            provider
+                .iter_ids_for_marker(HelloWorldV1::INFO)
+                .unwrap()
+                .len(),
+            HelloWorldProvider
+                .iter_ids()
+                .unwrap()
+                .iter()
+                .filter(|id| matches!(id.locale.language.as_str(), "de" | "en"))
+                .count()
+        );
+
+        // Prefix matches are resolved across blobs
+        assert_eq!(
+            load(
+                &provider,
+                DataRequest {
+                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                        DataMarkerAttributes::from_str_or_panic("rev"),
+                        &en,
+                    ),
+                    metadata: {
+                        let mut metadata = DataRequestMetadata::default();
+                        metadata.attributes_prefix_match = true;
+                        metadata
+                    },
+                },
+            )
+            .unwrap(),
+            "Olleh Dlrow"
+        );
+
+        assert!(provider.remove_blob(pack).is_some());
+        assert!(provider.remove_blob(pack).is_none());
+        assert_eq!(load_locale(&provider, &de), None);
+        assert_eq!(load_locale(&provider, &en).as_deref(), Some("Hello World"));
+
+        assert!(provider.remove_blob(base).is_some());
+        assert!(matches!(
+            load(&provider, Default::default()),
+            Err(DataError {
+                kind: DataErrorKind::MarkerNotFound,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let mut provider = MultiBlobDataProvider::new();
+        let de = langid!("de").into();
+
+        let (base, _) = provider
+            .add_blob(blob(|id| id.locale.language.as_str() == "de"))
+            .unwrap();
+        let (pack, conflicts) = provider.add_blob(blob(|id| id.locale == de)).unwrap();
+
+        let expected = HelloWorldProvider
+            .iter_ids()
+            .unwrap()
+            .into_iter()
+            .filter(|id| id.locale == de)
+            .map(|id| BlobConflict {
+                marker: HelloWorldV1::INFO.id.hashed(),
+                id,
+                used: base,
+                shadowed: pack,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(conflicts.len(), expected.len());
+        assert!(conflicts.iter().all(|c| expected.contains(c)));
+        assert_eq!(provider.conflicts(), conflicts);
+
+        // Conflicts are resolved when a blob is removed
+        provider.remove_blob(base);
+        assert!(provider.conflicts().is_empty());
+        assert_eq!(load_locale(&provider, &de).as_deref(), Some("Hallo Welt"));
+    }
+}
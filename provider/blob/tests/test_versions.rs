@@ -126,6 +126,21 @@ fn test_format() {
     check_hello_world(blob_provider.as_deserializing(), true);
 }
 
+#[test]
+#[cfg(feature = "compression")]
+fn test_format_compressed() {
+    let mut blob: Vec<u8> = Vec::new();
+    let exporter = BlobExporter::new_compressed_with_sink(Box::new(&mut blob));
+    run_driver(exporter, &HelloWorldProvider);
+
+    let blob_provider = BlobDataProvider::try_new_from_blob(blob.into_boxed_slice()).unwrap();
+    assert!(
+        blob_provider.internal_is_compressed(),
+        "Should have exported to compressed format"
+    );
+    check_hello_world(blob_provider.as_deserializing(), true);
+}
+
 // This tests that the Bigger format works by attempting to export something with 26^4 = 456976 data entries
 #[test]
 fn test_format_bigger() {
@@ -173,6 +188,24 @@ fn test_format_bigger() {
     }
 }
 
+#[test]
+#[cfg(feature = "compression")]
+fn test_format_compressed_bigger() {
+    let mut blob: Vec<u8> = Vec::new();
+    let exporter = BlobExporter::new_compressed_with_sink(Box::new(&mut blob));
+    run_driver(exporter, &ManyLocalesProvider);
+
+    let blob_provider = BlobDataProvider::try_new_from_blob(blob.into_boxed_slice()).unwrap();
+    assert!(
+        blob_provider.internal_is_compressed(),
+        "Should have exported to compressed format"
+    );
+    assert!(
+        blob_provider.internal_is_using_bigger_format(),
+        "Should have exported to Bigger format"
+    );
+}
+
 struct ManyLocalesProvider;
 
 impl DataProvider<HelloWorldV1> for ManyLocalesProvider {
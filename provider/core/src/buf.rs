@@ -5,6 +5,9 @@
 //! Traits for data providers that produce opaque buffers.
 
 use crate::prelude::*;
+use yoke::Yokeable;
+use zerofrom::ZeroFrom;
+use zerovec::blob::BlobULE;
 
 #[cfg(feature = "serde")]
 mod serde;
@@ -97,6 +100,84 @@ pub trait BufferProvider: DynamicDataProvider<BufferMarker> {}
 
 impl<P: DynamicDataProvider<BufferMarker> + ?Sized> BufferProvider for P {}
 
+impl DataPayload<BufferMarker> {
+    /// Converts a buffer containing a [`zerovec::blob`] into a concrete type, without Serde.
+    ///
+    /// The blob must have been written for the [`BlobULE`] type `U`, and `buffer_format` (usually
+    /// from [`DataResponseMetadata::buffer_format`]) must be [`BufferFormat::ZeroVecBlob`]. Loading
+    /// the payload validates the blob and then borrows the data struct from it with [`ZeroFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::buf::BufferFormat;
+    /// use icu_provider::prelude::*;
+    /// use std::borrow::Cow;
+    /// use zerovec::VarZeroVec;
+    ///
+    /// #[zerovec::make_varule(GreetingsULE)]
+    /// #[zerovec::derive(Blob)]
+    /// #[zerovec::skip_derive(Ord)]
+    /// #[derive(Debug, PartialEq, yoke::Yokeable)]
+    /// pub struct Greetings<'data> {
+    ///     pub default: Cow<'data, str>,
+    ///     pub others: VarZeroVec<'data, str>,
+    /// }
+    ///
+    /// struct GreetingsMarker;
+    /// impl DynamicDataMarker for GreetingsMarker {
+    ///     type DataStruct = Greetings<'static>;
+    /// }
+    ///
+    /// let greetings = Greetings {
+    ///     default: Cow::Borrowed("Hello"),
+    ///     others: VarZeroVec::from(&["Hallo", "Bonjour"]),
+    /// };
+    /// let blob = zerovec::blob::encode_blob::<GreetingsULE>(&greetings);
+    ///
+    /// let payload: DataPayload<GreetingsMarker> =
+    ///     DataPayload::<BufferMarker>::from_owned_buffer(blob.into_boxed_slice())
+    ///         .into_parsed_blob::<_, GreetingsULE>(BufferFormat::ZeroVecBlob)
+    ///         .expect("valid blob");
+    ///
+    /// assert_eq!(payload.get(), &greetings);
+    ///
+    /// // Other buffers are rejected
+    /// assert!(DataPayload::<BufferMarker>::from_static_buffer(b"Hello")
+    ///     .into_parsed_blob::<GreetingsMarker, GreetingsULE>(BufferFormat::ZeroVecBlob)
+    ///     .is_err());
+    ///
+    /// // So are buffers in other formats
+    /// assert!(DataPayload::<BufferMarker>::from_static_buffer(b"{}")
+    ///     .into_parsed_blob::<GreetingsMarker, GreetingsULE>(BufferFormat::Json)
+    ///     .is_err());
+    /// ```
+    pub fn into_parsed_blob<M, U>(
+        self,
+        buffer_format: BufferFormat,
+    ) -> Result<DataPayload<M>, DataError>
+    where
+        M: DynamicDataMarker,
+        U: BlobULE + ?Sized + 'static,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: ZeroFrom<'a, U>,
+    {
+        if buffer_format != BufferFormat::ZeroVecBlob {
+            return Err(DataErrorKind::Deserialize
+                .with_str_context("expected a zerovec blob")
+                .with_debug_context(&buffer_format));
+        }
+        self.try_map_project(|bytes, _| {
+            zerovec::blob::parse_blob::<U>(bytes)
+                .map(ZeroFrom::zero_from)
+                .map_err(|e| {
+                    DataErrorKind::Deserialize
+                        .with_str_context("zerovec blob")
+                        .with_display_context(&e)
+                })
+        })
+    }
+}
+
 /// An enum expressing all Serde formats known to ICU4X.
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
@@ -108,6 +189,9 @@ pub enum BufferFormat {
     Bincode1,
     /// Serialize using the [`postcard`] crate, version 1.
     Postcard1,
+    /// A standalone [`zerovec::blob`], which is loaded without Serde using
+    /// [`DataPayload::into_parsed_blob()`].
+    ZeroVecBlob,
 }
 
 impl BufferFormat {
@@ -128,6 +212,8 @@ impl BufferFormat {
             BufferFormat::Postcard1 => Ok(()),
             #[cfg(not(feature = "deserialize_postcard_1"))]
             BufferFormat::Postcard1 => Err(DataErrorKind::Deserialize.with_str_context("deserializing `BufferFormat::Postcard1` requires the `deserialize_postcard_1` Cargo feature")),
+
+            BufferFormat::ZeroVecBlob => Ok(()),
         }
     }
 }
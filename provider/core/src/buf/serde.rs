@@ -91,6 +91,9 @@ where
             Ok(Deserialize::deserialize(&mut d)?)
         }
 
+        BufferFormat::ZeroVecBlob => Err(DataErrorKind::Deserialize
+            .with_str_context("zerovec blobs are loaded with `into_parsed_blob`, not Serde")),
+
         // Allowed for cases in which all features are enabled
         #[allow(unreachable_patterns)]
         _ => {
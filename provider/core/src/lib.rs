@@ -107,6 +107,9 @@ pub mod hello_world;
 #[cfg(all(feature = "serde", feature = "alloc"))]
 #[doc(hidden)]
 pub mod serde_borrow_de_utils;
+pub mod verify;
+#[cfg(feature = "alloc")]
+pub mod version;
 
 mod data_provider;
 pub use data_provider::{
@@ -0,0 +1,245 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Verification that data loaded together was generated together.
+//!
+//! Data markers with [`DataMarkerInfo::has_checksum`] return a checksum in their
+//! [`DataResponseMetadata`]. Payloads whose checksums differ come from different data builds,
+//! and cannot be used together. This is a common mistake when mixing data of different
+//! provenance, such as multiple blobs.
+
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+
+/// Compares the checksums of data responses across markers.
+///
+/// Responses without a checksum are always accepted.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::hello_world::HelloWorldV1;
+/// use icu_provider::prelude::*;
+/// use icu_provider::verify::ChecksumVerifier;
+///
+/// let mut verifier = ChecksumVerifier::new();
+///
+/// verifier
+///     .verify(HelloWorldV1::INFO, &DataResponseMetadata::default().with_checksum(1234))
+///     .expect("first checksum");
+/// verifier
+///     .verify(HelloWorldV1::INFO, &DataResponseMetadata::default())
+///     .expect("no checksum");
+///
+/// assert!(matches!(
+///     verifier.verify(
+///         HelloWorldV1::INFO,
+///         &DataResponseMetadata::default().with_checksum(5678)
+///     ),
+///     Err(DataError {
+///         kind: DataErrorKind::InconsistentData(_),
+///         ..
+///     })
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChecksumVerifier {
+    first: Option<(u64, DataMarkerInfo)>,
+}
+
+impl ChecksumVerifier {
+    /// Creates a [`ChecksumVerifier`] that has not seen any checksums.
+    pub const fn new() -> Self {
+        Self { first: None }
+    }
+
+    /// Returns the checksum that all responses have to match, if any response had one.
+    pub fn checksum(&self) -> Option<u64> {
+        self.first.map(|(checksum, _)| checksum)
+    }
+
+    /// Checks the checksum of a response for the given marker.
+    ///
+    /// Returns [`DataErrorKind::InconsistentData`] with the marker of the first checksum
+    /// if the checksums don't match.
+    pub fn verify(
+        &mut self,
+        marker: DataMarkerInfo,
+        metadata: &DataResponseMetadata,
+    ) -> Result<(), DataError> {
+        match (self.first, metadata.checksum) {
+            (Some((expected, first_marker)), Some(checksum)) if expected != checksum => {
+                Err(DataErrorKind::InconsistentData(first_marker).with_marker(marker))
+            }
+            (None, Some(checksum)) => {
+                self.first = Some((checksum, marker));
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A data provider that verifies that all checksums returned by the inner provider match.
+///
+/// Pass this to a constructor to make sure that all data it loads was generated together.
+///
+/// ✨ *Enabled with the `std` Cargo feature.*
+///
+/// The provider is [`Sync`], so it can be shared between threads, in which case all threads
+/// have to load data with the same checksum.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_core::locale;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider::verify::ChecksumVerifyingProvider;
+///
+/// let provider = ChecksumVerifyingProvider::new(HelloWorldProvider);
+///
+/// HelloWorldFormatter::try_new_unstable(&provider, locale!("en").into())
+///     .expect("consistent data");
+///
+/// assert_eq!(provider.checksum(), Some(1234));
+/// ```
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct ChecksumVerifyingProvider<P> {
+    inner: P,
+    verifier: Mutex<ChecksumVerifier>,
+}
+
+#[cfg(feature = "std")]
+impl<P> ChecksumVerifyingProvider<P> {
+    /// Wraps a provider.
+    pub const fn new(inner: P) -> Self {
+        Self {
+            inner,
+            verifier: Mutex::new(ChecksumVerifier::new()),
+        }
+    }
+
+    /// Returns the checksum that all responses have to match, if any response had one.
+    pub fn checksum(&self) -> Option<u64> {
+        self.verifier
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .checksum()
+    }
+
+    /// Returns a reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Verifies the response under the lock, so that concurrent loads agree on the first
+    /// checksum.
+    fn verify(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+        metadata: &DataResponseMetadata,
+    ) -> Result<(), DataError> {
+        self.verifier
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .verify(marker, metadata)
+            .map_err(|e| e.with_req(marker, req))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M: DataMarker, P: DataProvider<M>> DataProvider<M> for ChecksumVerifyingProvider<P> {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        let response = self.inner.load(req)?;
+        self.verify(M::INFO, req, &response.metadata)?;
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M: DynamicDataMarker, P: DynamicDataProvider<M>> DynamicDataProvider<M>
+    for ChecksumVerifyingProvider<P>
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        let response = self.inner.load_data(marker, req)?;
+        self.verify(marker, req, &response.metadata)?;
+        Ok(response)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::hello_world::*;
+    use icu_locale_core::langid;
+
+    struct StaleProvider;
+
+    impl DataProvider<HelloWorldV1> for StaleProvider {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<HelloWorldV1>, DataError> {
+            let mut response = HelloWorldProvider.load(req)?;
+            if req.id.locale.language.as_str() == "de" {
+                response.metadata.checksum = Some(5678);
+            }
+            Ok(response)
+        }
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let provider = ChecksumVerifyingProvider::new(StaleProvider);
+        let load = |locale: &DataLocale| {
+            provider.load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(locale),
+                ..Default::default()
+            })
+        };
+
+        load(&langid!("en").into()).unwrap();
+        assert_eq!(provider.checksum(), Some(1234));
+        assert!(matches!(
+            load(&langid!("de").into()),
+            Err(DataError {
+                kind: DataErrorKind::InconsistentData(first),
+                marker: Some(marker),
+                ..
+            }) if first == HelloWorldV1::INFO && marker == HelloWorldV1::INFO.id
+        ));
+        // The failed response doesn't change the expected checksum
+        assert_eq!(provider.checksum(), Some(1234));
+        load(&langid!("ja").into()).unwrap();
+    }
+
+    #[test]
+    fn test_threads() {
+        let provider = ChecksumVerifyingProvider::new(StaleProvider);
+        std::thread::scope(|s| {
+            for locale in [langid!("en"), langid!("ja"), langid!("la")] {
+                let provider = &provider;
+                s.spawn(move || {
+                    provider
+                        .load(DataRequest {
+                            id: DataIdentifierBorrowed::for_locale(&locale.into()),
+                            ..Default::default()
+                        })
+                        .unwrap();
+                });
+            }
+        });
+        assert_eq!(provider.checksum(), Some(1234));
+    }
+}
@@ -0,0 +1,43 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data describing which sources a set of data was generated from.
+//!
+//! Data generated by `icu4x-datagen` includes [`DataVersionV1`], so that data of different
+//! provenance can be checked for compatibility at runtime.
+
+#![allow(clippy::exhaustive_structs)] // data struct module
+
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use yoke::*;
+use zerofrom::*;
+
+/// The versions of the sources that a set of data was generated from.
+#[derive(Debug, PartialEq, Clone, Default, Yokeable, ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "deserialize_json", feature = "export"),
+    derive(serde::Serialize)
+)]
+#[cfg_attr(feature = "export", derive(databake::Bake))]
+#[cfg_attr(feature = "export", databake(path = icu_provider::version))]
+pub struct DataVersion<'data> {
+    /// The CLDR version, such as `"48"`, or the empty string if the data was not generated from CLDR.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub cldr: Cow<'data, str>,
+    /// The version of ICU4X that generated the data, such as `"2.2.0"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub icu4x: Cow<'data, str>,
+}
+
+crate::data_struct!(DataVersion<'_>, #[cfg(feature = "export")]);
+
+data_marker!(
+    /// Marker type for [`DataVersion`].
+    #[derive(Debug)]
+    DataVersionV1,
+    DataVersion<'static>,
+    is_singleton = true,
+);
@@ -1,5 +1,5 @@
 // @generated
-include!("decimal_symbols_v1.rs.data");
+include!("decimal_symbols_v2.rs.data");
 include!("decimal_digits_v1.rs.data");
 include!("decimal_compact_short_v1.rs.data");
 include!("decimal_compact_long_v1.rs.data");
@@ -35,7 +35,7 @@ pub use __make_provider as make_provider;
 macro_rules! impl_data_provider {
     ($ provider : ty) => {
         make_provider!($provider);
-        impl_decimal_symbols_v1!($provider);
+        impl_decimal_symbols_v2!($provider);
         impl_decimal_digits_v1!($provider);
         impl_decimal_compact_short_v1!($provider);
         impl_decimal_compact_long_v1!($provider);
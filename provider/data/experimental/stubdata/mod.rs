@@ -67,6 +67,10 @@ include!("units_names_duration_core_v1.rs.data");
 include!("currency_fractions_v1.rs.data");
 include!("units_info_v1.rs.data");
 include!("narrow_year_relative_v1.rs.data");
+include!("decimal_range_patterns_v1.rs.data");
+include!("region_containment_v1.rs.data");
+include!("region_currencies_v1.rs.data");
+include!("territory_info_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -169,5 +173,9 @@ macro_rules! impl_data_provider {
         impl_currency_fractions_v1!($provider);
         impl_units_info_v1!($provider);
         impl_narrow_year_relative_v1!($provider);
+        impl_decimal_range_patterns_v1!($provider);
+        impl_region_containment_v1!($provider);
+        impl_region_currencies_v1!($provider);
+        impl_territory_info_v1!($provider);
     };
 }
@@ -2,7 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::{DataLocaleFamilyAnnotations, DeduplicationStrategy, ExportDriver, ExportMetadata};
+use crate::{
+    DataLocaleFamilyAnnotations, DeduplicationStrategy, ExportDriver, ExportMetadata, UsageManifest,
+};
 use icu_locale::LocaleFallbacker;
 use icu_locale::fallback::LocaleFallbackIterator;
 use icu_provider::export::*;
@@ -39,10 +41,34 @@ impl ExportDriver {
             fallbacker,
             deduplication_strategy,
             attributes_filters,
+            usage,
         } = self;
 
         let markers = markers.unwrap_or_else(|| provider.supported_markers());
 
+        let markers = if let Some(usage) = usage.as_ref() {
+            let supported = provider.supported_markers();
+            if let Some(name) = usage
+                .marker_names()
+                .find(|&name| !supported.iter().any(|m| m.id.name() == name))
+            {
+                return Err(DataError::custom("Unknown marker in usage manifest")
+                    .with_display_context(name));
+            }
+            for name in usage
+                .marker_names()
+                .filter(|&name| !markers.iter().any(|m| m.id.name() == name))
+            {
+                log::warn!("{name} is in the usage manifest but not selected; not exporting it");
+            }
+            markers
+                .into_iter()
+                .filter(|m| usage.marker_names().any(|name| m.id.name() == name))
+                .collect()
+        } else {
+            markers
+        };
+
         if markers.is_empty() {
             log::warn!("No markers selected");
         }
@@ -166,14 +192,18 @@ impl ExportDriver {
                 return Ok(());
             }
 
-            let locales_to_export = select_locales_for_marker(
-                provider,
-                marker,
-                &requested_families,
-                &attributes_filters,
-                include_full,
-                &fallbacker,
-            )?;
+            let locales_to_export = if let Some(usage) = usage.as_ref() {
+                select_ids_for_usage(provider, marker, usage, &fallbacker)?
+            } else {
+                select_locales_for_marker(
+                    provider,
+                    marker,
+                    &requested_families,
+                    &attributes_filters,
+                    include_full,
+                    &fallbacker,
+                )?
+            };
 
             let responses = locales_to_export
                 .into_par_iter()
@@ -386,6 +416,41 @@ fn select_locales_for_marker<'a>(
     Ok(selected_locales)
 }
 
+/// Selects the identifiers recorded in a [`UsageManifest`], and the supported identifiers that
+/// they fall back to. The latter are required to resolve the recorded identifiers at runtime if
+/// they get deduplicated.
+fn select_ids_for_usage<'a>(
+    provider: &'a dyn ExportableProvider,
+    marker: DataMarkerInfo,
+    usage: &'a UsageManifest,
+    fallbacker: &LocaleFallbacker,
+) -> Result<HashSet<DataIdentifierCow<'a>>, DataError> {
+    let supported = provider
+        .iter_ids_for_marker(marker)
+        .map_err(|e| e.with_marker(marker))?;
+    let mut selected_ids = HashSet::new();
+    for id in usage.ids_for_marker(marker) {
+        selected_ids.insert(id.into_owned());
+        let mut iter = fallbacker
+            .for_config(marker.fallback_config)
+            .fallback_for(*id.locale);
+        loop {
+            if let Some(parent_id) = supported.get(&DataIdentifierCow::from_borrowed_and_owned(
+                id.marker_attributes,
+                *iter.get(),
+            )) {
+                log::trace!("Including {parent_id}: fallback for {id}: {marker:?}");
+                selected_ids.insert(parent_id.clone());
+            }
+            if iter.get().is_unknown() {
+                break;
+            }
+            iter.step();
+        }
+    }
+    Ok(selected_ids)
+}
+
 fn deduplicate_responses<'a>(
     maximal: bool,
     marker: DataMarkerInfo,
@@ -59,8 +59,10 @@
 
 mod export_impl;
 mod locale_family;
+mod usage;
 use icu_provider::export::ExporterCloseMetadata;
 pub use locale_family::*;
+pub use usage::UsageManifest;
 
 #[cfg(feature = "baked_exporter")]
 pub use icu_provider_baked::export as baked_exporter;
@@ -127,6 +129,7 @@ pub struct ExportDriver {
     fallbacker: LocaleFallbacker,
     include_full: bool,
     deduplication_strategy: DeduplicationStrategy,
+    usage: Option<UsageManifest>,
 }
 
 impl core::fmt::Debug for ExportDriver {
@@ -138,6 +141,7 @@ impl core::fmt::Debug for ExportDriver {
             .field("fallbacker", &self.fallbacker)
             .field("include_full", &self.include_full)
             .field("deduplication_strategy", &self.deduplication_strategy)
+            .field("usage", &self.usage)
             .finish()
     }
 }
@@ -178,6 +182,7 @@ impl ExportDriver {
             include_full,
             fallbacker,
             deduplication_strategy: options.deduplication_strategy,
+            usage: None,
         }
         .with_recommended_segmenter_models()
         .with_additional_collations([])
@@ -213,6 +218,20 @@ impl ExportDriver {
         }
     }
 
+    /// Sets this driver to generate only the data recorded in the given [`UsageManifest`].
+    ///
+    /// Only the recorded markers are exported, restricted to the markers set by
+    /// [`with_markers`](Self::with_markers); a warning is logged for recorded markers that are
+    /// not selected. For every marker, the exported identifiers are the
+    /// recorded ones, resolved through fallback, together with the identifiers that they fall
+    /// back to at runtime. The locale families and marker attributes filters are ignored.
+    pub fn with_usage_manifest(self, usage: UsageManifest) -> Self {
+        Self {
+            usage: Some(usage),
+            ..self
+        }
+    }
+
     /// This option is only relevant if using `icu::collator`.
     ///
     /// By default, collations starting with `search` are excluded. This method can be used to reennable them.
@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// The data identifiers that an application loads, usually recorded at runtime.
+///
+/// Pass this to [`ExportDriver::with_usage_manifest`](crate::ExportDriver::with_usage_manifest)
+/// to export only the data that is actually used.
+///
+/// # Format
+///
+/// The text format contains one data request per line, consisting of the marker name
+/// (see [`DataMarkerId::name`](icu_provider::DataMarkerId::name)), a space, and the
+/// locale, optionally followed by `/` and the marker attributes. Empty lines and lines
+/// starting with `#` are ignored.
+///
+/// ```text
+/// # Recorded by the staging server
+/// HelloWorldV1 en
+/// HelloWorldV1 ja/reverse
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::hello_world::HelloWorldV1;
+/// use icu_provider::prelude::*;
+/// use icu_provider_export::UsageManifest;
+///
+/// let mut manifest = UsageManifest::new();
+/// manifest.insert(
+///     HelloWorldV1::INFO,
+///     DataIdentifierBorrowed::for_locale(&"en".parse().unwrap()),
+/// );
+///
+/// assert_eq!(manifest.to_string(), "HelloWorldV1 en\n");
+/// assert_eq!(manifest.to_string().parse::<UsageManifest>(), Ok(manifest));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageManifest {
+    ids: BTreeMap<String, BTreeSet<DataIdentifierCow<'static>>>,
+}
+
+impl UsageManifest {
+    /// Creates an empty [`UsageManifest`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a data request.
+    pub fn insert(&mut self, marker: DataMarkerInfo, id: DataIdentifierBorrowed) {
+        self.ids
+            .entry(marker.id.name().into())
+            .or_default()
+            .insert(id.into_owned());
+    }
+
+    /// Returns whether no data requests have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the names of the recorded markers.
+    pub fn marker_names(&self) -> impl Iterator<Item = &str> {
+        self.ids.keys().map(String::as_str)
+    }
+
+    /// Returns the identifiers recorded for a marker.
+    pub fn ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> impl Iterator<Item = DataIdentifierBorrowed<'_>> {
+        self.ids
+            .get(marker.id.name())
+            .into_iter()
+            .flatten()
+            .map(DataIdentifierCow::as_borrowed)
+    }
+
+    /// Parses a [`UsageManifest`] from its text format.
+    pub fn try_from_str(s: &str) -> Result<Self, DataError> {
+        let mut ids = BTreeMap::<String, BTreeSet<_>>::new();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error =
+                || DataError::custom("Invalid usage manifest line").with_display_context(&line);
+            let (marker, id) = line.split_once(' ').ok_or_else(error)?;
+            let (locale, attributes) = id.split_once('/').unwrap_or((id, ""));
+            ids.entry(marker.to_string())
+                .or_default()
+                .insert(DataIdentifierCow::from_owned(
+                    DataMarkerAttributes::try_from_string(attributes.to_string())
+                        .map_err(|_| error())?,
+                    locale.parse().map_err(|_| error())?,
+                ));
+        }
+        Ok(Self { ids })
+    }
+}
+
+impl FromStr for UsageManifest {
+    type Err = DataError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl fmt::Display for UsageManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (marker, ids) in &self.ids {
+            for id in ids {
+                writeln!(f, "{marker} {id}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale::langid;
+    use icu_provider::hello_world::*;
+    use icu_provider_adapters::record::RecordingProvider;
+
+    #[test]
+    fn test_recorded_manifest() {
+        let provider = RecordingProvider::new(HelloWorldProvider);
+        for (attributes, locale) in [("", langid!("en")), ("reverse", langid!("ja"))] {
+            let locale = locale.into();
+            let _ = DataProvider::<HelloWorldV1>::load(
+                &provider,
+                DataRequest {
+                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                        DataMarkerAttributes::from_str_or_panic(attributes),
+                        &locale,
+                    ),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let manifest = UsageManifest::try_from_str(&provider.usage_manifest()).unwrap();
+        assert_eq!(
+            manifest
+                .ids_for_marker(HelloWorldV1::INFO)
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>(),
+            ["en", "ja/reverse"]
+        );
+        assert_eq!(manifest.to_string(), provider.usage_manifest());
+    }
+}
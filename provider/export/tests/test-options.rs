@@ -12,6 +12,7 @@ use icu_locale::{LanguageIdentifier, langid};
 use icu_provider::export::*;
 use icu_provider::hello_world::*;
 use icu_provider::prelude::*;
+use icu_provider_export::UsageManifest;
 use icu_provider_export::prelude::*;
 use testutil::TestingExporter;
 
@@ -809,3 +810,60 @@ fn explicit_preresolved_empty() {
     // Should return the exact explicit locales set.
     assert_eq!(exported.keys().collect::<Vec<_>>(), locales);
 }
+
+#[test]
+fn usage_manifest() {
+    let usage = "HelloWorldV1 en-GB\nHelloWorldV1 sr-Latn\nHelloWorldV1 th/thai\n"
+        .parse::<UsageManifest>()
+        .unwrap();
+
+    let exported = export_to_map(
+        ExportDriver::new(
+            [DataLocaleFamily::FULL],
+            DeduplicationStrategy::None.into(),
+            LocaleFallbacker::new().static_to_owned(),
+        )
+        .with_usage_manifest(usage.clone()),
+        &TestingProvider::with_decimal_symbol_like_data(),
+    );
+
+    // The recorded identifiers, and the supported identifiers they fall back to
+    let locales = ["en", "en-001", "en-GB", "sr-Latn", "th/thai", "und"];
+
+    assert_eq!(exported.keys().collect::<Vec<_>>(), locales);
+
+    let exported = export_to_map(
+        ExportDriver::new(
+            [DataLocaleFamily::FULL],
+            DeduplicationStrategy::Maximal.into(),
+            LocaleFallbacker::new().static_to_owned(),
+        )
+        .with_usage_manifest(usage),
+        &TestingProvider::with_decimal_symbol_like_data(),
+    );
+
+    // "en-GB", "en-001", and "en" are the same as "und"
+    let locales = ["sr-Latn", "th/thai", "und"];
+
+    assert_eq!(exported.keys().collect::<Vec<_>>(), locales);
+}
+
+#[test]
+fn usage_manifest_unknown_marker() {
+    let mut exporter = TestingExporter::default();
+    let result = ExportDriver::new(
+        [DataLocaleFamily::FULL],
+        DeduplicationStrategy::None.into(),
+        LocaleFallbacker::new().static_to_owned(),
+    )
+    .with_usage_manifest("ListAndV1 en".parse().unwrap())
+    .export(
+        &TestingProvider::with_decimal_symbol_like_data(),
+        &mut exporter,
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        DataError::custom("Unknown marker in usage manifest")
+    );
+}
@@ -5,11 +5,13 @@
 use crate::datapath::marker_to_path;
 use crate::manifest::Manifest;
 use icu_provider::DynamicDryDataProvider;
+use icu_provider::IterableDynamicDataProvider;
 use icu_provider::prelude::*;
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::fmt::Write;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// A data provider that reads ICU4X data from a filesystem directory.
 ///
@@ -61,7 +63,11 @@ impl FsDataProvider {
         })
     }
 
-    fn dry_load_internal(
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub(crate) fn dry_load_internal(
         &self,
         marker: DataMarkerInfo,
         req: DataRequest,
@@ -117,6 +123,72 @@ impl FsDataProvider {
         metadata.checksum = checksum;
         Ok((metadata, path))
     }
+
+    pub(crate) fn iter_ids_internal(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'static>>, DataError> {
+        let mut path = marker_to_path(marker.id, &self.root);
+        if marker.is_singleton {
+            path.set_extension(self.manifest.file_extension);
+            return if path.exists() {
+                Ok([Default::default()].into_iter().collect())
+            } else {
+                Err(DataErrorKind::MarkerNotFound.with_marker(marker))
+            };
+        }
+        if !path.exists() {
+            return Err(DataErrorKind::MarkerNotFound.with_marker(marker));
+        }
+        let mut ids = BTreeSet::new();
+        self.collect_ids(&path, "", &mut ids)?;
+        Ok(ids)
+    }
+
+    /// Collects the identifiers of all files below `dir`, where `attributes` is the path from the
+    /// marker directory to `dir`.
+    fn collect_ids(
+        &self,
+        dir: &Path,
+        attributes: &str,
+        ids: &mut BTreeSet<DataIdentifierCow<'static>>,
+    ) -> Result<(), DataError> {
+        for entry in fs::read_dir(dir).map_err(|e| DataError::from(e).with_path_context(dir))? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') {
+                // `.checksum` and `.empty`
+                continue;
+            }
+            if path.is_dir() {
+                let attributes = if attributes.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{attributes}/{name}")
+                };
+                self.collect_ids(&path, &attributes, ids)?;
+            } else if let Some(locale) = name
+                .strip_suffix(self.manifest.file_extension)
+                .and_then(|n| n.strip_suffix('.'))
+            {
+                let locale = locale.parse().map_err(|e| {
+                    DataError::custom("Invalid locale in file name")
+                        .with_path_context(&path)
+                        .with_display_context(&e)
+                })?;
+                let marker_attributes = DataMarkerAttributes::try_from_string(
+                    attributes.to_string(),
+                )
+                .map_err(|_| {
+                    DataError::custom("Invalid marker attributes in path").with_path_context(&path)
+                })?;
+                ids.insert(DataIdentifierCow::from_owned(marker_attributes, locale));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl DynamicDataProvider<BufferMarker> for FsDataProvider {
@@ -143,3 +215,12 @@ impl DynamicDryDataProvider<BufferMarker> for FsDataProvider {
         Ok(self.dry_load_internal(marker, req)?.0)
     }
 }
+
+impl IterableDynamicDataProvider<BufferMarker> for FsDataProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        self.iter_ids_internal(marker)
+    }
+}
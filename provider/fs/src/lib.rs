@@ -73,6 +73,11 @@
 //! Cargo feature has to be activated on the [`icu_provider`] crate. See
 //! [`AsDeserializingBufferProvider::as_deserializing`](icu_provider::buf::AsDeserializingBufferProvider).
 //!
+//! # Reloading data
+//!
+//! [`ReloadingFsDataProvider`] detects changes to the directory by polling, and reloads changed
+//! files. This is useful when the data is edited while an application is running.
+//!
 //! # Exporting data
 //!
 //! To generate the data required for [`FsDataProvider`], run the following:
@@ -92,8 +97,10 @@
 mod datapath;
 mod fs_data_provider;
 mod manifest;
+mod reloading;
 
 #[cfg(feature = "export")]
 pub mod export;
 
 pub use fs_data_provider::FsDataProvider;
+pub use reloading::{PollingWatcher, ReloadingFsDataProvider};
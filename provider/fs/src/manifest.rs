@@ -22,7 +22,7 @@ struct JsonManifest {
 }
 
 impl Manifest {
-    const NAME: &'static str = "manifest.json";
+    pub(crate) const NAME: &'static str = "manifest.json";
 
     pub(crate) fn for_format(buffer_format: BufferFormat) -> Result<Self, DataError> {
         buffer_format.check_available()?;
@@ -0,0 +1,259 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::FsDataProvider;
+use crate::manifest::Manifest;
+use icu_provider::DynamicDryDataProvider;
+use icu_provider::IterableDynamicDataProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, PoisonError, RwLock, RwLockReadGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+/// The modification time and length of every file below a directory.
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+fn take_snapshot(dir: &Path, snapshot: &mut Snapshot) -> Result<(), DataError> {
+    for entry in fs::read_dir(dir).map_err(|e| DataError::from(e).with_path_context(dir))? {
+        let entry = entry?;
+        let metadata = entry
+            .metadata()
+            .map_err(|e| DataError::from(e).with_path_context(&entry.path()))?;
+        if metadata.is_dir() {
+            take_snapshot(&entry.path(), snapshot)?;
+        } else {
+            snapshot.insert(entry.path(), (metadata.modified().ok(), metadata.len()));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+struct State {
+    provider: FsDataProvider,
+    snapshot: Snapshot,
+    /// Cloning a payload only increments the reference count of its buffer.
+    cache: Mutex<HashMap<PathBuf, DataPayload<BufferMarker>>>,
+}
+
+/// A data provider that reads ICU4X data from a filesystem directory, and reloads data that
+/// changes while it is in use.
+///
+/// Unlike [`FsDataProvider`], this provider caches the contents of the files it reads. Changes to
+/// the directory are detected by comparing the modification times and sizes of all files, either
+/// explicitly with [`poll`](Self::poll), or periodically in a background thread with
+/// [`watch`](Self::watch). Changed files are evicted from the cache, and a change to
+/// `manifest.json` reloads the manifest.
+///
+/// Since file contents are not compared, a change that keeps both the size and the modification
+/// time of a file is not detected. This can happen if a file is rewritten with the same size
+/// within the timestamp granularity of the filesystem, which is as coarse as one or two seconds
+/// on some filesystems.
+///
+/// Every detected change increments the [`generation`](Self::generation). Objects constructed from
+/// this provider don't observe changes, so long-lived formatters can store the generation at
+/// construction time, and be reconstructed once it changes.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale_core::locale;
+/// use icu_provider::hello_world::HelloWorldFormatter;
+/// use icu_provider_fs::ReloadingFsDataProvider;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let provider = Arc::new(
+///     ReloadingFsDataProvider::try_new("tests/data/json".into())
+///         .expect("Directory exists"),
+/// );
+///
+/// // Check the directory for changes every second, until the watcher is dropped
+/// let _watcher = provider.watch(Duration::from_secs(1));
+///
+/// let generation = provider.generation();
+/// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+///     &*provider,
+///     locale!("la").into(),
+/// )
+/// .expect("locale exists");
+///
+/// // ...
+///
+/// if provider.generation() != generation {
+///     // The data has changed, reconstruct the formatter
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReloadingFsDataProvider {
+    state: RwLock<State>,
+    generation: AtomicU64,
+}
+
+impl ReloadingFsDataProvider {
+    /// Create a new [`ReloadingFsDataProvider`] given a filesystem directory.
+    pub fn try_new(root: PathBuf) -> Result<Self, DataError> {
+        let mut snapshot = Snapshot::new();
+        take_snapshot(&root, &mut snapshot)?;
+        Ok(Self {
+            state: RwLock::new(State {
+                provider: FsDataProvider::try_new(root)?,
+                snapshot,
+                cache: Default::default(),
+            }),
+            generation: AtomicU64::new(0),
+        })
+    }
+
+    /// Returns the number of changes that have been detected since construction.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Checks the directory for changes, and evicts changed files from the cache.
+    ///
+    /// Returns whether any change was detected. If the manifest cannot be reloaded, the error
+    /// is returned and the change will be detected again by the next call.
+    pub fn poll(&self) -> Result<bool, DataError> {
+        let root = self.read().provider.root().to_path_buf();
+        let mut snapshot = Snapshot::new();
+        take_snapshot(&root, &mut snapshot)?;
+
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        if snapshot == state.snapshot {
+            return Ok(false);
+        }
+        let manifest = root.join(Manifest::NAME);
+        let state = &mut *state;
+        let cache = state
+            .cache
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        if snapshot.get(&manifest) != state.snapshot.get(&manifest) {
+            state.provider = FsDataProvider::try_new(root)?;
+            cache.clear();
+        } else {
+            cache.retain(|path, _| snapshot.get(path) == state.snapshot.get(path));
+        }
+        state.snapshot = snapshot;
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        Ok(true)
+    }
+
+    /// Calls [`poll`](Self::poll) in a background thread, every `interval`.
+    ///
+    /// Polling stops when the returned [`PollingWatcher`] or the provider is dropped. Errors
+    /// are logged and polling continues.
+    pub fn watch(self: &Arc<Self>, interval: Duration) -> PollingWatcher {
+        let provider = Arc::downgrade(self);
+        // Nothing is ever sent; dropping the sender wakes the thread up and stops it
+        let (stop, stopped) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                let Some(provider) = provider.upgrade() else {
+                    break;
+                };
+                if let Err(_e) = provider.poll() {
+                    icu_provider::log::warn!("Polling for data changes failed: {_e}");
+                }
+            }
+        });
+        PollingWatcher {
+            stop: Some(stop),
+            handle: Some(handle),
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, State> {
+        self.state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl DynamicDataProvider<BufferMarker> for ReloadingFsDataProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        // Holding the read lock while loading prevents `poll` from evicting a file that
+        // is being cached.
+        let state = self.read();
+        let (metadata, path) = state.provider.dry_load_internal(marker, req)?;
+        let cached = state
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&path)
+            .cloned();
+        let payload = match cached {
+            Some(payload) => payload,
+            None => {
+                let payload = DataPayload::from_owned_buffer(
+                    fs::read(&path)
+                        .map_err(|e| DataError::from(e).with_path_context(&path))?
+                        .into_boxed_slice(),
+                );
+                state
+                    .cache
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(path, payload.clone());
+                payload
+            }
+        };
+        Ok(DataResponse { metadata, payload })
+    }
+}
+
+impl DynamicDryDataProvider<BufferMarker> for ReloadingFsDataProvider {
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponseMetadata, DataError> {
+        Ok(self.read().provider.dry_load_internal(marker, req)?.0)
+    }
+}
+
+impl IterableDynamicDataProvider<BufferMarker> for ReloadingFsDataProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        self.read().provider.iter_ids_internal(marker)
+    }
+}
+
+/// A handle to the background thread started by [`ReloadingFsDataProvider::watch`].
+///
+/// Dropping the handle stops polling, like [`stop`](Self::stop).
+#[derive(Debug)]
+pub struct PollingWatcher {
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl PollingWatcher {
+    /// Stops polling, and waits for the background thread to finish.
+    ///
+    /// This wakes the thread up immediately, but waits for a [`poll`](ReloadingFsDataProvider::poll)
+    /// that is in progress.
+    pub fn stop(self) {
+        drop(self)
+    }
+}
+
+impl Drop for PollingWatcher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
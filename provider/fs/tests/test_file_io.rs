@@ -5,7 +5,8 @@
 use icu_locale_core::langid;
 use icu_provider::hello_world::{HelloWorld, HelloWorldProvider, HelloWorldV1};
 use icu_provider::prelude::*;
-use icu_provider_fs::FsDataProvider;
+use icu_provider_fs::{FsDataProvider, ReloadingFsDataProvider};
+use std::path::Path;
 
 const PATHS: &[&str] = &[
     "tests/data/json",
@@ -44,6 +45,18 @@ fn test_provider() {
     }
 }
 
+#[test]
+fn test_iter() {
+    for path in PATHS {
+        let provider = FsDataProvider::try_new(path.into()).unwrap();
+        assert_eq!(
+            provider.iter_ids_for_marker(HelloWorldV1::INFO).unwrap(),
+            HelloWorldProvider.iter_ids().unwrap(),
+            "{path}"
+        );
+    }
+}
+
 #[test]
 fn test_errors() {
     for path in PATHS {
@@ -141,3 +154,72 @@ fn prefix_match() {
         );
     }
 }
+
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()));
+        } else {
+            std::fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_reloading() {
+    let root = std::env::temp_dir().join(format!("icu4x_fs_reloading_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    copy_dir(Path::new("tests/data/json"), &root);
+
+    let provider = ReloadingFsDataProvider::try_new(root.clone()).unwrap();
+    let load = || -> DataResponse<HelloWorldV1> {
+        provider
+            .as_deserializing()
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+                ..Default::default()
+            })
+            .unwrap()
+    };
+
+    assert_eq!(load().payload.get().message, "Hello World");
+    assert!(!provider.poll().unwrap());
+    assert_eq!(provider.generation(), 0);
+
+    // Served from the cache until the change is detected
+    std::fs::write(
+        root.join("hello/world/v1/en.json"),
+        r#"{"message":"Hello, reloaded World"}"#,
+    )
+    .unwrap();
+    assert_eq!(load().payload.get().message, "Hello World");
+
+    assert!(provider.poll().unwrap());
+    assert_eq!(provider.generation(), 1);
+    assert_eq!(load().payload.get().message, "Hello, reloaded World");
+
+    std::fs::remove_file(root.join("hello/world/v1/en.json")).unwrap();
+    assert!(provider.poll().unwrap());
+    assert_eq!(provider.generation(), 2);
+    assert!(
+        provider
+            .as_deserializing()
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+                ..Default::default()
+            })
+            .map(|r: DataResponse<HelloWorldV1>| r.payload)
+            .is_err()
+    );
+
+    // Stopping the watcher doesn't wait for the interval to elapse
+    let provider = std::sync::Arc::new(provider);
+    let watcher = provider.watch(std::time::Duration::from_secs(3600));
+    let start = std::time::Instant::now();
+    watcher.stop();
+    assert!(start.elapsed() < std::time::Duration::from_secs(60));
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
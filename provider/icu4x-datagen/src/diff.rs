@@ -0,0 +1,185 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `diff` subcommand.
+//!
+//! Payloads are compared as the JSON serialization of their data structs. Changes are
+//! reported with a [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) to the changed value.
+
+use crate::ReexportableBufferProvider;
+use icu_provider::export::*;
+use icu_provider::prelude::*;
+use icu_provider_blob::BlobDataProvider;
+use icu_provider_fs::FsDataProvider;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Loads an exported data set from a directory exported with `--format=fs`, or a blob file.
+///
+/// Baked data is not supported: it is Rust source code that has to be compiled into a binary
+/// to be loaded, so it is rejected with an error that asks for a runtime format instead.
+pub(crate) fn load(path: &Path) -> eyre::Result<ReexportableBufferProvider> {
+    if path.is_file() {
+        Ok(ReexportableBufferProvider(Box::new(
+            BlobDataProvider::try_new_from_blob(std::fs::read(path)?.into())?,
+        )))
+    } else if path.join("manifest.json").exists() {
+        Ok(ReexportableBufferProvider(Box::new(
+            FsDataProvider::try_new(path.into())?,
+        )))
+    } else if path.join("mod.rs").exists() {
+        eyre::bail!(
+            "{path:?} contains baked data, which cannot be loaded at runtime. \
+            Export the data with --format=fs or --format=blob to compare it"
+        )
+    } else {
+        eyre::bail!("{path:?} is neither a blob file nor a directory exported with --format=fs")
+    }
+}
+
+/// Returns the identifiers of a marker, or `None` if the data set doesn't contain the marker.
+fn ids(
+    provider: &impl ExportableProvider,
+    marker: DataMarkerInfo,
+) -> Result<Option<BTreeSet<DataIdentifierCow<'_>>>, DataError> {
+    match provider.iter_ids_for_marker(marker) {
+        Ok(ids) => Ok(Some(ids)),
+        Err(DataError {
+            kind: DataErrorKind::MarkerNotFound,
+            ..
+        }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn to_json(
+    provider: &impl ExportableProvider,
+    marker: DataMarkerInfo,
+    id: DataIdentifierBorrowed,
+) -> Result<Value, DataError> {
+    let payload = provider
+        .load_data(
+            marker,
+            DataRequest {
+                id,
+                ..Default::default()
+            },
+        )?
+        .payload;
+    let mut buffer = Vec::new();
+    payload.serialize(&mut serde_json::Serializer::new(&mut buffer))?;
+    Ok(serde_json::from_slice(&buffer)?)
+}
+
+/// Appends the changes between `old` and `new`, which are located at `path`.
+fn diff_values(path: &mut String, old: &Value, new: &Value, changes: &mut Vec<Value>) {
+    let len = path.len();
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for key in old
+                .keys()
+                .chain(new.keys().filter(|k| !old.contains_key(*k)))
+            {
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                diff_values(
+                    path,
+                    old.get(key).unwrap_or(&Value::Null),
+                    new.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+                path.truncate(len);
+            }
+        }
+        (Value::Array(old), Value::Array(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                path.push_str(&format!("/{i}"));
+                diff_values(path, old, new, changes);
+                path.truncate(len);
+            }
+        }
+        (old, new) if old != new => changes.push(json!({
+            "path": path,
+            "old": old,
+            "new": new,
+        })),
+        _ => {}
+    }
+}
+
+/// Compares two data sets, restricted to the given markers.
+///
+/// Markers supported by either provider are compared. Only markers that differ are part of the
+/// report.
+pub(crate) fn diff(
+    old: &impl ExportableProvider,
+    new: &impl ExportableProvider,
+    markers: Option<&[DataMarkerInfo]>,
+) -> Result<Value, DataError> {
+    let mut added_markers = Vec::new();
+    let mut removed_markers = Vec::new();
+    let mut changed_markers = Map::new();
+
+    let mut supported_markers = old.supported_markers();
+    supported_markers.extend(new.supported_markers());
+
+    for marker in supported_markers {
+        if markers.is_some_and(|m| !m.contains(&marker)) {
+            continue;
+        }
+        let name = marker.id.name();
+        let (old_ids, new_ids) = match (ids(old, marker)?, ids(new, marker)?) {
+            (None, None) => continue,
+            (None, Some(_)) => {
+                added_markers.push(name);
+                continue;
+            }
+            (Some(_), None) => {
+                removed_markers.push(name);
+                continue;
+            }
+            (Some(old_ids), Some(new_ids)) => (old_ids, new_ids),
+        };
+
+        let added = new_ids
+            .difference(&old_ids)
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        let removed = old_ids
+            .difference(&new_ids)
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        let mut changed = Map::new();
+        for id in old_ids.intersection(&new_ids) {
+            let mut changes = Vec::new();
+            diff_values(
+                &mut String::new(),
+                &to_json(old, marker, id.as_borrowed())?,
+                &to_json(new, marker, id.as_borrowed())?,
+                &mut changes,
+            );
+            if !changes.is_empty() {
+                changed.insert(id.to_string(), changes.into());
+            }
+        }
+
+        if !added.is_empty() || !removed.is_empty() || !changed.is_empty() {
+            changed_markers.insert(
+                name.to_string(),
+                json!({
+                    "added": added,
+                    "removed": removed,
+                    "changed": changed,
+                }),
+            );
+        }
+    }
+
+    Ok(json!({
+        "added_markers": added_markers,
+        "removed_markers": removed_markers,
+        "markers": changed_markers,
+    }))
+}
@@ -23,6 +23,15 @@
 //! ```
 //!
 //! More details can be found by running `--help`, or by consulting the [`icu_provider_export`] documentation.
+//!
+//! Besides exporting data, the binary can inspect data:
+//!
+//! ```bash
+//! # Byte sizes per marker and locale, and the savings of each deduplication strategy, as JSON
+//! $ icu4x-datagen --markers all --locales de en-AU size-report
+//! # Semantic differences between two exported data sets, as JSON (requires the `diff` Cargo feature)
+//! $ icu4x-datagen diff old.postcard new.postcard
+//! ```
 
 // If no exporter feature is enabled this all doesn't make sense
 #![cfg_attr(
@@ -40,7 +49,7 @@
 )]
 #![cfg_attr(icu4x_nightly_tests, feature(non_exhaustive_omitted_patterns_lint))]
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use displaydoc::Display;
 use eyre::WrapErr;
 use icu_provider::DataError;
@@ -56,6 +65,10 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+#[cfg(feature = "diff")]
+mod diff;
+mod size_report;
+
 #[derive(Clone)]
 struct Filter {
     domain: String,
@@ -112,18 +125,22 @@ impl FromStr for Filter {
 #[command(name = "icu4x-datagen")]
 #[command(author = "The ICU4X Project Developers", version = option_env!("CARGO_PKG_VERSION"))]
 #[command(about = format!("Learn more at: https://docs.rs/icu_provider_export/{}", option_env!("CARGO_PKG_VERSION").unwrap_or("")), long_about = None)]
+#[command(subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(short, long)]
     #[arg(help = "Requests verbose output")]
     verbose: bool,
 
-    #[arg(long, value_enum)]
+    #[arg(long, value_enum, required = true)]
     #[arg(
         help = "Select the output format: a directory tree of files (fs), a single blob (blob), or a Rust module (baked)."
     )]
-    format: Format,
+    format: Option<Format>,
 
-    #[arg(short = 'W', long)]
+    #[arg(short = 'W', long, global = true)]
     #[arg(help = "Delete the output before writing data.")]
     overwrite: bool,
 
@@ -135,6 +152,12 @@ struct Cli {
     #[arg(help = "--format=baked, --format=fs only: pretty-print the Rust or JSON output files.")]
     pretty: bool,
 
+    #[arg(long)]
+    #[arg(
+        help = "--format=blob only: compress the data with LZ4. Reading the blob requires the `compression` Cargo feature of `icu_provider_blob`."
+    )]
+    compress: bool,
+
     #[arg(short = 't', long, value_name = "TAG", default_value = "latest")]
     #[arg(
         help = "Download CLDR JSON data from this GitHub tag (https://github.com/unicode-org/cldr-json/tags)\n\
@@ -256,7 +279,8 @@ struct Cli {
     #[arg(long, short, num_args = 1..)]
     #[arg(
         help = "Include these data markers in the output. Accepts multiple arguments.\n\
-                Set to 'all' for all markers, or 'none' for no markers."
+                Set to 'all' for all markers, or 'none' for no markers.\n\
+                Add 'DataVersionV1' to embed the versions of the source data."
     )]
     markers: Vec<String>,
 
@@ -264,6 +288,14 @@ struct Cli {
     #[arg(help = "Analyzes the binary and only includes markers that are used by the binary.")]
     markers_for_bin: Option<PathBuf>,
 
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Only includes the data requests listed in this usage manifest, and the data they fall back to.\n\
+                  Usage manifests can be recorded at runtime, see `icu_provider_export::UsageManifest` for the format.\n\
+                  Markers that are not in the manifest are not exported, and locales are ignored."
+    )]
+    usage_manifest: Option<PathBuf>,
+
     #[arg(long, value_name = "FILTER")]
     #[arg(help = "Filter attributes on markers for a domain. Accepts form `domain=/regex/`.")]
     attribute_filter: Vec<Filter>,
@@ -281,7 +313,7 @@ struct Cli {
     #[arg(
         help = "Path to output directory or file. Must be empty or non-existent, unless \
                   --overwrite is present, in which case the directory is deleted first. \
-                  For --format=blob, omit this option to dump to stdout. \
+                  For --format=blob and size-report, omit this option to dump to stdout. \
                   For --format={dir,mod} defaults to 'icu4x_data'."
     )]
     output: Option<PathBuf>,
@@ -317,6 +349,45 @@ struct Cli {
     input_blob: Option<PathBuf>,
 }
 
+#[derive(Subcommand)]
+enum Command {
+    #[command(
+        about = "Instead of exporting data, report its postcard size per marker and locale, \
+                 as well as the savings of each deduplication strategy, as JSON. \
+                 Data is selected by the same options as for exporting."
+    )]
+    SizeReport,
+
+    #[command(
+        about = "Compare two exported data sets per marker and locale, and report the \
+                 differences of the deserialized data structs as JSON."
+    )]
+    #[cfg(feature = "diff")]
+    Diff {
+        #[arg(value_name = "OLD")]
+        #[arg(
+            help = "The old data set: a directory exported with --format=fs, or a blob file. \
+                    Baked data cannot be compared."
+        )]
+        old: PathBuf,
+
+        #[arg(value_name = "NEW")]
+        #[arg(
+            help = "The new data set: a directory exported with --format=fs, or a blob file. \
+                    Baked data cannot be compared."
+        )]
+        new: PathBuf,
+
+        #[arg(long, short, num_args = 1..)]
+        #[arg(help = "Only compare these data markers. Defaults to all markers.")]
+        markers: Vec<String>,
+
+        #[arg(long = "out", short, value_name = "PATH")]
+        #[arg(help = "Path to the output file. Omit this option to dump to stdout.")]
+        output: Option<PathBuf>,
+    },
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 enum Format {
     Fs,
@@ -397,38 +468,89 @@ fn main() -> eyre::Result<()> {
     run(cli)
 }
 
+fn parse_markers(markers: &[String]) -> eyre::Result<Vec<DataMarkerInfo>> {
+    Ok(match markers {
+        [x] if x == "none" => Default::default(),
+        [x] if x == "all" => {
+            #[cfg(feature = "unstable")]
+            log::info!(
+                "The icu4x-datagen crate has been built with the `unstable` feature, so `--markers all` includes unstable markers"
+            );
+            #[cfg(not(feature = "unstable"))]
+            log::info!(
+                "The icu4x-datagen crate has been built without the `unstable` feature, so `--markers all` does not include unstable markers"
+            );
+            all_markers()
+        }
+        markers => markers
+            .iter()
+            .map(|k| match marker_lookup().get(k.as_str()) {
+                Some(Some(marker)) => Ok(*marker),
+                Some(None) => {
+                    eyre::bail!("Marker {k:?} requires `unstable` Cargo feature")
+                }
+                None => eyre::bail!("Unknown marker {k:?}"),
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Writes a JSON report to the given path, or to stdout.
+fn write_json(
+    report: &serde_json::Value,
+    path: Option<&std::path::Path>,
+    overwrite: bool,
+) -> eyre::Result<()> {
+    let sink: Box<dyn std::io::Write> = if let Some(path) = path {
+        if !overwrite && path.exists() {
+            eyre::bail!("Output path is present: {:?}", path);
+        }
+        Box::new(std::fs::File::create(path).with_context(|| path.to_string_lossy().to_string())?)
+    } else {
+        Box::new(std::io::stdout())
+    };
+    serde_json::to_writer_pretty(sink, report)?;
+    Ok(())
+}
+
 fn run(cli: Cli) -> eyre::Result<()> {
+    #[cfg(feature = "diff")]
+    if let Some(Command::Diff {
+        old,
+        new,
+        markers,
+        output,
+    }) = &cli.command
+    {
+        let markers = if markers.is_empty() {
+            None
+        } else {
+            Some(parse_markers(markers)?)
+        };
+        let report = diff::diff(&diff::load(old)?, &diff::load(new)?, markers.as_deref())?;
+        return write_json(&report, output.as_deref(), cli.overwrite);
+    }
+
+    let usage = cli
+        .usage_manifest
+        .as_ref()
+        .map(|path| -> eyre::Result<_> {
+            Ok(std::fs::read_to_string(path)
+                .with_context(|| path.to_string_lossy().to_string())?
+                .parse::<icu_provider_export::UsageManifest>()?)
+        })
+        .transpose()?;
+
     let markers = if !cli.markers.is_empty() {
-        match cli.markers.as_slice() {
-            [x] if x == "none" => Default::default(),
-            [x] if x == "all" => {
-                #[cfg(feature = "unstable")]
-                log::info!(
-                    "The icu4x-datagen crate has been built with the `unstable` feature, so `--markers all` includes unstable markers"
-                );
-                #[cfg(not(feature = "unstable"))]
-                log::info!(
-                    "The icu4x-datagen crate has been built without the `unstable` feature, so `--markers all` does not include unstable markers"
-                );
-                all_markers()
-            }
-            markers => markers
-                .iter()
-                .map(|k| match marker_lookup().get(k.as_str()) {
-                    Some(Some(marker)) => Ok(*marker),
-                    Some(None) => {
-                        eyre::bail!("Marker {k:?} requires `unstable` Cargo feature")
-                    }
-                    None => eyre::bail!("Unknown marker {k:?}"),
-                })
-                .collect::<Result<_, _>>()?,
-        }
+        parse_markers(&cli.markers)?
     } else if let Some(bin_path) = &cli.markers_for_bin {
         icu::markers_for_bin(&std::fs::read(bin_path)?)?
             .into_iter()
             .collect()
+    } else if let Some(usage) = &usage {
+        parse_markers(&usage.marker_names().map(String::from).collect::<Vec<_>>())?
     } else {
-        eyre::bail!("--markers or --markers-for-bin are required.")
+        eyre::bail!("--markers, --markers-for-bin, or --usage-manifest are required.")
     };
 
     enum PreprocessedLocales {
@@ -491,7 +613,10 @@ fn run(cli: Cli) -> eyre::Result<()> {
                 std::fs::read(cli.input_blob.unwrap())?.into(),
             )?;
             let fallbacker = LocaleFallbacker::try_new_with_buffer_provider(&provider)?;
-            (Box::new(ReexportableBlobDataProvider(provider)), fallbacker)
+            (
+                Box::new(ReexportableBufferProvider(Box::new(provider))),
+                fallbacker,
+            )
         }
 
         #[cfg(all(not(feature = "provider"), feature = "blob_input"))]
@@ -629,43 +754,16 @@ fn run(cli: Cli) -> eyre::Result<()> {
         Some(Deduplication::RetainBaseLanguages) => DeduplicationStrategy::RetainBaseLanguages,
         Some(Deduplication::None) => DeduplicationStrategy::None,
         None => match cli.format {
-            Format::Fs | Format::Blob => DeduplicationStrategy::None,
-            Format::Baked if cli.no_internal_fallback && cli.deduplication.is_none() => {
+            None | Some(Format::Fs | Format::Blob) => DeduplicationStrategy::None,
+            Some(Format::Baked) if cli.no_internal_fallback && cli.deduplication.is_none() => {
                 eyre::bail!(
                     "--no-internal-fallback requires an explicit --deduplication value. Baked exporter would default to maximal deduplication, which might not be intended"
                 )
             }
-            Format::Baked => DeduplicationStrategy::Maximal,
+            Some(Format::Baked) => DeduplicationStrategy::Maximal,
         },
     };
 
-    let mut driver = ExportDriver::new(locale_families, deduplication_strategy.into(), fallbacker);
-
-    driver = driver.with_markers(markers);
-
-    driver = driver.with_additional_collations(
-        cli.include_collations
-            .iter()
-            .map(|c| c.to_datagen_value().to_owned()),
-    );
-    driver = if cli.segmenter_models.as_slice() == ["none"] {
-        driver.with_segmenter_models([])
-    } else if cli.segmenter_models.as_slice() == ["recommended"] {
-        driver.with_segmenter_models([
-            "Burmese_codepoints_exclusive_model4_heavy".into(),
-            "burmesedict".into(),
-            "cjdict".into(),
-            "Khmer_codepoints_exclusive_model4_heavy".into(),
-            "khmerdict".into(),
-            "Lao_codepoints_exclusive_model4_heavy".into(),
-            "laodict".into(),
-            "Thai_codepoints_exclusive_model4_heavy".into(),
-            "thaidict".into(),
-        ])
-    } else {
-        driver.with_segmenter_models(cli.segmenter_models.clone())
-    };
-
     let attribute_filters = cli.attribute_filter.into_iter().fold(
         HashMap::<_, Vec<(Regex, bool)>>::new(),
         |mut map, filter| {
@@ -675,15 +773,66 @@ fn run(cli: Cli) -> eyre::Result<()> {
             map
         },
     );
-    for (domain, filters) in attribute_filters {
-        driver = driver.with_marker_attributes_filter(&domain, move |attr| {
-            filters
+
+    let build_driver = |deduplication_strategy: DeduplicationStrategy| {
+        let mut driver = ExportDriver::new(
+            locale_families.clone(),
+            deduplication_strategy.into(),
+            fallbacker.clone(),
+        );
+
+        driver = driver.with_markers(markers.clone());
+
+        if let Some(usage) = &usage {
+            driver = driver.with_usage_manifest(usage.clone());
+        }
+
+        driver = driver.with_additional_collations(
+            cli.include_collations
                 .iter()
-                .all(|(regex, inverted)| regex.is_match(attr) ^ inverted)
-        })
+                .map(|c| c.to_datagen_value().to_owned()),
+        );
+        driver = if cli.segmenter_models.as_slice() == ["none"] {
+            driver.with_segmenter_models([])
+        } else if cli.segmenter_models.as_slice() == ["recommended"] {
+            driver.with_segmenter_models([
+                "Burmese_codepoints_exclusive_model4_heavy".into(),
+                "burmesedict".into(),
+                "cjdict".into(),
+                "Khmer_codepoints_exclusive_model4_heavy".into(),
+                "khmerdict".into(),
+                "Lao_codepoints_exclusive_model4_heavy".into(),
+                "laodict".into(),
+                "Thai_codepoints_exclusive_model4_heavy".into(),
+                "thaidict".into(),
+            ])
+        } else {
+            driver.with_segmenter_models(cli.segmenter_models.clone())
+        };
+
+        for (domain, filters) in attribute_filters.clone() {
+            driver = driver.with_marker_attributes_filter(&domain, move |attr| {
+                filters
+                    .iter()
+                    .all(|(regex, inverted)| regex.is_match(attr) ^ inverted)
+            })
+        }
+        driver
+    };
+
+    if let Some(Command::SizeReport) = cli.command {
+        let report = size_report::size_report(&provider, build_driver, deduplication_strategy)
+            .or_else(missing_data_message)?;
+        return write_json(&report, cli.output.as_deref(), cli.overwrite);
     }
 
-    let metadata: Result<ExportMetadata, DataError> = match cli.format {
+    let driver = build_driver(deduplication_strategy);
+
+    let Some(format) = cli.format else {
+        eyre::bail!("--format is required")
+    };
+
+    let metadata: Result<ExportMetadata, DataError> = match format {
         #[cfg(not(feature = "fs_exporter"))]
         Format::Fs => {
             eyre::bail!("Exporting to an FsProvider requires the `fs_exporter` Cargo feature")
@@ -728,7 +877,11 @@ fn run(cli: Cli) -> eyre::Result<()> {
             } else {
                 Box::new(std::io::stdout())
             };
-            BlobExporter::new_with_sink(sink)
+            if cli.compress {
+                BlobExporter::new_compressed_with_sink(sink)
+            } else {
+                BlobExporter::new_with_sink(sink)
+            }
         }),
         #[cfg(not(feature = "baked_exporter"))]
         Format::Baked => {
@@ -776,6 +929,8 @@ macro_rules! cb {
                 vec![
                     (stringify!(icu_provider::hello_world::HelloWorldV1).replace(' ', ""), Some(icu_provider::hello_world::HelloWorldV1::INFO)),
                     (stringify!(HelloWorldV1).into(), Some(icu_provider::hello_world::HelloWorldV1::INFO)),
+                    (stringify!(icu_provider::version::DataVersionV1).replace(' ', ""), Some(icu_provider::version::DataVersionV1::INFO)),
+                    (stringify!(DataVersionV1).into(), Some(icu_provider::version::DataVersionV1::INFO)),
                     $(
                         (stringify!($marker_ty).replace(' ', ""), Some(<$marker_ty>::INFO)),
                         (stringify!($marker).into(), Some(<$marker_ty>::INFO)),
@@ -807,9 +962,10 @@ macro_rules! cb {
 
         #[cfg(feature = "blob_input")]
         icu_provider::export::make_exportable_provider!(
-            ReexportableBlobDataProvider,
+            ReexportableBufferProvider,
             [
                 icu_provider::hello_world::HelloWorldV1,
+                icu_provider::version::DataVersionV1,
                 $(
                     $marker_ty,
                 )+
@@ -832,25 +988,27 @@ use icu_provider::prelude::*;
 #[cfg(feature = "blob_input")]
 use icu_provider_blob::BlobDataProvider;
 
+/// A [`BufferProvider`] whose data can be loaded from the data structs of any marker.
 #[cfg(feature = "blob_input")]
-struct ReexportableBlobDataProvider(BlobDataProvider);
+type InputProvider = dyn IterableDynamicDataProvider<BufferMarker> + Sync;
 
 #[cfg(feature = "blob_input")]
-impl<M: DataMarker> DataProvider<M> for ReexportableBlobDataProvider
+struct ReexportableBufferProvider(Box<InputProvider>);
+
+#[cfg(feature = "blob_input")]
+impl<M: DataMarker> DataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, InputProvider>: DataProvider<M>,
 {
     fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
-        self.0.as_deserializing().load(req)
+        (*self.0).as_deserializing().load(req)
     }
 }
 
 #[cfg(feature = "blob_input")]
-impl<M: DataMarker> IterableDataProvider<M> for ReexportableBlobDataProvider
+impl<M: DataMarker> IterableDataProvider<M> for ReexportableBufferProvider
 where
-    BlobDataProvider: AsDeserializingBufferProvider,
-    for<'a> DeserializingBufferProvider<'a, BlobDataProvider>: DataProvider<M>,
+    for<'a> DeserializingBufferProvider<'a, InputProvider>: DataProvider<M>,
 {
     fn iter_ids(&self) -> Result<std::collections::BTreeSet<DataIdentifierCow<'_>>, DataError> {
         self.0.iter_ids_for_marker(M::INFO)
@@ -892,6 +1050,141 @@ fn test_attributes_regex() {
     assert!(!std::fs::exists(out.join("hello/world/v1/lowercase")).unwrap());
 }
 
+#[test]
+fn test_size_report() {
+    let out = std::env::temp_dir().join("icu4x-datagen_test_size_report_out.json");
+
+    let mut args = Cli::parse_from([
+        "bin",
+        "--markers",
+        "HelloWorldV1",
+        "--locales",
+        "full",
+        "--overwrite",
+        "size-report",
+    ]);
+
+    args.output = Some(out.clone());
+
+    run(args).unwrap();
+
+    let report: serde_json::Value = serde_json::from_slice(&std::fs::read(&out).unwrap()).unwrap();
+    let hello_world = &report["markers"]["HelloWorldV1"];
+
+    assert_eq!(report["deduplication_strategy"], "none");
+    assert_eq!(hello_world["locales"]["de"], 11);
+    assert_eq!(
+        hello_world["bytes"],
+        hello_world["locales"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|v| v.as_u64().unwrap())
+            .sum::<u64>()
+    );
+    assert_eq!(hello_world["deduplication"]["none"]["saved_bytes"], 0);
+    assert_eq!(report["total"]["bytes"], hello_world["bytes"]);
+}
+
+#[test]
+fn test_usage_manifest() {
+    let usage = std::env::temp_dir().join("icu4x-datagen_test_usage_manifest.txt");
+    let out = std::env::temp_dir().join("icu4x-datagen_test_usage_manifest_out");
+    let _ = std::fs::remove_dir_all(&out);
+
+    std::fs::write(&usage, "HelloWorldV1 de-AT\nHelloWorldV1 ja/reverse\n").unwrap();
+
+    let mut args = Cli::parse_from([
+        "bin",
+        "--usage-manifest",
+        usage.to_str().unwrap(),
+        "--locales",
+        "full",
+        "--format",
+        "fs",
+    ]);
+
+    args.output = Some(out.clone());
+
+    run(args).unwrap();
+
+    assert!(std::fs::exists(out.join("hello/world/v1/de-AT.json")).unwrap());
+    assert!(std::fs::exists(out.join("hello/world/v1/de.json")).unwrap());
+    assert!(std::fs::exists(out.join("hello/world/v1/reverse/ja.json")).unwrap());
+    assert!(!std::fs::exists(out.join("hello/world/v1/en.json")).unwrap());
+    assert!(!std::fs::exists(out.join("hello/world/v1/reverse/en.json")).unwrap());
+}
+
+#[cfg(feature = "diff")]
+#[test]
+fn test_diff() {
+    let old = std::env::temp_dir().join("icu4x-datagen_test_diff_old");
+    let new = std::env::temp_dir().join("icu4x-datagen_test_diff_new");
+    let out = std::env::temp_dir().join("icu4x-datagen_test_diff_out.json");
+
+    for (path, filter) in [(&old, "hello=/.*/"), (&new, "hello=-/reverse/")] {
+        let mut args = Cli::parse_from([
+            "bin",
+            "--markers",
+            "HelloWorldV1",
+            "--locales",
+            "full",
+            "--format",
+            "fs",
+            "--overwrite",
+            "--attribute-filter",
+            filter,
+        ]);
+        args.output = Some(path.clone());
+        run(args).unwrap();
+    }
+    std::fs::write(
+        new.join("hello/world/v1/de.json"),
+        r#"{"message":"Guten Tag"}"#,
+    )
+    .unwrap();
+
+    let diff_args = |overwrite: bool| {
+        let mut args = vec![
+            "bin",
+            "diff",
+            old.to_str().unwrap(),
+            new.to_str().unwrap(),
+            "--out",
+            out.to_str().unwrap(),
+        ];
+        if overwrite {
+            args.push("--overwrite");
+        }
+        Cli::parse_from(args)
+    };
+    run(diff_args(true)).unwrap();
+    // The report exists now
+    assert!(run(diff_args(false)).is_err());
+
+    let baked = std::env::temp_dir().join("icu4x-datagen_test_diff_baked");
+    std::fs::create_dir_all(&baked).unwrap();
+    std::fs::write(baked.join("mod.rs"), "").unwrap();
+    assert!(diff::load(&baked).is_err());
+
+    let report: serde_json::Value = serde_json::from_slice(&std::fs::read(&out).unwrap()).unwrap();
+    let hello_world = &report["markers"]["HelloWorldV1"];
+
+    assert_eq!(hello_world["added"], serde_json::json!([]));
+    assert!(
+        hello_world["removed"]
+            .as_array()
+            .unwrap()
+            .contains(&"en/reverse".into())
+    );
+    assert_eq!(
+        hello_world["changed"],
+        serde_json::json!({
+            "de": [{ "path": "/message", "old": "Hallo Welt", "new": "Guten Tag" }]
+        })
+    );
+}
+
 #[cfg(test)]
 #[cfg_attr(icu4x_nightly_tests, deny(non_exhaustive_omitted_patterns))]
 #[allow(unreachable_patterns)]
@@ -0,0 +1,198 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `size-report` subcommand.
+//!
+//! Sizes are postcard sizes of the data structs. For every marker, the report contains
+//! * `payloads`: the number of exported data identifiers
+//! * `bytes`: the size of all payloads, as stored by the fs exporter
+//! * `unique_bytes`: the size of all distinct payloads, as stored by the blob and baked exporters
+//! * `deduplication`: the number of payloads and bytes under each [`DeduplicationStrategy`],
+//!   and the bytes saved compared to [`DeduplicationStrategy::None`]
+//! * `locales`: the size of every payload under the selected [`DeduplicationStrategy`]
+
+use icu_provider::export::*;
+use icu_provider::prelude::*;
+use icu_provider_export::prelude::*;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{DefaultHasher, Hasher};
+use std::sync::{Mutex, PoisonError};
+
+const STRATEGIES: [(&str, DeduplicationStrategy); 3] = [
+    ("none", DeduplicationStrategy::None),
+    (
+        "retain-base-languages",
+        DeduplicationStrategy::RetainBaseLanguages,
+    ),
+    ("maximal", DeduplicationStrategy::Maximal),
+];
+
+/// The postcard sizes and hashes of the exported payloads, by marker name and identifier.
+type Sizes = BTreeMap<&'static str, BTreeMap<String, (usize, u64)>>;
+
+/// A [`DataExporter`] that only measures payloads.
+#[derive(Default)]
+struct SizeExporter(Mutex<Sizes>);
+
+impl SizeExporter {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Sizes> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl DataExporter for SizeExporter {
+    fn put_payload(
+        &self,
+        marker: DataMarkerInfo,
+        id: DataIdentifierBorrowed,
+        payload: &DataPayload<ExportMarker>,
+    ) -> Result<(), DataError> {
+        let mut hasher = DefaultHasher::new();
+        let size = payload.hash_and_postcard_size(&mut hasher);
+        self.lock()
+            .entry(marker.id.name())
+            .or_default()
+            .insert(id.to_string(), (size, hasher.finish()));
+        Ok(())
+    }
+
+    fn flush(&self, marker: DataMarkerInfo, _metadata: FlushMetadata) -> Result<(), DataError> {
+        // Markers without any payloads are part of the report
+        self.lock().entry(marker.id.name()).or_default();
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<ExporterCloseMetadata, DataError> {
+        let sizes = core::mem::take(self.0.get_mut().unwrap_or_else(PoisonError::into_inner));
+        Ok(ExporterCloseMetadata(Some(Box::new(sizes))))
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct Totals {
+    payloads: usize,
+    bytes: usize,
+    unique_bytes: usize,
+}
+
+impl Totals {
+    fn of(ids: &BTreeMap<String, (usize, u64)>) -> Self {
+        Self {
+            payloads: ids.len(),
+            bytes: ids.values().map(|&(size, _)| size).sum(),
+            unique_bytes: ids
+                .values()
+                .map(|&(size, hash)| (hash, size))
+                .collect::<HashMap<_, _>>()
+                .values()
+                .sum(),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            payloads: self.payloads + other.payloads,
+            bytes: self.bytes + other.bytes,
+            unique_bytes: self.unique_bytes + other.unique_bytes,
+        }
+    }
+
+    /// The report for these totals, given the totals under each strategy.
+    fn to_json(self, by_strategy: &[(&str, Self)]) -> Map<String, Value> {
+        let baseline = by_strategy.first().map(|&(_, t)| t).unwrap_or(self);
+        let mut json = Map::new();
+        json.insert("payloads".into(), self.payloads.into());
+        json.insert("bytes".into(), self.bytes.into());
+        json.insert("unique_bytes".into(), self.unique_bytes.into());
+        json.insert(
+            "deduplication".into(),
+            by_strategy
+                .iter()
+                .map(|&(name, totals)| {
+                    (
+                        name.to_string(),
+                        json!({
+                            "payloads": totals.payloads,
+                            "bytes": totals.bytes,
+                            "saved_bytes": baseline.bytes.saturating_sub(totals.bytes),
+                        }),
+                    )
+                })
+                .collect::<Map<_, _>>()
+                .into(),
+        );
+        json
+    }
+}
+
+/// Exports the data under every [`DeduplicationStrategy`], and reports the sizes.
+pub(crate) fn size_report(
+    provider: &impl ExportableProvider,
+    build_driver: impl Fn(DeduplicationStrategy) -> ExportDriver,
+    deduplication_strategy: DeduplicationStrategy,
+) -> Result<Value, DataError> {
+    let mut selected = Sizes::default();
+    let mut totals = Vec::new();
+    for (name, strategy) in STRATEGIES {
+        log::info!("Measuring data with {name} deduplication");
+        let sizes = build_driver(strategy)
+            .export(provider, SizeExporter::default())?
+            .exporter
+            .0
+            .and_then(|sizes| sizes.downcast::<Sizes>().ok())
+            .map(|sizes| *sizes)
+            .unwrap_or_default();
+        totals.push((
+            name,
+            sizes
+                .iter()
+                .map(|(&marker, ids)| (marker, Totals::of(ids)))
+                .collect::<BTreeMap<_, _>>(),
+        ));
+        if strategy == deduplication_strategy {
+            selected = sizes;
+        }
+    }
+
+    let by_strategy = |marker: Option<&str>| {
+        totals
+            .iter()
+            .map(|(name, markers)| {
+                let totals = match marker {
+                    Some(marker) => markers.get(marker).copied().unwrap_or_default(),
+                    None => markers.values().fold(Totals::default(), |a, &b| a.add(b)),
+                };
+                (*name, totals)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let markers = selected
+        .iter()
+        .map(|(&marker, ids)| {
+            let mut json = Totals::of(ids).to_json(&by_strategy(Some(marker)));
+            json.insert(
+                "locales".into(),
+                ids.iter()
+                    .map(|(id, &(size, _))| (id.clone(), size.into()))
+                    .collect::<Map<_, _>>()
+                    .into(),
+            );
+            (marker.to_string(), json.into())
+        })
+        .collect::<Map<_, _>>();
+
+    Ok(json!({
+        "deduplication_strategy": STRATEGIES
+            .iter()
+            .find(|&&(_, s)| s == deduplication_strategy)
+            .map(|&(name, _)| name),
+        "total": selected
+            .values()
+            .fold(Totals::default(), |a, ids| a.add(Totals::of(ids)))
+            .to_json(&by_strategy(None)),
+        "markers": markers,
+    }))
+}
@@ -59,6 +59,7 @@ macro_rules! registry(
             icu::time::provider::TimezonePeriodsV1: TimezonePeriodsV1,
             icu::decimal::provider::DecimalDigitsV1: DecimalDigitsV1,
             icu::decimal::provider::DecimalSymbolsV1: DecimalSymbolsV1,
+            icu::decimal::provider::DecimalSymbolsV2: DecimalSymbolsV2,
             icu::list::provider::ListAndV1: ListAndV1,
             icu::list::provider::ListOrV1: ListOrV1,
             icu::list::provider::ListUnitV1: ListUnitV1,
@@ -295,6 +296,7 @@ macro_rules! registry(
             icu::experimental::dimension::provider::currency::extended::CurrencyExtendedDataV1: CurrencyExtendedDataV1,
             icu::experimental::dimension::provider::currency::fractions::CurrencyFractionsV1: CurrencyFractionsV1,
             icu::experimental::dimension::provider::percent::PercentEssentialsV1: PercentEssentialsV1,
+            icu::experimental::dimension::provider::range::DecimalRangePatternsV1: DecimalRangePatternsV1,
             icu::experimental::dimension::provider::units::display_names::UnitsDisplayNamesV1: UnitsDisplayNamesV1,
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesAreaCoreV1: UnitsNamesAreaCoreV1,
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesAreaExtendedV1: UnitsNamesAreaExtendedV1,
@@ -329,6 +331,9 @@ macro_rules! registry(
             icu::experimental::displaynames::provider::LocaleNamesVariantMediumV1: LocaleNamesVariantMediumV1,
             icu::experimental::displaynames::provider::LocaleNamesEssentialsV1: LocaleNamesEssentialsV1,
             icu::experimental::measure::provider::UnitIdsV1: UnitIdsV1,
+            icu::experimental::regioninfo::provider::RegionContainmentV1: RegionContainmentV1,
+            icu::experimental::regioninfo::provider::RegionCurrenciesV1: RegionCurrenciesV1,
+            icu::experimental::regioninfo::provider::TerritoryInfoV1: TerritoryInfoV1,
             icu::experimental::relativetime::provider::LongSecondRelativeV1: LongSecondRelativeV1,
             icu::experimental::relativetime::provider::ShortSecondRelativeV1: ShortSecondRelativeV1,
             icu::experimental::relativetime::provider::NarrowSecondRelativeV1: NarrowSecondRelativeV1,
@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+    )
+)]
+#![warn(missing_docs)]
+
+//! `icu_provider_resb` is one of the [`ICU4X`] components.
+//!
+//! It serves data from ICU4C resource bundles, in either the binary `.res` format or the
+//! `.txt` text format, through [`ResourceBundleProvider`].
+//!
+//! Resource bundles are not structured around data markers, so each data marker is mapped to
+//! a path within the bundles with [`ResourceBundleProvider::add_marker_path`]. The resource at
+//! that path is returned as JSON, and it can be deserialized into any data struct with a
+//! matching Serde implementation using
+//! [`as_deserializing()`](icu_provider::buf::AsDeserializingBufferProvider::as_deserializing).
+//!
+//! Resources are looked up in the bundle for the requested locale first, and then in the
+//! bundles for the locales produced by a [`LocaleFallbacker`](icu_locale::LocaleFallbacker),
+//! ending with the `root` bundle. Tables inherit the entries they lack from the bundles
+//! of less specific locales. This mirrors how ICU4C looks up resources.
+//!
+//! # Resource representation
+//!
+//! Resources are converted to JSON as follows:
+//!
+//! | Resource | JSON |
+//! |---|---|
+//! | string | string |
+//! | table | object |
+//! | array | array |
+//! | integer | number, interpreted as a signed 28-bit integer |
+//! | integer vector | array of numbers, interpreted as signed 32-bit integers |
+//! | binary | array of numbers, one per byte |
+//!
+//! Alias resources are not supported.
+//!
+//! [`ICU4X`]: ../icu/index.html
+
+mod provider;
+mod value;
+
+pub use provider::ResourceBundleProvider;
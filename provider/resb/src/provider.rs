@@ -0,0 +1,334 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::value::{ResourceValue, resource_to_value};
+use icu_locale::LocaleFallbacker;
+use icu_provider::IterableDynamicDataProvider;
+use icu_provider::buf::BufferFormat;
+use icu_provider::marker::DataMarkerId;
+use icu_provider::prelude::*;
+use resb::text::Reader;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// A data provider that serves resources from ICU4C resource bundles.
+///
+/// [`ResourceBundleProvider`] implements [`BufferProvider`], returning each resource as JSON.
+/// See the [crate-level documentation](crate) for how resources are found and represented.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale::locale;
+/// use icu_locale::LocaleFallbacker;
+/// use icu_provider::prelude::*;
+/// use icu_provider_resb::ResourceBundleProvider;
+///
+/// #[derive(Debug, PartialEq, serde::Deserialize, yoke::Yokeable)]
+/// pub struct Greetings {
+///     pub hello: String,
+///     pub goodbye: String,
+/// }
+///
+/// icu_provider::data_marker!(GreetingsV1, Greetings);
+/// icu_provider::data_struct!(Greetings);
+///
+/// let mut provider =
+///     ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+/// provider
+///     .try_add_text_bundle(r#"root{ greetings{ hello{"Hello"} goodbye{"Goodbye"} } }"#)
+///     .expect("valid bundle");
+/// provider
+///     .try_add_text_bundle(r#"de{ greetings{ hello{"Hallo"} } }"#)
+///     .expect("valid bundle");
+/// provider.add_marker_path(GreetingsV1::INFO, "greetings");
+///
+/// let response: DataResponse<GreetingsV1> = provider
+///     .as_deserializing()
+///     .load(DataRequest {
+///         id: DataIdentifierBorrowed::for_locale(&locale!("de-CH").into()),
+///         ..Default::default()
+///     })
+///     .expect("data is available");
+///
+/// // Each resource is found in the most specific bundle that contains it
+/// assert_eq!(
+///     response.payload.get(),
+///     &Greetings {
+///         hello: "Hallo".into(),
+///         goodbye: "Goodbye".into(),
+///     }
+/// );
+/// ```
+///
+/// [`BufferProvider`]: icu_provider::buf::BufferProvider
+#[derive(Debug, Clone)]
+pub struct ResourceBundleProvider {
+    bundles: HashMap<DataLocale, Bundle>,
+    paths: HashMap<DataMarkerId, Vec<String>>,
+    fallbacker: LocaleFallbacker,
+}
+
+#[derive(Debug, Clone)]
+struct Bundle {
+    root: Value,
+    is_locale_fallback_enabled: bool,
+}
+
+impl ResourceBundleProvider {
+    /// Creates a new [`ResourceBundleProvider`] without any bundles, which uses the given
+    /// fallbacker to find the bundles for a locale.
+    pub fn new(fallbacker: LocaleFallbacker) -> Self {
+        Self {
+            bundles: HashMap::new(),
+            paths: HashMap::new(),
+            fallbacker,
+        }
+    }
+
+    /// Serves data for `marker` from the resource at `path`, a list of table keys and array
+    /// indices separated by `/`.
+    ///
+    /// If a request has marker attributes, they are treated as additional path segments.
+    pub fn add_marker_path(&mut self, marker: DataMarkerInfo, path: &str) {
+        self.paths.insert(
+            marker.id,
+            path.split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(String::from)
+                .collect(),
+        );
+    }
+
+    /// Adds a bundle in the binary `.res` format.
+    ///
+    /// The name of the bundle is the ICU4C name of its locale, such as `de_CH` or `root`.
+    ///
+    /// Binary bundles consist of 32-bit words, so `bytes` has to be 4-byte aligned and its
+    /// length a multiple of 4.
+    pub fn try_add_binary_bundle(&mut self, name: &str, bytes: &[u8]) -> Result<(), DataError> {
+        if bytes.as_ptr().align_offset(4) != 0 {
+            return Err(
+                DataError::custom("Binary resource bundle is not 4-byte aligned")
+                    .with_display_context(name),
+            );
+        }
+        self.try_add_binary_bundle_unaligned(name, bytes)
+    }
+
+    /// Adds a binary bundle whose bytes don't need to be aligned, as the words are copied.
+    fn try_add_binary_bundle_unaligned(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<(), DataError> {
+        let locale = bundle_name_to_locale(name)?;
+        let (words, []) = bytes.as_chunks::<4>() else {
+            return Err(
+                DataError::custom("Binary resource bundle length is not a multiple of 4")
+                    .with_display_context(name),
+            );
+        };
+        let words = words
+            .iter()
+            .map(|&word| u32::from_ne_bytes(word))
+            .collect::<Vec<_>>();
+        let ResourceValue(root) = resb::binary::from_words(&words).map_err(|e| {
+            DataError::custom("Invalid binary resource bundle")
+                .with_display_context(name)
+                .with_display_context(&e)
+        })?;
+        self.bundles.insert(
+            locale,
+            Bundle {
+                root,
+                is_locale_fallback_enabled: true,
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds a bundle in the `.txt` text format.
+    ///
+    /// The name of the bundle, which is the key of its root table, is the ICU4C name of its
+    /// locale, such as `de_CH` or `root`.
+    pub fn try_add_text_bundle(&mut self, text: &str) -> Result<(), DataError> {
+        let (bundle, _) = Reader::read(text).map_err(|e| {
+            DataError::custom("Invalid text resource bundle").with_display_context(&e)
+        })?;
+        let locale = bundle_name_to_locale(bundle.name())?;
+        self.bundles.insert(
+            locale,
+            Bundle {
+                root: resource_to_value(bundle.root())
+                    .map_err(|e| e.with_display_context(bundle.name()))?,
+                is_locale_fallback_enabled: bundle.is_locale_fallback_enabled,
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds all `.res` and `.txt` bundles in a directory.
+    ///
+    /// Binary bundles are named after their file names, such as `de_CH.res`.
+    pub fn try_add_directory(&mut self, dir: &Path) -> Result<(), DataError> {
+        for entry in fs::read_dir(dir).map_err(|e| DataError::from(e).with_path_context(dir))? {
+            let path = entry?.path();
+            let Some(name) = path.file_stem().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            match path.extension().and_then(|e| e.to_str()) {
+                Some("res") => {
+                    let bytes =
+                        fs::read(&path).map_err(|e| DataError::from(e).with_path_context(&path))?;
+                    // `Vec<u8>` makes no alignment guarantees
+                    self.try_add_binary_bundle_unaligned(name, &bytes)
+                        .map_err(|e| e.with_path_context(&path))?;
+                }
+                Some("txt") => {
+                    let text = fs::read_to_string(&path)
+                        .map_err(|e| DataError::from(e).with_path_context(&path))?;
+                    self.try_add_text_bundle(&text)
+                        .map_err(|e| e.with_path_context(&path))?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the resource for the given marker attributes in the bundle for a locale.
+    ///
+    /// Returns `None` if there is no bundle for the locale.
+    fn lookup(
+        &self,
+        path: &[String],
+        attributes: &DataMarkerAttributes,
+        locale: &DataLocale,
+    ) -> Option<(Option<&Value>, bool)> {
+        let bundle = self.bundles.get(locale)?;
+        let attributes = attributes.as_str().split('/').filter(|s| !s.is_empty());
+        let resource = path.iter().map(String::as_str).chain(attributes).try_fold(
+            &bundle.root,
+            |resource, segment| match resource {
+                Value::Object(table) => table.get(segment),
+                Value::Array(array) => array.get(segment.parse::<usize>().ok()?),
+                _ => None,
+            },
+        );
+        Some((resource, bundle.is_locale_fallback_enabled))
+    }
+}
+
+impl DynamicDataProvider<BufferMarker> for ResourceBundleProvider {
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        if marker.is_singleton && !req.id.locale.is_unknown() {
+            return Err(DataErrorKind::InvalidRequest.with_req(marker, req));
+        }
+        let path = self
+            .paths
+            .get(&marker.id)
+            .ok_or_else(|| DataErrorKind::MarkerNotFound.with_req(marker, req))?;
+
+        let mut fallback_iterator = self
+            .fallbacker
+            .for_config(marker.fallback_config)
+            .fallback_for(*req.id.locale);
+        // The resource from the most specific bundle, and the locale of that bundle
+        let mut found: Option<(Value, DataLocale)> = None;
+        loop {
+            match self.lookup(path, req.id.marker_attributes, fallback_iterator.get()) {
+                Some((Some(resource), is_locale_fallback_enabled)) => {
+                    match found.as_mut() {
+                        // Tables inherit the entries they lack from less specific bundles
+                        Some((value, _)) => inherit_entries(value, resource),
+                        None => found = Some((resource.clone(), *fallback_iterator.get())),
+                    }
+                    if !is_locale_fallback_enabled {
+                        break;
+                    }
+                }
+                // Bundles that disable fallback are the last ones to be searched
+                Some((None, false)) => break,
+                Some((None, true)) | None => {}
+            }
+            // If we just checked und, break out of the loop.
+            if fallback_iterator.get().is_unknown() {
+                break;
+            }
+            fallback_iterator.step();
+        }
+
+        let Some((resource, locale)) = found else {
+            return Err(DataErrorKind::IdentifierNotFound.with_req(marker, req));
+        };
+        let buffer = serde_json::to_vec(&resource)
+            .map_err(|e| DataError::custom("JSON serialization").with_display_context(&e))?;
+        let mut metadata = DataResponseMetadata::default();
+        metadata.buffer_format = Some(BufferFormat::Json);
+        metadata.locale = Some(locale);
+        Ok(DataResponse {
+            metadata,
+            payload: DataPayload::from_owned_buffer(buffer.into_boxed_slice()),
+        })
+    }
+}
+
+/// Adds the entries of `fallback` that are missing from `value`, recursively, if both are
+/// tables.
+fn inherit_entries(value: &mut Value, fallback: &Value) {
+    let (Value::Object(table), Value::Object(fallback)) = (value, fallback) else {
+        return;
+    };
+    for (key, fallback) in fallback {
+        match table.get_mut(key) {
+            Some(value) => inherit_entries(value, fallback),
+            None => {
+                table.insert(key.clone(), fallback.clone());
+            }
+        }
+    }
+}
+
+impl IterableDynamicDataProvider<BufferMarker> for ResourceBundleProvider {
+    fn iter_ids_for_marker(
+        &self,
+        marker: DataMarkerInfo,
+    ) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        let path = self
+            .paths
+            .get(&marker.id)
+            .ok_or_else(|| DataErrorKind::MarkerNotFound.with_marker(marker))?;
+        Ok(self
+            .bundles
+            .keys()
+            .filter(|locale| !marker.is_singleton || locale.is_unknown())
+            .filter(|locale| {
+                matches!(
+                    self.lookup(path, DataMarkerAttributes::empty(), locale),
+                    Some((Some(_), _))
+                )
+            })
+            .map(|locale| DataIdentifierCow::from_locale(*locale))
+            .collect())
+    }
+}
+
+/// Parses the ICU4C name of a bundle, such as `de_CH` or `root`, as a locale.
+fn bundle_name_to_locale(name: &str) -> Result<DataLocale, DataError> {
+    if name == "root" {
+        return Ok(DataLocale::default());
+    }
+    DataLocale::try_from_str(&name.replace('_', "-")).map_err(|e| {
+        DataError::custom("Invalid locale in resource bundle name")
+            .with_display_context(name)
+            .with_display_context(&e)
+    })
+}
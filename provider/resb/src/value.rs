@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversion of resources into JSON values.
+
+use icu_provider::DataError;
+use resb::bundle::{Int28, Resource};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Converts a resource read from a text bundle into a JSON value.
+///
+/// Returns an error for resource types that have no JSON representation, such as aliases.
+pub(crate) fn resource_to_value(resource: &Resource) -> Result<Value, DataError> {
+    Ok(match resource {
+        Resource::String(value) => Value::from(&**value),
+        Resource::Array(elements) => elements
+            .iter()
+            .map(resource_to_value)
+            .collect::<Result<_, _>>()?,
+        Resource::Table(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, resource)| Ok((key.as_str().to_owned(), resource_to_value(resource)?)))
+                .collect::<Result<_, DataError>>()?,
+        ),
+        Resource::Binary(bytes) => bytes.iter().copied().collect(),
+        // Integers are signed, like ICU4C's `ures_getInt`.
+        Resource::Integer(value) => Value::from(i32::from(*value)),
+        Resource::IntVector(values) => values.iter().map(|&value| value as i32).collect(),
+        _ => return Err(DataError::custom("Unsupported resource type")),
+    })
+}
+
+/// A JSON value deserialized from a binary bundle.
+///
+/// [`Value`]'s own `Deserialize` implementation does not accept binary resources, so this
+/// wrapper uses the same representation as [`resource_to_value`].
+pub(crate) struct ResourceValue(pub(crate) Value);
+
+impl<'de> Deserialize<'de> for ResourceValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ResourceValueVisitor).map(Self)
+    }
+}
+
+struct ResourceValueVisitor;
+
+impl<'de> Visitor<'de> for ResourceValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a resource")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_i32<E: de::Error>(self, value: i32) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    /// The binary deserializer only produces unsigned values for 28-bit integers, which are
+    /// signed like in [`resource_to_value`].
+    fn visit_u32<E: de::Error>(self, value: u32) -> Result<Value, E> {
+        Ok(Value::from(i32::from(Int28::from(value))))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Value, E> {
+        Ok(value.iter().copied().collect())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(ResourceValue(element)) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(Value::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Map::new();
+        while let Some((key, ResourceValue(value))) = map.next_entry::<String, _>()? {
+            entries.insert(key, value);
+        }
+        Ok(Value::Object(entries))
+    }
+}
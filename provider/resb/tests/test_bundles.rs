@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locale::{LocaleFallbacker, langid};
+use icu_provider::IterableDynamicDataProvider;
+use icu_provider::hello_world::{HelloWorld, HelloWorldV1};
+use icu_provider::prelude::*;
+use icu_provider_resb::ResourceBundleProvider;
+use resb::binary::Serializer;
+use resb::text::Reader;
+
+fn provider() -> ResourceBundleProvider {
+    let mut provider = ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+    for text in [
+        r#"root{ hello{ message{"Hello World"} } }"#,
+        r#"de{ hello{ message{"Hallo Welt"} } }"#,
+        r#"de_AT{ other{"Servus"} }"#,
+        r#"ja:table(nofallback){ other{"こんにちは"} }"#,
+    ] {
+        provider.try_add_text_bundle(text).unwrap();
+    }
+    provider.add_marker_path(HelloWorldV1::INFO, "hello");
+    provider
+}
+
+fn load(provider: &ResourceBundleProvider, locale: DataLocale) -> Result<String, DataError> {
+    let response: DataResponse<HelloWorldV1> = provider.as_deserializing().load(DataRequest {
+        id: DataIdentifierBorrowed::for_locale(&locale),
+        ..Default::default()
+    })?;
+    let HelloWorld { message } = response.payload.get();
+    Ok(message.to_string())
+}
+
+#[test]
+fn test_fallback() {
+    let provider = provider();
+
+    assert_eq!(load(&provider, langid!("de").into()).unwrap(), "Hallo Welt");
+    assert_eq!(
+        load(&provider, langid!("de-AT").into()).unwrap(),
+        "Hallo Welt"
+    );
+    assert_eq!(
+        load(&provider, langid!("fr").into()).unwrap(),
+        "Hello World"
+    );
+
+    // Bundles without fallback don't inherit from root
+    assert_eq!(
+        load(&provider, langid!("ja").into()).unwrap_err().kind,
+        DataErrorKind::IdentifierNotFound
+    );
+}
+
+#[test]
+fn test_iter() {
+    let provider = provider();
+
+    assert_eq!(
+        provider.iter_ids_for_marker(HelloWorldV1::INFO).unwrap(),
+        [langid!("und"), langid!("de")]
+            .into_iter()
+            .map(|langid| DataIdentifierCow::from_locale(langid.into()))
+            .collect()
+    );
+}
+
+#[test]
+fn test_unknown_marker() {
+    let provider = ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+
+    assert_eq!(
+        load(&provider, langid!("de").into()).unwrap_err().kind,
+        DataErrorKind::MarkerNotFound
+    );
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize, yoke::Yokeable)]
+#[non_exhaustive]
+pub struct Number {
+    pub value: i32,
+    pub values: Vec<i32>,
+}
+
+icu_provider::data_marker!(NumberV1, Number);
+icu_provider::data_struct!(Number);
+
+#[test]
+fn test_binary_bundle() {
+    let text = r#"root{ number{ value:int{-2} values:intvector{ -3, 4 } } }"#;
+    let (bundle, keys) = Reader::read(text).unwrap();
+    let bytes = Serializer::to_bytes(&bundle, &keys).unwrap();
+
+    // Places the bundle at a 4-byte aligned offset
+    let mut buffer = vec![0; bytes.len() + 8];
+    let offset = buffer.as_ptr().align_offset(4);
+    buffer[offset..offset + bytes.len()].copy_from_slice(&bytes);
+    let aligned = &buffer[offset..offset + bytes.len()];
+
+    let mut binary = ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+    binary.try_add_binary_bundle("root", aligned).unwrap();
+    binary.add_marker_path(NumberV1::INFO, "number");
+    let mut textual = ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+    textual.try_add_text_bundle(text).unwrap();
+    textual.add_marker_path(NumberV1::INFO, "number");
+
+    // Integers are signed in both formats
+    for provider in [binary, textual] {
+        let response: DataResponse<NumberV1> = provider
+            .as_deserializing()
+            .load(Default::default())
+            .unwrap();
+        assert_eq!(
+            response.payload.get(),
+            &Number {
+                value: -2,
+                values: vec![-3, 4]
+            }
+        );
+    }
+
+    let mut provider = ResourceBundleProvider::new(LocaleFallbacker::new().static_to_owned());
+    let misaligned = &buffer[offset + 1..offset + 1 + bytes.len()];
+    assert!(
+        provider
+            .try_add_binary_bundle("root", misaligned)
+            .unwrap_err()
+            .to_string()
+            .contains("not 4-byte aligned")
+    );
+    let truncated = &aligned[..aligned.len() - 1];
+    assert!(
+        provider
+            .try_add_binary_bundle("root", truncated)
+            .unwrap_err()
+            .to_string()
+            .contains("not a multiple of 4")
+    );
+}
@@ -7,6 +7,7 @@
 //! Sample file:
 //! <https://github.com/unicode-cldr/cldr-core/blob/master/supplemental/currencyData.json>
 
+use icu::locale::subtags::Region;
 use serde::Deserialize;
 use std::collections::BTreeMap;
 use tinystr::TinyAsciiStr;
@@ -39,9 +40,25 @@ pub(crate) struct Fractions {
     pub(crate) currencies: BTreeMap<ISOCode, RoundingModes>,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct CurrencyPeriod {
+    #[serde(rename = "_from")]
+    pub(crate) from: Option<String>,
+
+    #[serde(rename = "_to")]
+    pub(crate) to: Option<String>,
+
+    #[serde(rename = "_tender")]
+    pub(crate) tender: Option<String>,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub(crate) struct CurrencyData {
     pub(crate) fractions: Fractions,
+
+    /// The currencies of each region, each entry being a single-element map from the
+    /// currency code to its period of use.
+    pub(crate) region: BTreeMap<Region, Vec<BTreeMap<ISOCode, CurrencyPeriod>>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -11,8 +11,15 @@ use icu::locale::LanguageIdentifier;
 use serde::Deserialize;
 use std::collections::HashMap;
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Version {
+    #[serde(rename = "_cldrVersion")]
+    pub(crate) cldr_version: String,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub(crate) struct Supplemental {
+    pub(crate) version: Version,
     #[serde(rename = "likelySubtags")]
     pub(crate) likely_subtags: HashMap<LanguageIdentifier, LanguageIdentifier>,
 }
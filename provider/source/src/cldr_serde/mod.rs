@@ -31,6 +31,10 @@ pub(crate) mod personnames;
 #[cfg(feature = "unstable")]
 pub(crate) mod plural_ranges;
 pub(crate) mod plurals;
+#[cfg(feature = "unstable")]
+pub(crate) mod territory_containment;
+#[cfg(feature = "unstable")]
+pub(crate) mod territory_info;
 pub(crate) mod time_zones;
 #[cfg(feature = "unstable")]
 pub(crate) mod transforms;
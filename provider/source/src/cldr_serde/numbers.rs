@@ -172,6 +172,7 @@ pub(crate) struct Symbols {
     #[serde(rename = "approximatelySign")]
     pub(crate) approximately_sign: String,
     pub(crate) decimal: String,
+    pub(crate) exponential: String,
     pub(crate) group: String,
     #[serde(rename = "minusSign")]
     pub(crate) minus_sign: String,
@@ -351,6 +352,14 @@ pub(crate) struct PercentFormattingPatterns {
     pub(crate) standard: String,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct MiscPatterns {
+    /// Pattern for approximate numbers, such as "~{0}"
+    pub(crate) approximately: String,
+    /// Pattern for number ranges, such as "{0}–{1}"
+    pub(crate) range: String,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub(crate) struct NumberingSystemData {
     /// Map from numbering system to symbols
@@ -361,6 +370,8 @@ pub(crate) struct NumberingSystemData {
     pub(crate) currency_patterns: HashMap<String, CurrencyFormattingPatterns>,
     /// Map from numbering system to percent patterns
     pub(crate) percent_patterns: HashMap<String, PercentFormattingPatterns>,
+    /// Map from numbering system to miscellaneous patterns
+    pub(crate) misc_patterns: HashMap<String, MiscPatterns>,
 }
 
 pub(crate) struct NumberingSystemDataVisitor;
@@ -401,6 +412,10 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: PercentFormattingPatterns = access.next_value()?;
                     result.percent_patterns.insert(numsys.to_string(), value);
                 }
+                "miscPatterns" => {
+                    let value: MiscPatterns = access.next_value()?;
+                    result.misc_patterns.insert(numsys.to_string(), value);
+                }
                 _ => {
                     // When needed, consume "scientificFormats", "percentFormats", ...
                     // For now, ignore them.
@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON territoryContainment.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/territoryContainment.json>
+
+use icu::locale::subtags::Region;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Group {
+    #[serde(rename = "_contains")]
+    pub(crate) contains: Vec<Region>,
+
+    #[serde(rename = "_status")]
+    pub(crate) status: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    /// Keys are region codes, optionally suffixed with `-status-grouping` or
+    /// `-status-deprecated` for the non-geographical and deprecated parts of a group.
+    #[serde(rename = "territoryContainment")]
+    pub(crate) territory_containment: BTreeMap<String, Group>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
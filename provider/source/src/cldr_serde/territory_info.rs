@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON territoryInfo.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/territoryInfo.json>
+
+use icu::locale::subtags::Region;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguagePopulation {
+    #[serde(rename = "_populationPercent")]
+    pub(crate) population_percent: String,
+
+    #[serde(rename = "_officialStatus")]
+    pub(crate) official_status: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Territory {
+    #[serde(rename = "_population")]
+    pub(crate) population: String,
+
+    #[serde(rename = "_literacyPercent")]
+    pub(crate) literacy_percent: String,
+
+    #[serde(rename = "_gdp")]
+    pub(crate) gdp: String,
+
+    /// Keys are language identifiers using `_` as a separator, such as `pa_Arab`.
+    #[serde(rename = "languagePopulation", default)]
+    pub(crate) language_population: BTreeMap<String, LanguagePopulation>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    #[serde(rename = "territoryInfo")]
+    pub(crate) territory_info: BTreeMap<Region, Territory>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
@@ -36,6 +36,8 @@ where
             Box::new(['\0'; 10])
         } else if type_id == TypeId::of::<DecimalSymbols>() {
             Box::new(DecimalSymbols::new_en_for_testing())
+        } else if type_id == TypeId::of::<DecimalSymbolsWithExponent>() {
+            Box::new(DecimalSymbolsWithExponent::new_en_for_testing())
         } else {
             panic!(
                 "Don't how how to create for debug: {}",
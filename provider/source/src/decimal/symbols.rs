@@ -11,10 +11,13 @@ use icu_provider::prelude::*;
 use std::collections::HashSet;
 use zerovec::VarZeroCow;
 
-impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
-    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV1>, DataError> {
-        self.check_req::<DecimalSymbolsV1>(req)?;
-
+impl SourceDataProvider {
+    /// Loads the strings and grouping sizes of the decimal symbols, in their latest form.
+    fn load_decimal_symbols(
+        &self,
+        req: DataRequest,
+        marker: DataMarkerInfo,
+    ) -> Result<(DecimalSymbolWithExponentStrsBuilder<'static>, GroupingSizes), DataError> {
         let resource: &cldr_serde::numbers::Resource = self
             .cldr()?
             .numbers()
@@ -29,10 +32,10 @@ impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
         };
 
         let Some(symbols) = &numbers.numsys_data.symbols.get(nsname) else {
-            return Err(DataErrorKind::IdentifierNotFound.with_req(DecimalSymbolsV1::INFO, req));
+            return Err(DataErrorKind::IdentifierNotFound.with_req(marker, req));
         };
         let Some(formats) = &numbers.numsys_data.formats.get(nsname) else {
-            return Err(DataErrorKind::IdentifierNotFound.with_req(DecimalSymbolsV1::INFO, req));
+            return Err(DataErrorKind::IdentifierNotFound.with_req(marker, req));
         };
 
         let positive = DecimalSubPattern::try_from_items(&formats.standard.positive)?;
@@ -48,7 +51,7 @@ impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
             .map(|n| (n.prefix.as_str(), n.suffix.as_str()))
             .unwrap_or_else(|| ("-", ""));
 
-        let strings = DecimalSymbolStrsBuilder {
+        let strings = DecimalSymbolWithExponentStrsBuilder {
             minus_sign_prefix: VarZeroCow::new_owned(
                 affixes.0.replace('-', &symbols.minus_sign).into_boxed_str(),
             ),
@@ -64,8 +67,8 @@ impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
             decimal_separator: VarZeroCow::new_owned(symbols.decimal.clone().into_boxed_str()),
             grouping_separator: VarZeroCow::new_owned(symbols.group.clone().into_boxed_str()),
             numsys: VarZeroCow::new_owned(nsname.to_owned().into_boxed_str()),
-        }
-        .build();
+            exponential: VarZeroCow::new_owned(symbols.exponential.clone().into_boxed_str()),
+        };
 
         if let Some(n) = negative.as_ref()
             && (
@@ -80,8 +83,9 @@ impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
                 n.secondary_grouping,
             )
         {
-            return Err(DataError::custom("positive/negative groupings don't match")
-                .with_req(DecimalSymbolsV1::INFO, req));
+            return Err(
+                DataError::custom("positive/negative groupings don't match").with_req(marker, req)
+            );
         }
 
         let grouping_sizes = GroupingSizes {
@@ -92,10 +96,45 @@ impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
 
         // TODO: do something with `numbers.(min/max)_fraction_digits`
 
+        Ok((strings, grouping_sizes))
+    }
+}
+
+impl DataProvider<DecimalSymbolsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV1>, DataError> {
+        self.check_req::<DecimalSymbolsV1>(req)?;
+
+        let (strings, grouping_sizes) = self.load_decimal_symbols(req, DecimalSymbolsV1::INFO)?;
+
         Ok(DataResponse {
             metadata: Default::default(),
             payload: DataPayload::from_owned(DecimalSymbols {
-                strings,
+                strings: DecimalSymbolStrsBuilder {
+                    minus_sign_prefix: strings.minus_sign_prefix,
+                    minus_sign_suffix: strings.minus_sign_suffix,
+                    plus_sign_prefix: strings.plus_sign_prefix,
+                    plus_sign_suffix: strings.plus_sign_suffix,
+                    decimal_separator: strings.decimal_separator,
+                    grouping_separator: strings.grouping_separator,
+                    numsys: strings.numsys,
+                }
+                .build(),
+                grouping_sizes,
+            }),
+        })
+    }
+}
+
+impl DataProvider<DecimalSymbolsV2> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV2>, DataError> {
+        self.check_req::<DecimalSymbolsV2>(req)?;
+
+        let (strings, grouping_sizes) = self.load_decimal_symbols(req, DecimalSymbolsV2::INFO)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(DecimalSymbolsWithExponent {
+                strings: strings.build(),
                 grouping_sizes,
             }),
         })
@@ -108,6 +147,12 @@ impl IterableDataProviderCached<DecimalSymbolsV1> for SourceDataProvider {
     }
 }
 
+impl IterableDataProviderCached<DecimalSymbolsV2> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.iter_ids_for_numbers_with_locales()
+    }
+}
+
 #[test]
 fn test_basic() {
     use icu::locale::langid;
@@ -122,4 +167,13 @@ fn test_basic() {
         .unwrap();
     assert_eq!(ar_decimal.payload.get().decimal_separator(), "٫");
     assert_eq!(ar_decimal.payload.get().numsys(), "arab");
+
+    let ar_decimal: DataResponse<DecimalSymbolsV2> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("ar-EG").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(ar_decimal.payload.get().decimal_separator(), "٫");
+    assert_eq!(ar_decimal.payload.get().exponential(), "أس");
 }
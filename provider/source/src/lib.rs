@@ -65,6 +65,10 @@ mod personnames;
 mod plurals;
 mod properties;
 #[cfg(feature = "unstable")]
+mod range;
+#[cfg(feature = "unstable")]
+mod regioninfo;
+#[cfg(feature = "unstable")]
 mod relativetime;
 mod segmenter;
 mod time_zones;
@@ -72,6 +76,7 @@ mod time_zones;
 mod transforms;
 #[cfg(feature = "unstable")]
 mod units;
+mod version;
 
 mod cldr_cache;
 mod source;
@@ -116,6 +121,7 @@ pub struct SourceDataProvider {
 macro_rules! cb {
     ($($marker_ty:ty:$marker:ident,)+ #[unstable] $($emarker_ty:ty:$emarker:ident,)+) => {
         icu_provider::export::make_exportable_provider!(SourceDataProvider, [
+            icu_provider::version::DataVersionV1,
             $($marker_ty,)+
             $(#[cfg(feature = "unstable")] $emarker_ty,)+
         ]);
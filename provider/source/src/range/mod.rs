@@ -0,0 +1,252 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::HashSet;
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+
+use icu::experimental::dimension::provider::range::*;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_pattern::SinglePlaceholderPattern;
+use icu_provider::prelude::*;
+use zerovec::VarZeroCow;
+
+impl DataProvider<DecimalRangePatternsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalRangePatternsV1>, DataError> {
+        self.check_req::<DecimalRangePatternsV1>(req)?;
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "numbers.json")?;
+
+        let numbers = &numbers_resource.main.value.numbers;
+
+        // CLDR doesn't vary the miscellaneous patterns between the numbering systems of a
+        // locale, so only those of the default numbering system are included. The digits are
+        // localized by the `DecimalFormatter`.
+        let misc_patterns = numbers
+            .numsys_data
+            .misc_patterns
+            .get(&numbers.default_numbering_system)
+            .or_else(|| numbers.numsys_data.misc_patterns.get("latn"))
+            .ok_or_else(|| DataError::custom("Could not find the misc patterns"))?;
+
+        let range =
+            DoublePlaceholderPattern::try_from_str(&misc_patterns.range, Default::default())
+                .map_err(|e| {
+                    DataError::custom("Could not parse range pattern").with_display_context(&e)
+                })?;
+        let range_spaced = DoublePlaceholderPattern::try_from_str(
+            &spaced(&misc_patterns.range),
+            Default::default(),
+        )
+        .map_err(|e| DataError::custom("Could not parse range pattern").with_display_context(&e))?;
+        let approximately = SinglePlaceholderPattern::try_from_str(
+            &misc_patterns.approximately,
+            Default::default(),
+        )
+        .map_err(|e| {
+            DataError::custom("Could not parse approximately pattern").with_display_context(&e)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(RangePatterns {
+                range: VarZeroCow::new_owned(range),
+                range_spaced: VarZeroCow::new_owned(range_spaced),
+                approximately: VarZeroCow::new_owned(approximately),
+            }),
+        })
+    }
+}
+
+/// Adds spaces around the range separator of a range pattern, unless it already has them.
+fn spaced(range: &str) -> String {
+    let (Some(first), Some(second)) = (range.find('{'), range.rfind('{')) else {
+        return range.to_string();
+    };
+    let Some(separator_start) = range[first..].find('}').map(|i| first + i + 1) else {
+        return range.to_string();
+    };
+    let separator = &range[separator_start..second];
+    let mut result = String::from(&range[..separator_start]);
+    if !separator.starts_with(char::is_whitespace) {
+        result.push(' ');
+    }
+    result.push_str(separator);
+    if !separator.ends_with(char::is_whitespace) {
+        result.push(' ');
+    }
+    result.push_str(&range[second..]);
+    result
+}
+
+impl IterableDataProviderCached<DecimalRangePatternsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::langid;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let load = |locale| {
+        let response: DataResponse<DecimalRangePatternsV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierCow::from_locale(locale).as_borrowed(),
+                ..Default::default()
+            })
+            .unwrap();
+        response.payload
+    };
+
+    let en = load(langid!("en").into());
+    assert_writeable_eq!(en.get().range.interpolate(("3", "5")), "3–5");
+    assert_writeable_eq!(en.get().range_spaced.interpolate(("3", "5")), "3 – 5");
+    assert_writeable_eq!(en.get().approximately.interpolate(["5"]), "~5");
+
+    let ja = load(langid!("ja").into());
+    assert_writeable_eq!(ja.get().range.interpolate(("3", "5")), "3～5");
+    assert_writeable_eq!(ja.get().range_spaced.interpolate(("3", "5")), "3 ～ 5");
+    assert_writeable_eq!(ja.get().approximately.interpolate(["5"]), "約 5");
+}
+
+#[test]
+fn test_decimal_range() {
+    use icu::decimal::options::DecimalFormatterOptions;
+    use icu::experimental::dimension::range::formatter::DecimalRangeFormatter;
+    use icu::experimental::dimension::range::options::RangeIdentityFallback;
+    use icu::locale::locale;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let mut decimal_options = DecimalFormatterOptions::default();
+    decimal_options.maximum_fraction_digits = Some(0);
+    let formatter = |locale, identity_fallback: RangeIdentityFallback| {
+        DecimalRangeFormatter::try_new_unstable(
+            &provider,
+            locale,
+            decimal_options,
+            identity_fallback.into(),
+        )
+        .unwrap()
+    };
+
+    let three = "3".parse().unwrap();
+    let five = "5".parse().unwrap();
+    let five_point_zero = "5.0".parse().unwrap();
+    let four_point_nine = "4.9".parse().unwrap();
+    let minus_three = "-3".parse().unwrap();
+    let minus_five = "-5".parse().unwrap();
+
+    let en = formatter(locale!("en").into(), RangeIdentityFallback::Approximately);
+    assert_writeable_eq!(en.format(&three, &five), "3–5");
+    assert_writeable_eq!(en.format(&five, &five), "~5");
+    assert_writeable_eq!(en.format(&minus_five, &minus_three), "-5 – -3");
+    assert_writeable_eq!(en.format(&minus_three, &five), "-3 – 5");
+
+    let es = formatter(locale!("es").into(), RangeIdentityFallback::Approximately);
+    assert_writeable_eq!(es.format(&three, &five), "3-5");
+
+    let en = formatter(locale!("en").into(), RangeIdentityFallback::SingleValue);
+    assert_writeable_eq!(en.format(&four_point_nine, &five), "5");
+
+    let en = formatter(
+        locale!("en").into(),
+        RangeIdentityFallback::ApproximatelyOrSingleValue,
+    );
+    assert_writeable_eq!(en.format(&five, &five_point_zero), "5");
+    assert_writeable_eq!(en.format(&four_point_nine, &five), "~5");
+
+    let en = formatter(locale!("en").into(), RangeIdentityFallback::Range);
+    assert_writeable_eq!(en.format(&five, &five), "5–5");
+}
+
+#[test]
+fn test_currency_range() {
+    use icu::experimental::dimension::currency::CurrencyCode;
+    use icu::experimental::dimension::currency::range_formatter::CurrencyRangeFormatter;
+    use icu::locale::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let formatter = CurrencyRangeFormatter::try_new_unstable(
+        &provider,
+        locale!("en").into(),
+        Default::default(),
+        Default::default(),
+    )
+    .unwrap();
+    let usd = CurrencyCode(tinystr!(3, "USD"));
+
+    let three = "3".parse().unwrap();
+    let five = "5".parse().unwrap();
+    let almost_five = "4.999".parse().unwrap();
+    let minus_three = "-3".parse().unwrap();
+    let minus_five = "-5".parse().unwrap();
+
+    assert_writeable_eq!(
+        formatter.format_fixed_decimal(&three, &five, &usd),
+        "$3.00–5.00"
+    );
+    assert_writeable_eq!(
+        formatter.format_fixed_decimal(&almost_five, &five, &usd),
+        "~$5.00"
+    );
+    assert_writeable_eq!(
+        formatter.format_fixed_decimal(&minus_five, &minus_three, &usd),
+        "-$5.00 – -$3.00"
+    );
+}
+
+#[test]
+fn test_units_range() {
+    use icu::experimental::dimension::units::options::Width;
+    use icu::experimental::dimension::units::range_formatter::UnitsRangeFormatter;
+    use icu::locale::locale;
+    use writeable::assert_writeable_eq;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let formatter = |width: Width| {
+        UnitsRangeFormatter::try_new_unstable(
+            &provider,
+            locale!("en").into(),
+            "kilogram",
+            width.into(),
+            Default::default(),
+        )
+        .unwrap()
+    };
+
+    let zero = "0".parse().unwrap();
+    let one = "1".parse().unwrap();
+    let three = "3".parse().unwrap();
+    let five = "5".parse().unwrap();
+    let minus_five = "-5".parse().unwrap();
+
+    let short = formatter(Width::Short);
+    assert_writeable_eq!(short.format_fixed_decimal(&three, &five), "3–5 kg");
+    assert_writeable_eq!(short.format_fixed_decimal(&five, &five), "~5 kg");
+    assert_writeable_eq!(short.format_fixed_decimal(&minus_five, &three), "-5 – 3 kg");
+
+    let long = formatter(Width::Long);
+    assert_writeable_eq!(long.format_fixed_decimal(&zero, &one), "0–1 kilograms");
+    assert_writeable_eq!(long.format_fixed_decimal(&one, &one), "~1 kilogram");
+}
@@ -0,0 +1,298 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+use icu::experimental::regioninfo::provider::*;
+use icu::locale::subtags::{Language, Region, Script};
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::{ZeroMap, ZeroSlice, ZeroVec};
+
+impl SourceDataProvider {
+    fn currency_regions(
+        &self,
+    ) -> Result<&cldr_serde::currencies::supplemental::CurrencyData, DataError> {
+        Ok(&self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::currencies::supplemental::Resource>(
+                "supplemental/currencyData.json",
+            )?
+            .supplemental
+            .currency_data)
+    }
+
+    fn territory_info(
+        &self,
+    ) -> Result<&BTreeMap<Region, cldr_serde::territory_info::Territory>, DataError> {
+        Ok(&self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::territory_info::Resource>(
+                "supplemental/territoryInfo.json",
+            )?
+            .supplemental
+            .territory_info)
+    }
+}
+
+fn region_from_attributes(req: DataRequest) -> Result<Region, DataError> {
+    Region::try_from_str(req.id.marker_attributes.as_str()).map_err(|_| {
+        DataErrorKind::IdentifierNotFound
+            .into_error()
+            .with_debug_context(req.id.marker_attributes.as_str())
+    })
+}
+
+fn parse_date(date: &str) -> Result<IsoDate, DataError> {
+    let mut parts = date.split('-');
+    let mut next = || {
+        parts
+            .next()
+            .ok_or_else(|| DataError::custom("Invalid date").with_display_context(date))
+    };
+    let parse_error = |_| DataError::custom("Invalid date").with_display_context(date);
+    Ok((
+        next()?.parse().map_err(parse_error)?,
+        next()?.parse().map_err(parse_error)?,
+        next()?.parse().map_err(parse_error)?,
+    ))
+}
+
+fn parse_number<T: core::str::FromStr>(value: &str) -> Result<T, DataError> {
+    value
+        .parse()
+        .map_err(|_| DataError::custom("Invalid number").with_display_context(value))
+}
+
+impl DataProvider<RegionCurrenciesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RegionCurrenciesV1>, DataError> {
+        self.check_req::<RegionCurrenciesV1>(req)?;
+
+        let region = region_from_attributes(req)?;
+        let periods = self
+            .currency_regions()?
+            .region
+            .get(&region)
+            .ok_or_else(|| DataErrorKind::IdentifierNotFound.into_error())?;
+
+        let currencies = periods
+            .iter()
+            .flat_map(|entry| entry.iter())
+            .map(|(currency, period)| {
+                Ok(CurrencyPeriod {
+                    currency: currency.to_unvalidated(),
+                    from: period.from.as_deref().map(parse_date).transpose()?,
+                    to: period.to.as_deref().map(parse_date).transpose()?,
+                    tender: period.tender.as_deref() != Some("false"),
+                })
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(RegionCurrencies {
+                currencies: ZeroVec::alloc_from_slice(&currencies),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<RegionCurrenciesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .currency_regions()?
+            .region
+            .keys()
+            .map(|region| {
+                DataIdentifierCow::from_marker_attributes_owned(
+                    DataMarkerAttributes::from_str_or_panic(region.as_str()).to_owned(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl DataProvider<TerritoryInfoV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<TerritoryInfoV1>, DataError> {
+        self.check_req::<TerritoryInfoV1>(req)?;
+
+        let region = region_from_attributes(req)?;
+        let territory = self
+            .territory_info()?
+            .get(&region)
+            .ok_or_else(|| DataErrorKind::IdentifierNotFound.into_error())?;
+
+        let mut languages = territory
+            .language_population
+            .iter()
+            .map(|(id, population)| {
+                let (language, script) = match id.split_once('_') {
+                    Some((language, script)) => (language, Some(script)),
+                    None => (id.as_str(), None),
+                };
+                let invalid_language =
+                    |_| DataError::custom("Invalid language").with_display_context(id);
+                Ok(LanguagePopulation {
+                    language: Language::try_from_str(language).map_err(invalid_language)?,
+                    script: script
+                        .map(Script::try_from_str)
+                        .transpose()
+                        .map_err(invalid_language)?,
+                    population_percent: parse_number(&population.population_percent)?,
+                    official_status: population
+                        .official_status
+                        .as_deref()
+                        .map(|status| match status {
+                            "official" => Ok(OfficialStatus::Official),
+                            "de_facto_official" => Ok(OfficialStatus::DeFactoOfficial),
+                            "official_regional" => Ok(OfficialStatus::OfficialRegional),
+                            "official_minority" => Ok(OfficialStatus::OfficialMinority),
+                            _ => Err(DataError::custom("Invalid official status")
+                                .with_display_context(status)),
+                        })
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        // Most widely used languages first
+        languages.sort_by(|a, b| b.population_percent.total_cmp(&a.population_percent));
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(TerritoryInfo {
+                population: parse_number(&territory.population)?,
+                literacy_percent: parse_number(&territory.literacy_percent)?,
+                gdp: parse_number(&territory.gdp)?,
+                languages: ZeroVec::alloc_from_slice(&languages),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<TerritoryInfoV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .territory_info()?
+            .keys()
+            .map(|region| {
+                DataIdentifierCow::from_marker_attributes_owned(
+                    DataMarkerAttributes::from_str_or_panic(region.as_str()).to_owned(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl DataProvider<RegionContainmentV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RegionContainmentV1>, DataError> {
+        self.check_req::<RegionContainmentV1>(req)?;
+
+        let resource: &cldr_serde::territory_containment::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        let mut contains = BTreeMap::<Region, Vec<Region>>::new();
+        for (key, group) in &resource.supplemental.territory_containment {
+            // The grouping part of a group is merged into the group itself, whereas
+            // deprecated containment is dropped.
+            let key = key.strip_suffix("-status-grouping").unwrap_or(key);
+            if group.status.as_deref() == Some("deprecated") {
+                continue;
+            }
+            let Ok(region) = Region::try_from_str(key) else {
+                continue;
+            };
+            contains
+                .entry(region)
+                .or_default()
+                .extend(group.contains.iter().copied());
+        }
+
+        let mut contained_in = BTreeMap::<Region, Vec<Region>>::new();
+        for (&parent, children) in &contains {
+            for &child in children {
+                contained_in.entry(child).or_default().push(parent);
+            }
+        }
+
+        fn to_map(
+            map: BTreeMap<Region, Vec<Region>>,
+        ) -> ZeroMap<'static, UnvalidatedTinyAsciiStr<3>, ZeroSlice<Region>> {
+            let entries = map
+                .into_iter()
+                .map(|(region, regions)| {
+                    (
+                        region.to_tinystr().to_unvalidated(),
+                        ZeroVec::alloc_from_slice(&regions),
+                    )
+                })
+                .collect::<Vec<_>>();
+            entries
+                .iter()
+                .map(|(region, regions)| (region, regions.as_slice()))
+                .collect()
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(RegionContainment {
+                contains: to_map(contains),
+                contained_in: to_map(contained_in),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<RegionContainmentV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::experimental::regioninfo::RegionInfo;
+    use icu::locale::subtags::{language, region};
+    use tinystr::tinystr;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let de = RegionInfo::try_new_unstable(&provider, region!("DE")).unwrap();
+    assert_eq!(de.current_currency().unwrap().0, tinystr!(3, "EUR"));
+    assert_eq!(de.currency_at((1990, 1, 1)).unwrap().0, tinystr!(3, "DEM"));
+    assert_eq!(de.currency_at((1900, 1, 1)), None);
+    assert_eq!(de.currencies().nth(1).unwrap().to, Some((2002, 2, 28)),);
+    assert_eq!(de.languages().next().unwrap().language, language!("de"));
+    assert!(de.population().unwrap() > 80_000_000);
+    assert!(de.is_contained_in(region!("155")));
+    assert!(de.is_contained_in(region!("150")));
+    assert!(de.is_contained_in(region!("001")));
+    assert!(de.is_contained_in(region!("EU")));
+    assert!(!de.is_contained_in(region!("019")));
+
+    let in_ = RegionInfo::try_new_unstable(&provider, region!("IN")).unwrap();
+    let mut languages = in_.languages();
+    assert_eq!(languages.next().unwrap().language, language!("hi"));
+    let pa_guru = in_
+        .languages()
+        .find(|l| l.language == language!("pa"))
+        .unwrap();
+    assert_eq!(pa_guru.script, Some(icu::locale::subtags::script!("Guru")));
+    assert_eq!(
+        pa_guru.official_status,
+        Some(OfficialStatus::OfficialRegional)
+    );
+
+    let europe = RegionInfo::try_new_unstable(&provider, region!("150")).unwrap();
+    assert_eq!(europe.current_currency(), None);
+    assert_eq!(europe.population(), None);
+    assert!(europe.contained_regions().any(|r| r == region!("155")));
+    assert!(europe.contained_regions().any(|r| r == region!("EU")));
+}
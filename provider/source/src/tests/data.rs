@@ -314,6 +314,8 @@ pub fn cldr_data() -> AbstractFs {
         "cldr-core/supplemental/pluralRanges.json",
         "cldr-core/supplemental/plurals.json",
         "cldr-core/supplemental/primaryZones.json",
+        "cldr-core/supplemental/territoryContainment.json",
+        "cldr-core/supplemental/territoryInfo.json",
         "cldr-core/supplemental/unitPreferenceData.json",
         "cldr-core/supplemental/units.json",
         "cldr-core/supplemental/weekData.json",
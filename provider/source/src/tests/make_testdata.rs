@@ -192,6 +192,10 @@ impl DataExporter for ZeroCopyCheckExporter {
                         let deserialized: DataPayload<icu_provider::hello_world::HelloWorldV1> = buffer_payload.into_deserialized(icu_provider::buf::BufferFormat::Postcard1).unwrap();
                         (MeasuringAllocator::end_measure(), UpcastDataPayload::upcast(deserialized))
                     }
+                    k if k == icu_provider::version::DataVersionV1::INFO => {
+                        let deserialized: DataPayload<icu_provider::version::DataVersionV1> = buffer_payload.into_deserialized(icu_provider::buf::BufferFormat::Postcard1).unwrap();
+                        (MeasuringAllocator::end_measure(), UpcastDataPayload::upcast(deserialized))
+                    }
                     $(
                         k if k == <$marker_ty>::INFO => {
                             let deserialized: DataPayload<$marker_ty> = buffer_payload.into_deserialized(icu_provider::buf::BufferFormat::Postcard1).unwrap();
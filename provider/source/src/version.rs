@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+use icu_provider::prelude::*;
+use icu_provider::version::*;
+use std::collections::HashSet;
+
+impl DataProvider<DataVersionV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DataVersionV1>, DataError> {
+        self.check_req::<DataVersionV1>(req)?;
+
+        let cldr = match self.cldr() {
+            Ok(cldr) => cldr
+                .core()
+                .read_and_parse::<cldr_serde::likely_subtags::Resource>(
+                    "supplemental/likelySubtags.json",
+                )?
+                .supplemental
+                .version
+                .cldr_version
+                .clone(),
+            Err(e) if Self::is_missing_cldr_error(e) => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(DataVersion {
+                cldr: cldr.into(),
+                icu4x: env!("CARGO_PKG_VERSION").into(),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<DataVersionV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+#[test]
+fn test_basic() {
+    let provider = SourceDataProvider::new_testing();
+
+    let version: DataResponse<DataVersionV1> = provider.load(Default::default()).unwrap();
+    assert_eq!(version.payload.get().cldr, "48");
+    assert_eq!(version.payload.get().icu4x, env!("CARGO_PKG_VERSION"));
+}
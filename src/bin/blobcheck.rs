@@ -0,0 +1,8 @@
+fn main() {
+    let blob = std::fs::read("/tmp/bn.blob").unwrap();
+    let p = icu_provider_blob::BlobDataProvider::try_new_from_blob(blob.into()).unwrap();
+    let f = icu::decimal::DecimalFormatter::try_new_with_buffer_provider(&p, icu::locale::locale!("bn").into(), Default::default()).unwrap();
+    let mut d = icu::decimal::input::Decimal::from(1234);
+    d.multiply_pow10(-2);
+    println!("{}", f.format(&d));
+}
@@ -0,0 +1,784 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Exact arithmetic and numeric comparison on [`UnsignedDecimal`] and [`Decimal`].
+//!
+//! All operations work on the decimal digits directly, so results are exact up to the
+//! magnitude limits of the types.
+
+use core::cmp::{self, Ordering};
+use core::iter;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use smallvec::SmallVec;
+
+use crate::{
+    ArithmeticError, Decimal, Sign, SignedRoundingMode, UnsignedDecimal, UnsignedRoundingMode,
+};
+
+type Digits = SmallVec<[u8; 8]>;
+
+/// The digits of an [`UnsignedDecimal`] with magnitudes widened to `i32`, so that
+/// intermediate results can exceed the limits.
+#[derive(Copy, Clone)]
+struct Operand<'a> {
+    /// Digits without leading or trailing zeros, most significant first.
+    digits: &'a [u8],
+    /// Magnitude of `digits[0]`.
+    magnitude: i32,
+    upper_magnitude: i32,
+    lower_magnitude: i32,
+}
+
+impl<'a> Operand<'a> {
+    fn new(value: &'a UnsignedDecimal) -> Self {
+        let range = value.magnitude_range();
+        Self {
+            digits: value.digits(),
+            magnitude: value.nonzero_magnitude_start() as i32,
+            upper_magnitude: *range.end() as i32,
+            lower_magnitude: *range.start() as i32,
+        }
+    }
+
+    fn is_zero(self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// The magnitude of the least significant nonzero digit.
+    fn nonzero_end(self) -> i32 {
+        self.magnitude - self.digits.len() as i32 + 1
+    }
+
+    fn digit_at(self, magnitude: i32) -> u8 {
+        usize::try_from(self.magnitude - magnitude)
+            .ok()
+            .and_then(|i| self.digits.get(i))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Compares the absolute values of two operands.
+    fn cmp(self, other: Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Neither digit list has trailing zeros, so if the magnitudes match, the
+            // lexicographic order is the numeric order.
+            (false, false) => self
+                .magnitude
+                .cmp(&other.magnitude)
+                .then_with(|| self.digits.cmp(other.digits)),
+        }
+    }
+
+    /// The upper magnitude if this operand has leading zeros, or 0.
+    fn leading_padding(self) -> i32 {
+        if self.upper_magnitude > self.magnitude {
+            self.upper_magnitude
+        } else {
+            0
+        }
+    }
+
+    /// The magnitude range of a sum or difference of two operands.
+    ///
+    /// Leading zeros of the operands are kept, and the result has as many fractional digits
+    /// as the operand with the most fractional digits.
+    fn padding(self, other: Self) -> (i32, i32) {
+        (
+            cmp::max(self.leading_padding(), other.leading_padding()),
+            cmp::min(self.lower_magnitude, other.lower_magnitude),
+        )
+    }
+
+    /// The lowest nonzero magnitude of either operand, or 0 if both are zero.
+    fn common_nonzero_end(self, other: Self) -> i32 {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => 0,
+            (true, false) => other.nonzero_end(),
+            (false, true) => self.nonzero_end(),
+            (false, false) => cmp::min(self.nonzero_end(), other.nonzero_end()),
+        }
+    }
+
+    /// Computes `self + other`.
+    fn add(self, other: Self) -> Result<UnsignedDecimal, ArithmeticError> {
+        let low = self.common_nonzero_end(other);
+        let high = cmp::max(self.magnitude, other.magnitude);
+        let mut digits = Digits::new();
+        let mut carry = 0;
+        for m in low..=high {
+            let sum = self.digit_at(m) + other.digit_at(m) + carry;
+            digits.push(sum % 10);
+            carry = sum / 10;
+        }
+        digits.push(carry);
+        digits.reverse();
+        let (upper, lower) = self.padding(other);
+        Ok(UnsignedDecimal::try_from_digits(
+            digits,
+            high + 1,
+            upper,
+            lower,
+        )?)
+    }
+
+    /// Computes `self - other`, where `self >= other`.
+    fn sub(self, other: Self) -> Result<UnsignedDecimal, ArithmeticError> {
+        debug_assert_ne!(self.cmp(other), Ordering::Less);
+        let low = self.common_nonzero_end(other);
+        let high = cmp::max(self.magnitude, other.magnitude);
+        let mut digits = Digits::new();
+        let mut borrow = 0;
+        for m in low..=high {
+            let difference = 10 + self.digit_at(m) - other.digit_at(m) - borrow;
+            digits.push(difference % 10);
+            borrow = 1 - difference / 10;
+        }
+        debug_assert_eq!(borrow, 0);
+        digits.reverse();
+        let (upper, lower) = self.padding(other);
+        Ok(UnsignedDecimal::try_from_digits(
+            digits, high, upper, lower,
+        )?)
+    }
+
+    /// Computes `self * other`.
+    ///
+    /// The result has as many fractional digits as both operands together.
+    fn mul(self, other: Self) -> Result<UnsignedDecimal, ArithmeticError> {
+        let lower = self.lower_magnitude + other.lower_magnitude;
+        if self.is_zero() || other.is_zero() {
+            return Ok(UnsignedDecimal::try_from_digits(
+                Digits::new(),
+                0,
+                0,
+                lower,
+            )?);
+        }
+        // Schoolbook multiplication on the digits in ascending order. The digit count is
+        // bounded by the magnitude limits, so the column sums fit into a u32.
+        let mut columns: SmallVec<[u32; 16]> =
+            iter::repeat_n(0, self.digits.len() + other.digits.len()).collect();
+        for (i, a) in self.digits.iter().rev().enumerate() {
+            for (j, b) in other.digits.iter().rev().enumerate() {
+                if let Some(column) = columns.get_mut(i + j) {
+                    *column += *a as u32 * *b as u32;
+                }
+            }
+        }
+        let mut digits = Digits::new();
+        let mut carry = 0;
+        for column in columns {
+            let sum = column + carry;
+            digits.push((sum % 10) as u8);
+            carry = sum / 10;
+        }
+        debug_assert_eq!(carry, 0);
+        digits.reverse();
+        let low = self.nonzero_end() + other.nonzero_end();
+        Ok(UnsignedDecimal::try_from_digits(
+            digits,
+            low + self.digits.len() as i32 + other.digits.len() as i32 - 1,
+            0,
+            lower,
+        )?)
+    }
+
+    /// Computes `self / other` truncated at magnitude `position - 1`.
+    ///
+    /// If the division is inexact, a nonzero digit is appended at magnitude `position - 2`,
+    /// so that rounding the result at `position` gives the correctly rounded quotient.
+    fn div(self, other: Self, position: i16) -> Result<UnsignedDecimal, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        if self.is_zero() {
+            return Ok(UnsignedDecimal::default());
+        }
+        let last = position as i32 - 1;
+        // With self = A * 10^a and other = B * 10^b for integers A and B, the quotient
+        // truncated at `last` is floor(A * 10^shift / B) * 10^last.
+        let shift = self.nonzero_end() - other.nonzero_end() - last;
+        let mut quotient = Digits::new();
+        let remainder_is_zero = if shift < 0 && -shift >= self.digits.len() as i32 {
+            // The divisor B * 10^-shift has more digits than A
+            false
+        } else {
+            let divisor: Digits = other
+                .digits
+                .iter()
+                .copied()
+                .chain(iter::repeat_n(0, cmp::max(-shift, 0) as usize))
+                .collect();
+            let mut remainder = Digits::new();
+            let dividend = self
+                .digits
+                .iter()
+                .copied()
+                .chain(iter::repeat_n(0, cmp::max(shift, 0) as usize));
+            for digit in dividend {
+                if !remainder.is_empty() || digit != 0 {
+                    remainder.push(digit);
+                }
+                let mut q = 0;
+                while cmp_integers(&remainder, &divisor) != Ordering::Less {
+                    sub_integers(&mut remainder, &divisor);
+                    q += 1;
+                }
+                quotient.push(q);
+            }
+            remainder.is_empty()
+        };
+        let magnitude = last + quotient.len() as i32 - 1;
+        if !remainder_is_zero {
+            quotient.push(1);
+        }
+        Ok(UnsignedDecimal::try_from_digits(quotient, magnitude, 0, 0)?)
+    }
+}
+
+/// Compares two integers given as digits without leading zeros.
+fn cmp_integers(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Subtracts `b` from `a`, where both are integers given as digits without leading zeros
+/// and `a >= b`.
+fn sub_integers(a: &mut Digits, b: &[u8]) {
+    let mut borrow = 0;
+    let mut b = b.iter().rev();
+    for digit in a.iter_mut().rev() {
+        let difference = 10 + *digit - b.next().copied().unwrap_or(0) - borrow;
+        *digit = difference % 10;
+        borrow = 1 - difference / 10;
+    }
+    debug_assert_eq!(borrow, 0);
+    let leading_zeros = a.iter().take_while(|&&d| d == 0).count();
+    a.drain(..leading_zeros);
+}
+
+impl UnsignedDecimal {
+    /// Compares the numeric values of two numbers, ignoring leading and trailing zeros.
+    ///
+    /// Note that [`PartialEq`] also compares the zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("1.50").unwrap();
+    /// let b = UnsignedDecimal::from_str("01.5").unwrap();
+    /// let c = UnsignedDecimal::from_str("1.499").unwrap();
+    ///
+    /// assert_ne!(a, b);
+    /// assert_eq!(a.numeric_cmp(&b), Ordering::Equal);
+    /// assert_eq!(a.numeric_cmp(&c), Ordering::Greater);
+    /// ```
+    pub fn numeric_cmp(&self, other: &Self) -> Ordering {
+        Operand::new(self).cmp(Operand::new(other))
+    }
+
+    /// Adds two numbers.
+    ///
+    /// The result is padded with zeros to the magnitude range of both operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("9.95").unwrap();
+    /// let b = UnsignedDecimal::from_str("0.050").unwrap();
+    ///
+    /// assert_eq!("10.000", a.try_add(&b).unwrap().to_string());
+    /// ```
+    pub fn try_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        Operand::new(self).add(Operand::new(other))
+    }
+
+    /// Subtracts a number from this number.
+    ///
+    /// Returns [`ArithmeticError::Negative`] if `other` is larger than this number; use
+    /// [`Decimal::try_sub`] to get negative results.
+    ///
+    /// The result is padded with zeros to the magnitude range of both operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{ArithmeticError, UnsignedDecimal};
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("10.5").unwrap();
+    /// let b = UnsignedDecimal::from_str("0.75").unwrap();
+    ///
+    /// assert_eq!("9.75", a.try_sub(&b).unwrap().to_string());
+    /// assert_eq!(Err(ArithmeticError::Negative), b.try_sub(&a));
+    /// ```
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let (a, b) = (Operand::new(self), Operand::new(other));
+        if a.cmp(b) == Ordering::Less {
+            return Err(ArithmeticError::Negative);
+        }
+        a.sub(b)
+    }
+
+    /// Multiplies two numbers.
+    ///
+    /// The number of fractional digits of the result is the sum of the numbers of fractional
+    /// digits of the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("1.5").unwrap();
+    /// let b = UnsignedDecimal::from_str("2.50").unwrap();
+    ///
+    /// assert_eq!("3.750", a.try_mul(&b).unwrap().to_string());
+    /// ```
+    pub fn try_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        Operand::new(self).mul(Operand::new(other))
+    }
+
+    /// Divides this number by `divisor`, rounding the quotient at a particular digit position
+    /// using the specified rounding mode.
+    ///
+    /// Returns [`ArithmeticError::DivisionByZero`] if `divisor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{UnsignedDecimal, UnsignedRoundingMode};
+    ///
+    /// let a = UnsignedDecimal::from(2u32);
+    /// let b = UnsignedDecimal::from(3u32);
+    ///
+    /// assert_eq!(
+    ///     "0.666",
+    ///     a.try_div_with_mode(&b, -3, UnsignedRoundingMode::Trunc)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "0.667",
+    ///     a.try_div_with_mode(&b, -3, UnsignedRoundingMode::HalfEven)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn try_div_with_mode(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: UnsignedRoundingMode,
+    ) -> Result<Self, ArithmeticError> {
+        Ok(Operand::new(self)
+            .div(Operand::new(divisor), position)?
+            .rounded_with_mode(position, mode))
+    }
+}
+
+/// Splits a [`Decimal`] into its absolute value and whether it is negative.
+fn signed_operand(value: &Decimal) -> (Operand<'_>, bool) {
+    (Operand::new(&value.absolute), value.sign == Sign::Negative)
+}
+
+/// Attaches a sign to an absolute value. Zero is never negative.
+fn with_sign(absolute: UnsignedDecimal, negative: bool) -> Decimal {
+    let sign = if negative && !absolute.is_zero() {
+        Sign::Negative
+    } else {
+        Sign::None
+    };
+    Decimal::new(sign, absolute)
+}
+
+/// Computes the sum of two signed operands.
+fn add_signed(
+    (a, a_negative): (Operand<'_>, bool),
+    (b, b_negative): (Operand<'_>, bool),
+) -> Result<Decimal, ArithmeticError> {
+    if a_negative == b_negative {
+        return Ok(with_sign(a.add(b)?, a_negative));
+    }
+    match a.cmp(b) {
+        Ordering::Less => Ok(with_sign(b.sub(a)?, b_negative)),
+        _ => Ok(with_sign(a.sub(b)?, a_negative)),
+    }
+}
+
+impl Decimal {
+    /// Compares the numeric values of two numbers, ignoring leading and trailing zeros.
+    ///
+    /// Positive and negative zero compare equal. Note that [`PartialEq`] also compares the
+    /// zeros and the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-1.50").unwrap();
+    /// let b = Decimal::from_str("-1.5").unwrap();
+    /// let c = Decimal::from_str("-0.5").unwrap();
+    ///
+    /// assert_eq!(a.numeric_cmp(&b), Ordering::Equal);
+    /// assert_eq!(a.numeric_cmp(&c), Ordering::Less);
+    /// assert_eq!(
+    ///     Decimal::from_str("-0").unwrap().numeric_cmp(&Decimal::from(0)),
+    ///     Ordering::Equal
+    /// );
+    /// ```
+    pub fn numeric_cmp(&self, other: &Self) -> Ordering {
+        let (a, a_negative) = signed_operand(self);
+        let (b, b_negative) = signed_operand(other);
+        match (a_negative && !a.is_zero(), b_negative && !b.is_zero()) {
+            (false, false) => a.cmp(b),
+            (true, true) => b.cmp(a),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+
+    /// Adds two numbers.
+    ///
+    /// The result is padded with zeros to the magnitude range of both operands. It is either
+    /// negative or has no sign; see [`Decimal::apply_sign_display`] for displaying signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-2.5").unwrap();
+    /// let b = Decimal::from_str("1.25").unwrap();
+    ///
+    /// assert_eq!("-1.25", a.try_add(&b).unwrap().to_string());
+    /// ```
+    pub fn try_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        add_signed(signed_operand(self), signed_operand(other))
+    }
+
+    /// Subtracts a number from this number.
+    ///
+    /// The result is padded with zeros to the magnitude range of both operands. It is either
+    /// negative or has no sign; see [`Decimal::apply_sign_display`] for displaying signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("0.75").unwrap();
+    /// let b = Decimal::from_str("10.5").unwrap();
+    ///
+    /// assert_eq!("-9.75", a.try_sub(&b).unwrap().to_string());
+    /// ```
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let (b, b_negative) = signed_operand(other);
+        add_signed(signed_operand(self), (b, !b_negative))
+    }
+
+    /// Multiplies two numbers.
+    ///
+    /// The number of fractional digits of the result is the sum of the numbers of fractional
+    /// digits of the operands. The result is either negative or has no sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-1.5").unwrap();
+    /// let b = Decimal::from_str("2.50").unwrap();
+    ///
+    /// assert_eq!("-3.750", a.try_mul(&b).unwrap().to_string());
+    /// ```
+    pub fn try_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let (a, a_negative) = signed_operand(self);
+        let (b, b_negative) = signed_operand(other);
+        Ok(with_sign(a.mul(b)?, a_negative != b_negative))
+    }
+
+    /// Divides this number by `divisor`, rounding the quotient at a particular digit position
+    /// using the specified rounding mode.
+    ///
+    /// Returns [`ArithmeticError::DivisionByZero`] if `divisor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Decimal, SignedRoundingMode};
+    ///
+    /// let a = Decimal::from(-7);
+    /// let b = Decimal::from(2);
+    ///
+    /// assert_eq!(
+    ///     "-4",
+    ///     a.try_div_with_mode(&b, 0, SignedRoundingMode::Floor)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "-3",
+    ///     a.try_div_with_mode(&b, 0, SignedRoundingMode::Ceil)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "-3.50",
+    ///     a.try_div_with_mode(&b, -2, SignedRoundingMode::Ceil)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn try_div_with_mode(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: SignedRoundingMode,
+    ) -> Result<Self, ArithmeticError> {
+        let (a, a_negative) = signed_operand(self);
+        let (b, b_negative) = signed_operand(divisor);
+        let quotient = with_sign(a.div(b, position)?, a_negative != b_negative)
+            .rounded_with_mode(position, mode);
+        // Rounding towards zero can produce a negative zero
+        Ok(with_sign(
+            quotient.absolute,
+            quotient.sign == Sign::Negative,
+        ))
+    }
+}
+
+macro_rules! impl_arithmetic_op {
+    ($type:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $try_fn:ident) => {
+        /// # Panics
+        ///
+        /// Panics if the result exceeds the limits. Use the
+        #[doc = concat!("`", stringify!($try_fn), "` method to handle this.")]
+        impl $trait<&$type> for &$type {
+            type Output = $type;
+            fn $fn(self, rhs: &$type) -> $type {
+                match self.$try_fn(rhs) {
+                    Ok(result) => result,
+                    Err(e) => panic!(concat!("`", stringify!($fn), "` failed: {:?}"), e),
+                }
+            }
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the result exceeds the limits. Use the
+        #[doc = concat!("`", stringify!($try_fn), "` method to handle this.")]
+        impl $trait for $type {
+            type Output = $type;
+            fn $fn(self, rhs: $type) -> $type {
+                (&self).$fn(&rhs)
+            }
+        }
+
+        impl $assign_trait<&$type> for $type {
+            fn $assign_fn(&mut self, rhs: &$type) {
+                *self = (&*self).$fn(rhs);
+            }
+        }
+
+        impl $assign_trait for $type {
+            fn $assign_fn(&mut self, rhs: $type) {
+                *self = (&*self).$fn(&rhs);
+            }
+        }
+    };
+}
+
+impl_arithmetic_op!(UnsignedDecimal, Add, add, AddAssign, add_assign, try_add);
+impl_arithmetic_op!(UnsignedDecimal, Mul, mul, MulAssign, mul_assign, try_mul);
+impl_arithmetic_op!(Decimal, Add, add, AddAssign, add_assign, try_add);
+impl_arithmetic_op!(Decimal, Sub, sub, SubAssign, sub_assign, try_sub);
+impl_arithmetic_op!(Decimal, Mul, mul, MulAssign, mul_assign, try_mul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let cases = [
+            ("0", "0", "0", "0"),
+            ("1", "2", "3", "-1"),
+            ("999", "1", "1000", "998"),
+            ("0.001", "999.999", "1000.000", "-999.998"),
+            ("-5", "3", "-2", "-8"),
+            ("-5", "-3", "-8", "-2"),
+            ("1.5", "-1.50", "0.00", "3.00"),
+            ("00.10", "0.2", "00.30", "-00.10"),
+            (
+                "123456789012345678901234567890",
+                "1",
+                "123456789012345678901234567891",
+                "123456789012345678901234567889",
+            ),
+            ("1000", "0.0001", "1000.0001", "999.9999"),
+        ];
+        for (a, b, sum, difference) in cases {
+            assert_eq!(
+                dec(a).try_add(&dec(b)).unwrap().to_string(),
+                sum,
+                "{a} + {b}"
+            );
+            assert_eq!(
+                dec(b).try_add(&dec(a)).unwrap().to_string(),
+                sum,
+                "{b} + {a}"
+            );
+            assert_eq!(
+                dec(a).try_sub(&dec(b)).unwrap().to_string(),
+                difference,
+                "{a} - {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        let cases = [
+            ("0", "5", "0"),
+            ("0.0", "-5.5", "0.00"),
+            ("1", "1", "1"),
+            ("-12", "12", "-144"),
+            ("-0.5", "-0.5", "0.25"),
+            ("99999999999", "99999999999", "9999999999800000000001"),
+            ("1000", "0.001", "1.000"),
+            ("2.50", "4", "10.00"),
+        ];
+        for (a, b, product) in cases {
+            assert_eq!(
+                dec(a).try_mul(&dec(b)).unwrap().to_string(),
+                product,
+                "{a} * {b}"
+            );
+            assert_eq!(
+                dec(b).try_mul(&dec(a)).unwrap().to_string(),
+                product,
+                "{b} * {a}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_div() {
+        use SignedRoundingMode::*;
+        use UnsignedRoundingMode::*;
+        let cases = [
+            ("1", "4", -3, Unsigned(HalfEven), "0.250"),
+            ("1", "8", -2, Unsigned(HalfEven), "0.12"),
+            ("3", "8", -2, Unsigned(HalfEven), "0.38"),
+            ("1", "8", -2, Unsigned(HalfTrunc), "0.12"),
+            ("1", "8", -2, Unsigned(HalfExpand), "0.13"),
+            ("10001", "80000", -2, Unsigned(HalfTrunc), "0.13"),
+            ("1", "3", 0, Unsigned(Expand), "1"),
+            ("1", "3", 0, Unsigned(Trunc), "0"),
+            ("-1", "3", 0, Ceil, "0"),
+            ("-1", "3", -1, Unsigned(Trunc), "-0.3"),
+            ("-1", "30", -1, Unsigned(Trunc), "0.0"),
+            ("-1", "3", 0, Floor, "-1"),
+            ("-5", "2", 0, HalfCeil, "-2"),
+            ("-5", "2", 0, HalfFloor, "-3"),
+            ("1000", "0.001", 0, Unsigned(Trunc), "1000000"),
+            ("123456", "1", 3, Unsigned(Trunc), "123000"),
+            ("1", "1000000000", -2, Unsigned(Expand), "0.01"),
+            ("1", "1000000000", -2, Unsigned(Trunc), "0.00"),
+            ("22", "7", -10, Unsigned(HalfEven), "3.1428571429"),
+            ("0", "7", -1, Unsigned(HalfEven), "0.0"),
+        ];
+        for (a, b, position, mode, quotient) in cases {
+            assert_eq!(
+                dec(a)
+                    .try_div_with_mode(&dec(b), position, mode)
+                    .unwrap()
+                    .to_string(),
+                quotient,
+                "{a} / {b} at {position} with {mode:?}"
+            );
+        }
+        assert_eq!(
+            dec("1").try_div_with_mode(&dec("0.00"), 0, Unsigned(HalfEven)),
+            Err(ArithmeticError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_cmp() {
+        let sorted = [
+            "-100", "-1.5", "-1", "-0.001", "0", "0.001", "0.01", "1", "1.5", "100",
+        ];
+        for (i, a) in sorted.iter().enumerate() {
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(dec(a).numeric_cmp(&dec(b)), i.cmp(&j), "{a} <=> {b}");
+            }
+        }
+        assert_eq!(dec("-0.0").numeric_cmp(&dec("00")), Ordering::Equal);
+        assert_eq!(dec("1.500").numeric_cmp(&dec("001.5")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let a = UnsignedDecimal::from(5u32);
+        let b = UnsignedDecimal::from(7u32);
+        assert_eq!((&a + &b).to_string(), "12");
+        assert_eq!((a.clone() * b.clone()).to_string(), "35");
+        assert_eq!(a.try_sub(&b), Err(ArithmeticError::Negative));
+        assert_eq!(b.try_sub(&a).unwrap().to_string(), "2");
+        assert_eq!(a.try_sub(&a).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut x = dec("1.5");
+        x += dec("2");
+        x -= &dec("5");
+        x *= dec("-2");
+        assert_eq!(x.to_string(), "3.0");
+        assert_eq!((dec("1") - dec("3")).to_string(), "-2");
+    }
+
+    #[test]
+    fn test_limits() {
+        let max = UnsignedDecimal::from(9u32).multiplied_pow10(i16::MAX);
+        assert_eq!(max.try_add(&max), Err(ArithmeticError::Limit));
+        let min = UnsignedDecimal::from(1u32).multiplied_pow10(i16::MIN);
+        assert_eq!(min.try_mul(&min), Err(ArithmeticError::Limit));
+        assert_eq!(
+            UnsignedDecimal::ONE.try_div_with_mode(&max, -5, UnsignedRoundingMode::HalfEven),
+            Ok(UnsignedDecimal::from(0u32).padded_end(-5))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`add` failed: Limit")]
+    fn test_operator_overflow() {
+        let max = UnsignedDecimal::from(9u32).multiplied_pow10(i16::MAX);
+        let _ = &max + &max;
+    }
+}
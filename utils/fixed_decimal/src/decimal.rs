@@ -10,10 +10,10 @@ use core::fmt;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
-use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
-use crate::{ParseError, RoundingIncrement, UnsignedRoundingMode};
+use crate::FloatPrecision;
+use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
+use crate::{LimitError, ParseError, RoundingIncrement, UnsignedRoundingMode};
 
 // UnsignedDecimal assumes usize (digits.len()) is at least as big as a u16
 #[cfg(not(any(
@@ -510,6 +510,48 @@ impl UnsignedDecimal {
         self.check_invariants();
     }
 
+    /// Gets the digits of this number, most significant first, without leading or trailing zeros.
+    pub(crate) fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Creates a [`UnsignedDecimal`] from digits and the magnitude of the most significant
+    /// digit, padded with zeros to at least the given upper and lower magnitudes.
+    ///
+    /// Leading and trailing zeros in `digits` are removed. Returns an error if the digits or
+    /// the padding exceed the limits.
+    pub(crate) fn try_from_digits(
+        mut digits: SmallVec<[u8; 8]>,
+        mut magnitude: i32,
+        upper_magnitude: i32,
+        lower_magnitude: i32,
+    ) -> Result<Self, LimitError> {
+        let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+        digits.drain(..leading_zeros);
+        magnitude -= leading_zeros as i32;
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            magnitude = 0;
+        }
+        let nonzero_end = magnitude - (digits.len() as i32 - 1).max(0);
+        let upper_magnitude = cmp::max(cmp::max(upper_magnitude, magnitude), 0);
+        let lower_magnitude = cmp::min(cmp::min(lower_magnitude, nonzero_end), 0);
+        if upper_magnitude > i16::MAX as i32 || lower_magnitude < i16::MIN as i32 {
+            return Err(LimitError);
+        }
+        let result = Self {
+            digits,
+            magnitude: magnitude as i16,
+            upper_magnitude: upper_magnitude as i16,
+            lower_magnitude: lower_magnitude as i16,
+        };
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
     /// Shift the digits of this number by a power of 10.
     ///
     /// Leading or trailing zeros may be added to keep the digit at magnitude 0 (the last digit
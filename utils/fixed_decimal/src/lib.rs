@@ -49,6 +49,7 @@
 //!
 //! [`ICU4X`]: ../icu/index.html
 
+mod arithmetic;
 mod compact;
 mod decimal;
 mod integer;
@@ -130,6 +131,29 @@ pub enum ParseError {
 
 impl core::error::Error for ParseError {}
 
+/// An error returned by arithmetic operations on [`UnsignedDecimal`] and [`Decimal`].
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// See [`LimitError`].
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+    /// The divisor of a division is zero.
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
+    /// The result of a subtraction of [`UnsignedDecimal`]s is negative.
+    #[displaydoc("Negative result")]
+    Negative,
+}
+
+impl From<LimitError> for ArithmeticError {
+    fn from(_: LimitError) -> Self {
+        Self::Limit
+    }
+}
+
+impl core::error::Error for ArithmeticError {}
+
 // TODO(#5065): implement these while `WithCompactExponent` and `WithScientificExponent` are implemented.
 // pub type FixedDecimalOrInfinity = WithInfinity<UnsignedDecimal>;
 // pub type DecimalOrInfinity = Signed<FixedDecimalOrInfinity>;
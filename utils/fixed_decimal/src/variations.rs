@@ -24,7 +24,7 @@ pub enum Sign {
 ///
 /// **The primary definition of this type is in the [`fixed_decimal`](https://docs.rs/fixed_decimal) crate. Other ICU4X crates re-export it for convenience.**
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
 pub enum SignDisplay {
     /// Render the sign according to locale preferences. In most cases, this means a minus sign
     /// will be shown on negative numbers, and no sign will be shown on positive numbers.
@@ -22,6 +22,8 @@ fn main() {
     );
     println!("first_day: {:?}", icu_host_info::first_day_of_week());
     println!("collation: {:?}", icu_host_info::collation());
+    #[cfg(feature = "time")]
+    println!("time_zone: {:?}", icu_host_info::time_zone());
     println!("-----");
     println!(
         "unicode_extensions: {:?}",
@@ -32,4 +34,6 @@ fn main() {
         "datetimeformatter_preferences: {:#?}",
         icu_host_info::datetime_preferences()
     );
+    println!("-----");
+    println!("host_preferences: {:#?}", icu_host_info::host_preferences());
 }
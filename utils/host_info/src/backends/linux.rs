@@ -2,44 +2,30 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use std::path::PathBuf;
+
 use crate::{
-    backends::{HostInfoBackend, RawHostInfoBackend},
+    backends::{
+        shared::{
+            posix::{raw_locale_categories, LocaleCategory},
+            time_zone::TimeZoneConfig,
+        },
+        HostInfoBackend, RawHostInfoBackend,
+    },
     error::HostInfoError,
-    locale::PosixLocale,
+    locale::{GlibcLocaleDefinition, PosixLocale},
+};
+use icu_locale_core::{
+    preferences::extensions::unicode::keywords::{FirstDay, HourCycle, MeasurementSystem},
+    Locale,
 };
-use icu_locale_core::{preferences::extensions::unicode::keywords::HourCycle, Locale};
+
+/// The default location of glibc locale definition files.
+const GLIBC_LOCALES_DIR: &str = "/usr/share/i18n/locales";
 
 pub struct LinuxHostInfoBackend;
 
 impl HostInfoBackend for LinuxHostInfoBackend {
-    #[cfg(feature = "datetime")]
-    fn datetime_preferences() -> Result<icu_datetime::DateTimeFormatterPreferences, HostInfoError> {
-        use crate::backends::shared::posix::{raw_locale_categories, LocaleCategory};
-
-        let mut categories = raw_locale_categories()?;
-
-        let mut locale = Locale::UNKNOWN;
-        if let Some(lc_time) = categories.remove(&LocaleCategory::Time) {
-            if let Ok(loc) = PosixLocale::try_from_str(&lc_time) {
-                if let Ok(loc) = Locale::try_from(loc) {
-                    locale = loc;
-                }
-            }
-        } else if let Some(lc_all) = categories.remove(&LocaleCategory::All) {
-            if let Ok(loc) = PosixLocale::try_from_str(&lc_all) {
-                if let Ok(loc) = Locale::try_from(loc) {
-                    locale = loc;
-                }
-            }
-        }
-
-        let mut result = icu_datetime::DateTimeFormatterPreferences::from(locale);
-        result.numbering_system = None;
-        result.hour_cycle = Self::hour_cycle()?;
-        result.calendar_algorithm = Self::calendar()?;
-        Ok(result)
-    }
-
     fn requested_locales() -> Result<Vec<Locale>, HostInfoError> {
         Ok(Self::raw_requested_locales()?
             .into_iter()
@@ -51,12 +37,32 @@ impl HostInfoBackend for LinuxHostInfoBackend {
             .collect())
     }
 
+    fn time_locale() -> Result<Option<Locale>, HostInfoError> {
+        category_locale(LocaleCategory::Time)
+    }
+
+    fn numeric_locale() -> Result<Option<Locale>, HostInfoError> {
+        category_locale(LocaleCategory::Number)
+    }
+
+    fn measurement_locale() -> Result<Option<Locale>, HostInfoError> {
+        category_locale(LocaleCategory::Measurement)
+    }
+
     fn hour_cycle() -> Result<Option<HourCycle>, HostInfoError> {
         #[cfg(icu4x_gio_available)]
         if let Some(hc) = gnome_clock_format_hc() {
             return Ok(Some(hc));
         }
-        Ok(None)
+        Ok(glibc_definition(LocaleCategory::Time)?.and_then(|def| def.hour_cycle()))
+    }
+
+    fn first_day_of_week() -> Result<Option<FirstDay>, HostInfoError> {
+        Ok(glibc_definition(LocaleCategory::Time)?.and_then(|def| def.first_day()))
+    }
+
+    fn measurement_system() -> Result<Option<MeasurementSystem>, HostInfoError> {
+        Ok(glibc_definition(LocaleCategory::Measurement)?.and_then(|def| def.measurement_system()))
     }
 }
 
@@ -85,6 +91,57 @@ impl RawHostInfoBackend for LinuxHostInfoBackend {
 
         Ok(vec![])
     }
+
+    /// Resolves the `TZ` environment variable or `/etc/localtime`.
+    fn raw_time_zone() -> Result<Option<String>, HostInfoError> {
+        Ok(TimeZoneConfig::from_host().resolve())
+    }
+}
+
+/// Retrieves the raw locale set for `category`, falling back to `LC_ALL`.
+fn raw_category_locale(category: LocaleCategory) -> Result<Option<String>, HostInfoError> {
+    let mut categories = raw_locale_categories()?;
+    Ok(categories
+        .remove(&category)
+        .or_else(|| categories.remove(&LocaleCategory::All)))
+}
+
+fn category_locale(category: LocaleCategory) -> Result<Option<Locale>, HostInfoError> {
+    Ok(raw_category_locale(category)?.and_then(|raw| {
+        PosixLocale::try_from_str(&raw)
+            .ok()
+            .and_then(|posix_locale| Locale::try_from(posix_locale).ok())
+    }))
+}
+
+/// Loads the glibc locale definition of `category` for the locale set for that category.
+///
+/// Definitions are looked up in the directories of `I18NPATH` (as `localedef` does), followed
+/// by the default location.
+fn glibc_definition(
+    category: LocaleCategory,
+) -> Result<Option<GlibcLocaleDefinition>, HostInfoError> {
+    let Some(raw) = raw_category_locale(category)? else {
+        return Ok(None);
+    };
+    let Ok(posix_locale) = PosixLocale::try_from_str(&raw) else {
+        return Ok(None);
+    };
+
+    let mut search_path: Vec<PathBuf> = std::env::var_os("I18NPATH")
+        .map(|paths| {
+            std::env::split_paths(&paths)
+                .map(|path| path.join("locales"))
+                .collect()
+        })
+        .unwrap_or_default();
+    search_path.push(PathBuf::from(GLIBC_LOCALES_DIR));
+
+    Ok(GlibcLocaleDefinition::try_load_for_locale(
+        &search_path,
+        &posix_locale,
+        category.to_env_var_name(),
+    ))
 }
 
 #[cfg(icu4x_gio_available)]
@@ -33,7 +33,7 @@ use std::os::raw::c_char;
 use std::{ffi::CStr, str::FromStr};
 
 use crate::{
-    backends::{HostInfoBackend, RawHostInfoBackend},
+    backends::{shared::time_zone::TimeZoneConfig, HostInfoBackend, RawHostInfoBackend},
     error::HostInfoError,
 };
 
@@ -369,6 +369,12 @@ impl RawHostInfoBackend for MacOSHostInfoBackend {
 
         Ok(None)
     }
+
+    /// Resolves the `TZ` environment variable or `/etc/localtime`, which links into
+    /// `/var/db/timezone/zoneinfo`.
+    fn raw_time_zone() -> Result<Option<String>, HostInfoError> {
+        Ok(TimeZoneConfig::from_host().resolve())
+    }
 }
 
 /// RAII wrapper for CFLocaleRef to ensure proper cleanup
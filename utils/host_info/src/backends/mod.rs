@@ -21,7 +21,7 @@
 use std::str::FromStr;
 
 use icu_locale_core::{
-    extensions::unicode::{self, key, Unicode, Value},
+    extensions::unicode::{self, Unicode},
     preferences::extensions::unicode::keywords::{
         CalendarAlgorithm, CollationType, FirstDay, HourCycle, MeasurementSystem,
         MeasurementUnitOverride,
@@ -30,7 +30,7 @@ use icu_locale_core::{
     Locale,
 };
 
-use crate::error::HostInfoError;
+use crate::{error::HostInfoError, HostPreferences};
 
 #[cfg(any(
     target_os = "android",
@@ -76,51 +76,53 @@ pub trait HostInfoBackend: RawHostInfoBackend {
     /// The implementation should attempt to collect all relevant regional preferences available in the given
     /// host environment into a unicode extensions bag.
     fn unicode_extensions() -> Result<Unicode, HostInfoError> {
-        let mut result = Unicode::new();
-        if let Some(calendar) = Self::calendar()? {
-            result.keywords.set(key!("ca"), calendar.into());
-        }
-        if let Some(hc) = Self::hour_cycle()? {
-            result.keywords.set(key!("hc"), hc.into());
-        }
-        if let Some(ms) = Self::measurement_system()? {
-            result.keywords.set(key!("ms"), ms.into());
-        }
-        if let Some(mu) = Self::measurement_unit_override()? {
-            result.keywords.set(key!("mu"), mu.into());
-        }
-        if let Some(fw) = Self::first_day_of_week()? {
-            result.keywords.set(key!("fw"), fw.into());
-        }
-        if let Some((_lang, co)) = Self::collation()? {
-            result.keywords.set(key!("co"), co.into());
-        }
-        if let Some(rg) = Self::region()? {
-            let mut rg_str = rg.to_string();
-            rg_str.push_str("zzzz");
-            if let Ok(value) = Value::try_from_str(&rg_str) {
-                result.keywords.set(key!("rg"), value);
-            }
-        }
-        Ok(result)
+        let prefs = HostPreferences {
+            calendar: Self::calendar()?,
+            region: Self::region()?,
+            hour_cycle: Self::hour_cycle()?,
+            measurement_system: Self::measurement_system()?,
+            measurement_unit_override: Self::measurement_unit_override()?,
+            first_day_of_week: Self::first_day_of_week()?,
+            collation: Self::collation()?,
+            #[cfg(feature = "time")]
+            time_zone: Self::time_zone()?,
+            ..Default::default()
+        };
+        Ok(prefs.unicode_extensions())
+    }
+
+    /// The implementation should attempt to collect all regional preferences available in the given
+    /// host environment into a `HostPreferences` struct.
+    fn host_preferences() -> Result<HostPreferences, HostInfoError> {
+        Ok(HostPreferences {
+            requested_locales: Self::requested_locales()?,
+            time_locale: Self::time_locale()?,
+            numeric_locale: Self::numeric_locale()?,
+            measurement_locale: Self::measurement_locale()?,
+            calendar: Self::calendar()?,
+            region: Self::region()?,
+            hour_cycle: Self::hour_cycle()?,
+            measurement_system: Self::measurement_system()?,
+            measurement_unit_override: Self::measurement_unit_override()?,
+            first_day_of_week: Self::first_day_of_week()?,
+            collation: Self::collation()?,
+            #[cfg(feature = "time")]
+            time_zone: Self::time_zone()?,
+        })
     }
 
     /// The implementation should attempt to retrieve date/time related regional preferences and collect
     /// them into `DateTimeFormatterPreferences` bag.
     #[cfg(feature = "datetime")]
     fn datetime_preferences() -> Result<icu_datetime::DateTimeFormatterPreferences, HostInfoError> {
-        use icu_locale_core::Locale;
-
-        let requested_locales = Self::requested_locales()?;
-        let requested_locale = requested_locales
-            .first()
-            .cloned()
-            .unwrap_or(Locale::UNKNOWN);
-        let mut result = icu_datetime::DateTimeFormatterPreferences::from(requested_locale);
-        result.numbering_system = None;
-        result.hour_cycle = Self::hour_cycle()?;
-        result.calendar_algorithm = Self::calendar()?;
-        Ok(result)
+        Ok((&Self::host_preferences()?).into())
+    }
+
+    /// The implementation should attempt to retrieve number related regional preferences and collect
+    /// them into `DecimalFormatterPreferences` bag.
+    #[cfg(feature = "decimal")]
+    fn decimal_preferences() -> Result<icu_decimal::DecimalFormatterPreferences, HostInfoError> {
+        Ok((&Self::host_preferences()?).into())
     }
 
     /// The implementation should attempt to retrieve requested locales set by the user in the host system.
@@ -131,6 +133,33 @@ pub trait HostInfoBackend: RawHostInfoBackend {
             .collect())
     }
 
+    /// The implementation should attempt to retrieve the locale set by the user for formatting dates and
+    /// times, if the host system allows setting it separately from the requested locales.
+    fn time_locale() -> Result<Option<Locale>, HostInfoError> {
+        Ok(None)
+    }
+
+    /// The implementation should attempt to retrieve the locale set by the user for formatting numbers,
+    /// if the host system allows setting it separately from the requested locales.
+    fn numeric_locale() -> Result<Option<Locale>, HostInfoError> {
+        Ok(None)
+    }
+
+    /// The implementation should attempt to retrieve the locale set by the user for measurement units,
+    /// if the host system allows setting it separately from the requested locales.
+    fn measurement_locale() -> Result<Option<Locale>, HostInfoError> {
+        Ok(None)
+    }
+
+    /// The implementation should attempt to retrieve the time zone set in the host system.
+    #[cfg(feature = "time")]
+    fn time_zone() -> Result<Option<icu_time::TimeZone>, HostInfoError> {
+        let parser = icu_time::zone::iana::IanaParser::new();
+        Ok(Self::raw_time_zone()?
+            .map(|raw| parser.parse(&raw))
+            .filter(|tz| !tz.is_unknown()))
+    }
+
     /// The implementation should attempt to retrieve calendar set by the user in the host system.
     fn calendar() -> Result<Option<CalendarAlgorithm>, HostInfoError> {
         Ok(Self::raw_calendar()?
@@ -247,6 +276,11 @@ pub trait RawHostInfoBackend {
         Ok(None)
     }
 
+    /// Attempt to retrieve the IANA identifier of the time zone set in the host system.
+    fn raw_time_zone() -> Result<Option<String>, HostInfoError> {
+        Ok(None)
+    }
+
     /// Attempt to retrieve customized date format set in the host regional preferences by the user.
     fn raw_date_format() -> Result<Option<String>, HostInfoError> {
         Ok(None)
@@ -3,3 +3,5 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod posix;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+pub mod time_zone;
@@ -29,7 +29,7 @@ pub(crate) enum LocaleCategory {
 
 impl LocaleCategory {
     #[inline]
-    fn to_env_var_name(self) -> &'static str {
+    pub(crate) fn to_env_var_name(self) -> &'static str {
         match self {
             LocaleCategory::Character => "LC_CTYPE",
             LocaleCategory::Number => "LC_NUMERIC",
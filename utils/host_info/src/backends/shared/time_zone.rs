@@ -0,0 +1,389 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Detection of the host time zone on systems using the tz database.
+//!
+//! The time zone is resolved the same way the C library does: the `TZ` environment
+//! variable takes precedence over `/etc/localtime`.
+
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// The default root of the tz database.
+const TZDIR: &str = "/usr/share/zoneinfo";
+
+/// The locations of the host's time zone configuration.
+pub(crate) struct TimeZoneConfig {
+    /// The value of the `TZ` environment variable.
+    pub(crate) tz: Option<OsString>,
+    /// The local time zone file, usually `/etc/localtime`.
+    pub(crate) localtime: PathBuf,
+    /// The Debian-style time zone name file, usually `/etc/timezone`.
+    pub(crate) timezone: PathBuf,
+    /// The root of the tz database, `TZDIR` or `/usr/share/zoneinfo`.
+    pub(crate) tzdir: PathBuf,
+}
+
+impl TimeZoneConfig {
+    /// Reads the configuration of the current host.
+    pub(crate) fn from_host() -> Self {
+        Self {
+            tz: std::env::var_os("TZ"),
+            localtime: PathBuf::from("/etc/localtime"),
+            timezone: PathBuf::from("/etc/timezone"),
+            tzdir: std::env::var_os("TZDIR")
+                .filter(|dir| !dir.is_empty())
+                .map_or_else(|| PathBuf::from(TZDIR), PathBuf::from),
+        }
+    }
+
+    /// Resolves the configuration into an IANA time zone identifier.
+    pub(crate) fn resolve(&self) -> Option<String> {
+        match self.tz.as_ref().map(|tz| tz.to_str()) {
+            Some(Some(tz)) => self.resolve_tz(tz),
+            // A non-UTF-8 `TZ` cannot name a zone
+            Some(None) => None,
+            None => self.resolve_localtime(&self.localtime),
+        }
+    }
+
+    /// Resolves a `TZ` value, which is either `[:]Area/City`, `:/path/to/file`,
+    /// or a POSIX-style time zone string.
+    fn resolve_tz(&self, tz: &str) -> Option<String> {
+        let name = tz.strip_prefix(':').unwrap_or(tz);
+        if name.is_empty() {
+            // An empty `TZ` is UTC, while a bare `:` is the implementation default
+            return if tz.is_empty() {
+                Some("Etc/UTC".to_string())
+            } else {
+                self.resolve_localtime(&self.localtime)
+            };
+        }
+
+        if name.starts_with('/') {
+            return self.resolve_localtime(Path::new(name));
+        }
+
+        // Zone names, including legacy names such as `EST5EDT`, are relative to the
+        // tz database and may not escape it
+        let path = Path::new(name);
+        if path.components().all(|c| matches!(c, Component::Normal(_)))
+            && self.tzdir.join(path).is_file()
+        {
+            return Some(strip_variant(name).to_string());
+        }
+
+        match parse_posix(name)? {
+            // Zones with a whole-hour fixed offset, such as `JST-9`
+            (offset, false) if offset % 3600 == 0 => match offset / 3600 {
+                0 => Some("Etc/UTC".to_string()),
+                hours @ 1..=12 => Some(format!("Etc/GMT+{hours}")),
+                hours @ -14..=-1 => Some(format!("Etc/GMT{hours}")),
+                _ => None,
+            },
+            // Other POSIX-style strings do not identify a single zone
+            _ => None,
+        }
+    }
+
+    /// Resolves a time zone file, either by its symlink target into the tz database
+    /// or by comparing its contents against the tz database.
+    fn resolve_localtime(&self, path: &Path) -> Option<String> {
+        if let Ok(target) = fs::read_link(path) {
+            // Relative targets are relative to the symlink's directory
+            let target = path.parent().unwrap_or(path).join(target);
+            if let Some(id) = zoneinfo_id(&target) {
+                return Some(id);
+            }
+        }
+
+        let contents = fs::read(path).ok()?;
+        if !contents.starts_with(b"TZif") {
+            return None;
+        }
+
+        let mut matches = Vec::new();
+        self.find_matches(&self.tzdir, &contents, &mut matches);
+
+        // Prefer the name recorded by the system, then canonical zones, then the first match
+        let recorded = fs::read_to_string(&self.timezone).ok();
+        let recorded = recorded.as_deref().map(str::trim);
+        let canonical = ["zone1970.tab", "zone.tab"]
+            .iter()
+            .filter_map(|tab| fs::read_to_string(self.tzdir.join(tab)).ok())
+            .collect::<Vec<_>>();
+        let is_canonical = |id: &str| {
+            canonical
+                .iter()
+                .flat_map(|tab| tab.lines())
+                .any(|line| !line.starts_with('#') && line.split('\t').nth(2) == Some(id))
+        };
+
+        matches
+            .iter()
+            .find(|id| Some(id.as_str()) == recorded)
+            .or_else(|| matches.iter().find(|id| is_canonical(id)))
+            .or(matches.first())
+            .cloned()
+    }
+
+    /// Collects the identifiers of all zones below `dir` with the given contents, in order.
+    fn find_matches(&self, dir: &Path, contents: &[u8], matches: &mut Vec<String>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        let mut entries = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        for path in entries {
+            let Ok(metadata) = fs::symlink_metadata(&path) else {
+                continue;
+            };
+            // Links are aliases of the zone they point to
+            if metadata.file_type().is_symlink() {
+                continue;
+            }
+            let Some(id) = path
+                .strip_prefix(&self.tzdir)
+                .ok()
+                .and_then(|id| id.to_str())
+                .map(|id| id.replace(std::path::MAIN_SEPARATOR, "/"))
+            else {
+                continue;
+            };
+            if metadata.is_dir() {
+                // Skip the alternative `posix/` and `right/` trees
+                if id != "posix" && id != "right" {
+                    self.find_matches(&path, contents, matches);
+                }
+            } else if id != "localtime"
+                && id != "posixrules"
+                && metadata.len() == contents.len() as u64
+                && fs::read(&path).is_ok_and(|candidate| candidate == contents)
+            {
+                matches.push(id);
+            }
+        }
+    }
+}
+
+/// Extracts the zone identifier from a path into the tz database, such as
+/// `/usr/share/zoneinfo/Europe/Berlin`. The path has to exist.
+fn zoneinfo_id(path: &Path) -> Option<String> {
+    if !path.is_file() {
+        return None;
+    }
+    let path = path.to_str()?;
+    let (_, id) = path.rsplit_once("zoneinfo/")?;
+    Some(strip_variant(id).to_string()).filter(|id| !id.is_empty())
+}
+
+/// Strips the prefix of the alternative `posix/` and `right/` trees some distributions ship.
+fn strip_variant(id: &str) -> &str {
+    id.strip_prefix("posix/")
+        .or_else(|| id.strip_prefix("right/"))
+        .unwrap_or(id)
+}
+
+/// Parses a POSIX-style time zone string, returning the standard offset in seconds
+/// west of UTC and whether a daylight saving time zone is specified.
+///
+/// See section 8.3 of the POSIX spec for more details:
+/// <https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html#tag_08_03>
+fn parse_posix(tz: &str) -> Option<(i64, bool)> {
+    let rest = parse_posix_name(tz)?;
+
+    let (sign, rest) = match rest.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, rest.strip_prefix('+').unwrap_or(rest)),
+    };
+    let offset_len = rest
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(rest.len());
+    let (offset, rest) = rest.split_at(offset_len);
+
+    let mut seconds = 0;
+    let mut fields = 0;
+    for (field, max) in offset.split(':').zip([24, 59, 59]) {
+        let value = field.parse::<i64>().ok().filter(|&value| value <= max)?;
+        seconds = seconds * 60 + value;
+        fields += 1;
+    }
+    if fields == 0 || offset.split(':').count() > 3 {
+        return None;
+    }
+    for _ in fields..3 {
+        seconds *= 60;
+    }
+
+    let has_dst = !rest.is_empty();
+    if has_dst {
+        parse_posix_name(rest)?;
+    }
+    Some((sign * seconds, has_dst))
+}
+
+/// Parses a zone abbreviation, either alphabetic or quoted in `<>`, returning the rest.
+fn parse_posix_name(tz: &str) -> Option<&str> {
+    let (name, rest) = match tz.strip_prefix('<') {
+        Some(quoted) => quoted.split_once('>')?,
+        None => tz.split_at(
+            tz.find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(tz.len()),
+        ),
+    };
+    (name.len() >= 3).then_some(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/time_zone")
+    }
+
+    fn config(tz: Option<&str>, localtime: &str) -> TimeZoneConfig {
+        TimeZoneConfig {
+            tz: tz.map(OsString::from),
+            localtime: fixtures().join(localtime),
+            timezone: fixtures().join("etc/missing"),
+            tzdir: fixtures().join("zoneinfo"),
+        }
+    }
+
+    #[test]
+    fn tz_names() {
+        for (tz, expected) in [
+            (":Asia/Tokyo", Some("Asia/Tokyo")),
+            ("Asia/Tokyo", Some("Asia/Tokyo")),
+            ("posix/Asia/Kolkata", Some("Asia/Kolkata")),
+            (":Asia/Nowhere", None),
+            (":../etc/localtime", None),
+            ("", Some("Etc/UTC")),
+        ] {
+            assert_eq!(
+                config(Some(tz), "etc/localtime-unknown")
+                    .resolve()
+                    .as_deref(),
+                expected,
+                "TZ={tz}"
+            );
+        }
+
+        // An absolute path is resolved like `/etc/localtime`
+        let path = fixtures().join("zoneinfo/America/New_York");
+        let tz = format!(":{}", path.display());
+        assert_eq!(
+            config(Some(&tz), "etc/localtime-unknown")
+                .resolve()
+                .as_deref(),
+            Some("America/New_York")
+        );
+
+        // A bare `:` falls back to `/etc/localtime`
+        assert_eq!(
+            config(Some(":"), "etc/localtime").resolve().as_deref(),
+            Some("Asia/Kolkata")
+        );
+    }
+
+    #[test]
+    fn tz_posix() {
+        for (tz, expected) in [
+            ("UTC0", Some("Etc/UTC")),
+            ("GMT+0", Some("Etc/UTC")),
+            ("JST-9", Some("Etc/GMT-9")),
+            ("<-03>3", Some("Etc/GMT+3")),
+            ("<+14>-14", Some("Etc/GMT-14")),
+            ("EST5EDT", Some("EST5EDT")),
+            // Legacy names have to exist in the tz database
+            ("CST6CDT", None),
+            ("<+15>-15", None),
+            ("IST-5:30", None),
+            ("<+0330>-3:30", None),
+            ("CET-1CEST,M3.5.0,M10.5.0/3", None),
+            ("EST5EDT,M3.2.0,M11.1.0", None),
+            ("UT0", None),
+            ("CET", None),
+        ] {
+            assert_eq!(
+                config(Some(tz), "etc/localtime").resolve().as_deref(),
+                expected,
+                "TZ={tz}"
+            );
+        }
+    }
+
+    #[test]
+    fn copied_localtime() {
+        // `Asia/Calcutta` and `Asia/Kolkata` are identical; the canonical zone is preferred
+        assert_eq!(
+            config(None, "etc/localtime").resolve().as_deref(),
+            Some("Asia/Kolkata")
+        );
+
+        // ... unless the system recorded the name it was copied from
+        let recorded = TimeZoneConfig {
+            timezone: fixtures().join("etc/timezone"),
+            ..config(None, "etc/localtime")
+        };
+        assert_eq!(recorded.resolve().as_deref(), Some("Asia/Calcutta"));
+
+        // Files that are not in the tz database cannot be resolved
+        assert_eq!(config(None, "etc/localtime-unknown").resolve(), None);
+        assert_eq!(config(None, "etc/timezone").resolve(), None);
+        assert_eq!(config(None, "etc/missing").resolve(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_localtime() {
+        let dir = std::env::temp_dir().join(format!("icu_host_info_tz_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Absolute symlinks are resolved by name, even if the target is not a canonical zone
+        let absolute = dir.join("absolute");
+        std::os::unix::fs::symlink(fixtures().join("zoneinfo/Asia/Calcutta"), &absolute).unwrap();
+        // Relative symlinks are relative to their directory
+        let relative = dir.join("relative");
+        fs::create_dir_all(dir.join("zoneinfo/right/Asia")).unwrap();
+        fs::copy(
+            fixtures().join("zoneinfo/Asia/Tokyo"),
+            dir.join("zoneinfo/right/Asia/Tokyo"),
+        )
+        .unwrap();
+        let target = Path::new("..")
+            .join(dir.file_name().unwrap())
+            .join("zoneinfo/right/Asia/Tokyo");
+        std::os::unix::fs::symlink(target, &relative).unwrap();
+        // Dangling symlinks into a tz database are not trusted
+        let dangling = dir.join("dangling");
+        std::os::unix::fs::symlink(fixtures().join("zoneinfo/Asia/Nowhere"), &dangling).unwrap();
+        // Symlinks outside of a tz database are resolved by their contents
+        let other = dir.join("other");
+        std::os::unix::fs::symlink(fixtures().join("etc/localtime"), &other).unwrap();
+
+        for (localtime, expected) in [
+            (absolute, Some("Asia/Calcutta")),
+            (relative, Some("Asia/Tokyo")),
+            (other, Some("Asia/Kolkata")),
+            (dangling, None),
+        ] {
+            let symlinked = TimeZoneConfig {
+                localtime,
+                ..config(None, "etc/missing")
+            };
+            assert_eq!(symlinked.resolve().as_deref(), expected);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -15,6 +15,7 @@ use icu_locale_core::{
 use crate::{
     backends::{self, HostInfoBackend},
     error::HostInfoError,
+    HostPreferences,
 };
 
 use super::HostKind;
@@ -77,6 +78,34 @@ pub fn datetime_preferences() -> Result<icu_datetime::DateTimeFormatterPreferenc
     backends::Impl::datetime_preferences()
 }
 
+/// Retrieves `Preferences` object for `DecimalFormatter`.
+///
+/// # Example
+///
+/// ```
+/// let prefs = icu_host_info::decimal_preferences()
+///     .expect("Failed to retrieve decimal preferences");
+/// ```
+#[cfg(feature = "decimal")]
+pub fn decimal_preferences() -> Result<icu_decimal::DecimalFormatterPreferences, HostInfoError> {
+    backends::Impl::decimal_preferences()
+}
+
+/// Retrieves all regional preferences set in the host environment, including
+/// locales set for individual categories.
+///
+/// See [`HostPreferences`] for converting them into component preferences.
+///
+/// # Example
+///
+/// ```
+/// let prefs = icu_host_info::host_preferences()
+///     .expect("Failed to retrieve host preferences");
+/// ```
+pub fn host_preferences() -> Result<HostPreferences, HostInfoError> {
+    backends::Impl::host_preferences()
+}
+
 /// Retrieves an ordered list of locales set as requested by the user in the host
 /// environment regional preferences.
 ///
@@ -189,6 +218,27 @@ pub fn measurement_unit_override() -> Result<Option<MeasurementUnitOverride>, Ho
     backends::Impl::measurement_unit_override()
 }
 
+/// Retrieves the time zone set in the host environment.
+///
+/// On systems using the tz database, the `TZ` environment variable (`:Area/City`,
+/// a path to a time zone file, or a POSIX-style string with a fixed offset) takes
+/// precedence over `/etc/localtime`, which is resolved by its symlink target or by
+/// comparing its contents against the tz database in `TZDIR`.
+///
+/// In `::unicode_extensions()` this field is being encoded as `tz`.
+///
+/// ✨ *Enabled with the `time` Cargo feature.*
+///
+/// # Example
+///
+/// ```
+/// let tz = icu_host_info::time_zone().expect("Failed to retrieve time zone");
+/// ```
+#[cfg(feature = "time")]
+pub fn time_zone() -> Result<Option<icu_time::TimeZone>, HostInfoError> {
+    backends::Impl::time_zone()
+}
+
 pub fn resolved_backend() -> Option<HostKind> {
     RESOLVED_BACKEND
 }
@@ -62,14 +62,18 @@
 //! | Calendar            |   🚧    | 🚧  | 🚧                  |   ✅  |    ✅   |
 //! | Region              |   🚧    | 🚧  | 🚧                  |   ✅  |    ✅   |
 //! | Hour cycle          |   🚧    | 🚧  | ✅                  |   ✅  |    🚧   |
-//! | Measurement System  |   🚧    | 🚧  | 🚧                  |   ✅  |    🚧   |
+//! | Measurement System  |   🚧    | 🚧  | ✅                  |   ✅  |    🚧   |
 //! | Measurement Override|   🚧    | 🚧  | 🚧                  |   ✅  |    🚧   |
-//! | First Day of week   |   🚧    | 🚧  | 🚧                  |   ✅  |    ✅   |
+//! | First Day of week   |   🚧    | 🚧  | ✅                  |   ✅  |    ✅   |
 //! | Collation           |   🚧    | 🚧  | 🚧                  |   ✅  |    ❌    |
 //! | Date format         |   🚧    | 🚧  | 🚧                  |   🚧  |    🚧   |
 //! | Number format       |   🚧    | 🚧  | 🚧                  |   🚧  |    🚧   |
+//! | Category locales    |   ❌    | ❌  | ✅                  |   ❌  |    ❌   |
+//! | Time zone           |   🚧    | 🚧  | ✅                  |   ✅  |    🚧   |
 //!
 //! <sup>(1)</sup> In the case of Linux, different desktop environments such as Gnome and KDE are supported together.
+//! The `LC_TIME`, `LC_NUMERIC` and `LC_MEASUREMENT` categories are honored separately, with preferences derived from
+//! the glibc locale definition files of the locale set for each category.
 //!
 //! # Integrating preferences into ICU formatters
 //!
@@ -96,6 +100,23 @@
 //!     .expect("Failed to create datetime formatter.");
 //! ```
 //!
+//! All preferences, including locales the host allows to set for individual categories (for example
+//! `LC_TIME` on Linux), can also be retrieved at once into a [`HostPreferences`] struct, which converts
+//! into the `Preferences` of each component.
+//!
+//! ### Example
+//!
+//! ```ignore
+//! use icu::datetime::{fieldsets, DateTimeFormatter};
+//!
+//! let prefs = icu_host_info::host_preferences()
+//!     .expect("Failed to retrieve host info");
+//!
+//! // requires feature `datetime`
+//! let dtf = DateTimeFormatter::try_new((&prefs).into(), fieldsets::YMD::long())
+//!     .expect("Failed to create datetime formatter.");
+//! ```
+//!
 //! ## 2. Locale
 //!
 //! For all components that `icu_host_info` does not have special preference getter for,
@@ -299,8 +320,10 @@ pub mod backends;
 mod error;
 mod host_info;
 pub mod locale;
+mod preferences;
 
 pub use host_info::*;
+pub use preferences::HostPreferences;
 
 /// Enumeration of known hosts.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
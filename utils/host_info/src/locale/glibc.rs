@@ -0,0 +1,574 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing functionality for glibc locale definition files.
+//! For more information, see [`GlibcLocaleDefinition`].
+//!
+//! # Usage example
+//! ```
+//! use icu_host_info::locale::GlibcLocaleDefinition;
+//! use icu_locale_core::preferences::extensions::unicode::keywords::{
+//!     FirstDay, HourCycle,
+//! };
+//!
+//! let definition = GlibcLocaleDefinition::try_from_str(
+//!     r#"
+//! comment_char %
+//! escape_char /
+//!
+//! LC_TIME
+//! % Appropriate time representation (%X)
+//! t_fmt "%T"
+//! week 7;19971130;4
+//! first_weekday 2
+//! END LC_TIME
+//!
+//! LC_MEASUREMENT
+//! copy "i18n"
+//! END LC_MEASUREMENT
+//! "#,
+//! )
+//! .expect("Failed to parse locale definition");
+//!
+//! assert_eq!(definition.hour_cycle(), Some(HourCycle::H23));
+//! assert_eq!(definition.first_day(), Some(FirstDay::Mon));
+//!
+//! // The measurement system is defined by another locale definition
+//! assert_eq!(definition.measurement_system(), None);
+//! assert_eq!(definition.copy_source("LC_MEASUREMENT"), Some("i18n"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use displaydoc::Display;
+use icu_locale_core::preferences::extensions::unicode::keywords::{
+    FirstDay, HourCycle, MeasurementSystem,
+};
+
+use super::PosixLocale;
+
+/// The maximum number of `copy` directives followed by [`GlibcLocaleDefinition::try_load`].
+const MAX_COPY_DEPTH: usize = 16;
+
+#[derive(Display, Debug, PartialEq)]
+/// An error while parsing a glibc locale definition file
+pub enum GlibcParseError {
+    #[displaydoc("Unterminated string on line {line}")]
+    UnterminatedString { line: usize },
+    #[displaydoc("Invalid character reference on line {line}")]
+    InvalidCharacterReference { line: usize },
+    #[displaydoc("Category beginning on line {line} is not terminated")]
+    UnterminatedCategory { line: usize },
+    #[displaydoc("Mismatched `END` on line {line}")]
+    MismatchedEnd { line: usize },
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Category {
+    copy: Option<String>,
+    keywords: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// A parsed glibc locale definition file.
+///
+/// These are the source files `localedef` compiles locales from, usually installed in
+/// `/usr/share/i18n/locales`. Each file defines a number of categories (`LC_TIME`,
+/// `LC_MEASUREMENT`, ...), either directly or by copying them from another file.
+///
+/// Only the operands of each keyword are retained; strings are decoded, including
+/// `<Uxxxx>` character references and escaped characters.
+///
+/// See section 7.3 of the POSIX spec for more details:
+/// <https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap07.html#tag_07_03>
+pub struct GlibcLocaleDefinition {
+    categories: BTreeMap<String, Category>,
+}
+
+impl GlibcLocaleDefinition {
+    /// Attempt to parse a glibc locale definition file.
+    pub fn try_from_str(src: &str) -> Result<Self, GlibcParseError> {
+        let mut comment_char = '#';
+        let mut escape_char = '\\';
+        let mut categories = BTreeMap::new();
+        let mut current: Option<(String, Category, usize)> = None;
+
+        let mut lines = src.lines().enumerate();
+        while let Some((index, first_line)) = lines.next() {
+            let line = index + 1;
+            if first_line.trim_start().starts_with(comment_char) {
+                continue;
+            }
+
+            // Join continuation lines, which end in an unescaped escape character
+            let mut logical = String::new();
+            let mut physical = first_line.trim_end();
+            loop {
+                let trailing_escapes = physical
+                    .chars()
+                    .rev()
+                    .take_while(|&c| c == escape_char)
+                    .count();
+                match physical.strip_suffix(escape_char) {
+                    Some(rest) if trailing_escapes % 2 == 1 => {
+                        logical.push_str(rest);
+                        physical = lines
+                            .next()
+                            .map(|(_, next)| next.trim())
+                            .unwrap_or_default();
+                    }
+                    _ => {
+                        logical.push_str(physical);
+                        break;
+                    }
+                }
+            }
+
+            let logical = logical.trim();
+            if logical.is_empty() {
+                continue;
+            }
+            let (keyword, operands) = logical
+                .split_once(char::is_whitespace)
+                .map(|(keyword, operands)| (keyword, operands.trim()))
+                .unwrap_or((logical, ""));
+
+            match current.take() {
+                None => match keyword {
+                    "comment_char" => {
+                        comment_char = operands.chars().next().unwrap_or(comment_char);
+                    }
+                    "escape_char" => {
+                        escape_char = operands.chars().next().unwrap_or(escape_char);
+                    }
+                    _ if keyword.starts_with("LC_") => {
+                        current = Some((keyword.to_string(), Category::default(), line));
+                    }
+                    // Other top-level keywords do not affect the categories
+                    _ => (),
+                },
+                Some((name, mut category, start)) => {
+                    match keyword {
+                        "END" if operands == name => {
+                            categories.insert(name, category);
+                            continue;
+                        }
+                        "END" => return Err(GlibcParseError::MismatchedEnd { line }),
+                        "copy" => {
+                            category.copy = split_operands(operands, escape_char, line)?
+                                .into_iter()
+                                .next();
+                        }
+                        _ => {
+                            category.keywords.insert(
+                                keyword.to_string(),
+                                split_operands(operands, escape_char, line)?,
+                            );
+                        }
+                    }
+                    current = Some((name, category, start));
+                }
+            }
+        }
+
+        if let Some((_, _, line)) = current {
+            return Err(GlibcParseError::UnterminatedCategory { line });
+        }
+
+        Ok(Self { categories })
+    }
+
+    /// Attempt to load the definition of `category` for the locale definition file `name`,
+    /// following `copy` directives.
+    ///
+    /// The definition file is looked up in each directory of `search_path` in order.
+    /// Returns the definition that defines `category` itself, or `None` if it cannot
+    /// be found or parsed.
+    pub fn try_load(search_path: &[impl AsRef<Path>], name: &str, category: &str) -> Option<Self> {
+        let mut name = name.to_string();
+        for _ in 0..MAX_COPY_DEPTH {
+            // Locale names are file names, never paths
+            if name.is_empty() || name.starts_with('.') || name.contains('/') {
+                return None;
+            }
+            let definition = search_path
+                .iter()
+                .find_map(|dir| std::fs::read_to_string(dir.as_ref().join(&name)).ok())
+                .and_then(|src| Self::try_from_str(&src).ok())?;
+            match definition.copy_source(category) {
+                Some(source) => name = source.to_string(),
+                None if definition.categories.contains_key(category) => return Some(definition),
+                None => return None,
+            }
+        }
+        None
+    }
+
+    /// Attempt to load the definition of `category` for a POSIX locale, following `copy`
+    /// directives.
+    ///
+    /// Definition files are named after the locale without its codeset; a definition
+    /// without the modifier is used if there is none with it.
+    pub fn try_load_for_locale(
+        search_path: &[impl AsRef<Path>],
+        locale: &PosixLocale,
+        category: &str,
+    ) -> Option<Self> {
+        locale
+            .glibc_names()
+            .iter()
+            .find_map(|name| Self::try_load(search_path, name, category))
+    }
+
+    /// Returns the name of the locale definition `category` is copied from, if any.
+    pub fn copy_source(&self, category: &str) -> Option<&str> {
+        self.categories.get(category)?.copy.as_deref()
+    }
+
+    /// Returns the decoded operands of `keyword` in `category`, if defined.
+    ///
+    /// Operands are separated by `;` in the source file; strings are returned without quotes.
+    pub fn operands(&self, category: &str, keyword: &str) -> Option<&[String]> {
+        self.categories
+            .get(category)?
+            .keywords
+            .get(keyword)
+            .map(Vec::as_slice)
+    }
+
+    /// Returns the hour cycle of the time format (`t_fmt` in `LC_TIME`).
+    pub fn hour_cycle(&self) -> Option<HourCycle> {
+        let mut chars = self.operands("LC_TIME", "t_fmt")?.first()?.chars();
+        while chars.any(|c| c == '%') {
+            // Skip glibc flags, field width and the `E`/`O` modifiers
+            match chars.find(|c| !matches!(c, '_' | '-' | '^' | '#' | '0'..='9' | 'E' | 'O'))? {
+                'H' | 'k' | 'R' | 'T' => return Some(HourCycle::H23),
+                'I' | 'l' | 'r' => return Some(HourCycle::H12),
+                _ => (),
+            }
+        }
+        None
+    }
+
+    /// Returns the first day of the week (`week` and `first_weekday` in `LC_TIME`).
+    pub fn first_day(&self) -> Option<FirstDay> {
+        let week = self.operands("LC_TIME", "week");
+        let first_weekday = self.operands("LC_TIME", "first_weekday");
+        if week.is_none() && first_weekday.is_none() {
+            return None;
+        }
+
+        // The first day is relative to the weekday of the reference date in `week`,
+        // which defaults to Sunday, 1997-11-30.
+        let reference = week
+            .and_then(|week| week.get(1))
+            .map(String::as_str)
+            .unwrap_or("19971130");
+        let first_weekday = first_weekday
+            .and_then(|operands| operands.first())
+            .map(|operand| operand.parse::<i64>())
+            .unwrap_or(Ok(1))
+            .ok()?;
+
+        let year = reference.get(0..4)?.parse().ok()?;
+        let month = reference.get(4..6)?.parse().ok()?;
+        let day = reference.get(6..8)?.parse().ok()?;
+
+        const DAYS: [FirstDay; 7] = [
+            FirstDay::Sun,
+            FirstDay::Mon,
+            FirstDay::Tue,
+            FirstDay::Wed,
+            FirstDay::Thu,
+            FirstDay::Fri,
+            FirstDay::Sat,
+        ];
+        let index = (weekday(year, month, day)? + first_weekday - 1).rem_euclid(7);
+        DAYS.get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// Returns the measurement system (`measurement` in `LC_MEASUREMENT`).
+    pub fn measurement_system(&self) -> Option<MeasurementSystem> {
+        match self
+            .operands("LC_MEASUREMENT", "measurement")?
+            .first()?
+            .as_str()
+        {
+            "1" => Some(MeasurementSystem::Metric),
+            "2" => Some(MeasurementSystem::USSystem),
+            _ => None,
+        }
+    }
+}
+
+/// Splits `;`-separated operands, decoding strings and character references.
+fn split_operands(
+    src: &str,
+    escape_char: char,
+    line: usize,
+) -> Result<Vec<String>, GlibcParseError> {
+    let mut operands = Vec::new();
+    let mut operand = String::new();
+    let mut in_string = false;
+
+    let mut chars = src.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_string = !in_string,
+            ';' if !in_string => operands.push(core::mem::take(&mut operand)),
+            c if c.is_whitespace() && !in_string => (),
+            c if c == escape_char => {
+                if let Some(escaped) = chars.next() {
+                    operand.push(escaped);
+                }
+            }
+            '<' => {
+                let rest = chars.as_str();
+                match rest.split_once('>') {
+                    Some((name, rest)) if name.starts_with('U') => {
+                        let decoded = name
+                            .get(1..)
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or(GlibcParseError::InvalidCharacterReference { line })?;
+                        operand.push(decoded);
+                        chars = rest.chars();
+                    }
+                    // Symbolic names other than `<Uxxxx>` depend on the charmap; keep them as-is
+                    _ => operand.push('<'),
+                }
+            }
+            c => operand.push(c),
+        }
+    }
+
+    if in_string {
+        return Err(GlibcParseError::UnterminatedString { line });
+    }
+    if !src.is_empty() {
+        operands.push(operand);
+    }
+    Ok(operands)
+}
+
+/// Returns the day of the week of a Gregorian date, with 0 being Sunday.
+fn weekday(year: i64, month: usize, day: i64) -> Option<i64> {
+    const MONTH_OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let offset = MONTH_OFFSETS.get(month.checked_sub(1)?)?;
+    let year = if month < 3 { year - 1 } else { year };
+    Some(
+        (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + offset + day)
+            .rem_euclid(7),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DE_DE: &str = r#"comment_char %
+escape_char /
+
+% Locale for German locale in Germany
+
+LC_IDENTIFICATION
+title      "German locale for Germany"
+address    "https:////www.gnu.org//software//libc//"
+END LC_IDENTIFICATION
+
+LC_TIME
+abday	"So";"Mo";/
+	"Di";"Mi";/
+	"Do";"Fr";/
+	"Sa"
+d_fmt   "%d.%m.%Y"
+t_fmt   "%T"
+t_fmt_ampm ""
+week    7;19971130;4
+first_weekday 2
+END LC_TIME
+
+LC_MEASUREMENT
+copy "i18n"
+END LC_MEASUREMENT
+"#;
+
+    #[test]
+    fn operands() {
+        let definition = GlibcLocaleDefinition::try_from_str(DE_DE).unwrap();
+
+        assert_eq!(
+            definition.operands("LC_IDENTIFICATION", "address"),
+            Some(&["https://www.gnu.org/software/libc/".to_string()][..])
+        );
+        assert_eq!(
+            definition.operands("LC_TIME", "abday"),
+            Some(&["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"].map(String::from)[..])
+        );
+        assert_eq!(
+            definition.operands("LC_TIME", "week"),
+            Some(&["7", "19971130", "4"].map(String::from)[..])
+        );
+        assert_eq!(
+            definition.operands("LC_TIME", "t_fmt_ampm"),
+            Some(&[String::new()][..])
+        );
+        assert_eq!(definition.operands("LC_TIME", "date_fmt"), None);
+        assert_eq!(definition.operands("LC_MEASUREMENT", "measurement"), None);
+        assert_eq!(definition.copy_source("LC_MEASUREMENT"), Some("i18n"));
+        assert_eq!(definition.copy_source("LC_TIME"), None);
+    }
+
+    #[test]
+    fn character_references() {
+        let definition = GlibcLocaleDefinition::try_from_str(
+            "LC_TIME\nt_fmt \"<U0025><U0048>:%M <U9EDE>\"\nam_pm \"<AM>\";\"<PM>\"\nEND LC_TIME",
+        )
+        .unwrap();
+
+        assert_eq!(
+            definition.operands("LC_TIME", "t_fmt"),
+            Some(&["%H:%M 點".to_string()][..])
+        );
+        assert_eq!(
+            definition.operands("LC_TIME", "am_pm"),
+            Some(&["<AM>", "<PM>"].map(String::from)[..])
+        );
+        assert_eq!(definition.hour_cycle(), Some(HourCycle::H23));
+    }
+
+    #[test]
+    fn hour_cycle() {
+        for (t_fmt, expected) in [
+            ("%T", Some(HourCycle::H23)),
+            ("%H:%M:%S", Some(HourCycle::H23)),
+            ("%OH:%OM:%OS", Some(HourCycle::H23)),
+            ("kl. %H.%M %z", Some(HourCycle::H23)),
+            ("%r", Some(HourCycle::H12)),
+            ("%Z %I:%M:%S %p", Some(HourCycle::H12)),
+            ("%l:%M:%S %p", Some(HourCycle::H12)),
+            ("%-I:%M %p", Some(HourCycle::H12)),
+            ("100%% %M", None),
+            ("", None),
+        ] {
+            let definition = GlibcLocaleDefinition::try_from_str(&format!(
+                "comment_char %\nescape_char /\nLC_TIME\nt_fmt \"{t_fmt}\"\nEND LC_TIME\n"
+            ))
+            .unwrap();
+            assert_eq!(definition.hour_cycle(), expected, "t_fmt: `{t_fmt}`");
+        }
+    }
+
+    #[test]
+    fn first_day() {
+        for (lc_time, expected) in [
+            ("week 7;19971130;4\nfirst_weekday 2", Some(FirstDay::Mon)),
+            ("week 7;19971130;1", Some(FirstDay::Sun)),
+            ("week 7;19971201;4", Some(FirstDay::Mon)),
+            ("week 7;19971130;1\nfirst_weekday 7", Some(FirstDay::Sat)),
+            ("first_weekday 2", Some(FirstDay::Mon)),
+            ("t_fmt \"%T\"", None),
+        ] {
+            let definition =
+                GlibcLocaleDefinition::try_from_str(&format!("LC_TIME\n{lc_time}\nEND LC_TIME\n"))
+                    .unwrap();
+            assert_eq!(definition.first_day(), expected, "LC_TIME: `{lc_time}`");
+        }
+    }
+
+    #[test]
+    fn measurement_system() {
+        for (measurement, expected) in [
+            ("1", Some(MeasurementSystem::Metric)),
+            ("2", Some(MeasurementSystem::USSystem)),
+            ("3", None),
+        ] {
+            let definition = GlibcLocaleDefinition::try_from_str(&format!(
+                "LC_MEASUREMENT\nmeasurement {measurement}\nEND LC_MEASUREMENT\n"
+            ))
+            .unwrap();
+            assert_eq!(definition.measurement_system(), expected);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            GlibcLocaleDefinition::try_from_str("LC_TIME\nt_fmt \"%T\nEND LC_TIME"),
+            Err(GlibcParseError::UnterminatedString { line: 2 })
+        );
+        assert_eq!(
+            GlibcLocaleDefinition::try_from_str("LC_TIME\nt_fmt \"<UZZZZ>\"\nEND LC_TIME"),
+            Err(GlibcParseError::InvalidCharacterReference { line: 2 })
+        );
+        assert_eq!(
+            GlibcLocaleDefinition::try_from_str("\nLC_TIME\nt_fmt \"%T\""),
+            Err(GlibcParseError::UnterminatedCategory { line: 2 })
+        );
+        assert_eq!(
+            GlibcLocaleDefinition::try_from_str("LC_TIME\nt_fmt \"%T\"\nEND LC_NUMERIC"),
+            Err(GlibcParseError::MismatchedEnd { line: 3 })
+        );
+    }
+
+    #[test]
+    fn load() {
+        let dir = std::env::temp_dir().join(format!("icu_host_info_glibc_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, src) in [
+            ("de_DE", DE_DE),
+            ("de_AT", "LC_TIME\ncopy \"de_DE\"\nEND LC_TIME\n"),
+            (
+                "i18n",
+                "LC_MEASUREMENT\nmeasurement 1\nEND LC_MEASUREMENT\n",
+            ),
+            ("loop", "LC_TIME\ncopy \"loop\"\nEND LC_TIME\n"),
+        ] {
+            std::fs::write(dir.join(name), src).unwrap();
+        }
+        let search_path = [dir.join("missing"), dir.clone()];
+
+        let lc_time = GlibcLocaleDefinition::try_load(&search_path, "de_AT", "LC_TIME").unwrap();
+        assert_eq!(lc_time.first_day(), Some(FirstDay::Mon));
+
+        let lc_measurement =
+            GlibcLocaleDefinition::try_load(&search_path, "de_DE", "LC_MEASUREMENT").unwrap();
+        assert_eq!(
+            lc_measurement.measurement_system(),
+            Some(MeasurementSystem::Metric)
+        );
+
+        // The definition without the modifier is used
+        let locale = PosixLocale::try_from_str("de_AT.UTF-8@euro").unwrap();
+        let lc_time =
+            GlibcLocaleDefinition::try_load_for_locale(&search_path, &locale, "LC_TIME").unwrap();
+        assert_eq!(lc_time.hour_cycle(), Some(HourCycle::H23));
+
+        // Categories that are not defined
+        assert_eq!(
+            GlibcLocaleDefinition::try_load(&search_path, "de_AT", "LC_MEASUREMENT"),
+            None
+        );
+        assert_eq!(
+            GlibcLocaleDefinition::try_load(&search_path, "loop", "LC_TIME"),
+            None
+        );
+        assert_eq!(
+            GlibcLocaleDefinition::try_load(&search_path, "../de_DE", "LC_TIME"),
+            None
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(super::weekday(1997, 11, 30), Some(0));
+        assert_eq!(super::weekday(1997, 12, 1), Some(1));
+        assert_eq!(super::weekday(2000, 2, 29), Some(2));
+        assert_eq!(super::weekday(2026, 10, 19), Some(1));
+        assert_eq!(super::weekday(2026, 13, 1), None);
+    }
+}
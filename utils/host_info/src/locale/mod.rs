@@ -7,8 +7,10 @@
 //! Some popular host environments provide custom definition of a `Locale`.
 //! This module contains APIs allowing for encoding of those variants and their conversion
 //! to ICU4X Locale.
+pub mod glibc;
 pub mod posix;
 pub mod windows;
 
+pub use glibc::GlibcLocaleDefinition;
 pub use posix::PosixLocale;
 pub use windows::WindowsLocale;
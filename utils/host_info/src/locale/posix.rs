@@ -185,6 +185,22 @@ impl<'src> PosixLocale<'src> {
 
         Ok(locale)
     }
+
+    /// Returns the names a glibc locale definition file for this locale may have,
+    /// in order of preference.
+    ///
+    /// Locale definition files are named without a codeset.
+    pub(crate) fn glibc_names(&self) -> Vec<String> {
+        let mut name = self.language.to_string();
+        if let Some(territory) = self.territory {
+            name.push('_');
+            name.push_str(territory);
+        }
+        match self.modifier {
+            Some(modifier) => vec![format!("{name}@{modifier}"), name],
+            None => vec![name],
+        }
+    }
 }
 
 impl<'s> TryFrom<PosixLocale<'s>> for Locale {
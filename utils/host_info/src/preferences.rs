@@ -0,0 +1,180 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locale_core::{
+    extensions::unicode::{key, Unicode, Value},
+    preferences::extensions::unicode::keywords::{
+        CalendarAlgorithm, CollationType, FirstDay, HourCycle, MeasurementSystem,
+        MeasurementUnitOverride,
+    },
+    subtags::{Language, Region},
+    Locale,
+};
+
+/// All regional preferences retrieved from the host environment.
+///
+/// Some hosts allow the user to select a different locale for individual categories,
+/// such as the `LC_TIME` and `LC_NUMERIC` categories on POSIX systems. Those locales are
+/// kept separately from the requested locales, and are used when converting into the
+/// preferences of the matching component.
+///
+/// # Example
+///
+/// ```
+/// use icu::locale::Locale;
+///
+/// let prefs = icu_host_info::host_preferences()
+///     .expect("Failed to retrieve host preferences");
+///
+/// // Preferences for any component can be created from the locale
+/// let locale = Locale::from(&prefs);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HostPreferences {
+    /// The ordered list of locales requested by the user.
+    pub requested_locales: Vec<Locale>,
+    /// The locale used for formatting dates and times, if set separately.
+    pub time_locale: Option<Locale>,
+    /// The locale used for formatting numbers, if set separately.
+    pub numeric_locale: Option<Locale>,
+    /// The locale used for measurement units, if set separately.
+    pub measurement_locale: Option<Locale>,
+    /// The calendar preference.
+    pub calendar: Option<CalendarAlgorithm>,
+    /// The region preference.
+    pub region: Option<Region>,
+    /// The hour cycle preference.
+    pub hour_cycle: Option<HourCycle>,
+    /// The measurement system preference.
+    pub measurement_system: Option<MeasurementSystem>,
+    /// The measurement unit override preference.
+    pub measurement_unit_override: Option<MeasurementUnitOverride>,
+    /// The first day of week preference.
+    pub first_day_of_week: Option<FirstDay>,
+    /// The collation preference.
+    pub collation: Option<(Language, CollationType)>,
+    /// The system time zone.
+    ///
+    /// ✨ *Enabled with the `time` Cargo feature.*
+    #[cfg(feature = "time")]
+    pub time_zone: Option<icu_time::TimeZone>,
+}
+
+impl HostPreferences {
+    /// Collects the preferences that can be expressed as Unicode extensions.
+    pub fn unicode_extensions(&self) -> Unicode {
+        let mut result = Unicode::new();
+        if let Some(calendar) = self.calendar {
+            result.keywords.set(key!("ca"), calendar.into());
+        }
+        if let Some(hc) = self.hour_cycle {
+            result.keywords.set(key!("hc"), hc.into());
+        }
+        if let Some(ms) = self.measurement_system {
+            result.keywords.set(key!("ms"), ms.into());
+        }
+        if let Some(mu) = self.measurement_unit_override {
+            result.keywords.set(key!("mu"), mu.into());
+        }
+        if let Some(fw) = self.first_day_of_week {
+            result.keywords.set(key!("fw"), fw.into());
+        }
+        if let Some((_lang, co)) = self.collation {
+            result.keywords.set(key!("co"), co.into());
+        }
+        if let Some(rg) = self.region {
+            let mut rg_str = rg.to_string();
+            rg_str.push_str("zzzz");
+            if let Ok(value) = Value::try_from_str(&rg_str) {
+                result.keywords.set(key!("rg"), value);
+            }
+        }
+        #[cfg(feature = "time")]
+        if let Some(tz) = self.time_zone {
+            result
+                .keywords
+                .set(key!("tz"), Value::from_subtag(Some(tz.0)));
+        }
+        result
+    }
+
+    /// Returns the given category locale, or the first requested locale if it is not set.
+    fn category_locale(&self, category_locale: Option<&Locale>) -> Locale {
+        category_locale
+            .or(self.requested_locales.first())
+            .cloned()
+            .unwrap_or(Locale::UNKNOWN)
+    }
+}
+
+/// Returns the first requested locale, with all preferences that can be expressed as
+/// Unicode extensions applied.
+impl From<&HostPreferences> for Locale {
+    fn from(prefs: &HostPreferences) -> Self {
+        let mut locale = prefs.category_locale(None);
+        locale.extensions.unicode.extend(prefs.unicode_extensions());
+        locale
+    }
+}
+
+/// Uses the time locale, falling back to the first requested locale.
+///
+/// ✨ *Enabled with the `datetime` Cargo feature.*
+#[cfg(feature = "datetime")]
+impl From<&HostPreferences> for icu_datetime::DateTimeFormatterPreferences {
+    fn from(prefs: &HostPreferences) -> Self {
+        let mut result = Self::from(prefs.category_locale(prefs.time_locale.as_ref()));
+        result.numbering_system = None;
+        result.hour_cycle = prefs.hour_cycle;
+        result.calendar_algorithm = prefs.calendar;
+        result
+    }
+}
+
+/// Uses the numeric locale, falling back to the first requested locale.
+///
+/// ✨ *Enabled with the `decimal` Cargo feature.*
+#[cfg(feature = "decimal")]
+impl From<&HostPreferences> for icu_decimal::DecimalFormatterPreferences {
+    fn from(prefs: &HostPreferences) -> Self {
+        let mut result = Self::from(prefs.category_locale(prefs.numeric_locale.as_ref()));
+        result.numbering_system = None;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::locale;
+
+    #[test]
+    fn category_locales() {
+        let prefs = HostPreferences {
+            requested_locales: vec![locale!("en-US"), locale!("de-DE")],
+            time_locale: Some(locale!("en-GB")),
+            hour_cycle: Some(HourCycle::H23),
+            first_day_of_week: Some(FirstDay::Mon),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Locale::from(&prefs),
+            "en-US-u-fw-mon-hc-h23".parse::<Locale>().unwrap()
+        );
+
+        #[cfg(feature = "datetime")]
+        {
+            let datetime = icu_datetime::DateTimeFormatterPreferences::from(&prefs);
+            assert_eq!(datetime, (&locale!("en-GB-u-hc-h23")).into());
+        }
+
+        #[cfg(feature = "decimal")]
+        {
+            let decimal = icu_decimal::DecimalFormatterPreferences::from(&prefs);
+            assert_eq!(decimal, (&locale!("en-US")).into());
+        }
+    }
+}
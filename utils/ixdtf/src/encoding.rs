@@ -29,10 +29,17 @@ pub trait EncodingType: private::Sealed {
     /// Checks for the known calendar annotation key `u-ca`.
     #[doc(hidden)]
     fn check_calendar_key(key: &[Self::CodeUnit]) -> bool;
+
+    /// Writes the code units to a sink, replacing ill-formed sequences with U+FFFD.
+    #[doc(hidden)]
+    fn write_to<W: core::fmt::Write + ?Sized>(
+        source: &[Self::CodeUnit],
+        sink: &mut W,
+    ) -> core::fmt::Result;
 }
 
 /// A marker type that signals a parser should parse the source as UTF-16 bytes.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 #[allow(clippy::exhaustive_structs)] // ZST Marker trait, no fields should be added
 pub struct Utf16;
 
@@ -51,6 +58,16 @@ impl EncodingType for Utf16 {
     fn check_calendar_key(key: &[Self::CodeUnit]) -> bool {
         key == [0x75, 0x2d, 0x63, 0x61]
     }
+
+    fn write_to<W: core::fmt::Write + ?Sized>(
+        source: &[Self::CodeUnit],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for c in char::decode_utf16(source.iter().copied()) {
+            sink.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
 }
 
 #[inline]
@@ -62,7 +79,7 @@ fn to_ascii_byte(b: u16) -> ParserResult<u8> {
 }
 
 /// A marker type that signals a parser should parse the source as UTF-8 bytes.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 #[allow(clippy::exhaustive_structs)] // ZST Marker trait, no fields should be added.
 pub struct Utf8;
 
@@ -82,4 +99,17 @@ impl EncodingType for Utf8 {
     fn check_calendar_key(key: &[Self::CodeUnit]) -> bool {
         key == "u-ca".as_bytes()
     }
+
+    fn write_to<W: core::fmt::Write + ?Sized>(
+        source: &[Self::CodeUnit],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for chunk in source.utf8_chunks() {
+            sink.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                sink.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
 }
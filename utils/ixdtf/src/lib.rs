@@ -371,6 +371,50 @@
 //! allow the user to define any known keys of their own and therefore also handle the logic around
 //! criticality.
 //!
+//! ## Writing IXDTF strings
+//!
+//! With the `writer` feature, the records implement [`Writeable`](writeable::Writeable) and
+//! [`Display`](core::fmt::Display), serializing them back to RFC 9557 strings. Records can be
+//! written after each other to add annotations, and the output of a parsed record can be parsed
+//! again into an identical record.
+//!
+//! ```rust
+//! # #[cfg(feature = "writer")] {
+//! use ixdtf::{
+//!     encoding::Utf8,
+//!     parsers::IxdtfParser,
+//!     records::{Annotation, DateRecord, IxdtfParseRecord, TimeRecord},
+//! };
+//! use writeable::{assert_writeable_eq, concat_writeable};
+//!
+//! let mut record = IxdtfParseRecord::<Utf8>::default();
+//! record.date = Some(DateRecord {
+//!     year: 2025,
+//!     month: 3,
+//!     day: 1,
+//! });
+//! record.time = Some(TimeRecord {
+//!     hour: 10,
+//!     minute: 0,
+//!     second: 0,
+//!     fraction: None,
+//! });
+//! record.calendar = Some("hebrew".as_bytes());
+//!
+//! assert_writeable_eq!(record, "2025-03-01T10:00:00[u-ca=hebrew]");
+//!
+//! // A critical annotation with an application defined key
+//! let annotation = Annotation::<Utf8>::new(true, b"answer-to-universe", b"fortytwo");
+//! assert_writeable_eq!(
+//!     concat_writeable!(&record, &annotation),
+//!     "2025-03-01T10:00:00[u-ca=hebrew][!answer-to-universe=fortytwo]"
+//! );
+//!
+//! let string = record.to_string();
+//! assert_eq!(IxdtfParser::from_str(&string).parse(), Ok(record));
+//! # }
+//! ```
+//!
 //! ## Additional grammar resources
 //!
 //! Additional resources for Date and Time string grammar can be found in [RFC3339][rfc3339]
@@ -393,6 +437,8 @@ pub(crate) mod core;
 pub mod encoding;
 pub mod parsers;
 pub mod records;
+#[cfg(feature = "writer")]
+mod writer;
 
 pub use error::ParseError;
 
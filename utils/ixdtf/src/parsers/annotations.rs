@@ -23,6 +23,7 @@ use crate::{
 pub(crate) struct AnnotationSet<'a, T: EncodingType> {
     pub(crate) tz: Option<TimeZoneAnnotation<'a, T>>,
     pub(crate) calendar: Option<&'a [T::CodeUnit]>,
+    pub(crate) calendar_critical: bool,
 }
 
 /// Parse a `TimeZoneAnnotation` `Annotations` set
@@ -40,21 +41,25 @@ pub(crate) fn parse_annotation_set<'a, T: EncodingType>(
         let calendar = parse_annotations(cursor, handler)?;
         return Ok(AnnotationSet {
             tz: tz_annotation,
-            calendar,
+            calendar: calendar.as_ref().map(|a| a.value),
+            calendar_critical: calendar.is_some_and(|a| a.critical),
         });
     }
 
     Ok(AnnotationSet {
         tz: tz_annotation,
         calendar: None,
+        calendar_critical: false,
     })
 }
 
-/// Parse any number of `KeyValueAnnotation`s
+/// Parse any number of `KeyValueAnnotation`s, returning the calendar annotation
+///
+/// The returned calendar annotation is critical if any of the calendar annotations is.
 pub(crate) fn parse_annotations<'a, T: EncodingType>(
     cursor: &mut Cursor<'a, T>,
     mut handler: impl FnMut(Annotation<'a, T>) -> Option<Annotation<'a, T>>,
-) -> ParserResult<Option<&'a [T::CodeUnit]>> {
+) -> ParserResult<Option<Annotation<'a, T>>> {
     let mut calendar: Option<Annotation<'a, T>> = None;
 
     while cursor.check_or(false, is_annotation_open)? {
@@ -64,7 +69,7 @@ pub(crate) fn parse_annotations<'a, T: EncodingType>(
             // Check if the key is the registered key "u-ca".
             Some(kv) if T::check_calendar_key(kv.key) => {
                 // Check the calendar
-                match calendar {
+                match &mut calendar {
                     Some(calendar)
                         // if calendars do not match and one of them is critical
                         if calendar.value != kv.value && (calendar.critical || kv.critical) =>
@@ -75,7 +80,11 @@ pub(crate) fn parse_annotations<'a, T: EncodingType>(
                     None => {
                         calendar = Some(kv);
                     }
-                    _ => {}
+                    Some(calendar) => {
+                        if calendar.value == kv.value {
+                            calendar.critical |= kv.critical;
+                        }
+                    }
                 }
             }
             Some(unknown_kv) if unknown_kv.critical => {
@@ -86,7 +95,7 @@ pub(crate) fn parse_annotations<'a, T: EncodingType>(
         }
     }
 
-    Ok(calendar.map(|a| a.value))
+    Ok(calendar)
 }
 
 /// Parse an annotation with an `AnnotationKey`=`AnnotationValue` pair.
@@ -55,6 +55,7 @@ pub(crate) fn parse_annotated_date_time<'a, T: EncodingType>(
             offset: date_time.time_zone,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         });
     }
 
@@ -68,6 +69,7 @@ pub(crate) fn parse_annotated_date_time<'a, T: EncodingType>(
         offset: date_time.time_zone,
         tz: annotation_set.tz,
         calendar: annotation_set.calendar,
+        calendar_critical: annotation_set.calendar_critical,
     })
 }
 
@@ -140,6 +142,7 @@ pub(crate) fn parse_annotated_year_month<'a, T: EncodingType>(
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         });
     }
 
@@ -151,6 +154,7 @@ pub(crate) fn parse_annotated_year_month<'a, T: EncodingType>(
         offset: None,
         tz: annotation_set.tz,
         calendar: annotation_set.calendar,
+        calendar_critical: annotation_set.calendar_critical,
     })
 }
 
@@ -186,6 +190,7 @@ pub(crate) fn parse_annotated_month_day<'a, T: EncodingType>(
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         });
     }
 
@@ -197,6 +202,7 @@ pub(crate) fn parse_annotated_month_day<'a, T: EncodingType>(
         offset: None,
         tz: annotation_set.tz,
         calendar: annotation_set.calendar,
+        calendar_critical: annotation_set.calendar_critical,
     })
 }
 
@@ -868,6 +868,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -885,6 +886,7 @@ fn test_correct_datetime() {
             time: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -907,6 +909,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -929,6 +932,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -951,6 +955,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -976,6 +981,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1001,6 +1007,7 @@ fn test_correct_datetime() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1109,6 +1116,7 @@ fn test_zulu_offset() {
                 tz: TimeZoneRecord::Name("America/Chicago".as_bytes())
             }),
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1131,6 +1139,7 @@ fn test_zulu_offset() {
             offset: Some(UtcOffsetRecordOrZ::Z),
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1207,6 +1216,7 @@ fn subsecond_string_tests() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1232,6 +1242,7 @@ fn subsecond_string_tests() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1257,6 +1268,7 @@ fn subsecond_string_tests() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1282,6 +1294,7 @@ fn subseconds_parsing_extended_nanoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1304,6 +1317,7 @@ fn subseconds_parsing_extended_nanoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1329,6 +1343,7 @@ fn subseconds_parsing_extended_picoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1351,6 +1366,7 @@ fn subseconds_parsing_extended_picoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1376,6 +1392,7 @@ fn subseconds_parsing_extended_femtoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1398,6 +1415,7 @@ fn subseconds_parsing_extended_femtoseconds() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1423,6 +1441,7 @@ fn subseconds_parsing_extended_truncated() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 
@@ -1445,6 +1464,7 @@ fn subseconds_parsing_extended_truncated() {
             offset: None,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         })
     );
 }
@@ -1515,6 +1535,7 @@ fn utf16_basic_test() {
                     tz: TimeZoneRecord::Name(id),
                 }),
             calendar: None,
+            calendar_critical: false,
         }) => id,
         _ => unreachable!(),
     };
@@ -55,6 +55,7 @@ pub(crate) fn parse_annotated_time_record<'a, T: EncodingType>(
             offset,
             tz: None,
             calendar: None,
+            calendar_critical: false,
         });
     }
 
@@ -69,6 +70,7 @@ pub(crate) fn parse_annotated_time_record<'a, T: EncodingType>(
         offset,
         tz: annotations.tz,
         calendar: annotations.calendar,
+        calendar_critical: annotations.calendar_critical,
     })
 }
 
@@ -22,6 +22,8 @@ pub struct IxdtfParseRecord<'a, T: EncodingType> {
     pub tz: Option<TimeZoneAnnotation<'a, T>>,
     /// The parsed calendar value.
     pub calendar: Option<&'a [T::CodeUnit]>,
+    /// Whether the calendar annotation is critical, as in `[!u-ca=iso8601]`.
+    pub calendar_critical: bool,
 }
 
 #[non_exhaustive]
@@ -36,6 +38,17 @@ pub struct Annotation<'a, T: EncodingType> {
     pub value: &'a [T::CodeUnit],
 }
 
+impl<'a, T: EncodingType> Annotation<'a, T> {
+    /// Creates an `Annotation` from its key and value.
+    pub fn new(critical: bool, key: &'a [T::CodeUnit], value: &'a [T::CodeUnit]) -> Self {
+        Self {
+            critical,
+            key,
+            value,
+        }
+    }
+}
+
 #[allow(clippy::exhaustive_structs)] // DateRecord only allows for a year, month, and day value.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 /// The record of a parsed date.
@@ -72,6 +85,13 @@ pub struct TimeZoneAnnotation<'a, T: EncodingType> {
     pub tz: TimeZoneRecord<'a, T>,
 }
 
+impl<'a, T: EncodingType> TimeZoneAnnotation<'a, T> {
+    /// Creates a `TimeZoneAnnotation` from a `TimeZoneRecord`.
+    pub fn new(critical: bool, tz: TimeZoneRecord<'a, T>) -> Self {
+        Self { critical, tz }
+    }
+}
+
 /// Parsed `TimeZone` data, which can be either a UTC Offset value or IANA Time Zone Name value.
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
@@ -303,6 +323,34 @@ pub struct Fraction {
 }
 
 impl Fraction {
+    /// Creates a `Fraction` from a nanosecond value, omitting trailing zero digits.
+    ///
+    /// Returns `None` if the value is zero or exceeds 999,999,999 nanoseconds.
+    ///
+    /// ```rust
+    /// use ixdtf::records::Fraction;
+    ///
+    /// let fraction = Fraction::from_nanoseconds(45_000_000).unwrap();
+    /// assert_eq!(fraction.to_nanoseconds(), Some(45_000_000));
+    ///
+    /// assert_eq!(Fraction::from_nanoseconds(0), None);
+    /// ```
+    pub fn from_nanoseconds(nanoseconds: u32) -> Option<Self> {
+        if nanoseconds == 0 || nanoseconds > 999_999_999 {
+            return None;
+        }
+        let mut value = nanoseconds;
+        let mut digits = 9;
+        while value % 10 == 0 {
+            value /= 10;
+            digits -= 1;
+        }
+        Some(Self {
+            digits: NonZeroU8::new(digits)?,
+            value: u64::from(value),
+        })
+    }
+
     /// Returns Some(`u32`) representing the `Fraction` as it's computed
     /// nanosecond value or `None` if the digits exceeds 9 digits.
     ///
@@ -0,0 +1,442 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`Writeable`] implementations that serialize the records back to RFC 9557 strings.
+
+use core::fmt::{self, Write};
+
+use writeable::{LengthHint, Writeable};
+
+use crate::encoding::EncodingType;
+use crate::records::{
+    Annotation, DateRecord, Fraction, FullPrecisionOffset, IxdtfParseRecord, MinutePrecisionOffset,
+    Sign, TimeRecord, TimeZoneAnnotation, TimeZoneRecord, UtcOffsetRecord, UtcOffsetRecordOrZ,
+};
+#[cfg(feature = "duration")]
+use crate::records::{DateDurationRecord, DurationParseRecord, TimeDurationRecord};
+
+macro_rules! impl_display {
+    ($type:ty $(, where $($generics:tt)*)?) => {
+        impl $(<$($generics)*>)? fmt::Display for $type {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.write_to(f)
+            }
+        }
+    };
+}
+
+/// Writes the digits of a fraction, without the leading decimal separator.
+impl Writeable for Fraction {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write!(
+            sink,
+            "{:0width$}",
+            self.value,
+            width = usize::from(self.digits.get())
+        )
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::exact(usize::from(self.digits.get()))
+    }
+}
+
+impl_display!(Fraction);
+
+fn write_fraction<W: Write + ?Sized>(fraction: Option<Fraction>, sink: &mut W) -> fmt::Result {
+    if let Some(fraction) = fraction {
+        sink.write_char('.')?;
+        fraction.write_to(sink)?;
+    }
+    Ok(())
+}
+
+fn fraction_length_hint(fraction: Option<Fraction>) -> LengthHint {
+    match fraction {
+        Some(fraction) => fraction.writeable_length_hint() + 1,
+        None => LengthHint::exact(0),
+    }
+}
+
+/// Writes `YYYY-MM-DD`, or `±YYYYYY-MM-DD` for years outside of 0 to 9999.
+impl Writeable for DateRecord {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(sink, "{:04}", self.year)?;
+        } else {
+            let sign = if self.year < 0 { '-' } else { '+' };
+            write!(sink, "{sign}{:06}", self.year.unsigned_abs())?;
+        }
+        write!(sink, "-{:02}-{:02}", self.month, self.day)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        if (0..=9999).contains(&self.year) {
+            LengthHint::exact(10)
+        } else {
+            LengthHint::at_least(13)
+        }
+    }
+}
+
+impl_display!(DateRecord);
+
+/// Writes `HH:MM:SS`, followed by the fraction if present.
+impl Writeable for TimeRecord {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write!(
+            sink,
+            "{:02}:{:02}:{:02}",
+            self.hour, self.minute, self.second
+        )?;
+        write_fraction(self.fraction, sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        fraction_length_hint(self.fraction) + 8
+    }
+}
+
+impl_display!(TimeRecord);
+
+/// Writes `±HH:MM`.
+impl Writeable for MinutePrecisionOffset {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let sign = match self.sign {
+            Sign::Positive => '+',
+            Sign::Negative => '-',
+        };
+        write!(sink, "{sign}{:02}:{:02}", self.hour, self.minute)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::exact(6)
+    }
+}
+
+impl_display!(MinutePrecisionOffset);
+
+/// Writes `±HH:MM:SS`, followed by the fraction if present.
+impl Writeable for FullPrecisionOffset {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.minute_precision_offset.write_to(sink)?;
+        write!(sink, ":{:02}", self.second)?;
+        write_fraction(self.fraction, sink)
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        fraction_length_hint(self.fraction) + 9
+    }
+}
+
+impl_display!(FullPrecisionOffset);
+
+impl Writeable for UtcOffsetRecord {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Self::MinutePrecision(offset) => offset.write_to(sink),
+            Self::FullPrecisionOffset(offset) => offset.write_to(sink),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match self {
+            Self::MinutePrecision(offset) => offset.writeable_length_hint(),
+            Self::FullPrecisionOffset(offset) => offset.writeable_length_hint(),
+        }
+    }
+}
+
+impl_display!(UtcOffsetRecord);
+
+impl Writeable for UtcOffsetRecordOrZ {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Self::Offset(offset) => offset.write_to(sink),
+            Self::Z => sink.write_char('Z'),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match self {
+            Self::Offset(offset) => offset.writeable_length_hint(),
+            Self::Z => LengthHint::exact(1),
+        }
+    }
+}
+
+impl_display!(UtcOffsetRecordOrZ);
+
+/// Writes the time zone identifier, without brackets.
+impl<T: EncodingType> Writeable for TimeZoneRecord<'_, T> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Self::Name(name) => T::write_to(name, sink),
+            Self::Offset(offset) => offset.write_to(sink),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        match self {
+            Self::Name(name) => LengthHint::at_least(name.len()),
+            Self::Offset(offset) => offset.writeable_length_hint(),
+        }
+    }
+}
+
+impl_display!(TimeZoneRecord<'_, T>, where T: EncodingType);
+
+/// Writes `[Europe/Paris]`, or `[!Europe/Paris]` if the annotation is critical.
+impl<T: EncodingType> Writeable for TimeZoneAnnotation<'_, T> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_char('[')?;
+        if self.critical {
+            sink.write_char('!')?;
+        }
+        self.tz.write_to(sink)?;
+        sink.write_char(']')
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        self.tz.writeable_length_hint() + 2 + usize::from(self.critical)
+    }
+}
+
+impl_display!(TimeZoneAnnotation<'_, T>, where T: EncodingType);
+
+/// Writes `[key=value]`, or `[!key=value]` if the annotation is critical.
+impl<T: EncodingType> Writeable for Annotation<'_, T> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_char('[')?;
+        if self.critical {
+            sink.write_char('!')?;
+        }
+        T::write_to(self.key, sink)?;
+        sink.write_char('=')?;
+        T::write_to(self.value, sink)?;
+        sink.write_char(']')
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        LengthHint::at_least(self.key.len() + self.value.len() + 3 + usize::from(self.critical))
+    }
+}
+
+impl_display!(Annotation<'_, T>, where T: EncodingType);
+
+/// Writes the date, time, offset, time zone annotation, and calendar annotation that are
+/// present.
+///
+/// A time without a date is written without the `T` designator, as accepted by
+/// [`IxdtfParser::parse_time`](crate::parsers::IxdtfParser::parse_time).
+impl<T: EncodingType> Writeable for IxdtfParseRecord<'_, T> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        if let Some(date) = self.date {
+            date.write_to(sink)?;
+            if self.time.is_some() {
+                sink.write_char('T')?;
+            }
+        }
+        if let Some(time) = self.time {
+            time.write_to(sink)?;
+        }
+        if let Some(offset) = self.offset {
+            offset.write_to(sink)?;
+        }
+        if let Some(tz) = &self.tz {
+            tz.write_to(sink)?;
+        }
+        if let Some(calendar) = self.calendar {
+            sink.write_char('[')?;
+            if self.calendar_critical {
+                sink.write_char('!')?;
+            }
+            sink.write_str("u-ca=")?;
+            T::write_to(calendar, sink)?;
+            sink.write_char(']')?;
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> LengthHint {
+        let mut hint = LengthHint::exact(0);
+        if let Some(date) = self.date {
+            hint += date.writeable_length_hint();
+            if self.time.is_some() {
+                hint += 1;
+            }
+        }
+        if let Some(time) = self.time {
+            hint += time.writeable_length_hint();
+        }
+        if let Some(offset) = self.offset {
+            hint += offset.writeable_length_hint();
+        }
+        if let Some(tz) = &self.tz {
+            hint += tz.writeable_length_hint();
+        }
+        if let Some(calendar) = self.calendar {
+            hint += LengthHint::at_least(calendar.len() + 7 + usize::from(self.calendar_critical));
+        }
+        hint
+    }
+}
+
+impl_display!(IxdtfParseRecord<'_, T>, where T: EncodingType);
+
+/// Writes an ISO 8601 duration such as `-P1Y2M3W4DT5H6M7.5S`.
+///
+/// Date components that are zero are omitted, as are time components before the last one.
+/// A duration without components is written as `PT0S`.
+#[cfg(feature = "duration")]
+impl Writeable for DurationParseRecord {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        if self.sign == Sign::Negative {
+            sink.write_char('-')?;
+        }
+        sink.write_char('P')?;
+        if let Some(DateDurationRecord {
+            years,
+            months,
+            weeks,
+            days,
+        }) = self.date
+        {
+            for (value, designator) in [(years, 'Y'), (months, 'M'), (weeks, 'W')] {
+                if value != 0 {
+                    write!(sink, "{value}{designator}")?;
+                }
+            }
+            if days != 0 || (years == 0 && months == 0 && weeks == 0 && self.time.is_none()) {
+                write!(sink, "{days}D")?;
+            }
+        }
+        fn write_unit<W: Write + ?Sized>(
+            sink: &mut W,
+            value: u64,
+            designator: char,
+        ) -> fmt::Result {
+            if value != 0 {
+                write!(sink, "{value}{designator}")?;
+            }
+            Ok(())
+        }
+        match self.time {
+            Some(TimeDurationRecord::Hours { hours, fraction }) => {
+                write!(sink, "T{hours}")?;
+                write_fraction(fraction, sink)?;
+                sink.write_char('H')?;
+            }
+            Some(TimeDurationRecord::Minutes {
+                hours,
+                minutes,
+                fraction,
+            }) => {
+                sink.write_char('T')?;
+                write_unit(sink, hours, 'H')?;
+                write!(sink, "{minutes}")?;
+                write_fraction(fraction, sink)?;
+                sink.write_char('M')?;
+            }
+            Some(TimeDurationRecord::Seconds {
+                hours,
+                minutes,
+                seconds,
+                fraction,
+            }) => {
+                sink.write_char('T')?;
+                write_unit(sink, hours, 'H')?;
+                write_unit(sink, minutes, 'M')?;
+                write!(sink, "{seconds}")?;
+                write_fraction(fraction, sink)?;
+                sink.write_char('S')?;
+            }
+            None if self.date.is_none() => sink.write_str("T0S")?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "duration")]
+impl_display!(DurationParseRecord);
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::Utf16;
+    use crate::parsers::{IsoDurationParser, IxdtfParser, TimeZoneParser};
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn round_trip() {
+        for s in [
+            "2025-03-01T10:00:00+01:00[Europe/Paris][u-ca=hebrew]",
+            "2025-03-01T10:00:00.045Z[!America/New_York]",
+            "2025-03-01T10:00:00[!u-ca=islamic-civil]",
+            "2025-03-01[!Asia/Tokyo][!u-ca=japanese]",
+            "-000001-01-01T00:00:00-05:30:15.5[-05:30]",
+            "+010000-12-31",
+            "2025-03-01T23:59:59.123456789",
+        ] {
+            let record = IxdtfParser::from_str(s).parse().unwrap();
+            assert_writeable_eq!(record, s);
+            assert_eq!(
+                IxdtfParser::from_str(&record.to_string()).parse(),
+                Ok(record)
+            );
+        }
+
+        let time = IxdtfParser::from_str("T10:00:05.5").parse_time().unwrap();
+        assert_writeable_eq!(time, "10:00:05.5");
+
+        let utf16 = "2025-03-01T10:00:00[Europe/Paris][u-ca=japanese]"
+            .encode_utf16()
+            .collect::<Vec<_>>();
+        let record = IxdtfParser::<Utf16>::new(&utf16).parse().unwrap();
+        assert_writeable_eq!(record, "2025-03-01T10:00:00[Europe/Paris][u-ca=japanese]");
+
+        let offset = TimeZoneParser::from_str("-08:00").parse_offset().unwrap();
+        assert_writeable_eq!(offset, "-08:00");
+    }
+
+    #[test]
+    fn normalization() {
+        let record = IxdtfParser::from_str("20250301T1000+0100[u-ca=gregory]")
+            .parse()
+            .unwrap();
+        assert_writeable_eq!(record, "2025-03-01T10:00:00+01:00[u-ca=gregory]");
+
+        // Identical calendar annotations are merged, and critical if any of them is
+        let record = IxdtfParser::from_str("2025-03-01[u-ca=gregory][!u-ca=gregory]")
+            .parse()
+            .unwrap();
+        assert!(record.calendar_critical);
+        assert_writeable_eq!(record, "2025-03-01[!u-ca=gregory]");
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn duration() {
+        for s in [
+            "P1Y2M3W4DT5H6M7.5S",
+            "-P1D",
+            "P0D",
+            "PT0S",
+            "PT1.25H",
+            "P1MT2H30M",
+            "PT90M",
+        ] {
+            let record = IsoDurationParser::from_str(s).parse().unwrap();
+            assert_writeable_eq!(record, s);
+        }
+
+        let record = IsoDurationParser::from_str("P0Y0MT0H10M").parse().unwrap();
+        assert_writeable_eq!(record, "PT10M");
+        assert_eq!(
+            IsoDurationParser::from_str("PT10M").parse().unwrap().time,
+            record.time
+        );
+    }
+}
@@ -16,8 +16,8 @@ fn main() {
         .expect("Failed to generate binary bundle.");
 
     if bytes != include_bytes!("data/zoneinfo64.res") {
-        // TODO: This does not currently generate a file that matches ICU4C's, and the file it generates
-        // does not parse either.
+        // TODO: This does not currently generate a file that matches ICU4C's byte-for-byte, even
+        // though the file it generates parses to the same data.
         // std::fs::write(
         //     concat!(env!("CARGO_MANIFEST_DIR"), "/examples/data/zoneinfo64.res"),
         //     bytes,
@@ -14,7 +14,7 @@ pub mod helpers;
 #[cfg(feature = "serialize")]
 mod serializer;
 #[cfg(feature = "serialize")]
-pub use self::serializer::Serializer;
+pub use self::serializer::{BinarySerializerError, Serializer};
 
 use core::{fmt, slice::SliceIndex};
 
@@ -210,7 +210,9 @@ impl<'de> de::Deserializer<'de> for &mut ResourceTreeDeserializer<'de> {
             ResourceReprType::Table | ResourceReprType::Table16 | ResourceReprType::_Table32 => {
                 self.deserialize_map(visitor)
             }
-            ResourceReprType::_Alias => todo!(),
+            ResourceReprType::_Alias => Err(BinaryDeserializerError::unsupported_format(
+                "alias resources are not supported",
+            )),
             ResourceReprType::Int => self.deserialize_u32(visitor),
             ResourceReprType::Array | ResourceReprType::Array16 | ResourceReprType::IntVector => {
                 self.deserialize_seq(visitor)
@@ -334,7 +336,9 @@ impl<'de> de::Deserializer<'de> for &mut ResourceTreeDeserializer<'de> {
     {
         let descriptor = self.get_next_resource_descriptor()?;
         match descriptor.resource_type() {
-            ResourceReprType::_String => todo!(),
+            ResourceReprType::_String => Err(BinaryDeserializerError::unsupported_format(
+                "32-bit string resources are not supported",
+            )),
             ResourceReprType::StringV2 => {
                 if let Some(data_16_bit) = self.data_16_bit {
                     if descriptor.is_empty() {
@@ -591,8 +595,37 @@ impl<'de> de::Deserializer<'de> for &mut ResourceTreeDeserializer<'de> {
                     remaining: length as usize,
                 })
             }
-            ResourceReprType::_Table32 => todo!(),
-            ResourceReprType::Table16 => todo!(),
+            ResourceReprType::Table16 => {
+                if descriptor.is_empty() {
+                    // Handle empty descriptors per-type so we don't miss a type
+                    // mismatch.
+                    return visitor.visit_map(EmptyMapAccess);
+                }
+
+                let Some(data_16_bit) = self.data_16_bit else {
+                    return Err(BinaryDeserializerError::invalid_data(
+                        "Table16 resource with no 16-bit data",
+                    ));
+                };
+
+                let input = get_subslice(data_16_bit, descriptor.value_as_16_bit_offset()..)?;
+                let (length, keys) = read_u16(input)?;
+
+                // Values are 16-bit offsets immediately following the keys,
+                // without padding.
+                let values = get_subslice(keys, length as usize * size_of::<u16>()..)?;
+
+                visitor.visit_map(Table16MapAccess {
+                    key_block: self.keys,
+                    data_16_bit,
+                    keys,
+                    values,
+                    remaining: length as usize,
+                })
+            }
+            ResourceReprType::_Table32 => Err(BinaryDeserializerError::unsupported_format(
+                "table resource type is not supported",
+            )),
             _ => Err(BinaryDeserializerError::resource_type_mismatch(
                 "expected table resource",
             )),
@@ -865,6 +898,64 @@ impl<'de> de::MapAccess<'de> for TableMapAccess<'de, '_> {
     }
 }
 
+/// The `Table16MapAccess` struct provides deserialization for resources of type
+/// `Table16`.
+///
+/// See [`ResourceReprType`] for more details.
+struct Table16MapAccess<'de> {
+    key_block: &'de [u8],
+    data_16_bit: &'de [u8],
+    keys: &'de [u8],
+    values: &'de [u8],
+    remaining: usize,
+}
+
+impl<'de> de::MapAccess<'de> for Table16MapAccess<'de> {
+    type Error = BinaryDeserializerError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        // Keys are stored as a sequence of byte offsets into the key block. Pop
+        // one and feed it into the specialized key deserializer.
+        let (key, keys) = read_u16(self.keys)?;
+        self.keys = keys;
+        self.remaining -= 1;
+
+        let input = get_subslice(self.key_block, key as usize..).or(const {
+            Err(BinaryDeserializerError::invalid_data(
+                "unexpected end of data while deserializing key",
+            ))
+        })?;
+
+        let de = KeyDeserializer::new(input);
+        seed.deserialize(de).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        // Values are stored as a sequence of `u16` offsets into the 16-bit
+        // data block. Pop one and deserialize the corresponding resource.
+        let (offset, values) = read_u16(self.values)?;
+        self.values = values;
+
+        let input = get_subslice(self.data_16_bit, (offset as usize) * size_of::<u16>()..)?;
+        let de = Resource16BitDeserializer::new(input);
+        seed.deserialize(de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
 /// The `Resource16BitDeserializer` struct processes resources which are a part
 /// of the 16-bit data block of the resource bundle. A resource will be in the
 /// 16-bit data block if and only if it is a `StringV2`.
@@ -986,10 +1077,10 @@ impl<'de> de::Deserializer<'de> for IntVectorDeserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        // The contents of `IntVector`s are always 32-bit integers. We can
-        // safely generalize to `u32`, as there's no special handling needed
-        // for signed integers.
-        self.deserialize_u32(visitor)
+        // The contents of `IntVector`s are always 32-bit integers, which ICU4C
+        // exposes as signed integers. Unlike 28-bit integers, there's no
+        // special handling needed for them.
+        self.deserialize_i32(visitor)
     }
 
     forward_to_deserialize_any! {
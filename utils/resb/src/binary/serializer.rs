@@ -360,8 +360,11 @@ impl Serializer {
 
         // Pad the key block such that the end is aligned with a 32-bit
         // boundary.
-        let padding = (block_start_position as usize + key_block.len()) % 3;
-        key_block.resize(key_block.len() + padding, 0xaa);
+        let position = block_start_position as usize + key_block.len();
+        let u32_size = size_of::<u32>();
+        if position % u32_size != 0 {
+            key_block.resize(key_block.len() + (u32_size - position % u32_size), 0xaa);
+        }
 
         key_block
     }
@@ -480,7 +483,7 @@ impl Serializer {
                 #[expect(clippy::unwrap_used)]
                 let suffix_data = strings.get(suffix).unwrap();
                 suffix_data.borrow_mut().offset =
-                    (string.chars().count() - suffix.chars().count()) as u32;
+                    (string.encode_utf16().count() - suffix.encode_utf16().count()) as u32;
                 suffix_data.borrow_mut().containing_string = Some(string);
 
                 // Update the characters saved by the containing string.
@@ -528,12 +531,12 @@ impl Serializer {
             data.borrow_mut().offset = data_16_bit.len() as u32;
 
             // Build a length marker for the string if one is required.
-            let length = string.chars().count();
+            let length = string.encode_utf16().count();
             let length_words = get_string_length_marker_size(string)?;
 
             match length_words {
                 0 => (),
-                1 => data_16_bit.push(0xdc00 & length as u16),
+                1 => data_16_bit.push(0xdc00 | length as u16),
                 2 => {
                     data_16_bit.push(0xdfef + (length >> 16) as u16);
                     data_16_bit.push(length as u16);
@@ -661,8 +664,8 @@ impl Serializer {
                 ));
 
                 data_16_bit.push(size as u16);
-                for position in key_position_map.values() {
-                    data_16_bit.push(*position as u16);
+                for key in map.keys() {
+                    data_16_bit.push(*key_position_map.get(key)? as u16);
                 }
                 for descriptor in data_16_bit_offsets {
                     data_16_bit.push(descriptor);
@@ -906,7 +909,7 @@ impl Serializer {
 ///
 /// This count includes the string length marker and a null terminator.
 fn get_total_string_size(string: &str) -> Result<usize, BinarySerializerError> {
-    Ok(string.chars().count() + get_string_length_marker_size(string)? + 1)
+    Ok(string.encode_utf16().count() + get_string_length_marker_size(string)? + 1)
 }
 
 /// Gets the size of the length marker of a UTF-16 string in 16-bit characters.
@@ -917,7 +920,7 @@ fn get_total_string_size(string: &str) -> Result<usize, BinarySerializerError> {
 ///
 /// For more details, see [`ResourceReprType::StringV2`].
 fn get_string_length_marker_size(string: &str) -> Result<usize, BinarySerializerError> {
-    let length = match string.chars().count() {
+    let length = match string.encode_utf16().count() {
         0..=40 => 0,
         41..=0x3ee => 1,
         0x3ef..=0xf_ffff => 2,
@@ -1232,3 +1235,52 @@ enum ErrorKind {
     StringTooLong(usize),
     Unexpected(&'static str),
 }
+
+#[cfg(all(test, feature = "text"))]
+mod tests {
+    use super::*;
+    use crate::text::Reader;
+
+    fn round_trip<T: serde_core::de::DeserializeOwned>(input: &str) -> T {
+        let (bundle, keys) = Reader::read(input).expect("valid text bundle");
+        let bytes = Serializer::to_bytes(&bundle, &keys).expect("serializable bundle");
+        let words = bytes
+            .chunks_exact(4)
+            .map(|word| u32::from_ne_bytes(word.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        crate::binary::from_words(&words).expect("serialized bundle should be readable")
+    }
+
+    #[test]
+    fn strings() {
+        let long = "a string which is too long to be stored without a length marker";
+        let strings: BTreeMap<String, String> = round_trip(&format!(
+            r#"root{{ abc{{"x"}} d{{"yx"}} long{{"{long}"}} suffix{{"marker"}} emoji{{"😀"}} }}"#
+        ));
+        assert_eq!(
+            strings,
+            [
+                ("abc", "x"),
+                ("d", "yx"),
+                ("emoji", "😀"),
+                ("long", long),
+                ("suffix", "marker"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+        );
+    }
+
+    #[test]
+    fn int_vectors() {
+        let vectors: BTreeMap<String, Vec<u32>> =
+            round_trip("root{ a:intvector{ 1, 2 } bc:intvector{ 3 } }");
+        assert_eq!(
+            vectors,
+            [("a".to_string(), vec![1, 2]), ("bc".to_string(), vec![3])]
+                .into_iter()
+                .collect()
+        );
+    }
+}
@@ -93,12 +93,23 @@ pub type Table<'a> = BTreeMap<Key<'a>, Resource<'a>>;
 pub struct Key<'a>(Cow<'a, str>);
 
 impl Key<'_> {
+    /// Gets the string representing the key.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
     /// Converts the string representing the key into a slice of UTF-8 bytes.
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
 }
 
+impl<'a> From<Cow<'a, str>> for Key<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self(value)
+    }
+}
+
 impl<'a> From<&'a str> for Key<'a> {
     fn from(value: &'a str) -> Self {
         Self(Cow::from(value))
@@ -20,7 +20,7 @@
 //! resource bundle files.
 //!
 //! It comprises modules for reading and optionally writing [`binary`] `.res`
-//! files as well as optionally for reading [`text`] bundles.
+//! files as well as optionally for reading and writing [`text`] bundles.
 //!
 //! [`ICU4X`]: ../icu/index.html
 
@@ -2,12 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! The `text` module provides a reader for the text-based resource bundle
-//! format.
+//! The `text` module provides a reader and a writer for the text-based
+//! resource bundle format.
 //!
-//! WARNING: This reader is intended for use in development-time tools and is
-//! not written with runtime efficiency in mind.
+//! WARNING: This reader and writer are intended for use in development-time
+//! tools and are not written with runtime efficiency in mind.
 
 mod reader;
+mod writer;
 
 pub use reader::Reader;
+pub use writer::Writer;
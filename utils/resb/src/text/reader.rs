@@ -14,7 +14,7 @@ use nom::{
     error::{context, convert_error, make_error, ContextError, ParseError, VerboseError},
     multi::{many0, separated_list0},
     sequence::{delimited, pair, preceded, terminated},
-    Finish, IResult, Parser,
+    Finish, IResult, InputLength, Parser, Slice,
 };
 
 use crate::bundle::{Int28, Key, Resource, ResourceBundle, Table};
@@ -138,7 +138,7 @@ where
 {
     context(
         "invariant_chars",
-        take_while1(|c: char| c.is_alphanumeric() || c == '-'),
+        take_while1(|c: char| c.is_alphanumeric() || c == '-' || c == '_'),
     )(input)
 }
 
@@ -171,34 +171,110 @@ where
     context("comment", alt((eol_comment, delimited_comment)))(input)
 }
 
+/// Reads the character represented by an escape sequence, not including the
+/// leading backslash.
+///
+/// Returns the character and the remainder of the input following the escape
+/// sequence.
+fn escaped_char(input: &str) -> Option<(char, &str)> {
+    let mut chars = input.chars();
+    let escaped = match chars.next()? {
+        'u' => return hex_char(chars.as_str(), 4),
+        'U' => return hex_char(chars.as_str(), 8),
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        c @ ('"' | '\\') => c,
+        _ => return None,
+    };
+
+    Some((escaped, chars.as_str()))
+}
+
+/// Reads a character from the given number of hex digits at the beginning of
+/// the input.
+fn hex_char(input: &str, digits: usize) -> Option<(char, &str)> {
+    let (hex, rest) = input.split_at_checked(digits)?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some((char::from_u32(u32::from_str_radix(hex, 16).ok()?)?, rest))
+}
+
+/// Reads a quote-delimited string, resolving any character escapes.
+///
+/// The escapes `\\`, `\"`, `\n`, `\r`, `\t`, `\uXXXX`, and `\UXXXXXXXX` are
+/// supported. Surrogate pairs expressed as two `\u` escapes are not.
+fn quoted_string<'a, E>(input: ParseState<'a>) -> IResult<ParseState<'a>, Cow<'a, str>, E>
+where
+    E: ParseError<ParseState<'a>> + ContextError<ParseState<'a>>,
+{
+    let (start, _) = tag("\"")(input)?;
+
+    let mut rest = start.input();
+    let mut unescaped: Option<String> = None;
+    loop {
+        let Some(position) = rest.find(['"', '\\']) else {
+            return Err(nom::Err::Error(make_error(
+                start,
+                nom::error::ErrorKind::TakeUntil,
+            )));
+        };
+
+        let (chunk, tail) = rest.split_at(position);
+        let mut tail_chars = tail.chars();
+        if tail_chars.next() == Some('"') {
+            let value = match unescaped {
+                Some(mut unescaped) => {
+                    unescaped.push_str(chunk);
+                    Cow::Owned(unescaped)
+                }
+                None => Cow::Borrowed(chunk),
+            };
+
+            let remaining = tail_chars.as_str();
+            let consumed = start.input_len() - remaining.len();
+            return Ok((start.slice(consumed..), value));
+        }
+
+        let Some((escaped, after)) = escaped_char(tail_chars.as_str()) else {
+            let consumed = start.input_len() - tail.len();
+            return Err(nom::Err::Error(make_error(
+                start.slice(consumed..),
+                nom::error::ErrorKind::Escaped,
+            )));
+        };
+
+        let unescaped = unescaped.get_or_insert_with(String::new);
+        unescaped.push_str(chunk);
+        unescaped.push(escaped);
+        rest = after;
+    }
+}
+
 /// Reads a string.
 ///
 /// Strings may be quote-delimited or not. While the partial specification
 /// indicates that adjacent strings are combined, this is not supported in the
-/// current version of the parser. No support for character escapes is
-/// indicated.
+/// current version of the parser. Quote-delimited strings may contain
+/// character escapes; see [`quoted_string`].
 ///
 /// The specification is unclear on how string encoding is to be handled, so
 /// this parser assumes that strings are to be well-formed in the same encoding
 /// as the rest of the file and representable in Unicode.
 ///
-/// The specification also allows for the use of `\u` and `\U` literals. Support
-/// for these has also been omitted for the time being.
-///
 /// See [`Reader`] for more details on the specification.
-fn string<'a, E>(input: ParseState<'a>) -> IResult<ParseState<'a>, &'a str, E>
+fn string<'a, E>(input: ParseState<'a>) -> IResult<ParseState<'a>, Cow<'a, str>, E>
 where
     E: ParseError<ParseState<'a>> + ContextError<ParseState<'a>>,
 {
     context(
         "string",
-        map(
-            token(alt((
-                delimited(tag("\""), take_until("\""), tag("\"")),
-                invariant_chars,
-            ))),
-            |value| value.input(),
-        ),
+        token(alt((
+            quoted_string,
+            map(invariant_chars, |value| Cow::Borrowed(value.input())),
+        ))),
     )(input)
 }
 
@@ -432,7 +508,7 @@ where
 {
     let (rest, string) = resource!(binary_type, string).parse(input.clone())?;
 
-    let (_, elements) = match many0(binary_byte).parse(string) {
+    let (_, elements) = match many0(binary_byte).parse(&string) {
         Ok(elements) => elements,
         Err(err) => {
             println!("{err}");
@@ -460,7 +536,7 @@ where
         "string_resource",
         map(
             alt((resource_opt_tag!(string_type, string), string)),
-            |value| Resource::String(Cow::from(value)),
+            Resource::String,
         ),
     )(input)
 }
@@ -501,7 +577,7 @@ where
                 eof,
             ),
             |(name, (is_locale_fallback_enabled, root))| {
-                ResourceBundle::new(Cow::from(name), root, is_locale_fallback_enabled)
+                ResourceBundle::new(name, root, is_locale_fallback_enabled)
             },
         ),
     )(input)
@@ -0,0 +1,232 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::fmt::{self, Write};
+
+use crate::bundle::{Resource, ResourceBundle, Table};
+
+/// The number of spaces by which each level of the resource tree is indented.
+const INDENT_WIDTH: usize = 4;
+
+/// The `Writer` struct provides a means of writing a [`ResourceBundle`] in the
+/// text-based resource bundle format.
+///
+/// The output can be read by [`Reader`](super::Reader) as well as by ICU4C's
+/// `genrb` tool. Strings are always quote-delimited, with quotation marks,
+/// backslashes, and control characters escaped. Array, binary, integer, and
+/// integer vector resources are written with their type IDs so that they are
+/// read back as the same type of resource.
+///
+/// Because tables sort their entries by key, the order of table entries in the
+/// output may differ from the order in the text the bundle was read from.
+///
+/// # Examples
+///
+/// ```
+/// use resb::text::{Reader, Writer};
+///
+/// let input = r#"de{ greeting{"Hallo \"Welt\""} sizes:intvector{ 1, 2, 3 } }"#;
+///
+/// let (bundle, _) = Reader::read(input).expect("valid text bundle");
+/// let output = Writer::to_string(&bundle);
+///
+/// assert_eq!(
+///     output,
+///     concat!(
+///         "de{\n",
+///         "    greeting{\"Hallo \\\"Welt\\\"\"}\n",
+///         "    sizes:intvector{ 1, 2, 3 }\n",
+///         "}\n",
+///     )
+/// );
+///
+/// // The output can be read back
+/// let (reread, _) = Reader::read(&output).expect("valid text bundle");
+/// assert_eq!(Writer::to_string(&reread), output);
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Writer;
+
+impl Writer {
+    /// Writes the given resource bundle to `out` in the text format.
+    pub fn write<W: Write + ?Sized>(bundle: &ResourceBundle, out: &mut W) -> fmt::Result {
+        write_name(bundle.name(), out)?;
+        match bundle.root() {
+            Resource::Table(table) => {
+                if !bundle.is_locale_fallback_enabled {
+                    out.write_str(":table(nofallback)")?;
+                }
+                write_table_body(table, 0, out)?;
+            }
+            // The text format requires a table at the root, but we write other
+            // resources faithfully rather than failing.
+            root => write_resource(root, 0, out)?,
+        }
+
+        out.write_char('\n')
+    }
+
+    /// Writes the given resource bundle to a string in the text format.
+    pub fn to_string(bundle: &ResourceBundle) -> String {
+        let mut out = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = Self::write(bundle, &mut out);
+
+        out
+    }
+}
+
+/// Writes a key or bundle name, delimiting it with quotation marks if it
+/// contains characters which may not appear in a bare string.
+fn write_name<W: Write + ?Sized>(name: &str, out: &mut W) -> fmt::Result {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        out.write_str(name)
+    } else {
+        write_quoted_string(name, out)
+    }
+}
+
+/// Writes a quote-delimited string, escaping characters as necessary.
+fn write_quoted_string<W: Write + ?Sized>(value: &str, out: &mut W) -> fmt::Result {
+    out.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            c if c.is_control() => write!(out, "\\u{:04X}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+/// Writes the indentation for the given depth in the resource tree.
+fn write_indent<W: Write + ?Sized>(depth: usize, out: &mut W) -> fmt::Result {
+    write!(out, "{:width$}", "", width = depth * INDENT_WIDTH)
+}
+
+/// Writes the entries of a table resource, delimited by curly braces.
+fn write_table_body<W: Write + ?Sized>(table: &Table, depth: usize, out: &mut W) -> fmt::Result {
+    out.write_str("{\n")?;
+    for (key, resource) in table {
+        write_indent(depth + 1, out)?;
+        write_name(key.as_str(), out)?;
+        write_resource(resource, depth + 1, out)?;
+        out.write_char('\n')?;
+    }
+    write_indent(depth, out)?;
+    out.write_char('}')
+}
+
+/// Writes a resource, including its type ID where necessary, as it appears
+/// following a key in a table.
+fn write_resource<W: Write + ?Sized>(
+    resource: &Resource,
+    depth: usize,
+    out: &mut W,
+) -> fmt::Result {
+    match resource {
+        Resource::String(value) => {
+            out.write_char('{')?;
+            write_quoted_string(value, out)?;
+            out.write_char('}')
+        }
+        Resource::Array(elements) => {
+            out.write_str(":array{\n")?;
+            for element in elements {
+                write_indent(depth + 1, out)?;
+                match element {
+                    // Strings in arrays don't require delimiting braces.
+                    Resource::String(value) => write_quoted_string(value, out)?,
+                    element => write_resource(element, depth + 1, out)?,
+                }
+                out.write_str(",\n")?;
+            }
+            write_indent(depth, out)?;
+            out.write_char('}')
+        }
+        Resource::Table(table) => write_table_body(table, depth, out),
+        Resource::Binary(bytes) => {
+            out.write_str(":bin{\"")?;
+            for byte in bytes.iter() {
+                write!(out, "{byte:02x}")?;
+            }
+            out.write_str("\"}")
+        }
+        Resource::Integer(value) => write!(out, ":int{{{}}}", i32::from(*value)),
+        Resource::IntVector(values) => {
+            out.write_str(":intvector{")?;
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 {
+                    out.write_char(',')?;
+                }
+                write!(out, " {value}")?;
+            }
+            out.write_str(" }")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Reader;
+
+    #[test]
+    fn round_trip() {
+        let input = include_str!("../../examples/data/zoneinfo64.txt");
+        let (bundle, _) = Reader::read(input).expect("valid text bundle");
+
+        let output = Writer::to_string(&bundle);
+        let (reread, _) = Reader::read(&output).expect("written bundle should be readable");
+
+        assert!(!reread.is_locale_fallback_enabled);
+        assert_eq!(Writer::to_string(&reread), output);
+    }
+
+    #[test]
+    fn escapes() {
+        let input = "root{ s{\"tab\\tquote\\\"backslash\\\\bell\\u0007\\U0001F600\"} }";
+        let (bundle, _) = Reader::read(input).expect("valid text bundle");
+
+        let Resource::Table(table) = bundle.root() else {
+            panic!("root should be a table");
+        };
+        let Some(Resource::String(value)) = table.get(&"s".into()) else {
+            panic!("string resource should be present");
+        };
+        assert_eq!(value, "tab\tquote\"backslash\\bell\u{7}😀");
+
+        let output = Writer::to_string(&bundle);
+        assert_eq!(
+            output,
+            "root{\n    s{\"tab\\tquote\\\"backslash\\\\bell\\u0007😀\"}\n}\n"
+        );
+    }
+
+    #[test]
+    fn names() {
+        for (name, expected) in [
+            ("de_CH", "de_CH"),
+            ("a-b", "a-b"),
+            ("", "\"\""),
+            ("a b", "\"a b\""),
+            // Bare names are limited to ASCII
+            ("é", "\"é\""),
+            ("٣", "\"٣\""),
+        ] {
+            let mut out = String::new();
+            write_name(name, &mut out).unwrap();
+            assert_eq!(out, expected, "{name}");
+        }
+    }
+}
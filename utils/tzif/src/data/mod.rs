@@ -14,3 +14,21 @@ pub mod time;
 /// Structs for holding data parsed from `TZif` binary files, as specified by
 /// <https://datatracker.ietf.org/doc/html/rfc8536>
 pub mod tzif;
+
+use time::Seconds;
+
+/// The local time type in effect at a given instant, as determined by a `TZif` file or a
+/// POSIX time-zone string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalTimeType<'a> {
+    /// The number of seconds to be added to UT in order to determine local time.
+    ///
+    /// Note that this is the negation of the offsets in POSIX time-zone strings.
+    pub utoff: Seconds,
+
+    /// Whether local time is considered Daylight Saving Time (DST).
+    pub is_dst: bool,
+
+    /// The time-zone designation, such as "PST" or "PDT".
+    pub designation: &'a str,
+}
@@ -2,7 +2,12 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::time::Seconds;
+use super::time::{Hours, Seconds};
+use super::LocalTimeType;
+use std::fmt;
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
 
 /// A struct to hold a time-zone variant name and its offset.
 /// The offset is how many hours must be added to the time to reach UTC.
@@ -68,3 +73,292 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+impl PosixTzString {
+    /// Returns the local time type that this time-zone string specifies at the given
+    /// number of seconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tzif::data::time::{Hours, Seconds};
+    ///
+    /// let tz = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+    ///
+    /// // 2024-01-01T00:00:00Z
+    /// let winter = tz.local_time_type_at(Seconds(1_704_067_200));
+    /// assert_eq!(winter.utoff, Hours(-8).as_seconds());
+    /// assert_eq!(winter.designation, "PST");
+    /// assert!(!winter.is_dst);
+    ///
+    /// // 2024-07-01T00:00:00Z
+    /// let summer = tz.local_time_type_at(Seconds(1_719_792_000));
+    /// assert_eq!(summer.utoff, Hours(-7).as_seconds());
+    /// assert_eq!(summer.designation, "PDT");
+    /// assert!(summer.is_dst);
+    /// ```
+    pub fn local_time_type_at(&self, time: Seconds) -> LocalTimeType<'_> {
+        let std = LocalTimeType {
+            utoff: Seconds(-self.std_info.offset.0),
+            is_dst: false,
+            designation: &self.std_info.name,
+        };
+        let Some(dst_info) = &self.dst_info else {
+            return std;
+        };
+
+        // The rule is evaluated for the year of the given time in local standard time.
+        let year = year_from_days((time - self.std_info.offset).0.div_euclid(SECONDS_PER_DAY));
+        // The start date is specified in local standard time, and the end date in local DST.
+        let start = dst_info.start_date.local_time_in_year(year) + self.std_info.offset;
+        let end = dst_info.end_date.local_time_in_year(year) + dst_info.variant_info.offset;
+
+        let is_dst = if start <= end {
+            start <= time && time < end
+        } else {
+            // DST spans the end of the year, as in the southern hemisphere.
+            !(end <= time && time < start)
+        };
+        if is_dst {
+            LocalTimeType {
+                utoff: Seconds(-dst_info.variant_info.offset.0),
+                is_dst: true,
+                designation: &dst_info.variant_info.name,
+            }
+        } else {
+            std
+        }
+    }
+
+    /// Returns whether this time-zone string uses the extensions that require a
+    /// version 3 `TZif` file, namely transition times outside of the range `[0, 24]` hours.
+    pub fn requires_version_3(&self) -> bool {
+        self.dst_info.as_ref().is_some_and(|dst_info| {
+            [dst_info.start_date.time, dst_info.end_date.time]
+                .iter()
+                .any(|&time| time < Seconds(0) || time > Hours(24).as_seconds())
+        })
+    }
+}
+
+impl TransitionDate {
+    /// Returns the local time at which the transition occurs in the given year, in seconds
+    /// since the Unix epoch.
+    fn local_time_in_year(self, year: i64) -> Seconds {
+        Seconds(self.day.days_since_epoch(year) * SECONDS_PER_DAY) + self.time
+    }
+}
+
+impl TransitionDay {
+    /// Returns the day in the given year on which the transition occurs, in days since the
+    /// Unix epoch.
+    fn days_since_epoch(self, year: i64) -> i64 {
+        match self {
+            TransitionDay::NoLeap(day) => {
+                let day = i64::from(day);
+                let leap_day = i64::from(is_leap_year(year) && day >= 60);
+                days_from_civil(year, 1, 1) + day - 1 + leap_day
+            }
+            TransitionDay::WithLeap(day) => days_from_civil(year, 1, 1) + i64::from(day),
+            TransitionDay::Mwd(month, week, weekday) => {
+                let month = i64::from(month);
+                let first = days_from_civil(year, month, 1);
+                // The Unix epoch was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + (i64::from(week) - 1) * 7;
+                // Week 5 is the last such day in the month, which may be in week 4.
+                while day >= first + month_length(year, month) {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+impl fmt::Display for PosixTzString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_variant_name(&self.std_info.name, f)?;
+        write_time(self.std_info.offset, f)?;
+        if let Some(dst_info) = &self.dst_info {
+            write_variant_name(&dst_info.variant_info.name, f)?;
+            if dst_info.variant_info.offset != self.std_info.offset - Hours(1).as_seconds() {
+                write_time(dst_info.variant_info.offset, f)?;
+            }
+            for date in [dst_info.start_date, dst_info.end_date] {
+                match date.day {
+                    TransitionDay::NoLeap(day) => write!(f, ",J{day}")?,
+                    TransitionDay::WithLeap(day) => write!(f, ",{day}")?,
+                    TransitionDay::Mwd(month, week, weekday) => {
+                        write!(f, ",M{month}.{week}.{weekday}")?
+                    }
+                }
+                if date.time != Hours(2).as_seconds() {
+                    f.write_str("/")?;
+                    write_time(date.time, f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a time-zone variant name, enclosing it in angled brackets if it is not alphabetic.
+fn write_variant_name(name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if name.bytes().all(|b| b.is_ascii_alphabetic()) {
+        f.write_str(name)
+    } else {
+        write!(f, "<{name}>")
+    }
+}
+
+/// Writes a time of the form `[-]hh[:mm[:ss]]`.
+fn write_time(time: Seconds, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if time < Seconds(0) {
+        f.write_str("-")?;
+    }
+    let seconds = time.0.unsigned_abs();
+    write!(f, "{}", seconds / 3600)?;
+    match (seconds / 60 % 60, seconds % 60) {
+        (0, 0) => Ok(()),
+        (minutes, 0) => write!(f, ":{minutes:02}"),
+        (minutes, seconds) => write!(f, ":{minutes:02}:{seconds:02}"),
+    }
+}
+
+/// Returns whether the given year of the proleptic Gregorian calendar is a leap year.
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the given month.
+fn month_length(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since the Unix epoch of the given date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the year of the given number of days since the Unix epoch.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn year_from_days(days: i64) -> i64 {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Years start in March, so January and February belong to the next year.
+    let year = year_of_era + era * 400;
+    if day_of_year >= 306 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_posix_tz_string;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in [-800_000, -1, 0, 59, 11_016, 11_017, 800_000] {
+            let year = year_from_days(days);
+            assert!(days_from_civil(year, 1, 1) <= days);
+            assert!(days < days_from_civil(year + 1, 1, 1));
+        }
+    }
+
+    #[test]
+    fn transition_days() {
+        // March 2024 starts on a Friday, and has five Sundays
+        assert_eq!(
+            TransitionDay::Mwd(3, 2, 0).days_since_epoch(2024),
+            days_from_civil(2024, 3, 10)
+        );
+        assert_eq!(
+            TransitionDay::Mwd(3, 5, 0).days_since_epoch(2024),
+            days_from_civil(2024, 3, 31)
+        );
+        // February 2023 has four Tuesdays
+        assert_eq!(
+            TransitionDay::Mwd(2, 5, 2).days_since_epoch(2023),
+            days_from_civil(2023, 2, 28)
+        );
+        assert_eq!(
+            TransitionDay::NoLeap(60).days_since_epoch(2024),
+            days_from_civil(2024, 3, 1)
+        );
+        assert_eq!(
+            TransitionDay::WithLeap(59).days_since_epoch(2024),
+            days_from_civil(2024, 2, 29)
+        );
+    }
+
+    #[test]
+    fn evaluate_southern_hemisphere() {
+        let tz = parse_posix_tz_string(b"AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        let at = |y, m, d, s| {
+            tz.local_time_type_at(Seconds(days_from_civil(y, m, d) * SECONDS_PER_DAY + s))
+        };
+
+        assert!(at(2024, 1, 15, 0).is_dst);
+        assert!(!at(2024, 6, 15, 0).is_dst);
+        assert!(at(2024, 12, 31, 23 * 3600).is_dst);
+        // DST ends on 2024-04-07 at 03:00 AEDT, which is 16:00 UTC on the previous day
+        assert!(at(2024, 4, 6, 16 * 3600 - 1).is_dst);
+        assert!(!at(2024, 4, 6, 16 * 3600).is_dst);
+        // DST starts on 2024-10-06 at 02:00 AEST, which is 16:00 UTC on the previous day
+        assert!(!at(2024, 10, 5, 16 * 3600 - 1).is_dst);
+        assert_eq!(at(2024, 10, 5, 16 * 3600).utoff, Hours(11).as_seconds());
+    }
+
+    #[test]
+    fn evaluate_permanent_dst() {
+        // Version 3 extension: DST all year
+        let tz = parse_posix_tz_string(b"EST5EDT,0/0,J365/25").unwrap();
+        assert!(tz.requires_version_3());
+        for days in [0, 100, 200, 364, 365] {
+            let time = Seconds((days_from_civil(2023, 1, 1) + days) * SECONDS_PER_DAY + 12 * 3600);
+            assert_eq!(tz.local_time_type_at(time).designation, "EDT");
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for tz in [
+            "EST5EDT,M3.2.0,M11.1.0",
+            "<+0330>-3:30",
+            "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1",
+            "IST-2IDT,M3.4.4/26,M10.5.0",
+            "AEST-10AEDT,M10.1.0,M4.1.0/3",
+            "NZST-12NZDT-13:45:30,J60/1:30,300",
+            "GMT0",
+        ] {
+            let parsed = parse_posix_tz_string(tz.as_bytes()).unwrap();
+            assert_eq!(parsed.to_string(), tz);
+        }
+    }
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::time::Seconds;
+use super::LocalTimeType;
 use crate::data::posix::PosixTzString;
 
 /// A `TZif` file header.
@@ -91,6 +92,19 @@ impl TzifHeader {
             + self.isstdcnt
             + self.isutcnt
     }
+
+    /// Returns the header that describes the given data block.
+    pub(crate) fn for_data_block(version: usize, block: &DataBlock) -> Self {
+        Self {
+            version,
+            isutcnt: block.ut_local_indicators.len(),
+            isstdcnt: block.standard_wall_indicators.len(),
+            leapcnt: block.leap_second_records.len(),
+            timecnt: block.transition_times.len(),
+            typecnt: block.local_time_type_records.len(),
+            charcnt: block.time_zone_designations_len(),
+        }
+    }
 }
 
 /// A struct containing the data of a `TZif` file.
@@ -113,7 +127,7 @@ impl TzifHeader {
 /// >                     |   Footer    |
 /// >                     +-------------+
 /// > ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TzifData {
     /// The version-1 header, which is always present.
     pub header1: TzifHeader,
@@ -128,6 +142,45 @@ pub struct TzifData {
 }
 
 impl TzifData {
+    /// Creates the data of a version 2 or 3 `TZif` file from its version-2+ data block
+    /// and footer.
+    ///
+    /// The version is 3 if the footer requires it, and 2 otherwise. The version-1 data
+    /// block is derived from the version-2+ data block, omitting the transitions and
+    /// leap-second records that do not fit into 32 bits.
+    pub fn new(data_block: DataBlock, footer: PosixTzString) -> Self {
+        let version = if footer.requires_version_3() { 3 } else { 2 };
+        let data_block1 = data_block.to_version_1();
+        Self {
+            header1: TzifHeader::for_data_block(version, &data_block1),
+            data_block1,
+            header2: Some(TzifHeader::for_data_block(version, &data_block)),
+            data_block2: Some(data_block),
+            footer: Some(footer),
+        }
+    }
+
+    /// Returns the local time type in effect at the given number of seconds since the
+    /// Unix epoch.
+    ///
+    /// After the last transition, or if there are no transitions, the footer is used if it
+    /// is present. Returns [`None`] if the data is inconsistent.
+    pub fn local_time_type_at(&self, time: Seconds) -> Option<LocalTimeType<'_>> {
+        let block = self.data_block2.as_ref().unwrap_or(&self.data_block1);
+        let index = block.transition_times.partition_point(|&t| t <= time);
+        if index == block.transition_times.len() {
+            if let Some(footer) = &self.footer {
+                return Some(footer.local_time_type_at(time));
+            }
+        }
+        let type_index = match index.checked_sub(1) {
+            Some(index) => *block.transition_types.get(index)?,
+            // Local time before the first transition is specified by the first time type.
+            None => 0,
+        };
+        block.local_time_type(type_index)
+    }
+
     /// Returns the version number of this `TZif` data.
     pub fn version_number(&self) -> usize {
         self.header2
@@ -263,7 +316,7 @@ pub enum UtLocalIndicator {
 /// >      |  UT/local indicators       (isutcnt)                    |
 /// >      +---------------------------------------------------------+
 /// > ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DataBlock {
     /// A series of four- or eight-byte UNIX leap-time
     /// values sorted in strictly ascending order.  Each value is used as
@@ -301,6 +354,105 @@ pub struct DataBlock {
 }
 
 impl DataBlock {
+    /// Appends a transition to the local time type at `type_index` at the given time.
+    ///
+    /// Transitions must be appended in strictly ascending order of time.
+    pub fn push_transition(&mut self, time: Seconds, type_index: usize) {
+        self.transition_times.push(time);
+        self.transition_types.push(type_index);
+    }
+
+    /// Returns the index of the local time type with the given values, adding it and
+    /// its designation to the data block if necessary.
+    ///
+    /// If the data block has standard/wall or UT/local indicators, a new local time type
+    /// is indicated to use wall-clock and local time.
+    pub fn push_local_time_type(
+        &mut self,
+        utoff: Seconds,
+        is_dst: bool,
+        designation: &str,
+    ) -> usize {
+        let existing = self.local_time_type_records.iter().position(|record| {
+            record.utoff == utoff
+                && record.is_dst == is_dst
+                && self.time_zone_designation(record.idx) == Some(designation)
+        });
+        if let Some(index) = existing {
+            return index;
+        }
+
+        let mut idx = 0;
+        for existing in &self.time_zone_designations {
+            if existing == designation {
+                break;
+            }
+            idx += existing.len() + 1;
+        }
+        if idx == self.time_zone_designations_len() {
+            self.time_zone_designations.push(designation.to_string());
+        }
+
+        if !self.standard_wall_indicators.is_empty() {
+            self.standard_wall_indicators
+                .push(StandardWallIndicator::Wall);
+        }
+        if !self.ut_local_indicators.is_empty() {
+            self.ut_local_indicators.push(UtLocalIndicator::Local);
+        }
+        self.local_time_type_records
+            .push(LocalTimeTypeRecord { utoff, is_dst, idx });
+        self.local_time_type_records.len() - 1
+    }
+
+    /// Returns the local time type at index `type_index`.
+    pub fn local_time_type(&self, type_index: usize) -> Option<LocalTimeType<'_>> {
+        let record = self.local_time_type_records.get(type_index)?;
+        Some(LocalTimeType {
+            utoff: record.utoff,
+            is_dst: record.is_dst,
+            designation: self.time_zone_designation(record.idx)?,
+        })
+    }
+
+    /// Returns the total number of bytes of the time-zone designations, including
+    /// their NUL terminators.
+    pub fn time_zone_designations_len(&self) -> usize {
+        self.time_zone_designations
+            .iter()
+            .map(|designation| designation.len() + 1)
+            .sum()
+    }
+
+    /// Returns the version-1 equivalent of this data block, omitting transitions and
+    /// leap-second records that do not fit into 32 bits.
+    ///
+    /// If earlier transitions are omitted, a transition is added at the earliest 32-bit
+    /// time so that the local time type in effect at that time is preserved.
+    fn to_version_1(&self) -> Self {
+        let in_range = |time: Seconds| i32::try_from(time.0).is_ok();
+        let mut block = Self {
+            transition_times: Vec::new(),
+            transition_types: Vec::new(),
+            leap_second_records: self
+                .leap_second_records
+                .iter()
+                .filter(|record| in_range(record.occurrence))
+                .copied()
+                .collect(),
+            ..self.clone()
+        };
+        for (&time, &type_index) in self.transition_times.iter().zip(&self.transition_types) {
+            if in_range(time) {
+                block.push_transition(time, type_index);
+            } else if time < Seconds(i32::MIN.into()) {
+                block.transition_times = vec![Seconds(i32::MIN.into())];
+                block.transition_types = vec![type_index];
+            }
+        }
+        block
+    }
+
     /// Retrieves the timezone designation at index `idx`.
     pub fn time_zone_designation(&self, mut idx: usize) -> Option<&str> {
         self.time_zone_designations.iter().find_map(|d| {
@@ -20,6 +20,9 @@
 //! Also includes a parser for [POSIX time-zone strings](https://www.gnu.org/software/libc/manual/html_node/TZ-Variable.html),
 //! which is used by the `TZif` parser, but also available separately.
 //!
+//! The parsed data can be evaluated to find the local time type at a given instant, and version 2
+//! and 3 `TZif` files can be written.
+//!
 //! Resources to generate `TZif` files are provided by the [IANA database](https://www.iana.org/time-zones).
 //! `TZif` files are also included in some operating systems.
 //!
@@ -36,11 +39,40 @@
 //! let data =
 //!     tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
 //! ```
+//!
+//! ### Evaluate `TZif` data
+//! ```no_run
+//! # use std::path::Path;
+//! use tzif::data::time::Seconds;
+//!
+//! let data = tzif::parse_tzif_file(Path::new("path_to_file")).unwrap();
+//! let local_time_type = data.local_time_type_at(Seconds(1_700_000_000)).unwrap();
+//! println!("{} ({:?})", local_time_type.designation, local_time_type.utoff);
+//! ```
+//!
+//! ### Write `TZif` files
+//! ```rust
+//! use tzif::data::time::{Hours, Seconds};
+//! use tzif::data::tzif::{DataBlock, TzifData};
+//!
+//! let mut block = DataBlock::default();
+//! let lmt = block.push_local_time_type(Seconds(-28378), false, "LMT");
+//! let pst = block.push_local_time_type(Hours(-8).as_seconds(), false, "PST");
+//! block.push_transition(Seconds(-2717640000), pst);
+//! assert_eq!(lmt, 0);
+//!
+//! let footer = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+//! let data = TzifData::new(block, footer);
+//!
+//! let mut bytes = Vec::new();
+//! tzif::write::tzif(&data, &mut bytes).unwrap();
+//! ```
 
 use combine::{stream, Parser};
 use data::{posix::PosixTzString, tzif::TzifData};
 use error::Error;
 use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 /// The parsed data representations.
 pub mod data;
@@ -48,6 +80,9 @@ pub mod data;
 /// The parser implementations.
 pub mod parse;
 
+/// The writer implementations.
+pub mod write;
+
 /// Error types an implementations.
 pub mod error;
 
@@ -65,3 +100,13 @@ pub fn parse_tzif_file(path: &Path) -> Result<TzifData, Error> {
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
 }
+
+/// Writes a `TZif` file at the provided `path`.
+///
+/// See [`write::tzif`] for more information.
+pub fn write_tzif_file(path: &Path, data: &TzifData) -> Result<(), Error> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write::tzif(data, &mut file)?;
+    file.flush()?;
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::posix::PosixTzString;
+use crate::data::time::Seconds;
+use crate::data::tzif::{DataBlock, StandardWallIndicator, TzifData, TzifHeader, UtLocalIndicator};
+use std::io::{self, Write};
+
+/// Writes `TZif` data according to the following specification:
+/// <https://datatracker.ietf.org/doc/html/rfc8536>
+///
+/// Only the versions of the headers in `data` are used; the counts in the written headers
+/// are determined by the data blocks. Version 2 and 3 data must have a version-2+ data block.
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidInput`] if a value does not fit into
+/// the `TZif` format.
+pub fn tzif<W: Write + ?Sized>(data: &TzifData, out: &mut W) -> io::Result<()> {
+    let version = data.version_number();
+    header(TzifHeader::for_data_block(version, &data.data_block1), out)?;
+    data_block::<1, _>(&data.data_block1, out)?;
+    if version == 1 {
+        return Ok(());
+    }
+
+    let data_block2 = data
+        .data_block2
+        .as_ref()
+        .ok_or_else(|| invalid_input("version 2+ data should have a version-2+ data block"))?;
+    header(TzifHeader::for_data_block(version, data_block2), out)?;
+    data_block::<2, _>(data_block2, out)?;
+    footer(data.footer.as_ref(), out)
+}
+
+/// Writes a `TZif` header, starting with the magic sequence `TZif`.
+fn header<W: Write + ?Sized>(header: TzifHeader, out: &mut W) -> io::Result<()> {
+    out.write_all(b"TZif")?;
+    out.write_all(&[match header.version {
+        1 => 0,
+        2 => b'2',
+        3 => b'3',
+        _ => return Err(invalid_input("version should be 1, 2, or 3")),
+    }])?;
+    out.write_all(&[0; 15])?;
+    for count in [
+        header.isutcnt,
+        header.isstdcnt,
+        header.leapcnt,
+        header.timecnt,
+        header.typecnt,
+        header.charcnt,
+    ] {
+        out.write_all(&u32::try_from(count).map_err(invalid_input)?.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+/// Writes a four-byte time value for version 1 data blocks, and an eight-byte time
+/// value otherwise.
+fn time<const V: usize, W: Write + ?Sized>(time: Seconds, out: &mut W) -> io::Result<()> {
+    match V {
+        1 => out.write_all(&i32::try_from(time.0).map_err(invalid_input)?.to_be_bytes()),
+        _ => out.write_all(&time.0.to_be_bytes()),
+    }
+}
+
+/// Writes a data block, which must be described by the preceding header.
+fn data_block<const V: usize, W: Write + ?Sized>(block: &DataBlock, out: &mut W) -> io::Result<()> {
+    for &transition_time in &block.transition_times {
+        time::<V, _>(transition_time, out)?;
+    }
+    for &transition_type in &block.transition_types {
+        out.write_all(&[u8::try_from(transition_type).map_err(invalid_input)?])?;
+    }
+    for record in &block.local_time_type_records {
+        out.write_all(
+            &i32::try_from(record.utoff.0)
+                .map_err(invalid_input)?
+                .to_be_bytes(),
+        )?;
+        out.write_all(&[
+            u8::from(record.is_dst),
+            u8::try_from(record.idx).map_err(invalid_input)?,
+        ])?;
+    }
+    for designation in &block.time_zone_designations {
+        out.write_all(designation.as_bytes())?;
+        out.write_all(&[0])?;
+    }
+    for record in &block.leap_second_records {
+        time::<V, _>(record.occurrence, out)?;
+        out.write_all(&record.correction.to_be_bytes())?;
+    }
+    for indicator in &block.standard_wall_indicators {
+        out.write_all(&[u8::from(*indicator == StandardWallIndicator::Standard)])?;
+    }
+    for indicator in &block.ut_local_indicators {
+        out.write_all(&[u8::from(*indicator == UtLocalIndicator::Ut)])?;
+    }
+    Ok(())
+}
+
+/// Writes the footer, a POSIX time-zone string enclosed in newlines, which is empty
+/// if there is no time-zone string.
+fn footer<W: Write + ?Sized>(footer: Option<&PosixTzString>, out: &mut W) -> io::Result<()> {
+    match footer {
+        Some(footer) => writeln!(out, "\n{footer}"),
+        None => out.write_all(b"\n\n"),
+    }
+}
+
+/// Creates an [`io::Error`] for data that cannot be written.
+fn invalid_input(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
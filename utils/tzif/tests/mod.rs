@@ -3,6 +3,8 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use std::path::Path;
+use tzif::data::time::{Hours, Seconds};
+use tzif::data::tzif::{DataBlock, TzifData};
 use walkdir::WalkDir;
 
 fn parse_tzif_file(path: &Path) -> Result<(), tzif::error::Error> {
@@ -27,3 +29,81 @@ fn parse_tzif_testdata() -> Result<(), tzif::error::Error> {
 fn parse_posix_tz_string() {
     assert!(tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").is_ok());
 }
+
+#[test]
+fn write_tzif_testdata() -> Result<(), tzif::error::Error> {
+    for entry in WalkDir::new("testdata").follow_links(true) {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            let parsed = tzif::parse_tzif_file(entry.path())?;
+
+            let mut bytes = Vec::new();
+            tzif::write::tzif(&parsed, &mut bytes)?;
+            assert_eq!(
+                bytes,
+                std::fs::read(entry.path())?,
+                "{:?} should be written unchanged",
+                entry.path()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn evaluate_tzif_testdata() -> Result<(), tzif::error::Error> {
+    let data = tzif::parse_tzif_file(Path::new("testdata/America/Los_Angeles"))?;
+    let at = |time| data.local_time_type_at(Seconds(time)).unwrap();
+
+    // Before the first transition
+    assert_eq!(at(-5_000_000_000).designation, "LMT");
+    // 1944-01-01, during the war time transitions
+    assert_eq!(at(-820_540_800).designation, "PWT");
+    // 2024-01-01 and 2024-07-01
+    assert_eq!(at(1_704_067_200).utoff, Hours(-8).as_seconds());
+    assert_eq!(at(1_719_792_000).utoff, Hours(-7).as_seconds());
+    // 2100-07-01, evaluated from the footer
+    assert!(at(4_118_083_200).is_dst);
+    assert!(!at(4_102_444_800).is_dst);
+
+    Ok(())
+}
+
+#[test]
+fn write_new_tzif() -> Result<(), tzif::error::Error> {
+    let mut block = DataBlock::default();
+    let lmt = block.push_local_time_type(Seconds(-28378), false, "LMT");
+    let pst = block.push_local_time_type(Hours(-8).as_seconds(), false, "PST");
+    let pdt = block.push_local_time_type(Hours(-7).as_seconds(), true, "PDT");
+    assert_eq!(
+        block.push_local_time_type(Hours(-8).as_seconds(), false, "PST"),
+        pst
+    );
+    block.push_transition(Seconds(-5_000_000_000), pst);
+    block.push_transition(Seconds(1_710_064_800), pdt);
+
+    let footer = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0")?;
+    let data = TzifData::new(block, footer);
+    assert_eq!(data.version_number(), 2);
+    // The 64-bit transition is replaced by one at the earliest 32-bit time
+    assert_eq!(
+        data.data_block1.transition_times,
+        [Seconds(i32::MIN.into()), Seconds(1_710_064_800)]
+    );
+    assert_eq!(data.data_block1.transition_types, [pst, pdt]);
+
+    let path = std::env::temp_dir().join("tzif_write_new_tzif");
+    tzif::write_tzif_file(&path, &data)?;
+    let reparsed = tzif::parse_tzif_file(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(reparsed, data);
+
+    let at = |time| reparsed.local_time_type_at(Seconds(time)).unwrap();
+    assert_eq!(at(-6_000_000_000).designation, "LMT");
+    assert_eq!(at(0).designation, "PST");
+    assert_eq!(at(1_719_792_000).designation, "PDT");
+    assert_eq!(at(1_733_011_200).designation, "PST");
+    assert_eq!(lmt, 0);
+
+    Ok(())
+}
@@ -88,7 +88,11 @@ pub use error::ZeroTrieBuildError;
 #[cfg(feature = "alloc")]
 pub use crate::zerotrie::ZeroTrieStringIterator;
 #[cfg(feature = "alloc")]
+pub use crate::zerotrie::ZeroTrieStringRangeIterator;
+#[cfg(feature = "alloc")]
 pub use reader::ZeroTrieIterator;
+#[cfg(feature = "alloc")]
+pub use reader::ZeroTrieRangeIterator;
 
 #[doc(hidden)]
 pub mod _internal {
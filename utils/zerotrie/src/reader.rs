@@ -211,7 +211,11 @@ use crate::varint::read_varint_meta2;
 use crate::varint::read_varint_meta3;
 
 #[cfg(feature = "alloc")]
-use alloc::string::String;
+use alloc::{boxed::Box, string::String};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use core::ops::{Bound, RangeBounds};
 
 /// Given a slice starting with an offset table, returns the trie for the given index.
 ///
@@ -297,13 +301,68 @@ fn byte_type(b: u8) -> NodeType {
     }
 }
 
+/// Returns whether the trie byte `b` of an ASCII node matches the input byte `c`.
+#[inline]
+fn ascii_matches<T: ZeroTrieWithOptions + ?Sized>(b: u8, c: u8) -> bool {
+    if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase) {
+        b.eq_ignore_ascii_case(&c)
+    } else {
+        b == c
+    }
+}
+
+/// Evaluates a branch node for the input byte `c`.
+///
+/// Arguments:
+/// - `trie` = a trie pointing after the varint of the branch node
+/// - `x` = the varint of the branch node
+/// - `c` = the input byte
+///
+/// Returns the sub-trie reachable by `c`, or `None` if `c` is not in the branch.
+#[inline]
+fn get_branch_for_byte<T: ZeroTrieWithOptions + ?Sized>(
+    mut trie: &[u8],
+    x: usize,
+    c: u8,
+) -> Option<&[u8]> {
+    let (i, search);
+    let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
+    let w = if matches!(T::OPTIONS.capacity_mode, CapacityMode::Extended) {
+        w
+    } else {
+        // See the table below regarding this assertion
+        debug_assert!(w <= 3, "get: w > 3 but we assume w <= 3");
+        w & 0x3
+    };
+    let x = if x == 0 { 256 } else { x };
+    if matches!(T::OPTIONS.phf_mode, PhfMode::BinaryOnly) || x < 16 {
+        // binary search
+        (search, trie) = trie.debug_split_at(x);
+        let bsearch_result = if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase) {
+            search.binary_search_by_key(&c.to_ascii_lowercase(), |x| x.to_ascii_lowercase())
+        } else {
+            search.binary_search(&c)
+        };
+        i = bsearch_result.ok()?;
+    } else {
+        // phf
+        (search, trie) = trie.debug_split_at(x * 2 + 1);
+        i = PerfectByteHashMap::from_store(search).get(c)?;
+    }
+    Some(if w == 0 {
+        get_branch_w0(trie, i, x)
+    } else {
+        get_branch(trie, i, x, w)
+    })
+}
+
 #[inline]
 pub(crate) fn get_parameterized<T: ZeroTrieWithOptions + ?Sized>(
     mut trie: &[u8],
     mut ascii: &[u8],
 ) -> Option<usize> {
     loop {
-        let (b, x, i, search);
+        let (b, x);
         (b, trie) = trie.split_first()?;
         let byte_type = byte_type(*b);
         (x, trie) = match byte_type {
@@ -321,13 +380,7 @@ pub(crate) fn get_parameterized<T: ZeroTrieWithOptions + ?Sized>(
         };
         if let Some((c, temp)) = ascii.split_first() {
             if matches!(byte_type, NodeType::Ascii) {
-                let is_match = if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase)
-                {
-                    b.eq_ignore_ascii_case(c)
-                } else {
-                    b == c
-                };
-                if is_match {
+                if ascii_matches::<T>(*b, *c) {
                     // Matched a byte
                     ascii = temp;
                     continue;
@@ -355,37 +408,7 @@ pub(crate) fn get_parameterized<T: ZeroTrieWithOptions + ?Sized>(
                 }
             }
             // Branch node
-            let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
-            let w = if matches!(T::OPTIONS.capacity_mode, CapacityMode::Extended) {
-                w
-            } else {
-                // See the table below regarding this assertion
-                debug_assert!(w <= 3, "get: w > 3 but we assume w <= 3");
-                w & 0x3
-            };
-            let x = if x == 0 { 256 } else { x };
-            if matches!(T::OPTIONS.phf_mode, PhfMode::BinaryOnly) || x < 16 {
-                // binary search
-                (search, trie) = trie.debug_split_at(x);
-                let bsearch_result =
-                    if matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase) {
-                        search.binary_search_by_key(&c.to_ascii_lowercase(), |x| {
-                            x.to_ascii_lowercase()
-                        })
-                    } else {
-                        search.binary_search(c)
-                    };
-                i = bsearch_result.ok()?;
-            } else {
-                // phf
-                (search, trie) = trie.debug_split_at(x * 2 + 1);
-                i = PerfectByteHashMap::from_store(search).get(*c)?;
-            }
-            trie = if w == 0 {
-                get_branch_w0(trie, i, x)
-            } else {
-                get_branch(trie, i, x, w)
-            };
+            trie = get_branch_for_byte::<T>(trie, x, *c)?;
             ascii = temp;
             continue;
         } else {
@@ -398,6 +421,68 @@ pub(crate) fn get_parameterized<T: ZeroTrieWithOptions + ?Sized>(
     }
 }
 
+/// Finds the longest prefix of `ascii` that is in the trie.
+///
+/// Returns the length of the prefix and its value.
+pub(crate) fn get_longest_prefix_parameterized<T: ZeroTrieWithOptions + ?Sized>(
+    mut trie: &[u8],
+    input: &[u8],
+) -> Option<(usize, usize)> {
+    let mut ascii = input;
+    let mut longest = None;
+    loop {
+        let (b, x);
+        (b, trie) = match trie.split_first() {
+            Some(v) => v,
+            None => return longest,
+        };
+        let byte_type = byte_type(*b);
+        (x, trie) = match byte_type {
+            NodeType::Ascii => (0, trie),
+            NodeType::Span => {
+                if matches!(T::OPTIONS.ascii_mode, AsciiMode::BinarySpans) {
+                    read_varint_meta3(*b, trie)
+                } else {
+                    debug_assert!(false, "Span node found in ASCII trie!");
+                    return longest;
+                }
+            }
+            NodeType::Value => read_varint_meta3(*b, trie),
+            NodeType::Branch => read_varint_meta2(*b, trie),
+        };
+        if matches!(byte_type, NodeType::Value) {
+            // The string up to here is in the trie
+            longest = Some((input.len() - ascii.len(), x));
+            continue;
+        }
+        let Some((c, temp)) = ascii.split_first() else {
+            // End of string
+            return longest;
+        };
+        match byte_type {
+            NodeType::Ascii if ascii_matches::<T>(*b, *c) => {
+                ascii = temp;
+            }
+            NodeType::Span => {
+                let trie_span;
+                (trie_span, trie) = trie.debug_split_at(x);
+                match ascii.split_at_checked(x) {
+                    Some((ascii_span, rest)) if trie_span == ascii_span => ascii = rest,
+                    _ => return longest,
+                }
+            }
+            NodeType::Branch => match get_branch_for_byte::<T>(trie, x, *c) {
+                Some(branch) => {
+                    trie = branch;
+                    ascii = temp;
+                }
+                None => return longest,
+            },
+            _ => return longest,
+        }
+    }
+}
+
 // DISCUSS: This function is 7% faster *on aarch64* if we assert a max on w.
 //
 // | Bench         | No Assert, x86_64 | No Assert, aarch64 | Assertion, x86_64 | Assertion, aarch64 |
@@ -729,3 +814,320 @@ pub(crate) fn get_iter_ascii_or_panic<S: AsRef<[u8]> + ?Sized>(
         (ascii_str, v)
     })
 }
+
+/// The upper bound of a [`ZeroTrieRangeIterator`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+enum UpperBound {
+    Unbounded,
+    Included(Box<[u8]>),
+    Excluded(Box<[u8]>),
+    /// All strings starting with the given prefix
+    Prefix(Box<[u8]>),
+}
+
+/// Iterator type for walking the byte sequences contained in a [`ZeroTrie`](crate::ZeroTrie)
+/// in lexicographic order, restricted to a range of strings.
+///
+/// The lexicographic order is by byte value, ignoring ASCII case for
+/// [`ZeroAsciiIgnoreCaseTrie`](crate::ZeroAsciiIgnoreCaseTrie).
+///
+/// Subtrees of the trie that are outside of the range are skipped without being visited.
+///
+/// The iterator allocates its bounds and traversal stack when it is created, and
+/// [`Iterator::next`] allocates a new [`Vec`] for every item. Use
+/// [`next_borrowed`](Self::next_borrowed) to visit the items without allocating per item.
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ZeroTrieRangeIterator<'a> {
+    /// Whether the PHF is enabled on this trie.
+    use_phf: bool,
+    /// Whether strings are compared ignoring ASCII case.
+    ignore_case: bool,
+    lower: Bound<Box<[u8]>>,
+    upper: UpperBound,
+    /// The string that leads to the current position.
+    string: Vec<u8>,
+    /// Intermediate state during iteration:
+    /// 1. A trie (usually a slice of the original, bigger trie)
+    /// 2. The length of the string that leads to the trie
+    /// 3. If the trie's lead node is a branch node, the lowest rank of the next edge to
+    ///    evaluate. The rank is the index for binary search branches and the byte for
+    ///    perfect hash branches.
+    state: Vec<(&'a [u8], usize, usize)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> ZeroTrieRangeIterator<'a> {
+    fn new<T: ZeroTrieWithOptions + ?Sized>(
+        store: &'a [u8],
+        lower: Bound<Box<[u8]>>,
+        upper: UpperBound,
+    ) -> Self {
+        ZeroTrieRangeIterator {
+            use_phf: matches!(T::OPTIONS.phf_mode, PhfMode::UsePhf),
+            ignore_case: matches!(T::OPTIONS.case_sensitivity, CaseSensitivity::IgnoreCase),
+            lower,
+            upper,
+            string: Vec::new(),
+            state: alloc::vec![(store, 0, 0)],
+        }
+    }
+
+    /// Compares two strings up to the length of the shorter one.
+    fn cmp_common_prefix(&self, a: &[u8], b: &[u8]) -> Ordering {
+        for (a, b) in a.iter().zip(b) {
+            let (a, b) = if self.ignore_case {
+                (a.to_ascii_lowercase(), b.to_ascii_lowercase())
+            } else {
+                (*a, *b)
+            };
+            match a.cmp(&b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn cmp(&self, a: &[u8], b: &[u8]) -> Ordering {
+        self.cmp_common_prefix(a, b)
+            .then_with(|| a.len().cmp(&b.len()))
+    }
+
+    /// Whether no string starting with the current string is in the range because all
+    /// of them are less than the lower bound.
+    fn is_below_range(&self) -> bool {
+        match &self.lower {
+            Bound::Included(lower) | Bound::Excluded(lower) => {
+                self.cmp_common_prefix(&self.string, lower) == Ordering::Less
+            }
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Whether no string starting with the current string is in the range because all
+    /// of them are greater than the upper bound.
+    fn is_above_range(&self) -> bool {
+        match &self.upper {
+            UpperBound::Included(upper) => self.cmp(&self.string, upper) == Ordering::Greater,
+            UpperBound::Excluded(upper) => self.cmp(&self.string, upper) != Ordering::Less,
+            UpperBound::Prefix(prefix) => {
+                self.cmp_common_prefix(&self.string, prefix) == Ordering::Greater
+            }
+            UpperBound::Unbounded => false,
+        }
+    }
+
+    /// Whether the current string is in the range.
+    fn is_in_range(&self) -> bool {
+        let after_lower = match &self.lower {
+            Bound::Included(lower) => self.cmp(&self.string, lower) != Ordering::Less,
+            Bound::Excluded(lower) => self.cmp(&self.string, lower) == Ordering::Greater,
+            Bound::Unbounded => true,
+        };
+        let before_upper = match &self.upper {
+            UpperBound::Included(upper) => self.cmp(&self.string, upper) != Ordering::Greater,
+            UpperBound::Excluded(upper) => self.cmp(&self.string, upper) == Ordering::Less,
+            UpperBound::Prefix(prefix) => {
+                self.string.len() >= prefix.len()
+                    && self.cmp_common_prefix(&self.string, prefix) == Ordering::Equal
+            }
+            UpperBound::Unbounded => true,
+        };
+        after_lower && before_upper
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ZeroTrieRangeIterator<'_> {
+    /// Advances the iterator and returns the next item, borrowing the string from the
+    /// iterator instead of allocating it.
+    ///
+    /// ```
+    /// use zerotrie::ZeroTriePerfectHash;
+    ///
+    /// let trie: ZeroTriePerfectHash<Vec<u8>> =
+    ///     [(&b"apple"[..], 1), (b"banana", 2), (b"cherry", 3)]
+    ///         .into_iter()
+    ///         .collect();
+    ///
+    /// let mut it = trie.iter_range(&b"b"[..]..);
+    /// assert_eq!(it.next_borrowed(), Some((&b"banana"[..], 2)));
+    /// assert_eq!(it.next_borrowed(), Some((&b"cherry"[..], 3)));
+    /// assert_eq!(it.next_borrowed(), None);
+    /// ```
+    pub fn next_borrowed(&mut self) -> Option<(&[u8], usize)> {
+        let value = self.advance()?;
+        Some((&self.string, value))
+    }
+
+    /// Moves to the next string in the range and returns its value. The string is left
+    /// in `self.string`.
+    fn advance(&mut self) -> Option<usize> {
+        let (mut trie, mut string_len, mut rank);
+        (trie, string_len, rank) = self.state.pop()?;
+        self.string.truncate(string_len);
+        loop {
+            let (b, x, span, search);
+            let return_trie = trie;
+            (b, trie) = match trie.split_first() {
+                Some(tpl) => tpl,
+                None => {
+                    // At end of current branch; step back to the branch node.
+                    // If there are no more branches, we are finished.
+                    (trie, string_len, rank) = self.state.pop()?;
+                    self.string.truncate(string_len);
+                    continue;
+                }
+            };
+            let byte_type = byte_type(*b);
+            (x, trie) = match byte_type {
+                NodeType::Ascii => (0, trie),
+                NodeType::Span | NodeType::Value => read_varint_meta3(*b, trie),
+                NodeType::Branch => read_varint_meta2(*b, trie),
+            };
+            match byte_type {
+                NodeType::Ascii => self.string.push(*b),
+                NodeType::Span => {
+                    (span, trie) = trie.debug_split_at(x);
+                    self.string.extend(span);
+                }
+                NodeType::Value => {
+                    if self.is_in_range() {
+                        // Return to this position on the next step
+                        self.state.push((trie, self.string.len(), 0));
+                        return Some(x);
+                    }
+                    continue;
+                }
+                NodeType::Branch => {
+                    let (x, w) = if x >= 256 { (x & 0xff, x >> 8) } else { (x, 0) };
+                    let x = if x == 0 { 256 } else { x };
+                    // Find the edge with the lowest rank that is at least `rank`
+                    let edge = if x < 16 || !self.use_phf {
+                        // binary search: the bytes are sorted
+                        (search, trie) = trie.debug_split_at(x);
+                        search.get(rank).map(|byte| (rank, *byte, rank + 1))
+                    } else {
+                        // phf: the bytes are in hash order
+                        (search, trie) = trie.debug_split_at(x * 2 + 1);
+                        search
+                            .get(x + 1..)
+                            .debug_unwrap_or(&[])
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, byte)| usize::from(**byte) >= rank)
+                            .min_by_key(|(_, byte)| **byte)
+                            .map(|(i, byte)| (i, *byte, usize::from(*byte) + 1))
+                    };
+                    let Some((i, byte, next_rank)) = edge else {
+                        // No more edges; step back to the previous branch node
+                        trie = &[];
+                        continue;
+                    };
+                    // Return to this branch node at the next edge
+                    self.state.push((return_trie, self.string.len(), next_rank));
+                    self.string.push(byte);
+                    trie = if w == 0 {
+                        get_branch_w0(trie, i, x)
+                    } else {
+                        get_branch(trie, i, x, w)
+                    };
+                    rank = 0;
+                }
+            }
+            if self.is_above_range() {
+                // All remaining strings are greater
+                self.state.clear();
+                return None;
+            }
+            if self.is_below_range() {
+                // Skip this subtree
+                trie = &[];
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for ZeroTrieRangeIterator<'_> {
+    type Item = (Vec<u8>, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.advance()?;
+        Some((self.string.clone(), value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn to_boxed_bound<K: AsRef<[u8]> + ?Sized>(bound: Bound<&K>) -> Bound<Box<[u8]>> {
+    match bound {
+        Bound::Included(k) => Bound::Included(k.as_ref().into()),
+        Bound::Excluded(k) => Bound::Excluded(k.as_ref().into()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn get_range_iter_phf<T, K, R>(store: &[u8], range: R) -> ZeroTrieRangeIterator<'_>
+where
+    T: ZeroTrieWithOptions + ?Sized,
+    K: AsRef<[u8]> + ?Sized,
+    R: RangeBounds<K>,
+{
+    let upper = match range.end_bound() {
+        Bound::Included(k) => UpperBound::Included(k.as_ref().into()),
+        Bound::Excluded(k) => UpperBound::Excluded(k.as_ref().into()),
+        Bound::Unbounded => UpperBound::Unbounded,
+    };
+    ZeroTrieRangeIterator::new::<T>(store, to_boxed_bound(range.start_bound()), upper)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn get_prefix_iter_phf<'a, T: ZeroTrieWithOptions + ?Sized>(
+    store: &'a [u8],
+    prefix: &[u8],
+) -> ZeroTrieRangeIterator<'a> {
+    ZeroTrieRangeIterator::new::<T>(
+        store,
+        Bound::Included(prefix.into()),
+        UpperBound::Prefix(prefix.into()),
+    )
+}
+
+/// # Panics
+/// Panics if the trie contains non-ASCII items.
+#[cfg(feature = "alloc")]
+#[expect(clippy::type_complexity)]
+pub(crate) fn get_range_iter_ascii_or_panic<T, K, R>(
+    store: &[u8],
+    range: R,
+) -> core::iter::Map<ZeroTrieRangeIterator<'_>, fn((Vec<u8>, usize)) -> (String, usize)>
+where
+    T: ZeroTrieWithOptions + ?Sized,
+    K: AsRef<[u8]> + ?Sized,
+    R: RangeBounds<K>,
+{
+    get_range_iter_phf::<T, K, R>(store, range).map(|(k, v)| {
+        #[expect(clippy::unwrap_used)] // in signature of function
+        let ascii_str = String::from_utf8(k).unwrap();
+        (ascii_str, v)
+    })
+}
+
+/// # Panics
+/// Panics if the trie contains non-ASCII items.
+#[cfg(feature = "alloc")]
+#[expect(clippy::type_complexity)]
+pub(crate) fn get_prefix_iter_ascii_or_panic<'a, T: ZeroTrieWithOptions + ?Sized>(
+    store: &'a [u8],
+    prefix: &[u8],
+) -> core::iter::Map<ZeroTrieRangeIterator<'a>, fn((Vec<u8>, usize)) -> (String, usize)> {
+    get_prefix_iter_phf::<T>(store, prefix).map(|(k, v)| {
+        #[expect(clippy::unwrap_used)] // in signature of function
+        let ascii_str = String::from_utf8(k).unwrap();
+        (ascii_str, v)
+    })
+}
@@ -284,7 +284,7 @@ impl<Store> ZeroTrieExtendedCapacity<Store> {
 }
 
 macro_rules! impl_zerotrie_subtype {
-    ($name:ident, $iter_element:ty, $iter_fn:path, $iter_ty:ty, $cnv_fn:path) => {
+    ($name:ident, $iter_element:ty, $iter_fn:path, $iter_ty:ty, $range_iter_fn:ident, $prefix_iter_fn:ident, $range_iter_ty:ty, $cnv_fn:path) => {
         impl<Store> $name<Store> {
             /// Create a trie directly from a store.
             ///
@@ -327,6 +327,28 @@ macro_rules! impl_zerotrie_subtype {
             pub fn get<K>(&self, key: K) -> Option<usize> where K: AsRef<[u8]> {
                 reader::get_parameterized::<Self>(self.store.as_ref(), key.as_ref())
             }
+            /// Finds the longest prefix of a string that is in the trie.
+            ///
+            /// Returns the length of the prefix in bytes and its value, or `None` if no
+            /// prefix of the string, including the empty string, is in the trie.
+            ///
+            /// This function does not allocate.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            /// // A trie with two values: "abc" and "abcdef"
+            #[doc = concat!("let trie: &", stringify!($name), "<[u8]> = ", stringify!($name), "::from_bytes(b\"abc\\x80def\\x81\");")]
+            ///
+            /// assert_eq!(trie.longest_prefix(b"abcdxy"), Some((3, 0)));
+            /// assert_eq!(trie.longest_prefix(b"abcdefg"), Some((6, 1)));
+            /// assert_eq!(trie.longest_prefix(b"abxyz"), None);
+            /// ```
+            pub fn longest_prefix<K>(&self, key: K) -> Option<(usize, usize)> where K: AsRef<[u8]> {
+                reader::get_longest_prefix_parameterized::<Self>(self.store.as_ref(), key.as_ref())
+            }
             /// Returns `true` if the trie is empty.
             #[inline]
             pub fn is_empty(&self) -> bool {
@@ -421,6 +443,60 @@ macro_rules! impl_zerotrie_subtype {
             pub fn iter(&self) -> $iter_ty {
                  $iter_fn(self.as_bytes())
             }
+            /// Returns an iterator over the key/value pairs in this trie whose keys start with
+            /// `prefix`, in lexicographic order.
+            ///
+            /// Only the part of the trie reachable from the prefix is visited.
+            ///
+            /// ✨ *Enabled with the `alloc` Cargo feature.*
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let trie: ", stringify!($name), "<Vec<u8>> = [(\"app\", 1), (\"apple\", 2), (\"apply\", 3), (\"bar\", 4)]")]
+            ///     .into_iter()
+            ///     .collect();
+            ///
+            /// let mut it = trie.iter_prefix("appl");
+            /// assert_eq!(it.next(), Some(("apple".into(), 2)));
+            /// assert_eq!(it.next(), Some(("apply".into(), 3)));
+            /// assert_eq!(it.next(), None);
+            /// ```
+            pub fn iter_prefix<K>(&self, prefix: K) -> $range_iter_ty where K: AsRef<[u8]> {
+                reader::$prefix_iter_fn::<Self>(self.as_bytes(), prefix.as_ref())
+            }
+            /// Returns an iterator over the key/value pairs in this trie whose keys are in
+            /// `range`, in lexicographic order.
+            ///
+            /// Only the parts of the trie that can contain keys in the range are visited.
+            ///
+            /// ✨ *Enabled with the `alloc` Cargo feature.*
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use zerotrie::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let trie: ", stringify!($name), "<Vec<u8>> = [(\"app\", 1), (\"apple\", 2), (\"apply\", 3), (\"bar\", 4)]")]
+            ///     .into_iter()
+            ///     .collect();
+            ///
+            /// let mut it = trie.iter_range("apple".."b");
+            /// assert_eq!(it.next(), Some(("apple".into(), 2)));
+            /// assert_eq!(it.next(), Some(("apply".into(), 3)));
+            /// assert_eq!(it.next(), None);
+            ///
+            /// assert_eq!(trie.iter_range::<&str, _>(..).count(), 4);
+            /// ```
+            pub fn iter_range<K, R>(&self, range: R) -> $range_iter_ty
+            where
+                K: AsRef<[u8]> + ?Sized,
+                R: core::ops::RangeBounds<K>,
+            {
+                reader::$range_iter_fn::<Self, K, R>(self.as_bytes(), range)
+            }
         }
         impl $name<[u8]> {
             /// Casts from a byte slice to a reference to a trie with the same lifetime.
@@ -706,11 +782,19 @@ fn string_to_box_u8(input: String) -> Box<[u8]> {
 pub type ZeroTrieStringIterator<'a> =
     core::iter::Map<reader::ZeroTrieIterator<'a>, fn((Vec<u8>, usize)) -> (String, usize)>;
 
+#[doc(hidden)] // subject to change
+#[cfg(feature = "alloc")]
+pub type ZeroTrieStringRangeIterator<'a> =
+    core::iter::Map<reader::ZeroTrieRangeIterator<'a>, fn((Vec<u8>, usize)) -> (String, usize)>;
+
 impl_zerotrie_subtype!(
     ZeroTrieSimpleAscii,
     String,
     reader::get_iter_ascii_or_panic,
     ZeroTrieStringIterator<'_>,
+    get_range_iter_ascii_or_panic,
+    get_prefix_iter_ascii_or_panic,
+    ZeroTrieStringRangeIterator<'_>,
     string_to_box_u8
 );
 impl_zerotrie_subtype!(
@@ -718,6 +802,9 @@ impl_zerotrie_subtype!(
     String,
     reader::get_iter_ascii_or_panic,
     ZeroTrieStringIterator<'_>,
+    get_range_iter_ascii_or_panic,
+    get_prefix_iter_ascii_or_panic,
+    ZeroTrieStringRangeIterator<'_>,
     string_to_box_u8
 );
 impl_zerotrie_subtype!(
@@ -725,6 +812,9 @@ impl_zerotrie_subtype!(
     Vec<u8>,
     reader::get_iter_phf,
     reader::ZeroTrieIterator<'_>,
+    get_range_iter_phf,
+    get_prefix_iter_phf,
+    reader::ZeroTrieRangeIterator<'_>,
     Vec::into_boxed_slice
 );
 impl_zerotrie_subtype!(
@@ -732,6 +822,9 @@ impl_zerotrie_subtype!(
     Vec<u8>,
     reader::get_iter_phf,
     reader::ZeroTrieIterator<'_>,
+    get_range_iter_phf,
+    get_prefix_iter_phf,
+    reader::ZeroTrieRangeIterator<'_>,
     Vec::into_boxed_slice
 );
 
@@ -807,6 +900,15 @@ where
     {
         impl_dispatch!(&self, get(key))
     }
+    /// Finds the longest prefix of a string that is in the trie.
+    ///
+    /// Returns the length of the prefix in bytes and its value.
+    pub fn longest_prefix<K>(&self, key: K) -> Option<(usize, usize)>
+    where
+        K: AsRef<[u8]>,
+    {
+        impl_dispatch!(&self, longest_prefix(key))
+    }
     /// Returns `true` if the trie is empty.
     pub fn is_empty(&self) -> bool {
         impl_dispatch!(&self, is_empty())
@@ -824,6 +926,54 @@ impl<Store> ZeroTrie<Store>
 where
     Store: AsRef<[u8]>,
 {
+    /// Returns an iterator over the key/value pairs in this trie whose keys start with
+    /// `prefix`, in lexicographic order.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    pub fn iter_prefix<K>(&self, prefix: K) -> reader::ZeroTrieRangeIterator<'_>
+    where
+        K: AsRef<[u8]>,
+    {
+        let prefix = prefix.as_ref();
+        match &self.0 {
+            ZeroTrieFlavor::SimpleAscii(subtype) => {
+                reader::get_prefix_iter_phf::<ZeroTrieSimpleAscii<[u8]>>(subtype.as_bytes(), prefix)
+            }
+            ZeroTrieFlavor::PerfectHash(subtype) => {
+                reader::get_prefix_iter_phf::<ZeroTriePerfectHash<[u8]>>(subtype.as_bytes(), prefix)
+            }
+            ZeroTrieFlavor::ExtendedCapacity(subtype) => reader::get_prefix_iter_phf::<
+                ZeroTrieExtendedCapacity<[u8]>,
+            >(subtype.as_bytes(), prefix),
+        }
+    }
+    /// Returns an iterator over the key/value pairs in this trie whose keys are in
+    /// `range`, in lexicographic order.
+    ///
+    /// ✨ *Enabled with the `alloc` Cargo feature.*
+    pub fn iter_range<K, R>(&self, range: R) -> reader::ZeroTrieRangeIterator<'_>
+    where
+        K: AsRef<[u8]> + ?Sized,
+        R: core::ops::RangeBounds<K>,
+    {
+        match &self.0 {
+            ZeroTrieFlavor::SimpleAscii(subtype) => reader::get_range_iter_phf::<
+                ZeroTrieSimpleAscii<[u8]>,
+                K,
+                R,
+            >(subtype.as_bytes(), range),
+            ZeroTrieFlavor::PerfectHash(subtype) => reader::get_range_iter_phf::<
+                ZeroTriePerfectHash<[u8]>,
+                K,
+                R,
+            >(subtype.as_bytes(), range),
+            ZeroTrieFlavor::ExtendedCapacity(subtype) => reader::get_range_iter_phf::<
+                ZeroTrieExtendedCapacity<[u8]>,
+                K,
+                R,
+            >(subtype.as_bytes(), range),
+        }
+    }
     /// Exports the data from this [`ZeroTrie`] into a [`BTreeMap`].
     ///
     /// ✨ *Enabled with the `alloc` Cargo feature.*
@@ -23,6 +23,50 @@ macro_rules! assert_bytes_eq {
     };
 }
 
+/// Checks `longest_prefix`, `iter_prefix`, and `iter_range` against the contents of the `LiteMap`.
+fn check_queries(
+    items: &LiteMap<&[u8], usize>,
+    longest_prefix: impl Fn(&[u8]) -> Option<(usize, usize)>,
+    iter_prefix: impl Fn(&[u8]) -> Vec<(Vec<u8>, usize)>,
+    iter_range: impl Fn(&[u8], &[u8]) -> Vec<(Vec<u8>, usize)>,
+) {
+    let step = core::cmp::max(1, items.len() / 50);
+    let mut queries: Vec<Vec<u8>> = NON_EXISTENT_STRINGS
+        .iter()
+        .map(|s| s.as_bytes().to_vec())
+        .collect();
+    for (k, _) in items.iter().step_by(step) {
+        for i in 0..=k.len() {
+            queries.push(k[..i].to_vec());
+        }
+        queries.push([*k, b"x"].concat());
+    }
+    queries.sort();
+    queries.dedup();
+    for query in queries.iter() {
+        let expected = items
+            .iter()
+            .filter(|(k, _)| query.starts_with(k))
+            .map(|(k, v)| (k.len(), *v))
+            .next_back();
+        assert_eq!(longest_prefix(query), expected, "{query:?}");
+        let expected: Vec<(Vec<u8>, usize)> = items
+            .iter()
+            .filter(|(k, _)| k.starts_with(query))
+            .map(|(k, v)| (k.to_vec(), *v))
+            .collect();
+        assert_eq!(iter_prefix(query), expected, "{query:?}");
+    }
+    for bounds in queries.windows(2).step_by(7) {
+        let expected: Vec<(Vec<u8>, usize)> = items
+            .iter()
+            .filter(|(k, _)| **k >= &bounds[0][..] && **k < &bounds[1][..])
+            .map(|(k, v)| (k.to_vec(), *v))
+            .collect();
+        assert_eq!(iter_range(&bounds[0], &bounds[1]), expected, "{bounds:?}");
+    }
+}
+
 fn check_simple_ascii_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTrieSimpleAscii<S>)
 where
     S: AsRef<[u8]> + ?Sized,
@@ -40,6 +84,21 @@ where
         .iter()
         .map(|(s, v)| (String::from_utf8(s.to_vec()).unwrap(), *v))
         .eq(trie.iter()));
+    // Check the queries
+    check_queries(
+        items,
+        |s| trie.longest_prefix(s),
+        |s| {
+            trie.iter_prefix(s)
+                .map(|(k, v)| (k.into_bytes(), v))
+                .collect()
+        },
+        |a, b| {
+            trie.iter_range(a..b)
+                .map(|(k, v)| (k.into_bytes(), v))
+                .collect()
+        },
+    );
     // Check that the const builder works
     let const_trie = ZeroTrieSimpleAscii::try_from_litemap_with_const_builder(items).unwrap();
     assert_eq!(trie.as_bytes(), const_trie.as_bytes());
@@ -64,6 +123,13 @@ where
         items.to_borrowed_keys_values::<[u8], usize, Vec<_>>(),
         recovered_items.to_borrowed_keys_values()
     );
+    // Check the queries, which return items in order
+    check_queries(
+        items,
+        |s| trie.longest_prefix(s),
+        |s| trie.iter_prefix(s).collect(),
+        |a, b| trie.iter_range(a..b).collect(),
+    );
 }
 
 fn check_phf_bytes_trie<S>(items: &LiteMap<&[u8], usize>, trie: &ZeroTriePerfectHash<S>)
@@ -85,6 +151,13 @@ where
         items.to_borrowed_keys_values::<[u8], usize, Vec<_>>(),
         recovered_items.to_borrowed_keys_values()
     );
+    // Check the queries, which return items in order
+    check_queries(
+        items,
+        |s| trie.longest_prefix(s),
+        |s| trie.iter_prefix(s).collect(),
+        |a, b| trie.iter_range(a..b).collect(),
+    );
 }
 
 #[test]
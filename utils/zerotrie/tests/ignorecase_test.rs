@@ -36,6 +36,24 @@ fn test_ignore_case_coverage() {
         assert_eq!(trie.get(k_lower), Some(*v), "lower: {k:?}");
     }
 
+    // Test queries
+    assert_eq!(trie.longest_prefix("ABCDX"), Some((4, 2)));
+    assert_eq!(trie.longest_prefix("Ab"), Some((0, 0)));
+    assert_eq!(
+        trie.iter_prefix("ABC").collect::<Vec<_>>(),
+        [
+            ("aBc".into(), 1),
+            ("aBcD".into(), 2),
+            ("aBce".into(), 3),
+            ("aBcF".into(), 4),
+            ("aBcghi".into(), 5)
+        ]
+    );
+    assert_eq!(
+        trie.iter_range("abcd"..="ABCF").collect::<Vec<_>>(),
+        [("aBcD".into(), 2), ("aBce".into(), 3), ("aBcF".into(), 4)]
+    );
+
     // Test mixed-case strings
     let problematic_strs = &["A", "ab", "abc", "aBcd", "aBcgHi"];
     for problematic_str in problematic_strs {
@@ -97,6 +97,55 @@ struct MultiFieldTuple<'a>(
     #[serde(borrow)] Cow<'a, str>,
 );
 
+#[make_ule(BlobKindULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[zerovec::derive(Blob)]
+#[repr(u8)]
+enum BlobKind {
+    Full = 0,
+    Partial = 1,
+}
+
+#[make_varule(BlobStructULE)]
+#[derive(Clone, PartialEq, Debug)]
+#[zerovec::skip_derive(Ord)]
+#[zerovec::derive(Blob, Debug)]
+struct BlobStruct<'a> {
+    version: u16,
+    kind: BlobKind,
+    offsets: ZeroVec<'a, i32>,
+    names: VarZeroVec<'a, str>,
+    aliases: ZeroMap<'a, str, u32>,
+}
+
+type Offset = i32;
+
+/// The same layout as [`BlobStruct`], with different names
+#[make_varule(RenamedBlobStructULE)]
+#[derive(Clone, PartialEq, Debug)]
+#[zerovec::skip_derive(Ord)]
+#[zerovec::derive(Blob)]
+struct RenamedBlobStruct<'a> {
+    revision: u16,
+    kind: BlobKind,
+    utc_offsets: ZeroVec<'a, Offset>,
+    ids: VarZeroVec<'a, str>,
+    links: ZeroMap<'a, str, u32>,
+}
+
+/// A different layout than [`BlobStruct`]
+#[make_varule(WideBlobStructULE)]
+#[derive(Clone, PartialEq, Debug)]
+#[zerovec::skip_derive(Ord)]
+#[zerovec::derive(Blob)]
+struct WideBlobStruct<'a> {
+    version: u32,
+    kind: BlobKind,
+    offsets: ZeroVec<'a, i32>,
+    names: VarZeroVec<'a, str>,
+    aliases: ZeroMap<'a, str, u32>,
+}
+
 /// The `assert` function should have the body `|(stack, zero)| assert_eq!(stack, &U::zero_from(&zero))`
 ///
 /// We cannot do this internally because we technically need a different `U` with a shorter lifetime here
@@ -201,6 +250,33 @@ fn main() {
     assert_eq!(multi_ule.d, TEST_MULTIFIELD[0].d);
     assert_eq!(multi_ule.e(), TEST_MULTIFIELD[0].e);
     assert_eq!(multi_ule.f, TEST_MULTIFIELD[0].f.to_unaligned());
+
+    let blob_struct = BlobStruct {
+        version: 3,
+        kind: BlobKind::Partial,
+        offsets: ZeroVec::alloc_from_slice(&[-3600, 0, 7200]),
+        names: TEST_STRINGS1.into(),
+        aliases: [("one", 1), ("three", 3), ("two", 2)].into_iter().collect(),
+    };
+    let blob = blob::encode_blob::<BlobStructULE>(&blob_struct);
+    let parsed = blob::parse_blob::<BlobStructULE>(&blob).expect("blob was written for this type");
+    assert_eq!(BlobStruct::zero_from(parsed), blob_struct);
+    assert_eq!(parsed.aliases().keys().len(), 3);
+    assert_ne!(BlobKind::from_unaligned(parsed.kind), BlobKind::Full);
+    // The header is validated
+    assert!(blob::parse_blob::<BlobStructULE>(&blob[blob::HEADER_LEN..]).is_err());
+    // The fingerprint only depends on the layout
+    use blob::BlobLayout;
+    assert_eq!(
+        BlobStructULE::LAYOUT_FINGERPRINT,
+        RenamedBlobStructULE::LAYOUT_FINGERPRINT
+    );
+    assert!(blob::parse_blob::<RenamedBlobStructULE>(&blob).is_ok());
+    assert_ne!(
+        BlobStructULE::LAYOUT_FINGERPRINT,
+        WideBlobStructULE::LAYOUT_FINGERPRINT
+    );
+    assert!(blob::parse_blob::<WideBlobStructULE>(&blob).is_err());
 }
 
 const TEST_VARSTRUCTS: &[VarStruct<'static>] = &[
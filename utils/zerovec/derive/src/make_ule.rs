@@ -142,6 +142,17 @@ fn make_ule_enum_impl(
         .map(|d| quote::quote!(#d))
         .collect::<Vec<_>>();
 
+    // The valid discriminants are part of the layout, as they are validated
+    let maybe_blob = if attrs.blob {
+        let parts = discriminants
+            .iter()
+            .map(|d| quote!(#d as u64))
+            .collect::<Vec<_>>();
+        utils::blob_layout_impl(ule_name, "make_ule enum", &parts)
+    } else {
+        quote!()
+    };
+
     let maybe_ord_derives = if attrs.skip_ord {
         quote!()
     } else {
@@ -199,6 +210,8 @@ fn make_ule_enum_impl(
             }
         }
 
+        #maybe_blob
+
         impl #name {
             /// Attempt to construct the value from its corresponding integer,
             /// returning `None` if not possible
@@ -321,6 +334,20 @@ fn make_ule_struct_impl(
         quote!()
     };
 
+    let maybe_blob = if attrs.blob {
+        let parts = struc
+            .fields
+            .iter()
+            .map(|f| {
+                let ty = &f.ty;
+                quote!(<<#ty as zerovec::ule::AsULE>::ULE as zerovec::blob::BlobLayout>::LAYOUT_FINGERPRINT)
+            })
+            .collect::<Vec<_>>();
+        utils::blob_layout_impl(ule_name, "make_ule", &parts)
+    } else {
+        quote!()
+    };
+
     quote!(
         #asule_impl
 
@@ -331,5 +358,7 @@ fn make_ule_struct_impl(
         #maybe_ord_impls
 
         #maybe_hash
+
+        #maybe_blob
     )
 }
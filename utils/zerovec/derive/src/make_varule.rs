@@ -107,6 +107,28 @@ pub fn make_varule_impl(ule_name: Ident, mut input: DeriveInput) -> TokenStream2
 
     let unsized_field_info = UnsizedFields::new(unsized_fields, attrs.vzv_format);
 
+    let maybe_blob = if attrs.blob {
+        // The sized fields are laid out first, followed by the unsized fields
+        let mut parts = sized_fields
+            .iter()
+            .map(|f| {
+                let ty = &f.field.ty;
+                quote!(<<#ty as zerovec::ule::AsULE>::ULE as zerovec::blob::BlobLayout>::LAYOUT_FINGERPRINT)
+            })
+            .collect::<Vec<_>>();
+        if unsized_field_info.fields.len() > 1 {
+            let format_param = &unsized_field_info.format_param;
+            parts.push(quote!(<#format_param as zerovec::blob::BlobLayout>::LAYOUT_FINGERPRINT));
+        }
+        parts.extend(unsized_field_info.fields.iter().map(|f| {
+            let varule_ty = f.kind.varule_ty();
+            quote!(<#varule_ty as zerovec::blob::BlobLayout>::LAYOUT_FINGERPRINT)
+        }));
+        utils::blob_layout_impl(&ule_name, "make_varule", &parts)
+    } else {
+        quote!()
+    };
+
     let mut field_inits = crate::ule::make_ule_fields(&sized_fields);
     let last_field_ule = unsized_field_info.varule_ty();
 
@@ -328,6 +350,8 @@ pub fn make_varule_impl(ule_name: Ident, mut input: DeriveInput) -> TokenStream2
         #maybe_toowned
 
         #maybe_hash
+
+        #maybe_blob
     )
 }
 
@@ -494,6 +518,8 @@ enum UnsizedFieldKind<'a> {
     VarZeroCow(OwnULETy<'a>),
     ZeroVec(&'a Type),
     VarZeroVec(&'a Type),
+    /// `ZeroMap<'a, K, V>`, with the key and value types
+    ZeroMap(&'a Type, &'a Type),
     /// Custom `VarULE` type, and the identifier corresponding to the `VarULE` type
     Custom(&'a TypePath, Ident),
 
@@ -730,11 +756,11 @@ impl<'a> UnsizedFieldKind<'a> {
     ) -> Result<UnsizedFieldKind<'a>, String> {
         static PATH_TYPE_IDENTITY_ERROR: &str =
             "Can only automatically detect corresponding VarULE types for path types \
-            that are Cow, ZeroVec, VarZeroVec, Box, String, or Vec";
+            that are Cow, ZeroVec, VarZeroVec, ZeroMap, Box, String, or Vec";
         static PATH_TYPE_GENERICS_ERROR: &str =
             "Can only automatically detect corresponding VarULE types for path \
-            types with at most one lifetime and at most one generic parameter. VarZeroVecFormat
-            types are not currently supported";
+            types with at most one lifetime and at most one generic parameter (two for ZeroMap). \
+            VarZeroVecFormat types are not currently supported";
         match *ty {
             Type::Reference(ref tyref) => OwnULETy::new(&tyref.elem, "reference").map(UnsizedFieldKind::Ref),
             Type::Path(ref typath) => {
@@ -755,31 +781,37 @@ impl<'a> UnsizedFieldKind<'a> {
                         }
                     }
                     PathArguments::AngleBracketed(ref params) => {
-                        // At most one lifetime and exactly one generic parameter
+                        // At most one lifetime and one or two generic parameters
                         let mut lifetime = None;
-                        let mut generic = None;
+                        let mut generics = vec![];
                         for param in &params.args {
                             match param {
                                 GenericArgument::Lifetime(ref lt) if lifetime.is_none() => {
                                     lifetime = Some(lt)
                                 }
-                                GenericArgument::Type(ref ty) if generic.is_none() => {
-                                    generic = Some(ty)
+                                GenericArgument::Type(ref ty) if generics.len() < 2 => {
+                                    generics.push(ty)
                                 }
                                 _ => return Err(PATH_TYPE_GENERICS_ERROR.into()),
                             }
                         }
 
-                        // Must be exactly one generic parameter
+                        let ident = segment.ident.to_string();
+
+                        if let [key, value] = generics[..] {
+                            return if lifetime.is_some() && ident == "ZeroMap" {
+                                Ok(UnsizedFieldKind::ZeroMap(key, value))
+                            } else {
+                                Err(PATH_TYPE_GENERICS_ERROR.into())
+                            };
+                        }
+
+                        // Must be exactly one generic parameter otherwise
                         // (we've handled the zero generics case already)
-                        let generic = if let Some(g) = generic {
-                            g
-                        } else {
+                        let [generic] = generics[..] else {
                             return Err(PATH_TYPE_GENERICS_ERROR.into());
                         };
 
-                        let ident = segment.ident.to_string();
-
                         if lifetime.is_some() {
                             match &*ident {
                                 "ZeroVec" => Ok(UnsizedFieldKind::ZeroVec(generic)),
@@ -817,6 +849,7 @@ impl<'a> UnsizedFieldKind<'a> {
             Self::Custom(_, ref name) => quote!(#name),
             Self::ZeroVec(ref inner) => quote!(zerovec::ZeroSlice<#inner>),
             Self::VarZeroVec(ref inner) => quote!(zerovec::VarZeroSlice<#inner>),
+            Self::ZeroMap(ref key, ref value) => quote!(zerovec::maps::ZeroMapULE<#key, #value>),
         }
     }
 
@@ -829,7 +862,7 @@ impl<'a> UnsizedFieldKind<'a> {
             | Self::Growable(_)
             | Self::Boxed(_) => quote!(&*#value),
 
-            Self::Custom(..) => quote!(&#value),
+            Self::Custom(..) | Self::ZeroMap(..) => quote!(&#value),
             Self::ZeroVec(_) | Self::VarZeroVec(_) => quote!(&*#value),
         }
     }
@@ -846,6 +879,7 @@ impl<'a> UnsizedFieldKind<'a> {
             Self::Custom(ref path, _) => quote!(#path),
             Self::ZeroVec(ref ty) => quote!(zerovec::ZeroSlice<#ty>),
             Self::VarZeroVec(ref ty) => quote!(zerovec::VarZeroSlice<#ty>),
+            Self::ZeroMap(ref key, ref value) => quote!(zerovec::ZeroMap<'_, #key, #value>),
         }
     }
 
@@ -857,6 +891,7 @@ impl<'a> UnsizedFieldKind<'a> {
                 | Self::VarZeroCow(_)
                 | Self::ZeroVec(_)
                 | Self::VarZeroVec(_)
+                | Self::ZeroMap(..)
                 | Self::Custom(..)
         )
     }
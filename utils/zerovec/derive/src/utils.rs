@@ -308,6 +308,22 @@ pub fn extract_field_attributes(attrs: &mut Vec<Attribute>) -> Result<Option<Ide
     Ok(varule.first().cloned())
 }
 
+/// Generates a `BlobLayout` impl for `ule_name`, combining the fingerprints of `parts`, which
+/// are expressions of the `LAYOUT_FINGERPRINT`s of the ULE types the type is made of.
+pub fn blob_layout_impl(ule_name: &Ident, kind: &str, parts: &[TokenStream2]) -> TokenStream2 {
+    quote!(
+        impl zerovec::blob::BlobLayout for #ule_name {
+            const LAYOUT_FINGERPRINT: u64 = {
+                let hash = zerovec::blob::layout_fingerprint(#kind);
+                #(
+                    let hash = zerovec::blob::combine_fingerprints(hash, #parts);
+                )*
+                hash
+            };
+        }
+    )
+}
+
 #[derive(Default, Clone)]
 pub struct ZeroVecAttrs {
     pub skip_kv: bool,
@@ -318,6 +334,7 @@ pub struct ZeroVecAttrs {
     pub deserialize: bool,
     pub debug: bool,
     pub hash: bool,
+    pub blob: bool,
     pub vzv_format: Option<TokenStream2>,
 }
 
@@ -353,6 +370,8 @@ pub fn extract_attributes_common(
             attrs.debug = true;
         } else if ident == "Hash" {
             attrs.hash = true;
+        } else if ident == "Blob" {
+            attrs.blob = true;
         } else {
             return Err(Error::new(
                 ident.span(),
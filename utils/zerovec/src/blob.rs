@@ -0,0 +1,338 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A standalone binary format for [`VarULE`] types, which can be loaded without a deserializer.
+//!
+//! A blob consists of a 12-byte header followed by the bytes of a [`VarULE`] value. The header
+//! contains a magic number and the [`BlobLayout::LAYOUT_FINGERPRINT`] of the type, so that loading
+//! bytes that were not written for the expected type fails instead of producing garbage.
+//!
+//! Loading a blob validates the header and the [`VarULE`] bytes, and then casts the bytes to the
+//! [`VarULE`] type. This does not allocate and does not need Serde.
+//!
+//! Structs made of [`ZeroVec`](crate::ZeroVec), [`VarZeroVec`](crate::VarZeroVec),
+//! [`ZeroMap`](crate::ZeroMap), and [`AsULE`](crate::ule::AsULE) fields can be stored as blobs
+//! by generating their [`VarULE`] type with [`#[make_varule]`](crate::make_varule) and
+//! `#[zerovec::derive(Blob)]`. Fields of custom [`AsULE`](crate::ule::AsULE) types need a
+//! [`BlobLayout`] as well, which [`#[make_ule]`](crate::make_ule) types get with
+//! `#[zerovec::derive(Blob)]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use zerofrom::ZeroFrom;
+//! use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+//!
+//! #[zerovec::make_varule(TimeZoneDataULE)]
+//! #[zerovec::derive(Blob)]
+//! #[zerovec::skip_derive(Ord)]
+//! #[derive(Debug, PartialEq)]
+//! struct TimeZoneData<'a> {
+//!     version: u32,
+//!     offsets: ZeroVec<'a, i32>,
+//!     names: VarZeroVec<'a, str>,
+//!     aliases: ZeroMap<'a, str, str>,
+//!     comment: Cow<'a, str>,
+//! }
+//!
+//! let data = TimeZoneData {
+//!     version: 2,
+//!     offsets: ZeroVec::from_slice_or_alloc(&[0, 3600, -18000]),
+//!     names: VarZeroVec::from(&["UTC", "Europe/Paris", "America/New_York"]),
+//!     aliases: [("Asia/Calcutta", "Asia/Kolkata")].into_iter().collect(),
+//!     comment: Cow::Borrowed("sample"),
+//! };
+//!
+//! let bytes = zerovec::blob::encode_blob::<TimeZoneDataULE>(&data);
+//!
+//! // Loading the blob is a validated cast
+//! let ule = zerovec::blob::parse_blob::<TimeZoneDataULE>(&bytes)
+//!     .expect("bytes were written for this type");
+//! let loaded = TimeZoneData::zero_from(ule);
+//!
+//! assert_eq!(loaded, data);
+//! assert_eq!(loaded.aliases.get("Asia/Calcutta"), Some("Asia/Kolkata"));
+//!
+//! // Bytes with a corrupted header are rejected
+//! assert!(zerovec::blob::parse_blob::<TimeZoneDataULE>(&bytes[1..]).is_err());
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::maps::{ZeroMapKV, ZeroMapULE};
+use crate::ule::tuple::{Tuple2ULE, Tuple3ULE, Tuple4ULE, Tuple5ULE, Tuple6ULE};
+use crate::ule::tuplevar::{Tuple2VarULE, Tuple3VarULE, Tuple4VarULE, Tuple5VarULE, Tuple6VarULE};
+use crate::ule::vartuple::VarTupleULE;
+use crate::ule::{
+    AsULE, CharULE, EncodeAsVarULE, NicheBytes, NichedOptionULE, OptionULE, OptionVarULE,
+    RawBytesULE, UleError, VarULE, ULE,
+};
+use crate::vecs::{Index16, Index32, Index8, VarZeroSlice, VarZeroVecFormat};
+use crate::ZeroSlice;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::num::{NonZeroI8, NonZeroU8};
+
+/// The magic number at the start of every blob, including the version of the format.
+const MAGIC: [u8; 4] = *b"ZVB\x01";
+
+/// The length of the header of a blob.
+pub const HEADER_LEN: usize = MAGIC.len() + size_of::<u64>();
+
+/// A [`ULE`] or [`VarULE`] type with a fingerprint of its layout.
+///
+/// This is implemented for the ULE types of this crate, and with `#[zerovec::derive(Blob)]`
+/// for [`#[make_ule]`](crate::make_ule) and [`#[make_varule]`](crate::make_varule) types.
+pub trait BlobLayout {
+    /// A fingerprint of the layout of this type.
+    ///
+    /// Blobs are only loaded as a type if their header contains the same fingerprint, so it
+    /// has to change whenever the layout of the type changes. The fingerprints of composite types
+    /// are computed with [`combine_fingerprints()`] from the fingerprints of their parts, so two
+    /// types have the same fingerprint if their ULE types are laid out and validated the same way,
+    /// regardless of how they are named.
+    const LAYOUT_FINGERPRINT: u64;
+}
+
+/// A [`VarULE`] type that can be stored as a standalone blob.
+///
+/// This trait is implemented for all [`VarULE`] types with a [`BlobLayout`]. See the
+/// [module-level documentation](self).
+pub trait BlobULE: VarULE + BlobLayout {}
+
+impl<T: VarULE + BlobLayout + ?Sized> BlobULE for T {}
+
+/// Validates the header and contents of a blob and casts it to `T`.
+///
+/// See the [module-level documentation](self) for an example.
+pub fn parse_blob<T: BlobULE + ?Sized + 'static>(bytes: &[u8]) -> Result<&T, UleError> {
+    let Some((magic, rest)) = bytes.split_first_chunk::<4>() else {
+        return Err(UleError::length::<T>(bytes.len()));
+    };
+    let Some((fingerprint, payload)) = rest.split_first_chunk::<8>() else {
+        return Err(UleError::length::<T>(bytes.len()));
+    };
+    if *magic != MAGIC || u64::from_le_bytes(*fingerprint) != T::LAYOUT_FINGERPRINT {
+        return Err(UleError::parse::<T>());
+    }
+    T::parse_bytes(payload)
+}
+
+/// Returns the length of the blob that [`write_blob()`] writes for `value`.
+pub fn blob_len<T: BlobULE + ?Sized>(value: &(impl EncodeAsVarULE<T> + ?Sized)) -> usize {
+    HEADER_LEN + value.encode_var_ule_len()
+}
+
+/// Writes `value` as a blob of `T` to `dst`.
+///
+/// # Panics
+///
+/// Panics if `dst.len()` is not [`blob_len()`].
+pub fn write_blob<T: BlobULE + ?Sized>(value: &(impl EncodeAsVarULE<T> + ?Sized), dst: &mut [u8]) {
+    assert_eq!(
+        dst.len(),
+        blob_len(value),
+        "blob buffer has the wrong length"
+    );
+    let (magic, rest) = dst.split_at_mut(MAGIC.len());
+    magic.copy_from_slice(&MAGIC);
+    let (fingerprint, payload) = rest.split_at_mut(size_of::<u64>());
+    fingerprint.copy_from_slice(&T::LAYOUT_FINGERPRINT.to_le_bytes());
+    value.encode_var_ule_write(payload);
+}
+
+/// Encodes `value` as a blob of `T`.
+///
+/// See the [module-level documentation](self) for an example.
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+#[cfg(feature = "alloc")]
+pub fn encode_blob<T: BlobULE + ?Sized>(value: &(impl EncodeAsVarULE<T> + ?Sized)) -> Vec<u8> {
+    let mut bytes = alloc::vec![0; blob_len(value)];
+    write_blob(value, &mut bytes);
+    bytes
+}
+
+/// Computes a [`BlobLayout::LAYOUT_FINGERPRINT`] from a description of a layout.
+///
+/// This is the 64-bit FNV-1a hash of `description`.
+pub const fn layout_fingerprint(description: &str) -> u64 {
+    let bytes = description.as_bytes();
+    let mut hash = 0xcbf29ce484222325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        #[expect(clippy::indexing_slicing)] // i < bytes.len()
+        let byte = bytes[i];
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Combines the [`BlobLayout::LAYOUT_FINGERPRINT`] of a type with that of one of its parts,
+/// or with a number such as the length of an array.
+///
+/// This continues the FNV-1a hash of `fingerprint` with the little-endian bytes of `part`.
+pub const fn combine_fingerprints(fingerprint: u64, part: u64) -> u64 {
+    let bytes = part.to_le_bytes();
+    let mut hash = fingerprint;
+    let mut i = 0;
+    while i < bytes.len() {
+        #[expect(clippy::indexing_slicing)] // i < bytes.len()
+        let byte = bytes[i];
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+macro_rules! impl_blob_layout {
+    ($($ty:ty),+) => {
+        $(
+            impl BlobLayout for $ty {
+                const LAYOUT_FINGERPRINT: u64 = layout_fingerprint(stringify!($ty));
+            }
+        )+
+    };
+}
+
+impl_blob_layout!(
+    u8,
+    i8,
+    bool,
+    (),
+    NonZeroU8,
+    NonZeroI8,
+    CharULE,
+    str,
+    Index8,
+    Index16,
+    Index32
+);
+
+impl<const N: usize> BlobLayout for RawBytesULE<N> {
+    const LAYOUT_FINGERPRINT: u64 =
+        combine_fingerprints(layout_fingerprint("RawBytesULE"), N as u64);
+}
+
+impl<T: ULE + BlobLayout, const N: usize> BlobLayout for [T; N] {
+    const LAYOUT_FINGERPRINT: u64 = combine_fingerprints(
+        combine_fingerprints(layout_fingerprint("[T; N]"), T::LAYOUT_FINGERPRINT),
+        N as u64,
+    );
+}
+
+impl<U: ULE + BlobLayout> BlobLayout for OptionULE<U> {
+    const LAYOUT_FINGERPRINT: u64 =
+        combine_fingerprints(layout_fingerprint("OptionULE"), U::LAYOUT_FINGERPRINT);
+}
+
+impl<U: NicheBytes<N> + ULE + BlobLayout, const N: usize> BlobLayout for NichedOptionULE<U, N> {
+    const LAYOUT_FINGERPRINT: u64 = combine_fingerprints(
+        combine_fingerprints(layout_fingerprint("NichedOptionULE"), U::LAYOUT_FINGERPRINT),
+        N as u64,
+    );
+}
+
+macro_rules! impl_tuple_blob_layout {
+    ($name:ident, [$($t:ident),+]) => {
+        impl<$($t: ULE + BlobLayout),+> BlobLayout for $name<$($t),+> {
+            const LAYOUT_FINGERPRINT: u64 = {
+                let hash = layout_fingerprint(stringify!($name));
+                $(
+                    let hash = combine_fingerprints(hash, $t::LAYOUT_FINGERPRINT);
+                )+
+                hash
+            };
+        }
+    };
+}
+
+impl_tuple_blob_layout!(Tuple2ULE, [A, B]);
+impl_tuple_blob_layout!(Tuple3ULE, [A, B, C]);
+impl_tuple_blob_layout!(Tuple4ULE, [A, B, C, D]);
+impl_tuple_blob_layout!(Tuple5ULE, [A, B, C, D, E]);
+impl_tuple_blob_layout!(Tuple6ULE, [A, B, C, D, E, F]);
+
+/// Slices have the same layout as [`ZeroSlice`].
+impl<T: ULE + BlobLayout> BlobLayout for [T] {
+    const LAYOUT_FINGERPRINT: u64 =
+        combine_fingerprints(layout_fingerprint("[T]"), T::LAYOUT_FINGERPRINT);
+}
+
+impl<T: AsULE + 'static> BlobLayout for ZeroSlice<T>
+where
+    T::ULE: BlobLayout,
+{
+    const LAYOUT_FINGERPRINT: u64 = <[T::ULE] as BlobLayout>::LAYOUT_FINGERPRINT;
+}
+
+impl<T: VarULE + BlobLayout + ?Sized, F: VarZeroVecFormat + BlobLayout> BlobLayout
+    for VarZeroSlice<T, F>
+{
+    const LAYOUT_FINGERPRINT: u64 = combine_fingerprints(
+        combine_fingerprints(layout_fingerprint("VarZeroSlice"), T::LAYOUT_FINGERPRINT),
+        F::LAYOUT_FINGERPRINT,
+    );
+}
+
+impl<U: VarULE + BlobLayout + ?Sized> BlobLayout for OptionVarULE<U> {
+    const LAYOUT_FINGERPRINT: u64 =
+        combine_fingerprints(layout_fingerprint("OptionVarULE"), U::LAYOUT_FINGERPRINT);
+}
+
+impl<A: AsULE, V: VarULE + BlobLayout + ?Sized> BlobLayout for VarTupleULE<A, V>
+where
+    A::ULE: BlobLayout,
+{
+    const LAYOUT_FINGERPRINT: u64 = combine_fingerprints(
+        combine_fingerprints(
+            layout_fingerprint("VarTupleULE"),
+            A::ULE::LAYOUT_FINGERPRINT,
+        ),
+        V::LAYOUT_FINGERPRINT,
+    );
+}
+
+macro_rules! impl_tuple_var_blob_layout {
+    ($name:ident, [$($t:ident),+]) => {
+        impl<$($t: VarULE + BlobLayout + ?Sized,)+ Format: VarZeroVecFormat + BlobLayout> BlobLayout
+            for $name<$($t,)+ Format>
+        {
+            const LAYOUT_FINGERPRINT: u64 = {
+                let hash = layout_fingerprint(stringify!($name));
+                let hash = combine_fingerprints(hash, Format::LAYOUT_FINGERPRINT);
+                $(
+                    let hash = combine_fingerprints(hash, $t::LAYOUT_FINGERPRINT);
+                )+
+                hash
+            };
+        }
+    };
+}
+
+impl_tuple_var_blob_layout!(Tuple2VarULE, [A, B]);
+impl_tuple_var_blob_layout!(Tuple3VarULE, [A, B, C]);
+impl_tuple_var_blob_layout!(Tuple4VarULE, [A, B, C, D]);
+impl_tuple_var_blob_layout!(Tuple5VarULE, [A, B, C, D, E]);
+impl_tuple_var_blob_layout!(Tuple6VarULE, [A, B, C, D, E, F]);
+
+#[cfg(feature = "alloc")]
+impl<K, V> BlobLayout for ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + ?Sized,
+    V: ZeroMapKV<'static> + ?Sized,
+    K::Slice: VarULE + BlobLayout,
+    V::Slice: VarULE + BlobLayout,
+{
+    const LAYOUT_FINGERPRINT: u64 = combine_fingerprints(
+        combine_fingerprints(
+            layout_fingerprint("ZeroMapULE"),
+            K::Slice::LAYOUT_FINGERPRINT,
+        ),
+        V::Slice::LAYOUT_FINGERPRINT,
+    );
+}
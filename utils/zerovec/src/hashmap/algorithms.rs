@@ -132,8 +132,10 @@ pub fn compute_displacements(
         // start, end - 1 are always within bounds of `bucket_sizes`
         let buckets = &bucket_flatten[start..end];
 
-        'd0: for d0 in 0..len_u32 {
-            'd1: for d1 in 0..len_u32 {
+        // (0, 0) is reserved for empty buckets, so a single key needs a larger range
+        let d_max = len_u32.max(2);
+        'd0: for d0 in 0..d_max {
+            'd1: for d1 in 0..d_max {
                 if (d0, d1) == (0, 0) {
                     continue;
                 }
@@ -118,6 +118,81 @@ where
     pub fn contains_key(&self, key: &K) -> bool {
         self.index(key).is_some()
     }
+
+    /// Retain only the entries for which `predicate` returns `true`.
+    ///
+    /// If any entry is removed, the hash function is recomputed for the remaining keys,
+    /// and the underlying vectors are rebuilt in a single pass.
+    ///
+    /// # Example
+    /// ```
+    /// use zerovec::ZeroHashMap;
+    ///
+    /// let mut hashmap =
+    ///     ZeroHashMap::<i32, str>::from_iter([(0, "a"), (1, "b"), (2, "c")]);
+    /// hashmap.retain(|_, v| v != "b");
+    ///
+    /// assert_eq!(hashmap.len(), 2);
+    /// assert_eq!(hashmap.get(&0), Some("a"));
+    /// assert_eq!(hashmap.get(&1), None);
+    /// assert_eq!(hashmap.get(&2), Some("c"));
+    /// ```
+    pub fn retain(&mut self, mut predicate: impl FnMut(&K::GetType, &V::GetType) -> bool) {
+        let entries: Vec<_> = self.iter().filter(|&(k, v)| predicate(k, v)).collect();
+        if entries.len() != self.len() {
+            *self = Self::from_get_entries(&entries);
+        }
+    }
+
+    /// Remove the entries for which `predicate` returns `true`, returning them as a new hashmap.
+    ///
+    /// If any entry is removed, the hash function is recomputed for the remaining keys,
+    /// and the underlying vectors are rebuilt in a single pass.
+    ///
+    /// # Example
+    /// ```
+    /// use zerovec::ZeroHashMap;
+    ///
+    /// let mut hashmap =
+    ///     ZeroHashMap::<i32, str>::from_iter([(0, "a"), (1, "b"), (2, "c")]);
+    /// let removed = hashmap.extract_if(|_, v| v != "b");
+    ///
+    /// assert_eq!(hashmap.len(), 1);
+    /// assert_eq!(hashmap.get(&1), Some("b"));
+    /// assert_eq!(removed.len(), 2);
+    /// assert_eq!(removed.get(&0), Some("a"));
+    /// assert_eq!(removed.get(&2), Some("c"));
+    /// ```
+    pub fn extract_if(
+        &mut self,
+        mut predicate: impl FnMut(&K::GetType, &V::GetType) -> bool,
+    ) -> Self {
+        let (removed, kept): (Vec<_>, Vec<_>) = self.iter().partition(|&(k, v)| predicate(k, v));
+        let removed = Self::from_get_entries(&removed);
+        if !removed.is_empty() {
+            *self = Self::from_get_entries(&kept);
+        }
+        removed
+    }
+
+    /// Builds a hashmap from entries obtained from another map.
+    fn from_get_entries(entries: &[(&K::GetType, &V::GetType)]) -> Self {
+        let (displacements, reverse_mapping) = compute_displacements(
+            entries
+                .iter()
+                .map(|&(k, _)| K::Container::zvl_get_as_t(k, compute_hash::<K>)),
+        );
+        let (keys, values): (Vec<_>, Vec<_>) = reverse_mapping
+            .iter()
+            .filter_map(|&i| entries.get(i).copied())
+            .unzip();
+
+        Self {
+            displacements: ZeroVec::alloc_from_slice(&displacements),
+            keys: K::Container::zvl_from_get_elements(&keys),
+            values: V::Container::zvl_from_get_elements(&values),
+        }
+    }
 }
 
 impl<'a, K, V> ZeroHashMap<'a, K, V>
@@ -186,30 +261,21 @@ where
     /// assert_eq!(hashmap.get(&4), Some("d"));
     /// ```
     fn from_iter<T: IntoIterator<Item = (A, B)>>(iter: T) -> Self {
-        let iter = iter.into_iter();
-        let size_hint = match iter.size_hint() {
-            (_, Some(upper)) => upper,
-            (lower, None) => lower,
-        };
+        let entries: Vec<(A, B)> = iter.into_iter().collect();
 
-        let mut key_hashes = Vec::with_capacity(size_hint);
-        let mut keys = K::Container::zvl_with_capacity(size_hint);
-        let mut values = V::Container::zvl_with_capacity(size_hint);
-        for (k, v) in iter {
-            keys.zvl_push(k.borrow());
-            key_hashes.push(compute_hash(k.borrow()));
-            values.zvl_push(v.borrow());
-        }
+        let (displacements, reverse_mapping) =
+            compute_displacements(entries.iter().map(|(k, _)| compute_hash::<K>(k.borrow())));
 
-        let (displacements, mut reverse_mapping) = compute_displacements(key_hashes.into_iter());
-
-        keys.zvl_permute(&mut reverse_mapping.clone());
-        values.zvl_permute(&mut reverse_mapping);
+        let (keys, values): (Vec<&K>, Vec<&V>) = reverse_mapping
+            .iter()
+            .filter_map(|&i| entries.get(i))
+            .map(|(k, v)| (k.borrow(), v.borrow()))
+            .unzip();
 
         Self {
             displacements: ZeroVec::alloc_from_slice(&displacements),
-            values,
-            keys,
+            keys: K::Container::zvl_from_elements(&keys),
+            values: V::Container::zvl_from_elements(&values),
         }
     }
 }
@@ -237,6 +303,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_single_entry() {
+        for k in 0..100 {
+            let hashmap = ZeroHashMap::<u32, str>::from_iter([(k, "value")]);
+            assert_eq!(hashmap.get(&k), Some("value"));
+            assert_eq!(hashmap.get(&(k + 1)), None);
+        }
+    }
+
+    #[test]
+    fn test_retain_extract_if() {
+        const N: usize = 1000;
+        let seed = u64::from_le_bytes(*b"testseed");
+        let rng = Lcg64Xsh32::seed_from_u64(seed);
+        let keys: Vec<u32> = rng.sample_iter(&StandardUniform).take(N).collect();
+        let values: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let mut hashmap: ZeroHashMap<u32, str> =
+            ZeroHashMap::from_iter(keys.iter().zip(values.iter().map(String::as_str)));
+
+        let removed = hashmap.extract_if(|k, _| u32::from_unaligned(*k) % 2 == 0);
+        let mut retained = removed;
+        retained.retain(|_, v| v.len() > 9);
+        for k in &keys {
+            let value = k.to_string();
+            let (in_hashmap, in_retained) = if k % 2 == 1 {
+                (Some(value.as_str()), None)
+            } else if value.len() > 9 {
+                (None, Some(value.as_str()))
+            } else {
+                (None, None)
+            };
+            assert_eq!(hashmap.get(k), in_hashmap);
+            assert_eq!(retained.get(k), in_retained);
+        }
+        assert_eq!(
+            hashmap.len() + retained.len(),
+            keys.iter()
+                .filter(|&k| k % 2 == 1 || k.to_string().len() > 9)
+                .count()
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Hashmap too large for u32")]
     #[cfg(target_pointer_width = "64")]
@@ -213,6 +213,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod blob;
 mod cow;
 #[cfg(feature = "hashmap")]
 pub mod hashmap;
@@ -279,7 +280,7 @@ pub mod maps {
     pub use crate::map2d::ZeroMap2d;
     pub use crate::map2d::ZeroMap2dBorrowed;
 
-    pub use crate::map::{MutableZeroVecLike, ZeroMapKV, ZeroVecLike};
+    pub use crate::map::{DuplicatePolicy, MutableZeroVecLike, ZeroMapKV, ZeroMapULE, ZeroVecLike};
 
     pub use crate::map2d::ZeroMap2dCursor;
 }
@@ -337,6 +338,7 @@ pub mod vecs {
 /// The following traits are available to derive, but not automatic:
 ///
 /// - [`Debug`]
+/// - [`BlobLayout`](blob::BlobLayout), with `#[zerovec::derive(Blob)]`
 ///
 /// To enable one of these additional derives, use `#[zerovec::derive(...)]` like so: `#[zerovec::derive(Debug)]`.
 ///
@@ -415,7 +417,7 @@ pub use zerovec_derive::make_ule;
 /// implementations for this type
 ///
 /// This can be attached to structs containing only [`AsULE`] types with the last fields being
-/// [`Cow<'a, str>`](alloc::borrow::Cow), [`ZeroSlice`], or [`VarZeroSlice`]. If there is more than one such field, it will be represented
+/// [`Cow<'a, str>`](alloc::borrow::Cow), [`ZeroSlice`], [`VarZeroSlice`], or [`ZeroMap`]. If there is more than one such field, it will be represented
 /// using [`MultiFieldsULE`](crate::ule::MultiFieldsULE) and getters will be generated. Other [`VarULE`] fields will be detected if they are
 /// tagged with `#[zerovec::varule(NameOfVarULETy)]`.
 ///
@@ -440,6 +442,7 @@ pub use zerovec_derive::make_ule;
 /// - [`Debug`]
 /// - [`Serialize`](serde::Serialize)
 /// - [`Deserialize`](serde::Deserialize)
+/// - [`BlobLayout`](blob::BlobLayout), with `#[zerovec::derive(Blob)]`, making the type a [`BlobULE`](blob::BlobULE)
 ///
 /// To enable one of these additional derives, use `#[zerovec::derive(...)]` like so: `#[zerovec::derive(Debug)]`.
 ///
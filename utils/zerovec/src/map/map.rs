@@ -6,10 +6,12 @@ use super::*;
 use crate::ule::{AsULE, EncodeAsVarULE, UleError, VarULE};
 use crate::{VarZeroVec, ZeroSlice, ZeroVec};
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt;
 use core::iter::FromIterator;
+use core::mem;
 
 /// A zero-copy map datastructure, built on sorted binary-searchable [`ZeroVec`]
 /// and [`VarZeroVec`].
@@ -67,6 +69,48 @@ where
     pub(crate) values: V::Container,
 }
 
+/// How bulk operations on maps resolve multiple entries with the same key.
+///
+/// See [`ZeroMap::from_unsorted_iter()`] and [`ZeroMap::merge()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DuplicatePolicy {
+    /// Keep the first entry for each key, like [`ZeroMap::try_append()`].
+    KeepFirst,
+    /// Keep the last entry for each key, like [`ZeroMap::insert()`].
+    KeepLast,
+}
+
+impl DuplicatePolicy {
+    /// Removes consecutive entries for which `same_key` returns `true`, keeping one of them
+    /// according to this policy.
+    pub(crate) fn dedup_by<T>(
+        self,
+        entries: &mut Vec<T>,
+        mut same_key: impl FnMut(&T, &T) -> bool,
+    ) {
+        match self {
+            Self::KeepFirst => entries.dedup_by(|later, earlier| same_key(later, earlier)),
+            Self::KeepLast => entries.dedup_by(|later, earlier| {
+                let same = same_key(later, earlier);
+                if same {
+                    // `later` is the one that gets removed
+                    mem::swap(later, earlier);
+                }
+                same
+            }),
+        }
+    }
+
+    /// Chooses between two entries with the same key according to this policy.
+    pub(crate) fn choose<T>(self, first: T, last: T) -> T {
+        match self {
+            Self::KeepFirst => first,
+            Self::KeepLast => last,
+        }
+    }
+}
+
 impl<'a, K, V> Default for ZeroMap<'a, K, V>
 where
     K: ZeroMapKV<'a> + ?Sized,
@@ -278,6 +322,85 @@ where
         self.values.zvl_push(value);
         None
     }
+
+    /// Construct a [`ZeroMap`] from key-value pairs in arbitrary order.
+    ///
+    /// The entries are sorted once and the underlying vectors are built in a single pass,
+    /// which is much faster than calling [`Self::insert()`] for each entry. `duplicates`
+    /// decides which value is kept for keys that occur more than once.
+    ///
+    /// ```rust
+    /// use zerovec::maps::DuplicatePolicy;
+    /// use zerovec::ZeroMap;
+    ///
+    /// let entries = [(3, "tres"), (1, "uno"), (3, "three"), (2, "dos")];
+    ///
+    /// let map: ZeroMap<u32, str> =
+    ///     ZeroMap::from_unsorted_iter(entries, DuplicatePolicy::KeepFirst);
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map.get(&3), Some("tres"));
+    ///
+    /// let map: ZeroMap<u32, str> =
+    ///     ZeroMap::from_unsorted_iter(entries, DuplicatePolicy::KeepLast);
+    /// assert_eq!(map.get(&3), Some("three"));
+    /// ```
+    pub fn from_unsorted_iter<A, B>(
+        iter: impl IntoIterator<Item = (A, B)>,
+        duplicates: DuplicatePolicy,
+    ) -> Self
+    where
+        A: Borrow<K>,
+        B: Borrow<V>,
+    {
+        let mut entries: Vec<(A, B)> = iter.into_iter().collect();
+        // Stable, so that duplicates remain in iteration order
+        entries.sort_by(|(a, _), (b, _)| a.borrow().cmp(b.borrow()));
+        duplicates.dedup_by(&mut entries, |(a, _), (b, _)| a.borrow() == b.borrow());
+        let keys: Vec<&K> = entries.iter().map(|(k, _)| k.borrow()).collect();
+        let values: Vec<&V> = entries.iter().map(|(_, v)| v.borrow()).collect();
+        Self {
+            keys: K::Container::zvl_from_elements(&keys),
+            values: V::Container::zvl_from_elements(&values),
+        }
+    }
+
+    /// Insert all entries of `other` into this map.
+    ///
+    /// Since both maps are sorted, the underlying vectors are rebuilt in a single linear pass.
+    /// For keys that are contained in both maps, [`DuplicatePolicy::KeepFirst`] keeps the
+    /// value in `self`, and [`DuplicatePolicy::KeepLast`] replaces it with the value in `other`.
+    ///
+    /// ```rust
+    /// use zerovec::maps::DuplicatePolicy;
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map: ZeroMap<u32, str> = [(1, "one"), (3, "three")].into_iter().collect();
+    /// let other: ZeroMap<u32, str> = [(2, "two"), (3, "tres")].into_iter().collect();
+    ///
+    /// map.merge(&other, DuplicatePolicy::KeepLast);
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map.get(&2), Some("two"));
+    /// assert_eq!(map.get(&3), Some("tres"));
+    /// ```
+    pub fn merge(&mut self, other: &Self, duplicates: DuplicatePolicy) {
+        if other.is_empty() {
+            return;
+        }
+        let mut entries = Vec::with_capacity(self.len() + other.len());
+        let mut lhs = self.iter().peekable();
+        let mut rhs = other.iter().peekable();
+        while let (Some(a), Some(b)) = (lhs.peek(), rhs.peek()) {
+            let next = match K::Container::get_cmp_get(a.0, b.0) {
+                Ordering::Less => lhs.next(),
+                Ordering::Greater => rhs.next(),
+                Ordering::Equal => duplicates.choose(lhs.next(), rhs.next()),
+            };
+            entries.extend(next);
+        }
+        entries.extend(lhs);
+        entries.extend(rhs);
+        *self = Self::from_get_entries(&entries);
+    }
 }
 
 impl<'a, K, V> ZeroMap<'a, K, V>
@@ -319,6 +442,61 @@ where
         #[expect(clippy::unwrap_used)] // idx is in-range
         (0..self.values.zvl_len()).map(move |idx| self.values.zvl_get(idx).unwrap())
     }
+
+    /// Retain only the entries for which `predicate` returns `true`.
+    ///
+    /// The underlying vectors are rebuilt in a single pass if any entry is removed.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map: ZeroMap<u32, str> =
+    ///     [(1, "one"), (2, "two"), (3, "three")].into_iter().collect();
+    /// map.retain(|_, v| v.len() == 3);
+    /// assert_eq!(map.iter_values().collect::<Vec<_>>(), ["one", "two"]);
+    /// ```
+    pub fn retain(&mut self, mut predicate: impl FnMut(&K::GetType, &V::GetType) -> bool) {
+        let entries: Vec<_> = self.iter().filter(|&(k, v)| predicate(k, v)).collect();
+        if entries.len() != self.len() {
+            *self = Self::from_get_entries(&entries);
+        }
+    }
+
+    /// Remove the entries for which `predicate` returns `true`, returning them as a new map.
+    ///
+    /// The underlying vectors are rebuilt in a single pass if any entry is removed.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map: ZeroMap<u32, str> =
+    ///     [(1, "one"), (2, "two"), (3, "three")].into_iter().collect();
+    /// let removed = map.extract_if(|_, v| v.starts_with('t'));
+    /// assert_eq!(map.iter_values().collect::<Vec<_>>(), ["one"]);
+    /// assert_eq!(removed.iter_values().collect::<Vec<_>>(), ["two", "three"]);
+    /// ```
+    pub fn extract_if(
+        &mut self,
+        mut predicate: impl FnMut(&K::GetType, &V::GetType) -> bool,
+    ) -> Self {
+        let (removed, kept): (Vec<_>, Vec<_>) = self.iter().partition(|&(k, v)| predicate(k, v));
+        if removed.is_empty() {
+            return Self::new();
+        }
+        let removed = Self::from_get_entries(&removed);
+        *self = Self::from_get_entries(&kept);
+        removed
+    }
+
+    /// Builds a map from entries obtained from another map, which are sorted by key.
+    fn from_get_entries(entries: &[(&K::GetType, &V::GetType)]) -> Self {
+        let keys: Vec<_> = entries.iter().map(|&(k, _)| k).collect();
+        let values: Vec<_> = entries.iter().map(|&(_, v)| v).collect();
+        Self {
+            keys: K::Container::zvl_from_get_elements(&keys),
+            values: V::Container::zvl_from_get_elements(&values),
+        }
+    }
 }
 
 impl<'a, K, V> ZeroMap<'a, K, V>
@@ -630,17 +808,100 @@ where
     where
         T: IntoIterator<Item = (A, B)>,
     {
-        let iter = iter.into_iter();
-        let mut map = match iter.size_hint() {
-            (_, Some(upper)) => Self::with_capacity(upper),
-            (lower, None) => Self::with_capacity(lower),
-        };
-
-        for (key, value) in iter {
-            if let Some((key, value)) = map.try_append(key.borrow(), value.borrow()) {
-                map.insert(key, value);
-            }
+        Self::from_unsorted_iter(iter, DuplicatePolicy::KeepLast)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+
+    fn entries() -> Vec<(u32, String)> {
+        // Deterministic pseudo-random keys with many duplicates
+        (0..500u32)
+            .map(|i| ((i * 7919) % 211, alloc::format!("v{i}")))
+            .collect()
+    }
+
+    fn assert_map_eq(map: &ZeroMap<u32, str>, expected: &BTreeMap<u32, String>) {
+        assert!(map.keys.zvl_is_ascending());
+        assert_eq!(map.len(), expected.len());
+        for ((k, v), (ek, ev)) in map.iter().zip(expected) {
+            assert_eq!(u32::from_unaligned(*k), *ek);
+            assert_eq!(v, ev);
+        }
+    }
+
+    #[test]
+    fn test_from_unsorted_iter() {
+        let entries = entries();
+
+        let mut keep_first = BTreeMap::new();
+        for (k, v) in &entries {
+            keep_first.entry(*k).or_insert_with(|| v.clone());
+        }
+        let keep_last: BTreeMap<u32, String> = entries.iter().cloned().collect();
+
+        let map = ZeroMap::<u32, str>::from_unsorted_iter(
+            entries.iter().map(|(k, v)| (k, v.as_str())),
+            DuplicatePolicy::KeepFirst,
+        );
+        assert_map_eq(&map, &keep_first);
+
+        let map = ZeroMap::<u32, str>::from_unsorted_iter(
+            entries.iter().map(|(k, v)| (k, v.as_str())),
+            DuplicatePolicy::KeepLast,
+        );
+        assert_map_eq(&map, &keep_last);
+
+        // `FromIterator` agrees with repeated insertion
+        let collected: ZeroMap<u32, str> = entries.iter().map(|(k, v)| (k, v.as_str())).collect();
+        let mut inserted = ZeroMap::new();
+        for (k, v) in &entries {
+            inserted.insert(k, v.as_str());
         }
-        map
+        assert_eq!(collected, inserted);
+    }
+
+    #[test]
+    fn test_merge_retain_drain() {
+        let (a, b): (Vec<_>, Vec<_>) = entries().into_iter().partition(|(_, v)| v.len() % 2 == 0);
+        let a: BTreeMap<u32, String> = a.into_iter().collect();
+        let b: BTreeMap<u32, String> = b.into_iter().collect();
+
+        let za: ZeroMap<u32, str> = a.iter().map(|(k, v)| (k, v.as_str())).collect();
+        let zb: ZeroMap<u32, str> = b.iter().map(|(k, v)| (k, v.as_str())).collect();
+
+        let mut merged = za.clone();
+        merged.merge(&zb, DuplicatePolicy::KeepFirst);
+        let mut expected = b.clone();
+        expected.extend(a.clone());
+        assert_map_eq(&merged, &expected);
+
+        let mut merged = za.clone();
+        merged.merge(&zb, DuplicatePolicy::KeepLast);
+        let mut expected = a.clone();
+        expected.extend(b.clone());
+        assert_map_eq(&merged, &expected);
+
+        let mut retained = merged.clone();
+        retained.retain(|k, _| u32::from_unaligned(*k) % 3 == 0);
+        let mut drained = merged.clone();
+        let removed = drained.extract_if(|k, _| u32::from_unaligned(*k) % 3 != 0);
+        assert_eq!(retained, drained);
+        assert_eq!(retained.len() + removed.len(), merged.len());
+
+        let mut expected_retained = expected.clone();
+        expected_retained.retain(|k, _| k % 3 == 0);
+        assert_map_eq(&retained, &expected_retained);
+        expected.retain(|k, _| k % 3 != 0);
+        assert_map_eq(&removed, &expected);
+
+        // Nothing removed
+        let empty = drained.extract_if(|_, _| false);
+        assert!(empty.is_empty());
+        assert_eq!(retained, drained);
     }
 }
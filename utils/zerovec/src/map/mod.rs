@@ -8,6 +8,7 @@ mod borrowed;
 mod kv;
 #[expect(clippy::module_inception)] // module is purely internal
 pub(crate) mod map;
+mod ule;
 mod vecs;
 
 #[cfg(feature = "databake")]
@@ -20,4 +21,6 @@ mod serde_helpers;
 pub use crate::ZeroMap;
 pub use borrowed::ZeroMapBorrowed;
 pub use kv::ZeroMapKV;
+pub use map::DuplicatePolicy;
+pub use ule::ZeroMapULE;
 pub use vecs::{MutableZeroVecLike, ZeroVecLike};
@@ -0,0 +1,221 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{MutableZeroVecLike, ZeroMapKV, ZeroVecLike};
+use crate::ule::tuplevar::Tuple2VarULE;
+use crate::ule::{EncodeAsVarULE, UleError, VarULE};
+use crate::vecs::Index32;
+use crate::ZeroMap;
+use core::fmt;
+use zerofrom::ZeroFrom;
+
+/// The slices of keys and values of a [`ZeroMapULE`].
+type Slices<K, V> =
+    Tuple2VarULE<<K as ZeroMapKV<'static>>::Slice, <V as ZeroMapKV<'static>>::Slice, Index32>;
+
+/// The [`VarULE`] type for [`ZeroMap`], consisting of the slices of keys and values.
+///
+/// This allows using [`ZeroMap`] fields in [`#[make_varule]`](crate::make_varule) structs,
+/// which is how they are stored in [`blobs`](crate::blob).
+///
+/// Like deserializing a [`ZeroMap`], validating a [`ZeroMapULE`] checks that there are as many
+/// keys as values, and that the keys are sorted.
+///
+/// # Examples
+///
+/// ```
+/// use zerofrom::ZeroFrom;
+/// use zerovec::maps::ZeroMapULE;
+/// use zerovec::ule::{encode_varule_to_box, VarULE};
+/// use zerovec::ZeroMap;
+///
+/// let map: ZeroMap<u32, str> = [(1, "one"), (2, "two")].into_iter().collect();
+///
+/// let ule: Box<ZeroMapULE<u32, str>> = encode_varule_to_box(&map);
+/// let borrowed = ZeroMap::<u32, str>::zero_from(&*ule);
+///
+/// assert_eq!(borrowed, map);
+/// ```
+#[repr(transparent)]
+pub struct ZeroMapULE<K, V>(Slices<K, V>)
+where
+    K: ZeroMapKV<'static> + ?Sized,
+    V: ZeroMapKV<'static> + ?Sized;
+
+impl<K, V> ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + ?Sized,
+    V: ZeroMapKV<'static> + ?Sized,
+    K::Slice: VarULE,
+    V::Slice: VarULE,
+{
+    /// The keys of the map.
+    pub fn keys(&self) -> &K::Slice {
+        self.0.a()
+    }
+
+    /// The values of the map.
+    pub fn values(&self) -> &V::Slice {
+        self.0.b()
+    }
+}
+
+// Safety (based on the safety checklist on the VarULE trait):
+//  1. ZeroMapULE does not include any uninitialized or padding bytes: it is repr(transparent)
+//     over a VarULE type
+//  2. ZeroMapULE is aligned to 1 byte: it is repr(transparent) over a VarULE type
+//  3. The impl of `validate_bytes()` returns an error if any byte is not valid: it defers to
+//     the tuple type first
+//  4. The impl of `validate_bytes()` returns an error if the slice cannot be used in its
+//     entirety: it defers to the tuple type first
+//  5. The impl of `from_bytes_unchecked()` returns a reference to the same data
+//  6. All other methods are defaulted
+//  7. `ZeroMapULE` byte equality is semantic equality, as it is for the tuple type
+unsafe impl<K, V> VarULE for ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + Ord + ?Sized + 'static,
+    V: ZeroMapKV<'static> + ?Sized + 'static,
+    K::Slice: VarULE,
+    V::Slice: VarULE,
+{
+    fn validate_bytes(bytes: &[u8]) -> Result<(), UleError> {
+        Slices::<K, V>::validate_bytes(bytes)?;
+        // Safety: the bytes were just validated
+        let slices = unsafe { Slices::<K, V>::from_bytes_unchecked(bytes) };
+        if slices.a().zvl_len() != slices.b().zvl_len() || !slices.a().zvl_is_ascending() {
+            return Err(UleError::parse::<Self>());
+        }
+        Ok(())
+    }
+
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        let slices = Slices::<K, V>::from_bytes_unchecked(bytes);
+        // This type is repr(transparent) over the tuple type, so its slices can be transmuted
+        &*(slices as *const Slices<K, V> as *const Self)
+    }
+}
+
+impl<K, V> PartialEq for ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + Ord + ?Sized + 'static,
+    V: ZeroMapKV<'static> + ?Sized + 'static,
+    K::Slice: VarULE,
+    V::Slice: VarULE,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<K, V> Eq for ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + Ord + ?Sized + 'static,
+    V: ZeroMapKV<'static> + ?Sized + 'static,
+    K::Slice: VarULE,
+    V::Slice: VarULE,
+{
+}
+
+impl<K, V> fmt::Debug for ZeroMapULE<K, V>
+where
+    K: ZeroMapKV<'static> + ?Sized,
+    V: ZeroMapKV<'static> + ?Sized,
+    K::Slice: VarULE + fmt::Debug,
+    V::Slice: VarULE + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZeroMapULE")
+            .field("keys", &self.keys())
+            .field("values", &self.values())
+            .finish()
+    }
+}
+
+impl<'a, K, V, KS, VS> ZeroFrom<'a, ZeroMapULE<K, V>> for ZeroMap<'a, K, V>
+where
+    K: for<'b> ZeroMapKV<'b, Slice = KS> + ?Sized + 'static,
+    V: for<'b> ZeroMapKV<'b, Slice = VS> + ?Sized + 'static,
+    KS: VarULE + ?Sized,
+    VS: VarULE + ?Sized,
+{
+    fn zero_from(other: &'a ZeroMapULE<K, V>) -> Self {
+        Self {
+            keys: <K as ZeroMapKV<'a>>::Container::zvl_from_borrowed(other.keys()),
+            values: <V as ZeroMapKV<'a>>::Container::zvl_from_borrowed(other.values()),
+        }
+    }
+}
+
+// Safety: defers to the tuple impl, which writes the slices of keys and values. The keys
+// of a `ZeroMap` are sorted and have as many values, so the result is valid.
+unsafe impl<'a, K, V, KS, VS> EncodeAsVarULE<ZeroMapULE<K, V>> for ZeroMap<'a, K, V>
+where
+    K: for<'b> ZeroMapKV<'b, Slice = KS> + Ord + ?Sized + 'static,
+    V: for<'b> ZeroMapKV<'b, Slice = VS> + ?Sized + 'static,
+    KS: VarULE + ?Sized,
+    VS: VarULE + ?Sized,
+{
+    fn encode_var_ule_as_slices<R>(&self, _: impl FnOnce(&[&[u8]]) -> R) -> R {
+        // unnecessary if the other two are implemented
+        unreachable!()
+    }
+
+    fn encode_var_ule_len(&self) -> usize {
+        EncodeAsVarULE::<Slices<K, V>>::encode_var_ule_len(&(
+            self.keys.zvl_as_borrowed(),
+            self.values.zvl_as_borrowed(),
+        ))
+    }
+
+    fn encode_var_ule_write(&self, dst: &mut [u8]) {
+        EncodeAsVarULE::<Slices<K, V>>::encode_var_ule_write(
+            &(self.keys.zvl_as_borrowed(), self.values.zvl_as_borrowed()),
+            dst,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ule::encode_varule_to_box;
+    use crate::{VarZeroVec, ZeroVec};
+
+    fn encode(keys: &[u32], values: &[&str]) -> Box<Slices<u32, str>> {
+        let keys = ZeroVec::<u32>::alloc_from_slice(keys);
+        let values = VarZeroVec::<str>::from(values);
+        encode_varule_to_box(&(keys.as_slice(), values.as_slice()))
+    }
+
+    #[test]
+    fn test_validate() {
+        let valid = encode(&[1, 2], &["one", "two"]);
+        let ule = ZeroMapULE::<u32, str>::parse_bytes(valid.as_bytes()).unwrap();
+        assert_eq!(ZeroMap::zero_from(ule).get(&2), Some("two"));
+
+        // Unsorted keys
+        let unsorted = encode(&[2, 1], &["two", "one"]);
+        assert!(ZeroMapULE::<u32, str>::parse_bytes(unsorted.as_bytes()).is_err());
+
+        // Duplicate keys
+        let duplicate = encode(&[1, 1], &["one", "one"]);
+        assert!(ZeroMapULE::<u32, str>::parse_bytes(duplicate.as_bytes()).is_err());
+
+        // More keys than values
+        let mismatched = encode(&[1, 2], &["one"]);
+        assert!(ZeroMapULE::<u32, str>::parse_bytes(mismatched.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_malformed_blob() {
+        let map: ZeroMap<u32, str> = [(1, "one"), (2, "two")].into_iter().collect();
+        let mut blob = crate::blob::encode_blob::<ZeroMapULE<u32, str>>(&map);
+        assert!(crate::blob::parse_blob::<ZeroMapULE<u32, str>>(&blob).is_ok());
+
+        // A blob with the right header, but unsorted keys
+        let unsorted = encode(&[2, 1], &["two", "one"]);
+        blob[crate::blob::HEADER_LEN..].copy_from_slice(unsorted.as_bytes());
+        assert!(crate::blob::parse_blob::<ZeroMapULE<u32, str>>(&blob).is_err());
+    }
+}
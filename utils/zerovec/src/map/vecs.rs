@@ -158,6 +158,17 @@ pub trait MutableZeroVecLike<'a, T: ?Sized>: ZeroVecLike<T> {
     /// # Panics
     /// If `permutation` is not a valid permutation of length `zvl_len()`.
     fn zvl_permute(&mut self, permutation: &mut [usize]);
+    /// Construct an owned vector from a list of elements, in a single pass.
+    ///
+    /// # Panics
+    /// If the elements are too large for the vector's format.
+    fn zvl_from_elements(elements: &[&T]) -> Self;
+    /// Construct an owned vector from a list of elements obtained from [`ZeroVecLike::zvl_get()`],
+    /// in a single pass.
+    ///
+    /// # Panics
+    /// If the elements are too large for the vector's format.
+    fn zvl_from_get_elements(elements: &[&Self::GetType]) -> Self;
 
     /// Convert an owned value to a borrowed T
     fn owned_as_t(o: &Self::OwnedType) -> &T;
@@ -350,6 +361,19 @@ where
             permutation[curr] = curr;
         }
     }
+
+    fn zvl_from_elements(elements: &[&T]) -> Self {
+        if elements.is_empty() {
+            return ZeroVec::new();
+        }
+        ZeroVec::new_owned(elements.iter().map(|t| t.to_unaligned()).collect())
+    }
+    fn zvl_from_get_elements(elements: &[&T::ULE]) -> Self {
+        if elements.is_empty() {
+            return ZeroVec::new();
+        }
+        ZeroVec::new_owned(elements.iter().map(|&&u| u).collect())
+    }
 }
 
 impl<'a, T, F> ZeroVecLike<T> for VarZeroVec<'a, T, F>
@@ -523,6 +547,13 @@ where
         }
         *self = Self(VarZeroVecInner::Owned(result));
     }
+
+    fn zvl_from_elements(elements: &[&T]) -> Self {
+        VarZeroVec::from(elements)
+    }
+    fn zvl_from_get_elements(elements: &[&T]) -> Self {
+        VarZeroVec::from(elements)
+    }
 }
 
 #[cfg(test)]
@@ -2,8 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::map::DuplicatePolicy;
 use crate::ule::AsULE;
 use crate::ZeroVec;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
@@ -456,8 +458,231 @@ where
         None
     }
 
+    /// Construct a [`ZeroMap2d`] from entries in arbitrary order.
+    ///
+    /// The entries are sorted once and the underlying vectors are built in a single pass,
+    /// which is much faster than calling [`Self::insert()`] for each entry. `duplicates`
+    /// decides which value is kept for key pairs that occur more than once.
+    ///
+    /// ```rust
+    /// use zerovec::maps::DuplicatePolicy;
+    /// use zerovec::ZeroMap2d;
+    ///
+    /// let map: ZeroMap2d<u32, str, str> = ZeroMap2d::from_unsorted_iter(
+    ///     [(2, "two", "dos"), (1, "one", "uno"), (2, "two", "deux")],
+    ///     DuplicatePolicy::KeepLast,
+    /// );
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get_2d(&1, "one"), Some("uno"));
+    /// assert_eq!(map.get_2d(&2, "two"), Some("deux"));
+    /// ```
+    pub fn from_unsorted_iter<A, B, C>(
+        iter: impl IntoIterator<Item = (A, B, C)>,
+        duplicates: DuplicatePolicy,
+    ) -> Self
+    where
+        A: Borrow<K0>,
+        B: Borrow<K1>,
+        C: Borrow<V>,
+    {
+        let mut entries: Vec<(A, B, C)> = iter.into_iter().collect();
+        // Stable, so that duplicates remain in iteration order
+        entries.sort_by(|a, b| (a.0.borrow(), a.1.borrow()).cmp(&(b.0.borrow(), b.1.borrow())));
+        duplicates.dedup_by(&mut entries, |a, b| {
+            a.0.borrow() == b.0.borrow() && a.1.borrow() == b.1.borrow()
+        });
+
+        let mut keys0: Vec<&K0> = Vec::new();
+        let mut joiner = Vec::new();
+        for (i, (key0, _, _)) in entries.iter().enumerate() {
+            let key0 = key0.borrow();
+            if keys0.last() != Some(&key0) {
+                keys0.push(key0);
+                joiner.push(0);
+            }
+            if let Some(end) = joiner.last_mut() {
+                *end = i + 1;
+            }
+        }
+        let keys1: Vec<&K1> = entries.iter().map(|(_, k1, _)| k1.borrow()).collect();
+        let values: Vec<&V> = entries.iter().map(|(_, _, v)| v.borrow()).collect();
+
+        let map = Self {
+            keys0: K0::Container::zvl_from_elements(&keys0),
+            joiner: Self::joiner_from_ends(&joiner),
+            keys1: K1::Container::zvl_from_elements(&keys1),
+            values: V::Container::zvl_from_elements(&values),
+        };
+        #[cfg(debug_assertions)]
+        map.check_invariants();
+        map
+    }
+
+    /// Insert all entries of `other` into this map.
+    ///
+    /// Since both maps are sorted, the underlying vectors are rebuilt in a single linear pass.
+    /// For key pairs that are contained in both maps, [`DuplicatePolicy::KeepFirst`] keeps the
+    /// value in `self`, and [`DuplicatePolicy::KeepLast`] replaces it with the value in `other`.
+    ///
+    /// ```rust
+    /// use zerovec::maps::DuplicatePolicy;
+    /// use zerovec::ZeroMap2d;
+    ///
+    /// let mut map: ZeroMap2d<u32, str, str> =
+    ///     [(1, "one", "uno"), (2, "two", "dos")].into_iter().collect();
+    /// let other: ZeroMap2d<u32, str, str> =
+    ///     [(1, "eins", "ein"), (2, "two", "deux")].into_iter().collect();
+    ///
+    /// map.merge(&other, DuplicatePolicy::KeepFirst);
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map.get_2d(&1, "eins"), Some("ein"));
+    /// assert_eq!(map.get_2d(&2, "two"), Some("dos"));
+    /// ```
+    pub fn merge(&mut self, other: &Self, duplicates: DuplicatePolicy) {
+        if other.is_empty() {
+            return;
+        }
+        let mut entries = Vec::with_capacity(self.len() + other.len());
+        let mut lhs = self.iter_entries().peekable();
+        let mut rhs = other.iter_entries().peekable();
+        while let (Some(a), Some(b)) = (lhs.peek(), rhs.peek()) {
+            let ordering = K0::Container::get_cmp_get(a.0, b.0)
+                .then_with(|| K1::Container::get_cmp_get(a.1, b.1));
+            let next = match ordering {
+                Ordering::Less => lhs.next(),
+                Ordering::Greater => rhs.next(),
+                Ordering::Equal => duplicates.choose(lhs.next(), rhs.next()),
+            };
+            entries.extend(next);
+        }
+        entries.extend(lhs);
+        entries.extend(rhs);
+        *self = Self::from_get_entries(&entries);
+    }
+
+    /// Retain only the entries for which `predicate` returns `true`.
+    ///
+    /// The underlying vectors are rebuilt in a single pass if any entry is removed. Keys0
+    /// without any remaining entries are removed as well.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap2d;
+    ///
+    /// let mut map: ZeroMap2d<u32, str, str> =
+    ///     [(1, "one", "uno"), (2, "two", "dos"), (2, "zwei", "deux")]
+    ///         .into_iter()
+    ///         .collect();
+    /// map.retain(|_, k1, _| k1.starts_with('t'));
+    /// assert_eq!(map.len(), 1);
+    /// assert!(!map.contains_key0(&1));
+    /// assert_eq!(map.get_2d(&2, "two"), Some("dos"));
+    /// ```
+    pub fn retain(
+        &mut self,
+        mut predicate: impl FnMut(&K0::GetType, &K1::GetType, &V::GetType) -> bool,
+    ) {
+        let entries: Vec<_> = self
+            .iter_entries()
+            .filter(|&(k0, k1, v)| predicate(k0, k1, v))
+            .collect();
+        if entries.len() != self.len() {
+            *self = Self::from_get_entries(&entries);
+        }
+    }
+
+    /// Remove the entries for which `predicate` returns `true`, returning them as a new map.
+    ///
+    /// The underlying vectors are rebuilt in a single pass if any entry is removed.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap2d;
+    ///
+    /// let mut map: ZeroMap2d<u32, str, str> =
+    ///     [(1, "one", "uno"), (2, "two", "dos"), (2, "zwei", "deux")]
+    ///         .into_iter()
+    ///         .collect();
+    /// let removed = map.extract_if(|_, _, v| v.starts_with('d'));
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(removed.len(), 2);
+    /// assert_eq!(removed.get_2d(&2, "zwei"), Some("deux"));
+    /// ```
+    pub fn extract_if(
+        &mut self,
+        mut predicate: impl FnMut(&K0::GetType, &K1::GetType, &V::GetType) -> bool,
+    ) -> Self {
+        let (removed, kept): (Vec<_>, Vec<_>) = self
+            .iter_entries()
+            .partition(|&(k0, k1, v)| predicate(k0, k1, v));
+        if removed.is_empty() {
+            return Self::new();
+        }
+        let removed = Self::from_get_entries(&removed);
+        *self = Self::from_get_entries(&kept);
+        removed
+    }
+
     // INTERNAL ROUTINES FOLLOW //
 
+    /// Produce an ordered iterator over all entries
+    fn iter_entries<'l>(
+        &'l self,
+    ) -> impl Iterator<Item = (&'l K0::GetType, &'l K1::GetType, &'l V::GetType)> + 'l {
+        (0..self.keys0.zvl_len()).flat_map(move |key0_index| {
+            #[expect(clippy::unwrap_used)] // key0_index is in range
+            let key0 = self.keys0.zvl_get(key0_index).unwrap();
+            self.get_range_for_key0_index(key0_index).map(move |index| {
+                (
+                    key0,
+                    #[expect(clippy::unwrap_used)] // index is in range by invariants
+                    self.keys1.zvl_get(index).unwrap(),
+                    #[expect(clippy::unwrap_used)] // index is in range by invariants
+                    self.values.zvl_get(index).unwrap(),
+                )
+            })
+        })
+    }
+
+    /// Builds a map from entries obtained from another map, which are sorted by key pair.
+    fn from_get_entries(entries: &[(&K0::GetType, &K1::GetType, &V::GetType)]) -> Self {
+        let mut keys0: Vec<&K0::GetType> = Vec::new();
+        let mut joiner = Vec::new();
+        for (i, &(key0, _, _)) in entries.iter().enumerate() {
+            let is_new = keys0
+                .last()
+                .is_none_or(|&last| K0::Container::get_cmp_get(last, key0) != Ordering::Equal);
+            if is_new {
+                keys0.push(key0);
+                joiner.push(0);
+            }
+            if let Some(end) = joiner.last_mut() {
+                *end = i + 1;
+            }
+        }
+        let keys1: Vec<_> = entries.iter().map(|&(_, k1, _)| k1).collect();
+        let values: Vec<_> = entries.iter().map(|&(_, _, v)| v).collect();
+
+        let map = Self {
+            keys0: K0::Container::zvl_from_get_elements(&keys0),
+            joiner: Self::joiner_from_ends(&joiner),
+            keys1: K1::Container::zvl_from_get_elements(&keys1),
+            values: V::Container::zvl_from_get_elements(&values),
+        };
+        #[cfg(debug_assertions)]
+        map.check_invariants();
+        map
+    }
+
+    /// Builds the joiner from the end indices of the ranges of keys1
+    fn joiner_from_ends(ends: &[usize]) -> ZeroVec<'a, u32> {
+        ends.iter()
+            .map(|&end| {
+                // TODO(#1410): Make this fallible
+                #[expect(clippy::expect_used)] // slice overflow
+                u32::try_from(end).expect("Attempted to add more than 2^32 elements to a ZeroMap2d")
+            })
+            .collect()
+    }
+
     #[cfg(debug_assertions)]
     #[expect(clippy::unwrap_used)] // this is an assertion function
     pub(crate) fn check_invariants(&self) {
@@ -693,22 +918,7 @@ where
     where
         T: IntoIterator<Item = (A, B, C)>,
     {
-        let iter = iter.into_iter();
-        let mut map = match iter.size_hint() {
-            (_, Some(upper)) => Self::with_capacity(upper),
-            (lower, None) => Self::with_capacity(lower),
-        };
-
-        for (key0, key1, value) in iter {
-            if let Some((key0, key1, value)) =
-                map.try_append(key0.borrow(), key1.borrow(), value.borrow())
-            {
-                map.insert(key0, key1, value);
-            }
-        }
-        #[cfg(debug_assertions)]
-        map.check_invariants();
-        map
+        Self::from_unsorted_iter(iter, DuplicatePolicy::KeepLast)
     }
 }
 
@@ -871,4 +1081,72 @@ mod test {
         }
         assert!(btreemap_iter.next().is_none());
     }
+
+    #[test]
+    fn bulk_operations() {
+        // Deterministic pseudo-random keys with many duplicates
+        let source_data: Vec<(u8, u16, String)> = (0..500u32)
+            .map(|i| ((i * 31 % 17) as u8, (i * 7919 % 23) as u16, format!("v{i}")))
+            .collect();
+
+        let btreemap: BTreeMap<(u8, u16), String> = source_data
+            .iter()
+            .map(|(k0, k1, v)| ((*k0, *k1), v.clone()))
+            .collect();
+        let check = |zeromap2d: &ZeroMap2d<u8, u16, str>,
+                     expected: &BTreeMap<(u8, u16), String>| {
+            zeromap2d.check_invariants();
+            assert_eq!(zeromap2d.len(), expected.len());
+            let actual: Vec<_> = zeromap2d
+                .iter_entries()
+                .map(|(k0, k1, v)| ((*k0, k1.as_unsigned_int()), v))
+                .collect();
+            let expected: Vec<_> = expected.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            assert_eq!(actual, expected);
+        };
+
+        let zeromap2d: ZeroMap2d<u8, u16, str> = source_data
+            .iter()
+            .map(|(k0, k1, v)| (k0, k1, v.as_str()))
+            .collect();
+        check(&zeromap2d, &btreemap);
+
+        let mut keep_first = BTreeMap::new();
+        for (k0, k1, v) in &source_data {
+            keep_first.entry((*k0, *k1)).or_insert_with(|| v.clone());
+        }
+        let first = ZeroMap2d::<u8, u16, str>::from_unsorted_iter(
+            source_data.iter().map(|(k0, k1, v)| (k0, k1, v.as_str())),
+            DuplicatePolicy::KeepFirst,
+        );
+        check(&first, &keep_first);
+
+        // Split by key0 parity, so that some key0 have entries on both sides
+        let mut even = zeromap2d.clone();
+        let odd = even.extract_if(|_, k1, _| k1.as_unsigned_int() % 2 == 1);
+        let mut expected = btreemap.clone();
+        expected.retain(|(_, k1), _| k1 % 2 == 0);
+        check(&even, &expected);
+        expected = btreemap.clone();
+        expected.retain(|(_, k1), _| k1 % 2 == 1);
+        check(&odd, &expected);
+
+        let mut merged = even.clone();
+        merged.merge(&odd, DuplicatePolicy::KeepFirst);
+        assert_eq!(merged, zeromap2d);
+
+        // Overlapping merge
+        let mut merged = first.clone();
+        merged.merge(&zeromap2d, DuplicatePolicy::KeepLast);
+        assert_eq!(merged, zeromap2d);
+        merged.merge(&first, DuplicatePolicy::KeepFirst);
+        assert_eq!(merged, zeromap2d);
+
+        // Removing all keys1 of a key0
+        merged.retain(|&k0, _, _| k0 != 3);
+        expected = btreemap.clone();
+        expected.retain(|(k0, _), _| *k0 != 3);
+        check(&merged, &expected);
+        assert!(!merged.contains_key0(&3));
+    }
 }
@@ -0,0 +1,698 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compiling zoneinfo64 data from tzdb sources.
+//!
+//! [`ZoneInfo64Compiler`] turns a tzdb [`Table`], as parsed by the `parse-zoneinfo`
+//! crate from source files such as `africa` or `rearguard.zi`, into a resource bundle
+//! in the zoneinfo64 format. This way, the data does not have to be taken from ICU4C.
+//!
+//! [`check`] verifies data against the compiled tzdb by comparing the results of
+//! [`Zone::for_timestamp`] at every transition.
+//!
+//! ICU4C compiles the rearguard format of the tzdb, in which daylight saving time is
+//! never negative. Zones with negative daylight saving time in their recurring rules
+//! get explicit transitions up to 2100 instead of a final rule.
+
+use crate::rule::{Rule, TzRule};
+use crate::{Offset, Transition, UtcOffset, Zone, ZoneInfo64, EPOCH, SECONDS_IN_UTC_DAY};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use calendrical_calculations::gregorian;
+use core::fmt;
+use icu_locale_core::subtags::{region, Region};
+use parse_zoneinfo::line::{DaySpec, Month, TimeType, Year};
+use parse_zoneinfo::table::{RuleInfo, Saving, Table};
+use parse_zoneinfo::transitions::TableTransitions;
+use resb::binary::{BinarySerializerError, Serializer};
+use resb::bundle::{Key, Resource, ResourceBundle};
+use std::collections::{BTreeMap, HashSet};
+
+/// The last year for which `parse-zoneinfo` computes transitions.
+const LAST_YEAR: i32 = 2099;
+
+/// The region of zones that are not listed in a `zone.tab` file.
+const NON_GEOGRAPHIC: Region = region!("001");
+
+/// A compiler for zoneinfo64 data from a tzdb [`Table`].
+///
+/// # Examples
+///
+/// ```
+/// use parse_zoneinfo::line::Line;
+/// use parse_zoneinfo::table::TableBuilder;
+/// use zoneinfo64::compile::ZoneInfo64Compiler;
+/// use zoneinfo64::{UtcOffset, ZoneInfo64};
+///
+/// let mut builder = TableBuilder::new();
+/// for line in [
+///     "Rule EU 1981 max - Mar lastSun 1:00u 1:00 S",
+///     "Rule EU 1996 max - Oct lastSun 1:00u 0 -",
+///     "Zone Europe/Berlin 0:53:28 - LMT 1893 Apr",
+///     "        1:00 EU CE%sT",
+///     "Link Europe/Berlin Arctic/Longyearbyen",
+/// ] {
+///     match Line::new(line).expect("valid line") {
+///         Line::Zone(zone) => builder.add_zone_line(zone).unwrap(),
+///         Line::Continuation(cont) => builder.add_continuation_line(cont).unwrap(),
+///         Line::Rule(rule) => builder.add_rule_line(rule).unwrap(),
+///         Line::Link(link) => builder.add_link_line(link).unwrap(),
+///         Line::Space => {}
+///     }
+/// }
+/// let table = builder.build();
+///
+/// let mut compiler = ZoneInfo64Compiler::new(&table, "2025b");
+/// compiler
+///     .try_add_zone_tab("DE\t+5230+01322\tEurope/Berlin\tmost of Germany")
+///     .expect("valid zone.tab");
+/// let words = compiler.compile_to_words().expect("compilable tzdb");
+/// let zoneinfo = ZoneInfo64::try_from_u32s(&words).expect("valid zoneinfo64 data");
+///
+/// // 2024-07-01T00:00:00Z is in summer time
+/// let longyearbyen = zoneinfo.get("Arctic/Longyearbyen").unwrap();
+/// assert_eq!(
+///     longyearbyen.for_timestamp(1719792000).offset,
+///     UtcOffset::from_seconds(2 * 3600)
+/// );
+/// assert_eq!(longyearbyen.region().as_str(), "DE");
+///
+/// // The compiled data agrees with the tzdb
+/// assert_eq!(zoneinfo64::compile::check(&zoneinfo, &table), []);
+/// ```
+#[derive(Debug)]
+pub struct ZoneInfo64Compiler<'a> {
+    table: &'a Table,
+    version: &'a str,
+    regions: BTreeMap<&'a str, Region>,
+}
+
+impl<'a> ZoneInfo64Compiler<'a> {
+    /// Creates a compiler for the zones and links in `table`, which is from the tzdb
+    /// release `version`, such as `2025b`.
+    ///
+    /// All zones have the region `001` unless they are listed in a `zone.tab` file, see
+    /// [`Self::try_add_zone_tab`].
+    pub fn new(table: &'a Table, version: &'a str) -> Self {
+        Self {
+            table,
+            version,
+            regions: BTreeMap::new(),
+        }
+    }
+
+    /// Assigns regions to zones from the contents of a `zone.tab` or `zone1970.tab` file.
+    ///
+    /// Links that are not listed themselves have the region of their target zone. In
+    /// `zone1970.tab`, where a zone can be listed for multiple regions, the first one is used.
+    pub fn try_add_zone_tab(&mut self, zone_tab: &'a str) -> Result<(), CompileError> {
+        for line in zone_tab.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut columns = line.split('\t');
+            let (Some(codes), Some(_coordinates), Some(name)) =
+                (columns.next(), columns.next(), columns.next())
+            else {
+                return Err(CompileError::InvalidZoneTab(line.to_string()));
+            };
+            let region = codes
+                .split(',')
+                .next()
+                .and_then(|code| Region::try_from_str(code).ok())
+                .ok_or_else(|| CompileError::InvalidZoneTab(line.to_string()))?;
+            self.regions.insert(name, region);
+        }
+        Ok(())
+    }
+
+    /// Compiles the tzdb into a zoneinfo64 resource bundle.
+    ///
+    /// The bundle can be written in the binary format with [`Serializer`], see
+    /// [`Self::compile_to_words`].
+    pub fn compile(&self) -> Result<ResourceBundle<'static>, CompileError> {
+        let mut names = self
+            .table
+            .zonesets
+            .keys()
+            .chain(self.table.links.keys())
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+
+        // Links point to zones directly, even if the tzdb links them to other links
+        let targets = names
+            .iter()
+            .map(|&name| {
+                resolve(self.table, name).ok_or_else(|| CompileError::UnknownZone(name.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let index_of = |name: &str| names.binary_search(&name).unwrap_or_default() as u32;
+
+        let mut zones = Vec::with_capacity(names.len());
+        let mut regions = Vec::with_capacity(names.len());
+        let mut rules = BTreeMap::new();
+        for (&name, &target) in names.iter().zip(&targets) {
+            if name != target {
+                zones.push(Resource::Integer(index_of(target).into()));
+            } else {
+                let zone = CompiledZone::try_new(self.table, name)?;
+                let links = names
+                    .iter()
+                    .zip(&targets)
+                    .filter(|&(&link, &link_target)| link_target == name && link != name)
+                    .map(|(&link, _)| index_of(link))
+                    .collect::<Vec<_>>();
+                if let Some(FinalRule { name, raw, .. }) = &zone.final_rule {
+                    rules.insert(
+                        Key::from(name.clone()),
+                        Resource::IntVector(raw.iter().map(|&v| v as u32).collect()),
+                    );
+                }
+                zones.push(zone.into_resource(links));
+            }
+            let region = self
+                .regions
+                .get(name)
+                .or_else(|| self.regions.get(target))
+                .copied()
+                .unwrap_or(NON_GEOGRAPHIC);
+            regions.push(Resource::String(Cow::Owned(region.as_str().to_string())));
+        }
+
+        let root = BTreeMap::from([
+            (
+                Key::from("TZVersion"),
+                Resource::String(Cow::Owned(self.version.to_string())),
+            ),
+            (Key::from("Zones"), Resource::Array(zones)),
+            (
+                Key::from("Names"),
+                Resource::Array(
+                    names
+                        .iter()
+                        .map(|name| Resource::String(Cow::Owned(name.to_string())))
+                        .collect(),
+                ),
+            ),
+            (Key::from("Rules"), Resource::Table(rules)),
+            (Key::from("Regions"), Resource::Array(regions)),
+        ]);
+
+        Ok(ResourceBundle::new(
+            Cow::Borrowed("zoneinfo64"),
+            Resource::Table(root),
+            false,
+        ))
+    }
+
+    /// Compiles the tzdb into a binary zoneinfo64 resource bundle, which can be loaded
+    /// with [`ZoneInfo64::try_from_u32s`].
+    pub fn compile_to_words(&self) -> Result<Vec<u32>, CompileError> {
+        let bundle = self.compile()?;
+        let mut keys = Vec::new();
+        collect_keys(bundle.root(), &mut keys, &mut HashSet::new());
+        let bytes = Serializer::to_bytes(&bundle, &keys).map_err(CompileError::Serializer)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|chunk| {
+                let mut word = [0; 4];
+                word.copy_from_slice(chunk);
+                u32::from_ne_bytes(word)
+            })
+            .collect())
+    }
+}
+
+/// Follows links until reaching a zone.
+fn resolve<'a>(table: &'a Table, mut name: &'a str) -> Option<&'a str> {
+    // Links can't form a chain that's longer than the number of links
+    for _ in 0..=table.links.len() {
+        if table.zonesets.contains_key(name) {
+            return Some(name);
+        }
+        name = table.links.get(name)?;
+    }
+    None
+}
+
+/// Collects the keys of all tables in `resource` in the order they first appear.
+fn collect_keys<'a>(resource: &Resource<'a>, keys: &mut Vec<Key<'a>>, seen: &mut HashSet<Key<'a>>) {
+    match resource {
+        Resource::Table(table) => {
+            for (key, resource) in table {
+                if seen.insert(key.clone()) {
+                    keys.push(key.clone());
+                }
+                collect_keys(resource, keys, seen);
+            }
+        }
+        Resource::Array(array) => {
+            for resource in array {
+                collect_keys(resource, keys, seen);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A zone in the tzdb, in the representation of zoneinfo64.
+#[derive(Debug)]
+struct CompiledZone {
+    /// The distinct pairs of standard offset and daylight saving time, the first of which
+    /// is used before the first transition
+    type_offsets: Vec<(i32, i32)>,
+    /// Transitions with the index of the pair in `type_offsets` used after them
+    transitions: Vec<(i64, u8)>,
+    final_rule: Option<FinalRule>,
+}
+
+/// A recurring rule that applies after the explicit transitions of a zone.
+#[derive(Debug)]
+struct FinalRule {
+    /// The name of the tzdb rule
+    name: String,
+    /// The rule in the layout of the `Rules` table
+    raw: [i32; 11],
+    /// The standard offset of the zone while the rule is in effect
+    standard_offset_seconds: i32,
+    /// The first year in which the rule determines the transitions
+    start_year: i32,
+}
+
+impl CompiledZone {
+    fn try_new(table: &Table, name: &str) -> Result<Self, CompileError> {
+        let too_large = || CompileError::OffsetOutOfRange(name.to_string());
+        let pair = |utc_offset: i64, dst_offset: i64| -> Result<(i32, i32), CompileError> {
+            Ok((
+                i32::try_from(utc_offset).map_err(|_| too_large())?,
+                i32::try_from(dst_offset).map_err(|_| too_large())?,
+            ))
+        };
+
+        let timespans = table
+            .timespans(name)
+            .ok_or_else(|| CompileError::UnknownZone(name.to_string()))?;
+        let first = pair(timespans.first.utc_offset, timespans.first.dst_offset)?;
+
+        // Transitions that only change the abbreviation don't exist in zoneinfo64
+        let mut transitions = Vec::<(i64, (i32, i32))>::new();
+        for (since, timespan) in timespans.rest {
+            // A later timespan that starts at the same time replaces the earlier one
+            if transitions.last().is_some_and(|&(last, _)| last == since) {
+                transitions.pop();
+            }
+            let offsets = pair(timespan.utc_offset, timespan.dst_offset)?;
+            if transitions.last().map_or(first, |&(_, last)| last) != offsets {
+                transitions.push((since, offsets));
+            }
+        }
+
+        let final_rule = table
+            .zonesets
+            .get(name)
+            .and_then(|zoneset| zoneset.last())
+            .and_then(|zone| match &zone.saving {
+                Saving::Multiple(rule) => Some((rule, zone.offset)),
+                _ => None,
+            })
+            .and_then(|(rule, standard_offset)| {
+                Some((
+                    rule,
+                    final_rule_raw(table.rulesets.get(rule)?)?,
+                    i32::try_from(standard_offset).ok()?,
+                ))
+            })
+            .and_then(|(rule, raw, standard_offset_seconds)| {
+                let start_year = final_rule_start_year(
+                    first,
+                    &transitions,
+                    &TzRule::from_raw(&raw)?,
+                    standard_offset_seconds,
+                )?;
+                Some(FinalRule {
+                    name: rule.clone(),
+                    raw,
+                    standard_offset_seconds,
+                    start_year,
+                })
+            });
+
+        // Transitions in the years of the final rule are computed from the rule
+        if let Some(rule) = &final_rule {
+            transitions.retain(|&(since, _)| utc_year(since) < rule.start_year);
+        }
+
+        let mut type_offsets = Vec::from([first]);
+        let transitions = transitions
+            .into_iter()
+            .map(|(since, offsets)| {
+                let index = match type_offsets.iter().position(|&o| o == offsets) {
+                    Some(index) => index,
+                    None => {
+                        type_offsets.push(offsets);
+                        type_offsets.len() - 1
+                    }
+                };
+                let index = u8::try_from(index)
+                    .map_err(|_| CompileError::TooManyOffsets(name.to_string()))?;
+                Ok((since, index))
+            })
+            .collect::<Result<Vec<_>, CompileError>>()?;
+
+        Ok(Self {
+            type_offsets,
+            transitions,
+            final_rule,
+        })
+    }
+
+    fn into_resource(self, links: Vec<u32>) -> Resource<'static> {
+        let mut table = BTreeMap::new();
+        let mut insert = |key: &'static str, resource| {
+            table.insert(Key::from(key), resource);
+        };
+
+        insert(
+            "typeOffsets",
+            Resource::IntVector(
+                self.type_offsets
+                    .iter()
+                    .flat_map(|&(standard, dst)| [standard as u32, dst as u32])
+                    .collect(),
+            ),
+        );
+
+        let split = |since: i64| [(since >> 32) as u32, since as u32];
+        let pre32 = self
+            .transitions
+            .iter()
+            .filter(|&&(since, _)| since < i32::MIN.into())
+            .flat_map(|&(since, _)| split(since))
+            .collect::<Vec<_>>();
+        let trans = self
+            .transitions
+            .iter()
+            .filter_map(|&(since, _)| i32::try_from(since).ok())
+            .map(|since| since as u32)
+            .collect::<Vec<_>>();
+        let post32 = self
+            .transitions
+            .iter()
+            .filter(|&&(since, _)| since > i32::MAX.into())
+            .flat_map(|&(since, _)| split(since))
+            .collect::<Vec<_>>();
+        for (key, transitions) in [
+            ("transPre32", pre32),
+            ("trans", trans),
+            ("transPost32", post32),
+        ] {
+            if !transitions.is_empty() {
+                insert(key, Resource::IntVector(transitions));
+            }
+        }
+        if !self.transitions.is_empty() {
+            insert(
+                "typeMap",
+                Resource::Binary(Cow::Owned(
+                    self.transitions.iter().map(|&(_, index)| index).collect(),
+                )),
+            );
+        }
+
+        if let Some(rule) = self.final_rule {
+            insert("finalRule", Resource::String(Cow::Owned(rule.name)));
+            insert(
+                "finalRaw",
+                Resource::Integer(rule.standard_offset_seconds.into()),
+            );
+            insert("finalYear", Resource::Integer(rule.start_year.into()));
+        }
+
+        if !links.is_empty() {
+            insert("links", Resource::IntVector(links));
+        }
+
+        Resource::Table(table)
+    }
+}
+
+/// Encodes the two rules of a tzdb ruleset that apply indefinitely in the layout of
+/// the `Rules` table, if they can be represented.
+fn final_rule_raw(rules: &[RuleInfo]) -> Option<[i32; 11]> {
+    let mut ongoing = rules.iter().filter(|r| r.to_year == Some(Year::Maximum));
+    let (Some(a), Some(b), None) = (ongoing.next(), ongoing.next(), ongoing.next()) else {
+        return None;
+    };
+    let (start, end) = match (a.time_to_add, b.time_to_add) {
+        (save, 0) if save > 0 => (a, b),
+        (0, save) if save > 0 => (b, a),
+        _ => return None,
+    };
+
+    fn date(rule: &RuleInfo) -> Option<[i32; 5]> {
+        let month_length = match rule.month {
+            Month::April | Month::June | Month::September | Month::November => 30,
+            Month::February => 29,
+            _ => 31,
+        };
+        // Weekdays are negated, starting with Sunday = 1, to select the mode that
+        // searches from a day of the month
+        let (day, weekday) = match rule.day {
+            DaySpec::Ordinal(day) => (i32::from(day), 0),
+            // The last weekday of February can't be expressed relative to a fixed day
+            DaySpec::Last(_) if rule.month == Month::February => return None,
+            DaySpec::Last(weekday) => (-month_length, -(weekday as i32 + 1)),
+            DaySpec::LastOnOrBefore(weekday, day) => (-i32::from(day), -(weekday as i32 + 1)),
+            DaySpec::FirstOnOrAfter(weekday, day) => (i32::from(day), -(weekday as i32 + 1)),
+        };
+        let time = i32::try_from(rule.time)
+            .ok()
+            .filter(|&t| (0..=SECONDS_IN_UTC_DAY as i32).contains(&t))?;
+        let time_mode = match rule.time_type {
+            TimeType::Wall => 0,
+            TimeType::Standard => 1,
+            TimeType::UTC => 2,
+        };
+        Some([rule.month as i32 - 1, day, weekday, time, time_mode])
+    }
+
+    let [start_month, start_day, start_weekday, start_time, start_mode] = date(start)?;
+    let [end_month, end_day, end_weekday, end_time, end_mode] = date(end)?;
+    let raw = [
+        start_month,
+        start_day,
+        start_weekday,
+        start_time,
+        start_mode,
+        end_month,
+        end_day,
+        end_weekday,
+        end_time,
+        end_mode,
+        i32::try_from(start.time_to_add).ok()?,
+    ];
+    TzRule::from_raw(&raw).map(|_| raw)
+}
+
+/// Finds the first year from which on `rule` produces the same transitions as the
+/// tzdb, if any.
+///
+/// Before the first transition of that year, zoneinfo64 uses the offset of the last
+/// explicit transition, so that offset needs to agree with the rule as well.
+fn final_rule_start_year(
+    first: (i32, i32),
+    transitions: &[(i64, (i32, i32))],
+    rule: &TzRule,
+    standard_offset_seconds: i32,
+) -> Option<i32> {
+    let rule = Rule {
+        start_year: i32::MIN,
+        standard_offset_seconds,
+        inner: rule,
+    };
+    let to_transition = |&(since, (standard, dst)): &(i64, (i32, i32))| Transition {
+        since,
+        offset: UtcOffset(standard + dst),
+        rule_applies: dst > 0,
+    };
+
+    // Walk back through the years for as long as the explicit transitions are the
+    // ones of the rule
+    let mut start_year = LAST_YEAR + 1;
+    let mut explicit = transitions;
+    loop {
+        let year = start_year - 1;
+        let in_year = explicit
+            .iter()
+            .rev()
+            .take_while(|&&(since, _)| utc_year(since) == year)
+            .count();
+        let (before, in_year) = explicit.split_at(explicit.len() - in_year);
+        let day_before_year = gregorian::day_before_year(year);
+        let expected = [false, true].map(|second| rule.transition(year, day_before_year, second).1);
+        if !in_year.iter().map(to_transition).eq(expected) {
+            break;
+        }
+        start_year = year;
+        explicit = before;
+    }
+    if start_year > LAST_YEAR {
+        return None;
+    }
+
+    let (before_rule, _) =
+        rule.transition(start_year, gregorian::day_before_year(start_year), false);
+    let last_explicit = explicit.last().map_or(
+        Offset {
+            offset: UtcOffset(first.0 + first.1),
+            rule_applies: first.1 > 0,
+        },
+        |t| to_transition(t).into(),
+    );
+    if last_explicit == before_rule {
+        Some(start_year)
+    } else {
+        // The transitions of the start year are explicit, so the last explicit
+        // transition is the second transition of the rule
+        Some(start_year + 1)
+    }
+}
+
+/// Returns the UTC year of a timestamp.
+fn utc_year(seconds_since_epoch: i64) -> i32 {
+    gregorian::year_from_fixed(EPOCH + seconds_since_epoch.div_euclid(SECONDS_IN_UTC_DAY))
+        .unwrap_or(i32::MAX)
+}
+
+/// An error when compiling zoneinfo64 data.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CompileError {
+    /// A zone or link target is not in the tzdb
+    UnknownZone(String),
+    /// A zone has an offset that doesn't fit into 32 bits
+    OffsetOutOfRange(String),
+    /// A zone has more than 256 distinct offsets
+    TooManyOffsets(String),
+    /// A line of a `zone.tab` file is malformed
+    InvalidZoneTab(String),
+    /// The resource bundle could not be serialized
+    Serializer(BinarySerializerError),
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownZone(name) => write!(f, "Unknown zone: {name}"),
+            Self::OffsetOutOfRange(name) => write!(f, "Offset out of range in zone {name}"),
+            Self::TooManyOffsets(name) => write!(f, "Too many distinct offsets in zone {name}"),
+            Self::InvalidZoneTab(line) => write!(f, "Invalid zone.tab line: {line:?}"),
+            Self::Serializer(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl core::error::Error for CompileError {}
+
+/// A difference between zoneinfo64 data and the tzdb, as found by [`check`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Mismatch {
+    /// A zone or link in the tzdb is not in the zoneinfo64 data
+    MissingZone(String),
+    /// A zone has a different offset than in the tzdb
+    Offset {
+        /// The IANA name of the zone
+        name: String,
+        /// The time of the mismatch, in seconds since the Unix epoch
+        seconds_since_epoch: i64,
+        /// The offset according to the tzdb
+        expected: Offset,
+        /// The offset according to [`Zone::for_timestamp`]
+        actual: Offset,
+    },
+}
+
+/// Compares [`Zone::for_timestamp`] against the tzdb for all zones and links in `table`.
+///
+/// Offsets are compared right before and at every transition up to 2100, both the
+/// transitions of the tzdb and the ones of the zoneinfo64 data.
+pub fn check(zoneinfo: &ZoneInfo64, table: &Table) -> Vec<Mismatch> {
+    // 2100-01-01T00:00:00Z
+    const END: i64 = 4102444800;
+
+    let mut names = table
+        .zonesets
+        .keys()
+        .chain(table.links.keys())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+
+    let mut mismatches = Vec::new();
+    for name in names {
+        let Some(zone) = zoneinfo.get(name) else {
+            mismatches.push(Mismatch::MissingZone(name.clone()));
+            continue;
+        };
+        let Some(timespans) = resolve(table, name).and_then(|target| table.timespans(target))
+        else {
+            mismatches.push(Mismatch::MissingZone(name.clone()));
+            continue;
+        };
+        let to_offset = |utc_offset: i64, dst_offset: i64| Offset {
+            offset: UtcOffset((utc_offset + dst_offset) as i32),
+            rule_applies: dst_offset > 0,
+        };
+        let first = to_offset(timespans.first.utc_offset, timespans.first.dst_offset);
+        let transitions = timespans
+            .rest
+            .iter()
+            .map(|(since, timespan)| (*since, to_offset(timespan.utc_offset, timespan.dst_offset)))
+            .collect::<Vec<_>>();
+        let expected_at = |seconds_since_epoch: i64| {
+            let index = transitions.partition_point(|&(since, _)| since <= seconds_since_epoch);
+            index
+                .checked_sub(1)
+                .and_then(|i| transitions.get(i))
+                .map_or(first, |&(_, offset)| offset)
+        };
+
+        let mut check_around = |since: i64| {
+            for seconds_since_epoch in [since - 1, since] {
+                let expected = expected_at(seconds_since_epoch);
+                let actual = zone.for_timestamp(seconds_since_epoch);
+                if expected != actual {
+                    mismatches.push(Mismatch::Offset {
+                        name: name.clone(),
+                        seconds_since_epoch,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        };
+
+        for &(since, _) in transitions.iter().filter(|&&(since, _)| since < END) {
+            check_around(since);
+        }
+        let mut since = i64::MIN;
+        while let Some(transition) = next_transition(&zone, since) {
+            if transition.since >= END {
+                break;
+            }
+            check_around(transition.since);
+            since = transition.since;
+        }
+    }
+    mismatches
+}
+
+fn next_transition(zone: &Zone, seconds_since_epoch: i64) -> Option<Transition> {
+    zone.next_transition(seconds_since_epoch, false)
+        .filter(|t| t.since > seconds_since_epoch)
+}
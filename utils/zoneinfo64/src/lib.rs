@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc)), no_std)]
+#![cfg_attr(not(any(test, doc, feature = "compile")), no_std)]
 #![cfg_attr(
     not(test),
     deny(
@@ -49,6 +49,9 @@
 //! assert!(!after.rule_applies);
 //! assert_eq!(transition, 1762074000);
 //! ```
+//!
+//! With the `compile` Cargo feature, the `compile` module can build zoneinfo64 data
+//! directly from tzdb sources, and check it against them.
 
 extern crate alloc;
 
@@ -66,6 +69,9 @@ use icu_locale_core::subtags::Region;
 #[cfg(feature = "chrono")]
 mod chrono_impls;
 
+#[cfg(feature = "compile")]
+pub mod compile;
+
 mod rule;
 use rule::*;
 mod deserialize;
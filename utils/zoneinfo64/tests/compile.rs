@@ -0,0 +1,83 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compile")]
+
+use parse_zoneinfo::line::Line;
+use parse_zoneinfo::table::{Table, TableBuilder};
+use std::path::Path;
+use zoneinfo64::compile::{check, ZoneInfo64Compiler};
+use zoneinfo64::ZoneInfo64;
+
+fn tzdb(files: &[&str]) -> Table {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../provider/source/tests/data/tzdb");
+    let mut builder = TableBuilder::new();
+    for file in files {
+        let source = std::fs::read_to_string(dir.join(file)).unwrap();
+        for line in source.lines() {
+            match Line::new(line).unwrap() {
+                Line::Zone(zone) => builder.add_zone_line(zone).unwrap(),
+                Line::Continuation(cont) => builder.add_continuation_line(cont).unwrap(),
+                Line::Rule(rule) => builder.add_rule_line(rule).unwrap(),
+                Line::Link(link) => builder.add_link_line(link).unwrap(),
+                Line::Space => {}
+            }
+        }
+    }
+    builder.build()
+}
+
+#[test]
+fn test_rearguard() {
+    let table = tzdb(&["rearguard.zi"]);
+    let words = ZoneInfo64Compiler::new(&table, "test")
+        .compile_to_words()
+        .unwrap();
+    let zoneinfo = ZoneInfo64::try_from_u32s(&words).unwrap();
+
+    assert_eq!(check(&zoneinfo, &table), []);
+
+    // Zones with ongoing daylight saving time use a final rule, which continues
+    // after the last year of the tzdb transitions
+    for name in ["America/Los_Angeles", "Europe/London", "Australia/Sydney"] {
+        let zone = zoneinfo.get(name).unwrap();
+        // 2200-01-01T00:00:00Z
+        assert!(zone.next_transition(7258118400, false).is_some(), "{name}");
+    }
+    for name in ["Asia/Tokyo", "America/Vancouver", "Etc/UTC"] {
+        let zone = zoneinfo.get(name).unwrap();
+        // 2100-01-01T00:00:00Z
+        assert!(zone.next_transition(4102444800, false).is_none(), "{name}");
+    }
+}
+
+#[test]
+fn test_main() {
+    let table = tzdb(&[
+        "africa",
+        "antarctica",
+        "asia",
+        "australasia",
+        "europe",
+        "northamerica",
+        "southamerica",
+        "etcetera",
+        "factory",
+        "backward",
+    ]);
+    let words = ZoneInfo64Compiler::new(&table, "test")
+        .compile_to_words()
+        .unwrap();
+    let zoneinfo = ZoneInfo64::try_from_u32s(&words).unwrap();
+
+    assert_eq!(check(&zoneinfo, &table), []);
+
+    // Negative daylight saving time can't be represented by a final rule, so
+    // Irish time uses explicit transitions until 2100
+    let dublin = zoneinfo.get("Europe/Dublin").unwrap();
+    // 2099-01-01T00:00:00Z
+    assert!(dublin.next_transition(4070908800, false).is_some());
+    // 2100-01-01T00:00:00Z
+    assert!(dublin.next_transition(4102444800, false).is_none());
+}